@@ -15,7 +15,11 @@ use std::{
 use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_bgzf::{self as bgzf, index::optimize_chunks, VirtualPosition};
 use noodles_core::Region;
-use noodles_sam::header::{ReferenceSequence, ReferenceSequences};
+use noodles_sam::{
+    self as sam,
+    header::{ReferenceSequence, ReferenceSequences},
+    AlignmentReader, AlignmentRecord,
+};
 
 use super::{bai, Record, MAGIC_NUMBER};
 
@@ -361,6 +365,39 @@ where
     }
 }
 
+impl<R> AlignmentReader for Reader<R>
+where
+    R: Read,
+{
+    /// Reads and parses the SAM header and binary reference sequences.
+    ///
+    /// If the SAM header has no reference sequences (`@SQ`), the binary reference sequences are
+    /// used instead.
+    fn read_alignment_header(&mut self) -> io::Result<sam::Header> {
+        let mut header: sam::Header = self
+            .read_header()?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let reference_sequences = self.read_reference_sequences()?;
+
+        if header.reference_sequences().is_empty() {
+            *header.reference_sequences_mut() = reference_sequences;
+        }
+
+        Ok(header)
+    }
+
+    fn alignment_records(
+        &mut self,
+    ) -> Box<dyn Iterator<Item = io::Result<Box<dyn AlignmentRecord>>> + '_> {
+        Box::new(
+            self.records()
+                .map(|result| result.map(|record| Box::new(record) as Box<dyn AlignmentRecord>)),
+        )
+    }
+}
+
 fn read_magic<R>(reader: &mut R) -> io::Result<[u8; 4]>
 where
     R: Read,
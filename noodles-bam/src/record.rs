@@ -15,12 +15,16 @@ pub use self::{
 use std::{
     convert::TryFrom,
     ffi::{self, CStr},
-    fmt, mem,
+    fmt, io, mem,
     ops::{Deref, DerefMut},
 };
 
 use byteorder::{ByteOrder, LittleEndian};
-use noodles_sam as sam;
+use noodles_sam::{
+    self as sam,
+    header::{ReferenceSequence, ReferenceSequences},
+    AlignmentRecord,
+};
 
 pub(crate) const UNMAPPED_POSITION: i32 = -1;
 
@@ -345,6 +349,40 @@ impl Default for Record {
     }
 }
 
+impl AlignmentRecord for Record {
+    fn reference_sequence<'rs>(
+        &self,
+        reference_sequences: &'rs ReferenceSequences,
+    ) -> Option<io::Result<&'rs ReferenceSequence>> {
+        self.reference_sequence_id().map(|reference_sequence_id| {
+            let id = i32::from(reference_sequence_id);
+
+            reference_sequences
+                .get_index(id as usize)
+                .map(|(_, rs)| rs)
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+                })
+        })
+    }
+
+    fn flags(&self) -> sam::record::Flags {
+        self.flags()
+    }
+
+    fn alignment_start(&self) -> Option<sam::record::Position> {
+        self.position()
+    }
+
+    fn alignment_span(&self) -> io::Result<u32> {
+        self.cigar().reference_len()
+    }
+
+    fn mapping_quality(&self) -> sam::record::MappingQuality {
+        self.mapping_quality()
+    }
+}
+
 impl Deref for Record {
     type Target = [u8];
 
@@ -578,4 +616,40 @@ mod tests {
         assert_eq!(*record.data(), expected);
         Ok(())
     }
+
+    #[test]
+    fn test_alignment_record() -> io::Result<()> {
+        let record = build_record()?;
+
+        let reference_sequences: ReferenceSequences = (0..=10)
+            .map(|i| {
+                let name = format!("sq{}", i);
+                (name.clone(), ReferenceSequence::new(name, 1 << 20))
+            })
+            .collect();
+
+        let reference_sequence =
+            AlignmentRecord::reference_sequence(&record, &reference_sequences).transpose()?;
+        assert_eq!(reference_sequence.map(|rs| rs.name()), Some("sq10"));
+
+        assert_eq!(
+            AlignmentRecord::alignment_start(&record).map(i32::from),
+            Some(61062)
+        );
+        assert_eq!(AlignmentRecord::alignment_span(&record)?, 4);
+        assert_eq!(
+            AlignmentRecord::alignment_end(&record)
+                .transpose()?
+                .map(i32::from),
+            Some(61065)
+        );
+
+        let reference_sequences = ReferenceSequences::new();
+        assert!(matches!(
+            AlignmentRecord::reference_sequence(&record, &reference_sequences),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -2,15 +2,14 @@
 //!
 //! The result matches the output of `bcftools view --no-header <src> | wc -l`.
 
-use std::{env, fs::File, io};
+use std::{env, io};
 
 use noodles_bcf as bcf;
 
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).expect("missing src");
 
-    let mut reader = File::open(src).map(bcf::Reader::new)?;
-    reader.read_file_format()?;
+    let mut reader = bcf::Reader::from_path(src)?;
     reader.read_header()?;
 
     let mut n = 0;
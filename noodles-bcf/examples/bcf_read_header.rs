@@ -1,16 +1,15 @@
 //! Prints the header of a BCF file.
 
-use std::{env, fs::File, io};
+use std::{env, io};
 
 use noodles_bcf as bcf;
 
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).expect("missing src");
-    let mut reader = File::open(src).map(bcf::Reader::new)?;
-    reader.read_file_format()?;
+    let mut reader = bcf::Reader::from_path(src)?;
 
     let header = reader.read_header()?;
-    print!("{}", header);
+    print!("{}", header.vcf_header());
 
     Ok(())
 }
@@ -0,0 +1,5 @@
+//! Async BCF.
+
+mod reader;
+
+pub use self::reader::Reader;
@@ -0,0 +1,422 @@
+mod query;
+
+use std::{convert::TryFrom, ffi::CStr, io};
+
+use futures::{stream, Stream};
+use noodles_bgzf::{self as bgzf, index::optimize_chunks};
+use noodles_core::Region;
+use noodles_csi as csi;
+use noodles_vcf::header::Contigs;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek};
+
+use crate::{reader::resolve_region, Header, Record, MAGIC_NUMBER};
+
+/// An async BCF reader.
+///
+/// This is the async analog of [`crate::Reader`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// use futures::TryStreamExt;
+/// use noodles_bcf as bcf;
+/// use tokio::fs::File;
+///
+/// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+/// reader.read_header().await?;
+///
+/// let mut records = reader.records();
+///
+/// while let Some(record) = records.try_next().await? {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Reader<R> {
+    inner: bgzf::AsyncReader<R>,
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates an async BCF reader.
+    ///
+    /// The given stream is wrapped in an async BGZF decoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let data = [];
+    /// let reader = bcf::AsyncReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner: bgzf::AsyncReader::new(inner),
+        }
+    }
+
+    /// Reads the BCF header.
+    ///
+    /// This checks the BCF magic number, reads the file format version, and reads and parses the
+    /// VCF header, from which the string map is built.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+    /// let header = reader.read_header().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_header(&mut self) -> io::Result<Header> {
+        let file_format = self.read_file_format().await?;
+        let raw_header = self.read_raw_header().await?;
+        Header::parse(file_format, &raw_header)
+    }
+
+    async fn read_file_format(&mut self) -> io::Result<(u8, u8)> {
+        let mut buf = [0; 5];
+
+        self.inner.read_exact(&mut buf).await?;
+
+        let magic = &buf[..3];
+
+        if magic != MAGIC_NUMBER {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid file format",
+            ));
+        }
+
+        let major = buf[3];
+        let minor = buf[4];
+
+        Ok((major, minor))
+    }
+
+    async fn read_raw_header(&mut self) -> io::Result<String> {
+        let l_text = self.inner.read_u32_le().await.and_then(|n| {
+            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
+
+        let mut buf = vec![0; l_text];
+        self.inner.read_exact(&mut buf).await?;
+
+        CStr::from_bytes_with_nul(&buf)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            .and_then(|c_header| {
+                c_header
+                    .to_str()
+                    .map(|s| s.into())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+    }
+
+    /// Reads a single record.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// It is more ergnomic to read records using a stream (see [`Self::records`]), but using this
+    /// method directly allows the reuse of a single [`Record`] buffer.
+    ///
+    /// If successful, the record size is returned. If a record size of 0 is returned, the stream
+    /// reached EOF.
+    pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        let l_shared = match self.inner.read_u32_le().await {
+            Ok(len) => len,
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let l_indiv = self.inner.read_u32_le().await?;
+
+        let record_len = l_shared
+            .checked_add(l_indiv)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "invalid record length: l_shared = {}, l_indiv = {}",
+                        l_shared, l_indiv
+                    ),
+                )
+            })
+            .and_then(|len| {
+                usize::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })?;
+
+        let l_shared =
+            usize::try_from(l_shared).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let l_indiv =
+            usize::try_from(l_indiv).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        record.resize(l_shared, l_indiv);
+
+        let (shared, indiv) = record.buffers_mut();
+        self.inner.read_exact(shared).await?;
+        self.inner.read_exact(indiv).await?;
+
+        Ok(record_len)
+    }
+
+    /// Returns a stream over records starting from the current stream position.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bcf as bcf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+    /// reader.read_header().await?;
+    ///
+    /// let mut records = reader.records();
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     println!("{:?}", record.position());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + Unpin + '_ {
+        Box::pin(stream::try_unfold(
+            (self, Record::default()),
+            |(reader, mut record)| async {
+                match reader.read_record(&mut record).await? {
+                    0 => Ok(None),
+                    _ => Ok(Some((record.clone(), (reader, record)))),
+                }
+            },
+        ))
+    }
+
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    ///
+    /// let data = [];
+    /// let reader = bcf::AsyncReader::new(&data[..]);
+    /// let virtual_position = reader.virtual_position();
+    ///
+    /// assert_eq!(virtual_position.compressed(), 0);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    /// Seeks the underlying BGZF reader to the given virtual position.
+    ///
+    /// Virtual positions typically come from an associated BCF index file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// use noodles_bgzf as bgzf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::from(102334155);
+    /// reader.seek(virtual_position).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek(pos).await
+    }
+
+    /// Returns a stream over records that intersect the given region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bcf as bcf;
+    /// use noodles_core::Region;
+    /// use noodles_csi as csi;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::AsyncReader::new)?;
+    /// let header = reader.read_header().await?;
+    ///
+    /// let index = csi::read("sample.bcf.csi")?;
+    /// let region = Region::mapped("sq0", 8, 13);
+    /// let mut query = reader.query(header.vcf_header().contigs(), &index, &region)?;
+    ///
+    /// while let Some(record) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query(
+        &mut self,
+        contigs: &Contigs,
+        index: &csi::Index,
+        region: &Region,
+    ) -> io::Result<impl Stream<Item = io::Result<Record>> + Unpin + '_> {
+        let (i, start, end) = resolve_region(contigs, region)?;
+
+        let index_reference_sequence = index.reference_sequences().get(i).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid index reference sequence index: {}", i),
+            )
+        })?;
+
+        let query_bins = index_reference_sequence.query(
+            index.min_shift(),
+            index.depth(),
+            i64::from(start),
+            i64::from(end),
+        );
+
+        let chunks: Vec<_> = query_bins
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .cloned()
+            .collect();
+
+        let chunks = optimize_chunks(&chunks, bgzf::VirtualPosition::default());
+
+        Ok(query::query(self, chunks, i, start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use futures::TryStreamExt;
+    use noodles_bgzf::index::Chunk;
+    use noodles_csi::index::{reference_sequence::Bin, ReferenceSequence};
+    use noodles_vcf as vcf;
+
+    use crate::{header::StringMap, Writer};
+
+    use super::*;
+
+    static RAW_HEADER: &str = concat!(
+        "##fileformat=VCFv4.3\n",
+        "##contig=<ID=sq0>\n",
+        "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    );
+
+    fn build_data() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let header: vcf::Header = RAW_HEADER.parse()?;
+        let string_map: StringMap = RAW_HEADER.parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for line in &[
+            "sq0\t1\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t13\t.\tA\t.\t.\tPASS\t.",
+        ] {
+            let record = vcf::Record::try_from_str(line, &header)?;
+            writer.write_vcf_record(&header, &string_map, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    #[tokio::test]
+    async fn test_records() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header().await?;
+        assert_eq!(header.file_format(), (2, 2));
+        assert_eq!(header.vcf_header(), &RAW_HEADER.parse::<vcf::Header>()?);
+
+        let positions: Vec<_> = reader
+            .records()
+            .and_then(|record| async move { record.position().map(i32::from) })
+            .try_collect()
+            .await?;
+
+        assert_eq!(positions, [1, 13]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data()?;
+        let header: vcf::Header = RAW_HEADER.parse()?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_header().await?;
+
+        let start = reader.virtual_position();
+        while reader.read_record(&mut Record::default()).await? != 0 {}
+        let end = reader.virtual_position();
+
+        let index = csi::Index::builder()
+            .set_min_shift(14)
+            .set_depth(5)
+            .set_reference_sequences(vec![ReferenceSequence::new(
+                vec![Bin::new(0, start, vec![Chunk::new(start, end)])],
+                None,
+            )])
+            .build();
+
+        let region = Region::mapped("sq0", 8, 21);
+        let positions: Vec<_> = reader
+            .query(header.contigs(), &index, &region)?
+            .and_then(|record| async move { record.position().map(i32::from) })
+            .try_collect()
+            .await?;
+
+        assert_eq!(positions, [13]);
+
+        let region = Region::mapped("sq1", 8, 21);
+        assert!(reader.query(header.contigs(), &index, &region).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use std::{convert::TryFrom, io, mem, vec};
+
+use futures::{stream, Stream};
+use noodles_bgzf::{self as bgzf, index::Chunk};
+use tokio::io::{AsyncRead, AsyncSeek};
+
+use crate::Record;
+
+use super::Reader;
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+struct Context<'a, R> {
+    reader: &'a mut Reader<R>,
+    chunks: vec::IntoIter<Chunk>,
+    chromosome_id: usize,
+    start: i32,
+    end: i32,
+    state: State,
+    record: Record,
+}
+
+pub fn query<R>(
+    reader: &mut Reader<R>,
+    chunks: Vec<Chunk>,
+    chromosome_id: usize,
+    start: i32,
+    end: i32,
+) -> impl Stream<Item = io::Result<Record>> + Unpin + '_
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let ctx = Context {
+        reader,
+        chunks: chunks.into_iter(),
+        chromosome_id,
+        start,
+        end,
+        state: State::Seek,
+        record: Record::default(),
+    };
+
+    Box::pin(stream::try_unfold(ctx, |mut ctx| async {
+        loop {
+            match ctx.state {
+                State::Seek => {
+                    ctx.state = match ctx.chunks.next() {
+                        Some(chunk) => {
+                            ctx.reader.seek(chunk.start()).await?;
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    };
+                }
+                State::Read(chunk_end) => {
+                    if ctx.reader.read_record(&mut ctx.record).await? == 0 {
+                        ctx.state = State::Seek;
+                        continue;
+                    }
+
+                    if ctx.reader.virtual_position() >= chunk_end {
+                        ctx.state = State::Seek;
+                    }
+
+                    if intersects(&ctx.record, ctx.chromosome_id, ctx.start, ctx.end)? {
+                        // The buffer is only reused for records that are filtered out.
+                        let record = mem::take(&mut ctx.record);
+                        return Ok(Some((record, ctx)));
+                    }
+                }
+                State::Done => return Ok(None),
+            }
+        }
+    }))
+}
+
+fn intersects(record: &Record, chromosome_id: usize, start: i32, end: i32) -> io::Result<bool> {
+    let id = record.chromosome_id().and_then(|id| {
+        usize::try_from(id).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+    let record_start = record.position().map(i32::from)?;
+    let record_end = record.end().map(i32::from)?;
+
+    Ok(id == chromosome_id && record_start <= end && start <= record_end)
+}
@@ -0,0 +1,110 @@
+use std::io::{self, BufRead, Write};
+
+use noodles_vcf as vcf;
+
+use super::{header::StringMap, Writer};
+
+/// Converts a VCF stream to BCF.
+///
+/// This reads the VCF header and records from `reader` and writes them to `writer`, which includes
+/// the BCF file format, the header, and each encoded record. The string map is built from the VCF
+/// header. The writer is finished after the last record is written.
+///
+/// This returns the number of records converted.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf as bcf;
+///
+/// let data = concat!(
+///     "##fileformat=VCFv4.3\n",
+///     "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+///     "##contig=<ID=sq0>\n",
+///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+///     "sq0\t1\t.\tA\t.\t.\tPASS\t.\n",
+///     "sq0\t8\t.\tA\t.\t.\tPASS\t.\n",
+/// );
+///
+/// let mut writer = bcf::Writer::new(Vec::new());
+/// let n = bcf::convert_from_vcf(data.as_bytes(), &mut writer)?;
+///
+/// assert_eq!(n, 2);
+/// assert!(!writer.get_ref().is_empty());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn convert_from_vcf<R, W>(reader: R, writer: &mut Writer<W>) -> io::Result<u64>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut reader = vcf::Reader::new(reader);
+
+    let raw_header = reader.read_header()?;
+    let header: vcf::Header = raw_header
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let string_map: StringMap = raw_header
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    writer.write_file_format()?;
+    writer.write_header(&header)?;
+
+    let mut buf = String::new();
+    let mut n = 0;
+
+    loop {
+        buf.clear();
+
+        if reader.read_record(&mut buf)? == 0 {
+            break;
+        }
+
+        let record = vcf::Record::try_from_str(&buf, &header)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        writer.write_vcf_record(&header, &string_map, &record)?;
+        n += 1;
+    }
+
+    writer.try_finish()?;
+
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Reader;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_from_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        let data = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+            "sq0\t1\t.\tA\tG\t.\tPASS\tNS=1\n",
+            "sq0\t8\t.\tC\tT\t.\tPASS\t.\n",
+        );
+
+        let mut writer = Writer::new(Vec::new());
+        assert_eq!(convert_from_vcf(data.as_bytes(), &mut writer)?, 2);
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        let header = reader.read_header()?;
+
+        let records = reader
+            .vcf_records(header.vcf_header(), header.string_map())
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tG\t.\tPASS\tNS=1");
+        assert_eq!(records[1].to_string(), "sq0\t8\t.\tC\tT\t.\tPASS\t.");
+
+        Ok(())
+    }
+}
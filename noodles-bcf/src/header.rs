@@ -1,5 +1,114 @@
 //! BCF header.
 
-mod string_map;
+pub mod string_map;
 
 pub use self::string_map::StringMap;
+
+use std::io;
+
+use noodles_vcf as vcf;
+
+/// A BCF header.
+///
+/// This is the file format version, the VCF header, and the string map read from the start of a
+/// BCF file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    file_format: (u8, u8),
+    vcf_header: vcf::Header,
+    string_map: StringMap,
+}
+
+impl Header {
+    // Parses a raw VCF header and builds its string map.
+    pub(crate) fn parse(file_format: (u8, u8), raw_header: &str) -> io::Result<Self> {
+        let vcf_header: vcf::Header = raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let string_map: StringMap = raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(Self {
+            file_format,
+            vcf_header,
+            string_map,
+        })
+    }
+
+    /// Returns the BCF file format version.
+    ///
+    /// This is the major and minor format versions as a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
+    /// let header = reader.read_header()?;
+    /// let (major, minor) = header.file_format();
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn file_format(&self) -> (u8, u8) {
+        self.file_format
+    }
+
+    /// Returns the VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
+    /// let header = reader.read_header()?;
+    /// println!("{}", header.vcf_header());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn vcf_header(&self) -> &vcf::Header {
+        &self.vcf_header
+    }
+
+    /// Returns the string map built from the VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
+    /// let header = reader.read_header()?;
+    /// let string_map = header.string_map();
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn string_map(&self) -> &StringMap {
+        &self.string_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> io::Result<()> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header = Header::parse((2, 2), raw_header)?;
+
+        assert_eq!(header.file_format(), (2, 2));
+        assert_eq!(header.vcf_header().contigs().len(), 1);
+        assert_eq!(header.string_map().get_index_of("PASS"), Some(0));
+
+        assert!(Header::parse((2, 2), "").is_err());
+
+        Ok(())
+    }
+}
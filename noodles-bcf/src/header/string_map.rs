@@ -1,21 +1,270 @@
-use std::{convert::TryFrom, ops::Deref, str::FromStr};
+//! BCF header string map.
+
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    error, fmt,
+    str::FromStr,
+};
 
 use indexmap::IndexSet;
 use noodles_vcf::{
     self as vcf,
-    header::{Filter, ParseError, Record},
+    header::{filter, format, info, record, Filter, Format, Info, Record},
 };
-use vcf::header::{Format, Info};
 
 /// An indexed map of VCF strings.
 ///
 /// This is also called a dictionary of strings.
+///
+/// Strings are positioned by the index (`IDX`) field of their header record, when present.
+/// Strings without an explicit index are appended in the order they appear in the header, so
+/// there may be gaps in the map.
+///
+/// Use [`Self::get_index_of`] and [`Self::get_index`] to look up strings by index or
+/// [`Self::iter`] to visit the strings with their indices.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct StringMap(IndexSet<String>);
+pub struct StringMap {
+    strings: IndexSet<String>,
+    // The index of each string in `strings`, in ascending order.
+    indices: Vec<usize>,
+}
 
 impl StringMap {
-    fn insert(&mut self, value: String) {
-        self.0.insert(value);
+    /// Returns the index of the given string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMap;
+    /// let string_map = StringMap::default();
+    /// assert_eq!(string_map.get_index_of("PASS"), Some(0));
+    /// assert!(string_map.get_index_of("DP").is_none());
+    /// ```
+    pub fn get_index_of(&self, value: &str) -> Option<usize> {
+        self.strings.get_index_of(value).map(|i| self.indices[i])
+    }
+
+    /// Returns the string at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMap;
+    /// let string_map = StringMap::default();
+    /// assert_eq!(string_map.get_index(0), Some("PASS"));
+    /// assert!(string_map.get_index(1).is_none());
+    /// ```
+    pub fn get_index(&self, i: usize) -> Option<&str> {
+        self.indices
+            .binary_search(&i)
+            .ok()
+            .and_then(|j| self.strings.get_index(j))
+            .map(|value| value.as_str())
+    }
+
+    /// Returns an iterator over the indices and strings in the map, in index order.
+    ///
+    /// Gaps in the map are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMap;
+    /// let string_map = StringMap::default();
+    /// assert!(string_map.iter().eq([(0, "PASS")].iter().copied()));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.indices
+            .iter()
+            .copied()
+            .zip(self.strings.iter().map(|value| value.as_str()))
+    }
+
+    /// Returns the number of positions in the map, including gaps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMap;
+    /// let string_map = StringMap::default();
+    /// assert_eq!(string_map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.indices.last().map(|i| i + 1).unwrap_or(0)
+    }
+
+    /// Returns whether the map has any positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMap;
+    /// let string_map = StringMap::default();
+    /// assert!(!string_map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Validates the string map against a VCF header.
+    ///
+    /// This checks that
+    ///
+    ///   * `PASS` is the first entry,
+    ///   * there are no gaps in the map,
+    ///   * every filter (`FILTER`), information (`INFO`), and genotype format (`FORMAT`) record in
+    ///     the header has an entry, at its index (`IDX`), if set, and
+    ///   * every entry is defined by a header record.
+    ///
+    /// A string map that does not match the header written to a BCF file is silently
+    /// misinterpreted by readers that build the map from the header, e.g., htslib. Strings are
+    /// unique by construction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_bcf::header::{string_map::ValidationError, StringMap};
+    /// use noodles_vcf::{self as vcf, header::Info, record::info::field::Key};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::from(Key::SamplesWithDataCount))
+    ///     .build();
+    ///
+    /// let string_map = StringMap::try_from(&header)?;
+    /// assert!(string_map.validate(&header).is_ok());
+    ///
+    /// let string_map = StringMap::default();
+    /// assert_eq!(
+    ///     string_map.validate(&header),
+    ///     Err(ValidationError::MissingEntry(String::from("NS")))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate(&self, header: &vcf::Header) -> Result<(), ValidationError> {
+        let pass = Filter::pass();
+
+        if self.get_index_of(pass.id()) != Some(0) {
+            return Err(ValidationError::MissingPass);
+        }
+
+        let mut ids = HashSet::new();
+
+        for (id, idx) in header_entries(header) {
+            let i = self
+                .get_index_of(id)
+                .ok_or_else(|| ValidationError::MissingEntry(id.into()))?;
+
+            if let Some(j) = idx {
+                if i != j {
+                    return Err(ValidationError::PositionMismatch(id.into(), j, i));
+                }
+            }
+
+            ids.insert(id);
+        }
+
+        // Indices are ascending, so the first index that is not its position is after a gap.
+        for (j, (&i, value)) in self.indices.iter().zip(self.strings.iter()).enumerate() {
+            if i != j {
+                return Err(ValidationError::Gap(j));
+            } else if value != pass.id() && !ids.contains(value.as_str()) {
+                return Err(ValidationError::UnusedEntry(i, value.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes entries that are not defined by a VCF header and closes gaps.
+    ///
+    /// `PASS` is always kept. The remaining strings keep their relative order but may change
+    /// positions. Because of this, compacting is refused if any filter (`FILTER`), information
+    /// (`INFO`), or genotype format (`FORMAT`) record in the header has an explicit index (`IDX`),
+    /// and the string map is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::header::StringMap;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut string_map: StringMap = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##FILTER=<ID=q10,Description=\"Quality below 10\">\n",
+    ///     "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    /// )
+    /// .parse()?;
+    ///
+    /// let header: vcf::Header = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    /// )
+    /// .parse()?;
+    ///
+    /// string_map.compact(&header)?;
+    ///
+    /// assert_eq!(string_map.len(), 2);
+    /// assert_eq!(string_map.get_index_of("NS"), Some(1));
+    /// assert!(string_map.get_index_of("q10").is_none());
+    /// assert!(string_map.validate(&header).is_ok());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn compact(&mut self, header: &vcf::Header) -> Result<(), CompactError> {
+        if let Some((id, _)) = header_entries(header).find(|(_, idx)| idx.is_some()) {
+            return Err(CompactError::ExplicitIndex(id.into()));
+        }
+
+        let pass = Filter::pass();
+        let ids: HashSet<_> = header_entries(header).map(|(id, _)| id).collect();
+
+        self.strings
+            .retain(|value| value == pass.id() || ids.contains(value.as_str()));
+        self.indices = (0..self.strings.len()).collect();
+
+        Ok(())
+    }
+
+    // Builds a string map from strings at their positions.
+    fn from_entries(entries: Vec<Option<String>>) -> Self {
+        let mut strings = IndexSet::with_capacity(entries.len());
+        let mut indices = Vec::with_capacity(entries.len());
+
+        for (i, entry) in entries.into_iter().enumerate() {
+            if let Some(value) = entry {
+                strings.insert(value);
+                indices.push(i);
+            }
+        }
+
+        Self { strings, indices }
+    }
+
+    // Explicitly indexed strings are placed first so that strings without an index never take
+    // a position reserved by a later record.
+    fn try_from_entries<I, S>(entries: I) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = (S, Option<usize>)>,
+        S: Into<String>,
+    {
+        let mut builder = Builder::default();
+        let mut unindexed_entries = Vec::new();
+
+        for (value, idx) in entries {
+            match idx {
+                Some(i) => builder.insert_at(i, value.into())?,
+                None => unindexed_entries.push(value),
+            }
+        }
+
+        for value in unindexed_entries {
+            builder.insert(value.into());
+        }
+
+        Ok(Self::from_entries(builder.entries))
     }
 }
 
@@ -23,15 +272,155 @@ impl Default for StringMap {
     fn default() -> Self {
         // § 6.2.1 Dictionary of strings (2021-01-13): "Note that 'PASS' is always implicitly
         // encoded as the first entry in the header dictionary."
-        Self(vec![Filter::pass().id().into()].into_iter().collect())
+        Self::from_entries(vec![Some(Filter::pass().id().into())])
     }
 }
 
-impl Deref for StringMap {
-    type Target = IndexSet<String>;
+// A string map under construction, where strings may be placed at any position.
+struct Builder {
+    indices: HashMap<String, usize>,
+    entries: Vec<Option<String>>,
+}
+
+impl Builder {
+    fn insert(&mut self, value: String) {
+        if self.indices.contains_key(&value) {
+            return;
+        }
+
+        let i = self.entries.len();
+        self.indices.insert(value.clone(), i);
+        self.entries.push(Some(value));
+    }
+
+    fn insert_at(&mut self, i: usize, value: String) -> Result<(), ParseError> {
+        if let Some(&j) = self.indices.get(&value) {
+            if i == j {
+                return Ok(());
+            }
+
+            return Err(ParseError::StringMapPositionMismatch(
+                (i, value.clone()),
+                (j, value),
+            ));
+        }
+
+        if i >= self.entries.len() {
+            self.entries.resize(i + 1, None);
+        }
+
+        if let Some(other) = &self.entries[i] {
+            return Err(ParseError::StringMapPositionMismatch(
+                (i, value),
+                (i, other.clone()),
+            ));
+        }
+
+        self.indices.insert(value.clone(), i);
+        self.entries[i] = Some(value);
+
+        Ok(())
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        let pass = String::from(Filter::pass().id());
+
+        Self {
+            indices: vec![(pass.clone(), 0)].into_iter().collect(),
+            entries: vec![Some(pass)],
+        }
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+/// An error returned when a VCF header fails to parse as a string map.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A record is invalid.
+    InvalidRecord(record::ParseError),
+    /// An information record (`INFO`) is invalid.
+    InvalidInfo(info::TryFromRecordError),
+    /// A filter record (`FILTER`) is invalid.
+    InvalidFilter(filter::TryFromRecordError),
+    /// A genotype format record (`FORMAT`) is invalid.
+    InvalidFormat(format::TryFromRecordError),
+    /// A string is at a position (`IDX`) that is already taken, or a string is given different
+    /// positions.
+    ///
+    /// This includes the conflicting (position, string) pair and the existing pair.
+    StringMapPositionMismatch((usize, String), (usize, String)),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecord(e) => write!(f, "invalid record: {}", e),
+            Self::InvalidInfo(e) => write!(f, "invalid info: {}", e),
+            Self::InvalidFilter(e) => write!(f, "invalid filter: {}", e),
+            Self::InvalidFormat(e) => write!(f, "invalid format: {}", e),
+            Self::StringMapPositionMismatch(actual, expected) => write!(
+                f,
+                "string map position mismatch: expected {} at {}, got {} at {}",
+                expected.1, expected.0, actual.1, actual.0
+            ),
+        }
+    }
+}
+
+/// An error returned when a string map is not valid for a VCF header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// `PASS` is not the first entry.
+    MissingPass,
+    /// A position has no entry.
+    Gap(usize),
+    /// A string defined by a header record is missing.
+    MissingEntry(String),
+    /// A string is not at the index (`IDX`) of its header record.
+    ///
+    /// This includes the string, the expected position, and the actual position.
+    PositionMismatch(String, usize, usize),
+    /// An entry is not defined by a header record.
+    UnusedEntry(usize, String),
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPass => f.write_str("missing PASS at 0"),
+            Self::Gap(i) => write!(f, "missing entry at {}", i),
+            Self::MissingEntry(id) => write!(f, "missing entry: {}", id),
+            Self::PositionMismatch(id, expected, actual) => write!(
+                f,
+                "position mismatch for {}: expected {}, got {}",
+                id, expected, actual
+            ),
+            Self::UnusedEntry(i, id) => write!(f, "unused entry at {}: {}", i, id),
+        }
+    }
+}
+
+/// An error returned when a string map fails to compact.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CompactError {
+    /// A header record has an explicit index (`IDX`).
+    ///
+    /// Compacting may move strings, which would no longer match their explicit indices.
+    ExplicitIndex(String),
+}
+
+impl error::Error for CompactError {}
+
+impl fmt::Display for CompactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExplicitIndex(id) => write!(f, "explicit index: {}", id),
+        }
     }
 }
 
@@ -41,8 +430,7 @@ impl FromStr for StringMap {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use vcf::header::record::Key;
 
-        let pass_filter = Filter::pass();
-        let mut string_map = StringMap::default();
+        let mut entries: Vec<(String, _)> = Vec::new();
 
         for line in s.lines() {
             if line.starts_with("#CHROM") {
@@ -54,37 +442,91 @@ impl FromStr for StringMap {
             match record.key() {
                 Key::Filter => {
                     let filter = Filter::try_from(record).map_err(ParseError::InvalidFilter)?;
-
-                    if filter.id() != pass_filter.id() {
-                        string_map.insert(filter.id().into());
-                    }
+                    entries.push((filter.id().into(), filter.idx()));
                 }
                 Key::Format => {
                     let format = Format::try_from(record).map_err(ParseError::InvalidFormat)?;
-                    string_map.insert(format.id().as_ref().into());
+                    entries.push((format.id().as_ref().into(), format.idx()));
                 }
                 Key::Info => {
                     let info = Info::try_from(record).map_err(ParseError::InvalidInfo)?;
-                    string_map.insert(info.id().as_ref().into());
+                    entries.push((info.id().as_ref().into(), info.idx()));
                 }
                 _ => {}
             }
         }
 
-        Ok(string_map)
+        Self::try_from_entries(entries)
     }
 }
 
+impl TryFrom<&vcf::Header> for StringMap {
+    type Error = ParseError;
+
+    /// Builds a string map from a VCF header.
+    ///
+    /// Existing indices (`IDX`) are kept. Strings without an index are assigned positions in the
+    /// order of the header records, i.e., the order a reader of the written header (e.g.,
+    /// htslib) uses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_bcf::header::StringMap;
+    /// use noodles_vcf::{self as vcf, header::Info, record::info::field::Key};
+    ///
+    /// let mut ns = Info::from(Key::SamplesWithDataCount);
+    /// *ns.idx_mut() = Some(2);
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(ns)
+    ///     .add_info(Info::from(Key::TotalDepth))
+    ///     .build();
+    ///
+    /// let string_map = StringMap::try_from(&header)?;
+    /// assert_eq!(string_map.get_index(0), Some("PASS"));
+    /// assert!(string_map.get_index(1).is_none());
+    /// assert_eq!(string_map.get_index(2), Some("NS"));
+    /// assert_eq!(string_map.get_index(3), Some("DP"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn try_from(header: &vcf::Header) -> Result<Self, Self::Error> {
+        Self::try_from_entries(header.string_ids())
+    }
+}
+
+// Returns the IDs and indices (`IDX`) of the filter, information, and genotype format records.
+fn header_entries(header: &vcf::Header) -> impl Iterator<Item = (&str, Option<usize>)> {
+    let filters = header
+        .filters()
+        .values()
+        .map(|filter| (filter.id(), filter.idx()));
+
+    let infos = header
+        .infos()
+        .values()
+        .map(|info| (info.id().as_ref(), info.idx()));
+
+    let formats = header
+        .formats()
+        .values()
+        .map(|format| (format.id().as_ref(), format.idx()));
+
+    filters.chain(infos).chain(formats)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn build_string_map(entries: &[Option<&str>]) -> StringMap {
+        StringMap::from_entries(entries.iter().map(|e| e.map(String::from)).collect())
+    }
+
     #[test]
     fn test_default() {
-        assert_eq!(
-            StringMap::default(),
-            StringMap(vec![String::from("PASS")].into_iter().collect())
-        );
+        assert_eq!(StringMap::default(), build_string_map(&[Some("PASS")]));
     }
 
     #[test]
@@ -106,17 +548,226 @@ mod tests {
 
         assert_eq!(
             s.parse(),
-            Ok(StringMap(
-                vec![
-                    String::from("PASS"),
-                    String::from("DP"),
-                    String::from("NS"),
-                    String::from("q10"),
-                    String::from("GT"),
-                ]
-                .into_iter()
-                .collect()
+            Ok(build_string_map(&[
+                Some("PASS"),
+                Some("NS"),
+                Some("DP"),
+                Some("q10"),
+                Some("GT"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_missing_indices() {
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=3>
+##FILTER=<ID=q10,Description="Quality below 10">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        assert_eq!(
+            s.parse(),
+            Ok(build_string_map(&[
+                Some("PASS"),
+                None,
+                None,
+                Some("DP"),
+                Some("NS"),
+                Some("q10"),
+                Some("GT"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let string_map = build_string_map(&[Some("PASS"), None, Some("DP"), Some("NS")]);
+
+        assert_eq!(string_map.len(), 4);
+        assert_eq!(string_map.get_index(2), Some("DP"));
+        assert_eq!(string_map.get_index_of("NS"), Some(3));
+
+        assert!(string_map
+            .iter()
+            .eq([(0, "PASS"), (2, "DP"), (3, "NS")].iter().copied()));
+    }
+
+    #[test]
+    fn test_from_str_with_position_mismatch() {
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data",IDX=1>
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(
+            s.parse::<StringMap>(),
+            Err(ParseError::StringMapPositionMismatch(
+                (1, String::from("DP")),
+                (1, String::from("NS"))
+            ))
+        );
+
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=1>
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth",IDX=2>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(
+            s.parse::<StringMap>(),
+            Err(ParseError::StringMapPositionMismatch(
+                (2, String::from("DP")),
+                (1, String::from("DP"))
+            ))
+        );
+
+        let s = r#"##fileformat=VCFv4.3
+##FILTER=<ID=PASS,Description="All filters passed",IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(
+            s.parse::<StringMap>(),
+            Err(ParseError::StringMapPositionMismatch(
+                (1, String::from("PASS")),
+                (0, String::from("PASS"))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_from_vcf_header_for_string_map() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::record::{genotype, info::field::Key};
+
+        let header = vcf::Header::builder()
+            .add_info(Info::from(Key::SamplesWithDataCount))
+            .add_filter(Filter::new(
+                String::from("q10"),
+                String::from("Quality below 10"),
             ))
+            .add_format(Format::from(genotype::field::Key::Genotype))
+            .add_info(Info::from(Key::TotalDepth))
+            .build();
+
+        assert_eq!(
+            StringMap::try_from(&header)?,
+            build_string_map(&[
+                Some("PASS"),
+                Some("NS"),
+                Some("q10"),
+                Some("GT"),
+                Some("DP")
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=q10,Description=\"Quality below 10\">\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\",IDX=1>\n",
+            "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+
+        let string_map: StringMap = raw_header.parse()?;
+        assert_eq!(string_map.validate(&header), Ok(()));
+
+        let string_map = build_string_map(&[Some("NS"), Some("PASS"), Some("q10"), Some("GT")]);
+        assert_eq!(
+            string_map.validate(&header),
+            Err(ValidationError::MissingPass)
+        );
+
+        let string_map = build_string_map(&[Some("PASS"), Some("NS"), Some("q10")]);
+        assert_eq!(
+            string_map.validate(&header),
+            Err(ValidationError::MissingEntry(String::from("GT")))
+        );
+
+        let string_map = build_string_map(&[Some("PASS"), Some("q10"), Some("NS"), Some("GT")]);
+        assert_eq!(
+            string_map.validate(&header),
+            Err(ValidationError::PositionMismatch(String::from("NS"), 1, 2))
+        );
+
+        let string_map =
+            build_string_map(&[Some("PASS"), Some("NS"), Some("q10"), None, Some("GT")]);
+        assert_eq!(string_map.validate(&header), Err(ValidationError::Gap(3)));
+
+        let string_map = build_string_map(&[
+            Some("PASS"),
+            Some("NS"),
+            Some("q10"),
+            Some("GT"),
+            Some("DP"),
+        ]);
+        assert_eq!(
+            string_map.validate(&header),
+            Err(ValidationError::UnusedEntry(4, String::from("DP")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact() -> Result<(), Box<dyn std::error::Error>> {
+        let header: vcf::Header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+            "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        )
+        .parse()?;
+
+        let mut string_map = build_string_map(&[
+            Some("PASS"),
+            None,
+            Some("q10"),
+            Some("NS"),
+            None,
+            Some("GT"),
+        ]);
+        string_map.compact(&header)?;
+
+        assert_eq!(
+            string_map,
+            build_string_map(&[Some("PASS"), Some("NS"), Some("GT")])
+        );
+        assert_eq!(string_map.validate(&header), Ok(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_with_explicit_indices() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=q10,Description=\"Quality below 10\",IDX=1>\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\",IDX=3>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let mut string_map: StringMap = raw_header.parse()?;
+        let expected = string_map.clone();
+
+        assert_eq!(
+            string_map.compact(&header),
+            Err(CompactError::ExplicitIndex(String::from("q10")))
         );
+        assert_eq!(string_map, expected);
+
+        Ok(())
     }
 }
@@ -0,0 +1,214 @@
+//! Indexed BCF reader.
+
+use std::{
+    fs::File,
+    io::{self, Read, Seek},
+    path::Path,
+};
+
+use noodles_core::{path::push_extension, Region};
+use noodles_csi as csi;
+
+use super::{
+    reader::{Query, Records, VcfRecords},
+    Header, Reader,
+};
+
+const CSI_EXTENSION: &str = "csi";
+
+/// A BCF reader with an associated coordinate-sorted index (CSI).
+///
+/// This wraps a BCF reader, its parsed header and string map, and its index.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bcf as bcf;
+/// use noodles_core::Region;
+///
+/// let mut reader = bcf::IndexedReader::from_path("sample.bcf")?;
+/// let header = reader.header().clone();
+///
+/// let region = Region::mapped("sq0", 8, 13);
+///
+/// for result in reader.query(&region)? {
+///     let record = result?;
+///     let vcf_record = record.try_into_vcf_record(header.vcf_header(), header.string_map())?;
+///     println!("{}", vcf_record);
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    header: Header,
+    index: csi::Index,
+}
+
+impl IndexedReader<File> {
+    /// Opens a BCF file and its index.
+    ///
+    /// The index is discovered by appending `.csi` to the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let reader = bcf::IndexedReader::from_path("sample.bcf")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_path<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index = csi::read(push_extension(src, CSI_EXTENSION))?;
+        let file = File::open(src)?;
+
+        Self::new(file, index)
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read,
+{
+    /// Creates an indexed BCF reader.
+    ///
+    /// This immediately reads and parses the BCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    ///
+    /// let file = File::open("sample.bcf")?;
+    /// let index = csi::read("sample.bcf.csi")?;
+    ///
+    /// let reader = bcf::IndexedReader::new(file, index)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn new(inner: R, index: csi::Index) -> io::Result<Self> {
+        let mut inner = Reader::new(inner);
+        let header = inner.read_header()?;
+
+        Ok(Self {
+            inner,
+            header,
+            index,
+        })
+    }
+
+    /// Returns the BCF header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the associated index.
+    pub fn index(&self) -> &csi::Index {
+        &self.index
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// Directly after creating the reader, this is the first record after the header.
+    pub fn records(&mut self) -> Records<'_, R> {
+        self.inner.records()
+    }
+
+    /// Returns an iterator over records converted to VCF records starting from the current stream
+    /// position.
+    ///
+    /// Directly after creating the reader, this is the first record after the header.
+    pub fn vcf_records(&mut self) -> VcfRecords<'_, '_, R> {
+        self.inner
+            .vcf_records(self.header.vcf_header(), self.header.string_map())
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    pub fn query(&mut self, region: &Region) -> io::Result<Query<'_, R>> {
+        self.inner
+            .query(self.header.vcf_header().contigs(), &self.index, region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_vcf as vcf;
+
+    use crate::Writer;
+
+    use super::*;
+
+    fn build_data() -> Result<(Vec<u8>, csi::Index), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##contig=<ID=sq0>\n",
+            "##contig=<ID=sq1>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map = raw_header.parse()?;
+
+        let mut writer = Writer::with_indexer(Vec::new(), csi::Index::indexer());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for line in &[
+            "sq0\t8\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t13\t.\tA\t.\t.\tPASS\t.",
+            "sq1\t5\t.\tA\t.\t.\tPASS\t.",
+        ] {
+            let record = vcf::Record::try_from_str(line, &header)?;
+            writer.write_vcf_record(&header, &string_map, &record)?;
+        }
+
+        let (data, index) = writer.finish()?;
+        let index = index.expect("missing index");
+
+        Ok((data, index))
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, index) = build_data()?;
+        let mut reader = IndexedReader::new(Cursor::new(data), index)?;
+        assert_eq!(reader.header().vcf_header().contigs().len(), 2);
+
+        let region = Region::mapped("sq0", 10, 21);
+        let positions = reader
+            .query(&region)?
+            .map(|result| result.and_then(|record| record.position().map(i32::from)))
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(positions, [13]);
+
+        let region = Region::mapped("sq2", 1, 21);
+        assert!(reader.query(&region).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vcf_records() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, index) = build_data()?;
+        let mut reader = IndexedReader::new(Cursor::new(data), index)?;
+
+        let records = reader.vcf_records().collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2].to_string(), "sq1\t5\t.\tA\t.\t.\tPASS\t.");
+
+        Ok(())
+    }
+}
@@ -2,11 +2,22 @@
 
 //! **noodles-bcf** handles the reading and writing of the BCF format.
 
+#[cfg(feature = "async")]
+mod r#async;
+
+mod convert;
 pub mod header;
-mod reader;
-mod record;
+mod indexed_reader;
+pub mod reader;
+pub mod record;
 mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    convert::convert_from_vcf, header::Header, indexed_reader::IndexedReader, reader::Reader,
+    record::Record, writer::Writer,
+};
+
+#[cfg(feature = "async")]
+pub use self::r#async::Reader as AsyncReader;
 
 static MAGIC_NUMBER: &[u8] = b"BCF";
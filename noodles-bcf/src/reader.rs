@@ -1,30 +1,77 @@
+//! BCF reader.
+
+mod builder;
 mod query;
-pub mod record;
+pub(crate) mod record;
 mod records;
-pub mod string_map;
-pub mod value;
+pub(crate) mod string_map;
+pub(crate) mod value;
+mod vcf_records;
 
-pub use self::{query::Query, records::Records};
+pub use self::{builder::Builder, query::Query, records::Records, vcf_records::VcfRecords};
 
 use std::{
     convert::TryFrom,
     ffi::CStr,
-    io::{self, Read, Seek},
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek},
+    path::Path,
 };
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use noodles_bgzf as bgzf;
+use noodles_bgzf::{self as bgzf, index::optimize_chunks};
 use noodles_core::Region;
 use noodles_csi as csi;
-use noodles_vcf::header::Contigs;
+use noodles_vcf::{self as vcf, header::Contigs};
 
-use super::{Record, MAGIC_NUMBER};
+use super::{header::StringMap, Header, Record, MAGIC_NUMBER};
 
 /// A BCF reader.
 ///
 /// The BCF format is comprised of two parts: 1) a VCF header and 2) a list of records.
 pub struct Reader<R> {
-    inner: bgzf::Reader<R>,
+    inner: Inner<R>,
+}
+
+enum Inner<R> {
+    Bgzf(bgzf::Reader<R>),
+    MultithreadedBgzf(bgzf::MultithreadedReader<R>),
+    Raw(BufReader<R>),
+}
+
+impl<R> Read for Inner<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Bgzf(reader) => reader.read(buf),
+            Self::MultithreadedBgzf(reader) => reader.read(buf),
+            Self::Raw(reader) => reader.read(buf),
+        }
+    }
+}
+
+impl Reader<File> {
+    /// Opens a BCF file.
+    ///
+    /// The compression of the file is detected from its first bytes, i.e., the file can either
+    /// be BGZF-compressed or uncompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let reader = bcf::Reader::from_path("sample.bcf")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_path<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Builder::default().build_from_path(src)
+    }
 }
 
 impl<R> Reader<R>
@@ -43,80 +90,49 @@ where
     /// ```
     pub fn new(reader: R) -> Self {
         Self {
-            inner: bgzf::Reader::new(reader),
+            inner: Inner::Bgzf(bgzf::Reader::new(reader)),
         }
     }
 
-    /// Reads the BCF file format.
-    ///
-    /// The BCF magic number is also checked.
+    /// Creates a BCF reader for an uncompressed stream.
     ///
-    /// The position of the stream is expected to be at the start.
-    ///
-    /// This returns the major and minor format versions as a tuple.
+    /// This reads raw BCF data that is not wrapped in a BGZF container, e.g., the output of
+    /// `bcftools view --output-type u`.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use std::{fs::File, io};
+    /// # use std::io;
     /// use noodles_bcf as bcf;
-    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
-    /// let (major, minor) = reader.read_file_format()?;
+    /// let mut reader = bcf::Reader::new_uncompressed(io::stdin());
     /// # Ok::<(), io::Error>(())
     /// ```
-    pub fn read_file_format(&mut self) -> io::Result<(u8, u8)> {
-        let mut buf = [0; 5];
-
-        self.inner.read_exact(&mut buf)?;
-
-        let magic = &buf[..3];
-
-        if magic != MAGIC_NUMBER {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "invalid file format",
-            ));
+    pub fn new_uncompressed(reader: R) -> Self {
+        Self {
+            inner: Inner::Raw(BufReader::new(reader)),
         }
-
-        let major = buf[3];
-        let minor = buf[4];
-
-        Ok((major, minor))
     }
 
-    /// Reads the raw VCF header.
+    /// Reads the BCF header.
     ///
-    /// The position of the stream is expected to be directly after the file format.
+    /// This checks the BCF magic number, reads the file format version, and reads and parses the
+    /// VCF header, from which the string map is built.
     ///
-    /// This returns the raw VCF header as a [`std::string::String`]. It can subsequently be parsed
-    /// as a [`noodles_vcf::Header`].
+    /// The position of the stream is expected to be at the start.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use std::{fs::File, io};
+    /// # use std::io;
     /// use noodles_bcf as bcf;
-    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
-    /// reader.read_file_format()?;
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
     /// let header = reader.read_header()?;
     /// # Ok::<(), io::Error>(())
     /// ```
-    pub fn read_header(&mut self) -> io::Result<String> {
-        let l_text = self.inner.read_u32::<LittleEndian>().and_then(|n| {
-            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-        })?;
-
-        let mut buf = vec![0; l_text];
-        self.inner.read_exact(&mut buf)?;
-
-        CStr::from_bytes_with_nul(&buf)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            .and_then(|c_header| {
-                c_header
-                    .to_str()
-                    .map(|s| s.into())
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            })
+    pub fn read_header(&mut self) -> io::Result<Header> {
+        let file_format = read_file_format(&mut self.inner)?;
+        let raw_header = read_raw_header(&mut self.inner)?;
+        Header::parse(file_format, &raw_header)
     }
 
     /// Reads a single record.
@@ -124,10 +140,29 @@ where
     /// The stream is expected to be directly after the header or at the start of another record.
     ///
     /// It is more ergnomic to read records using an iterator (see [`Self::records`]), but using
-    /// this method directly allows the reuse of a single [`Record`] buffer.
+    /// this method directly allows the reuse of a single [`Record`] buffer. The shared and
+    /// per-sample buffers of the given record are refilled in place, so they are only reallocated
+    /// when a record is larger than any previously read.
     ///
     /// If successful, the record size is returned. If a record size of 0 is returned, the stream
     /// reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
+    /// reader.read_header()?;
+    ///
+    /// let mut record = bcf::Record::default();
+    ///
+    /// while reader.read_record(&mut record)? != 0 {
+    ///     println!("{:?}", record.position());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
         let l_shared = match self.inner.read_u32::<LittleEndian>() {
             Ok(len) => len,
@@ -152,8 +187,16 @@ where
                 usize::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
             })?;
 
-        record.resize(record_len);
-        self.inner.read_exact(record)?;
+        let l_shared =
+            usize::try_from(l_shared).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let l_indiv =
+            usize::try_from(l_indiv).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        record.resize(l_shared, l_indiv);
+
+        let (shared, indiv) = record.buffers_mut();
+        self.inner.read_exact(shared)?;
+        self.inner.read_exact(indiv)?;
 
         Ok(record_len)
     }
@@ -165,16 +208,15 @@ where
     /// # Examples
     ///
     /// ```no_run
-    /// # use std::{fs::File, io};
+    /// # use std::io;
     /// use noodles_bcf as bcf;
     ///
-    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
-    /// reader.read_file_format()?;
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
     /// reader.read_header()?;
     ///
     /// for result in reader.records() {
     ///     let record = result?;
-    ///     println!("{:?}", &record[..]);
+    ///     println!("{:?}", record.position());
     /// }
     /// # Ok::<(), io::Error>(())
     /// ```
@@ -182,8 +224,41 @@ where
         Records::new(self)
     }
 
+    /// Returns an iterator over records converted to VCF records starting from the current stream
+    /// position.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// This reuses a single [`Record`] buffer and converts each record using the given VCF header
+    /// and string map.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.vcf_records(header.vcf_header(), header.string_map()) {
+    ///     let record = result?;
+    ///     println!("{}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn vcf_records<'r, 'h>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        string_map: &'h StringMap,
+    ) -> VcfRecords<'r, 'h, R> {
+        VcfRecords::new(self, header, string_map)
+    }
+
     /// Returns the current virtual position of the underlying BGZF reader.
     ///
+    /// An uncompressed stream has no virtual positions, in which case, this is always 0.
+    ///
     /// # Examples
     ///
     /// ```
@@ -199,7 +274,11 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn virtual_position(&self) -> bgzf::VirtualPosition {
-        self.inner.virtual_position()
+        match &self.inner {
+            Inner::Bgzf(reader) => reader.virtual_position(),
+            Inner::MultithreadedBgzf(reader) => reader.virtual_position(),
+            Inner::Raw(_) => bgzf::VirtualPosition::default(),
+        }
     }
 }
 
@@ -209,7 +288,8 @@ where
 {
     /// Seeks the underlying BGZF reader to the given virtual position.
     ///
-    /// Virtual positions typically come from an associated BCF index file.
+    /// Virtual positions typically come from an associated BCF index file. Seeking an uncompressed
+    /// stream is not supported.
     ///
     /// # Examples
     ///
@@ -225,7 +305,14 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
-        self.inner.seek(pos)
+        match &mut self.inner {
+            Inner::Bgzf(reader) => reader.seek(pos),
+            Inner::MultithreadedBgzf(reader) => reader.seek(pos),
+            Inner::Raw(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot seek an uncompressed stream to a virtual position",
+            )),
+        }
     }
 
     /// Returns an iterator over records that intersects the given region.
@@ -257,11 +344,96 @@ where
             .cloned()
             .collect();
 
+        let chunks = optimize_chunks(&chunks, bgzf::VirtualPosition::default());
+
         Ok(Query::new(self, chunks, i, start, end))
     }
 }
 
-fn resolve_region(contigs: &Contigs, region: &Region) -> io::Result<(usize, i32, i32)> {
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    /// Creates a BCF reader, detecting whether the stream is BGZF-compressed or uncompressed.
+    ///
+    /// This peeks at the start of the stream without consuming it, which is useful when reading
+    /// from a pipe, e.g., standard input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let data = b"BCF\x02\x02";
+    /// let mut reader = bcf::Reader::detect(&data[..])?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn detect(mut reader: R) -> io::Result<Self> {
+        let buf = reader.fill_buf()?;
+
+        if is_uncompressed(buf) {
+            Ok(Self::new_uncompressed(reader))
+        } else {
+            Ok(Self::new(reader))
+        }
+    }
+}
+
+// An uncompressed stream starts directly with the BCF magic number rather than a gzip header.
+//
+// The buffer may be shorter than the magic number, and more data cannot be peeked without
+// consuming it. The first byte is enough to distinguish the two, as a gzip header always starts
+// with 0x1f.
+fn is_uncompressed(buf: &[u8]) -> bool {
+    buf.first() == MAGIC_NUMBER.first()
+}
+
+fn read_file_format<R>(reader: &mut R) -> io::Result<(u8, u8)>
+where
+    R: Read,
+{
+    let mut buf = [0; 5];
+
+    reader.read_exact(&mut buf)?;
+
+    let magic = &buf[..3];
+
+    if magic != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid file format",
+        ));
+    }
+
+    let major = buf[3];
+    let minor = buf[4];
+
+    Ok((major, minor))
+}
+
+fn read_raw_header<R>(reader: &mut R) -> io::Result<String>
+where
+    R: Read,
+{
+    let l_text = reader.read_u32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    let mut buf = vec![0; l_text];
+    reader.read_exact(&mut buf)?;
+
+    CStr::from_bytes_with_nul(&buf)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .and_then(|c_header| {
+            c_header
+                .to_str()
+                .map(|s| s.into())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+}
+
+pub(crate) fn resolve_region(contigs: &Contigs, region: &Region) -> io::Result<(usize, i32, i32)> {
     match region {
         Region::Mapped { name, start, end } => {
             let i = contigs.get_index_of(name).ok_or_else(|| {
@@ -295,9 +467,9 @@ mod tests {
     #[test]
     fn test_read_file_format() -> io::Result<()> {
         let data = compress(b"BCF\x02\x01")?;
-        let mut reader = Reader::new(&data[..]);
+        let mut reader = bgzf::Reader::new(&data[..]);
 
-        let (major, minor) = reader.read_file_format()?;
+        let (major, minor) = read_file_format(&mut reader)?;
 
         assert_eq!(major, 2);
         assert_eq!(minor, 1);
@@ -305,11 +477,138 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_detect() -> io::Result<()> {
+        let data = b"BCF\x02\x01";
+        let mut reader = Reader::detect(&data[..])?;
+        assert!(matches!(reader.inner, Inner::Raw(_)));
+        assert_eq!(
+            reader.read_header().err().map(|e| e.kind()),
+            Some(io::ErrorKind::UnexpectedEof)
+        );
+
+        let data = compress(b"BCF\x02\x01")?;
+        let reader = Reader::detect(&data[..])?;
+        assert!(matches!(reader.inner, Inner::Bgzf(_)));
+
+        // A short read does not fill the buffer with the whole magic number.
+        let data = b"BCF\x02\x01";
+        let reader = Reader::detect(io::BufReader::with_capacity(1, &data[..]))?;
+        assert!(matches!(reader.inner, Inner::Raw(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_a_reused_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{header::StringMap, Writer};
+
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let vcf_records = [
+            vcf::Record::try_from_str("sq0\t8\tr0\tACGT\tA,C\t.\tPASS\t.\tGT\t0|1\t1/2", &header)?,
+            vcf::Record::try_from_str("sq0\t13\t.\tA\tC\t.\tPASS\t.\tGT\t0\t1", &header)?,
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for vcf_record in &vcf_records {
+            writer.write_vcf_record(&header, &string_map, vcf_record)?;
+        }
+
+        let data = writer.finish().map(|(data, _)| data)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let mut record = Record::default();
+
+        for vcf_record in &vcf_records {
+            reader.read_record(&mut record)?;
+            let expected = Record::try_from_vcf_record(&header, &string_map, vcf_record)?;
+            assert_eq!(record, expected);
+        }
+
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_vcf::record::Filters;
+
+        use crate::{header::StringMap, Writer};
+
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+            "##FILTER=<ID=q10,Description=\"Quality below 10\">\n",
+            "##INFO=<ID=END,Number=1,Type=Integer,Description=\"End position\">\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let mut writer = Writer::with_indexer(Vec::new(), csi::Index::indexer());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for line in [
+            "sq0\t1\t.\tN\t<DEL>\t.\tPASS\tEND=20000",
+            "sq0\t20\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t16380\t.\tACGTA\tA\t.\tPASS\t.",
+            "sq0\t16388\t.\tA\t.\t.\tq10\t.",
+        ]
+        .iter()
+        {
+            let record = vcf::Record::try_from_str(line, &header)?;
+            writer.write_vcf_record(&header, &string_map, &record)?;
+        }
+
+        let (data, index) = writer.finish()?;
+        let index = index.expect("missing index");
+
+        let mut reader = Reader::new(io::Cursor::new(data));
+        reader.read_header()?;
+
+        let region = Region::mapped("sq0", 16384, 16390);
+
+        let positions = reader
+            .query(header.contigs(), &index, &region)?
+            .map(|result| result.and_then(|record| record.position().map(i32::from)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(positions, [1, 16380, 16388]);
+
+        let positions = reader
+            .query(header.contigs(), &index, &region)?
+            .set_filter(|record| record.filters(&string_map).map(|f| f == Filters::Pass))
+            .map(|result| result.and_then(|record| record.position().map(i32::from)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(positions, [1, 16380]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_file_format_with_an_invalid_magic_number() -> io::Result<()> {
         let data = compress(b"BAM\x02\x01")?;
-        let mut reader = Reader::new(&data[..]);
-        assert!(reader.read_file_format().is_err());
+        let mut reader = bgzf::Reader::new(&data[..]);
+        assert!(read_file_format(&mut reader).is_err());
         Ok(())
     }
 }
@@ -0,0 +1,154 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+
+use super::{is_uncompressed, Inner, Reader};
+
+/// A BCF reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    worker_count: Option<NonZeroUsize>,
+}
+
+impl Builder {
+    /// Sets the number of workers used to decompress BGZF blocks.
+    ///
+    /// By default, blocks are decompressed on the calling thread. When set, blocks are inflated in
+    /// parallel by the given number of worker threads. This has no effect on uncompressed streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::reader::Builder::default().set_worker_count(NonZeroUsize::new(4).unwrap());
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a BCF reader from a path.
+    ///
+    /// The compression of the file is detected from its first bytes, i.e., the file can either
+    /// be BGZF-compressed or uncompressed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{io, num::NonZeroUsize};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = bcf::reader::Builder::default()
+    ///     .set_worker_count(NonZeroUsize::new(4).unwrap())
+    ///     .build_from_path("sample.bcf")?;
+    ///
+    /// let header = reader.read_header()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(src)?;
+
+        let mut buf = [0; 3];
+        let is_uncompressed = match file.read_exact(&mut buf) {
+            Ok(()) => is_uncompressed(&buf),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => false,
+            Err(e) => return Err(e),
+        };
+
+        file.seek(SeekFrom::Start(0))?;
+
+        if is_uncompressed {
+            Ok(Reader {
+                inner: Inner::Raw(BufReader::new(file)),
+            })
+        } else {
+            Ok(self.build_from_reader(file))
+        }
+    }
+
+    /// Builds a BCF reader from a BGZF-compressed stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let data = [];
+    /// let reader = bcf::reader::Builder::default()
+    ///     .set_worker_count(NonZeroUsize::new(2).unwrap())
+    ///     .build_from_reader(&data[..]);
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: Read,
+    {
+        let inner = match self.worker_count {
+            Some(worker_count) => Inner::MultithreadedBgzf(
+                bgzf::MultithreadedReader::with_worker_count(worker_count, reader),
+            ),
+            None => Inner::Bgzf(bgzf::Reader::new(reader)),
+        };
+
+        Reader { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf as vcf;
+
+    use crate::{header::StringMap, Writer};
+
+    use super::*;
+
+    #[test]
+    fn test_build_from_reader_with_worker_count() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        let mut expected = Vec::new();
+
+        for position in 1..=8192 {
+            let line = format!("sq0\t{}\t.\tA\t.\t.\tPASS\t.", position);
+            let record = vcf::Record::try_from_str(&line, &header)?;
+            writer.write_vcf_record(&header, &string_map, &record)?;
+            expected.push(record);
+        }
+
+        let (data, _) = writer.finish()?;
+
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::new(3).unwrap())
+            .build_from_reader(&data[..]);
+
+        reader.read_header()?;
+
+        let actual = reader
+            .vcf_records(&header, &string_map)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use std::io::{self, Read, Seek};
+use std::{
+    convert::TryFrom,
+    io::{self, Read, Seek},
+};
 
 use noodles_bgzf::{self as bgzf, index::Chunk};
 
@@ -12,8 +15,14 @@ enum State {
     End,
 }
 
+type Filter<'a> = Box<dyn FnMut(&Record) -> io::Result<bool> + 'a>;
+
 /// An iterator over records of a BCF reader that intersects a given region.
 ///
+/// A record intersects the region if its span, i.e., from its position to its end position
+/// (`POS + rlen - 1`), overlaps the region. This includes deletions and symbolic structural
+/// variants that start before the region.
+///
 /// This is created by calling [`Reader::query`].
 pub struct Query<'a, R>
 where
@@ -27,6 +36,7 @@ where
     i: usize,
     state: State,
     record: Record,
+    filter: Option<Filter<'a>>,
 }
 
 impl<'a, R> Query<'a, R>
@@ -49,9 +59,49 @@ where
             i: 0,
             state: State::Seek,
             record: Record::default(),
+            filter: None,
         }
     }
 
+    /// Sets a record filter.
+    ///
+    /// Only intersecting records for which the filter returns `true` are yielded, e.g., records
+    /// that pass all filters (`FILTER`) or have a given flag set (`INFO`). The filter is applied
+    /// before a record is copied out of the reader's buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bcf as bcf;
+    /// use noodles_core::Region;
+    /// use noodles_csi as csi;
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// let mut reader = bcf::Reader::from_path("sample.bcf")?;
+    /// let header = reader.read_header()?;
+    /// let string_map = header.string_map();
+    ///
+    /// let index = csi::read("sample.bcf.csi")?;
+    /// let region = Region::mapped("sq0", 8, 13);
+    ///
+    /// let query = reader
+    ///     .query(header.vcf_header().contigs(), &index, &region)?
+    ///     .set_filter(|record| record.filters(string_map).map(|f| f == Filters::Pass));
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record.position());
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_filter<F>(mut self, filter: F) -> Self
+    where
+        F: FnMut(&Record) -> io::Result<bool> + 'a,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
     fn next_chunk(&mut self) -> io::Result<Option<bgzf::VirtualPosition>> {
         if self.i >= self.chunks.len() {
             return Ok(None);
@@ -65,11 +115,18 @@ where
         Ok(Some(chunk.end()))
     }
 
-    fn read_record(&mut self) -> Option<io::Result<Record>> {
-        match self.reader.read_record(&mut self.record) {
-            Ok(0) => None,
-            Ok(_) => Some(Ok(self.record.clone())),
-            Err(e) => Some(Err(e)),
+    fn read_record(&mut self) -> io::Result<usize> {
+        self.reader.read_record(&mut self.record)
+    }
+
+    fn is_match(&mut self) -> io::Result<bool> {
+        if !intersects(&self.record, self.chromosome_id, self.start, self.end)? {
+            return Ok(false);
+        }
+
+        match self.filter.as_mut() {
+            Some(filter) => filter(&self.record),
+            None => Ok(true),
         }
     }
 }
@@ -91,40 +148,19 @@ where
                     }
                 }
                 State::Read(chunk_end) => match self.read_record() {
-                    Some(result) => {
+                    Ok(0) => self.state = State::Seek,
+                    Ok(_) => {
                         if self.reader.virtual_position() >= chunk_end {
                             self.state = State::Seek;
                         }
 
-                        match result {
-                            Ok(record) => {
-                                let chromosome_id = match record.chromosome_id() {
-                                    Ok(id) => id as usize,
-                                    Err(e) => return Some(Err(e)),
-                                };
-
-                                let start = match record.position() {
-                                    Ok(pos) => i32::from(pos),
-                                    Err(e) => return Some(Err(e)),
-                                };
-
-                                let end = match record.end() {
-                                    Ok(pos) => i32::from(pos),
-                                    Err(e) => return Some(Err(e)),
-                                };
-
-                                if chromosome_id == self.chromosome_id
-                                    && in_interval(start, end, self.start, self.end)
-                                {
-                                    return Some(Ok(record));
-                                }
-                            }
+                        match self.is_match() {
+                            Ok(true) => return Some(Ok(self.record.clone())),
+                            Ok(false) => {}
                             Err(e) => return Some(Err(e)),
                         }
                     }
-                    None => {
-                        self.state = State::Seek;
-                    }
+                    Err(e) => return Some(Err(e)),
                 },
                 State::End => return None,
             }
@@ -132,6 +168,16 @@ where
     }
 }
 
+fn intersects(record: &Record, chromosome_id: usize, start: i32, end: i32) -> io::Result<bool> {
+    let id = record.chromosome_id().and_then(|id| {
+        usize::try_from(id).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+    let record_start = record.position().map(i32::from)?;
+    let record_end = record.end().map(i32::from)?;
+
+    Ok(id == chromosome_id && in_interval(record_start, record_end, start, end))
+}
+
 fn in_interval(a_start: i32, a_end: i32, b_start: i32, b_end: i32) -> bool {
     a_start <= b_end && b_start <= a_end
 }
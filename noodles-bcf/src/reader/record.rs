@@ -3,7 +3,7 @@ mod site;
 
 pub use self::{
     genotypes::read_genotypes,
-    site::{read_site, Site},
+    site::{read_filter, read_id, read_info, read_ref_alt, read_site, Site},
 };
 
 use std::io::{self, Read};
@@ -138,8 +138,13 @@ where
 
         match value {
             Int8::Value(n) => values.push(Some(Value::Integer(i32::from(n)))),
-            Int8::Missing => values.push(None),
-            _ => todo!("unhandled i8 value: {:?}", value),
+            Int8::Missing | Int8::EndOfVector => values.push(None),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid i8 value: {:?}", value),
+                ))
+            }
         }
     }
 
@@ -164,12 +169,15 @@ where
             .into_iter()
             .map(Int8::from)
             .filter_map(|value| match value {
-                Int8::Value(n) => Some(Some(i32::from(n))),
-                Int8::Missing => Some(None),
+                Int8::Value(n) => Some(Ok(Some(i32::from(n)))),
+                Int8::Missing => Some(Ok(None)),
                 Int8::EndOfVector => None,
-                _ => todo!("unhandled i8 array value: {:?}", value),
+                _ => Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid i8 array value: {:?}", value),
+                ))),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
         if vs.len() == 1 && vs[0].is_none() {
             values.push(None);
@@ -195,8 +203,13 @@ where
 
         match value {
             Int16::Value(n) => values.push(Some(Value::Integer(i32::from(n)))),
-            Int16::Missing => values.push(None),
-            _ => todo!("unhandled i16 value: {:?}", value),
+            Int16::Missing | Int16::EndOfVector => values.push(None),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid i16 value: {:?}", value),
+                ))
+            }
         }
     }
 
@@ -221,12 +234,15 @@ where
             .into_iter()
             .map(Int16::from)
             .filter_map(|value| match value {
-                Int16::Value(n) => Some(Some(i32::from(n))),
-                Int16::Missing => Some(None),
+                Int16::Value(n) => Some(Ok(Some(i32::from(n)))),
+                Int16::Missing => Some(Ok(None)),
                 Int16::EndOfVector => None,
-                _ => todo!("unhandled i16 array value: {:?}", value),
+                _ => Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid i16 array value: {:?}", value),
+                ))),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
         if vs.len() == 1 && vs[0].is_none() {
             values.push(None);
@@ -252,8 +268,13 @@ where
 
         match value {
             Int32::Value(n) => values.push(Some(Value::Integer(n))),
-            Int32::Missing => values.push(None),
-            _ => todo!("unhandled i32 value: {:?}", value),
+            Int32::Missing | Int32::EndOfVector => values.push(None),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid i32 value: {:?}", value),
+                ))
+            }
         }
     }
 
@@ -278,12 +299,15 @@ where
             .into_iter()
             .map(Int32::from)
             .filter_map(|value| match value {
-                Int32::Value(n) => Some(Some(n)),
-                Int32::Missing => Some(None),
+                Int32::Value(n) => Some(Ok(Some(n))),
+                Int32::Missing => Some(Ok(None)),
                 Int32::EndOfVector => None,
-                _ => todo!("unhandled i32 array value: {:?}", value),
+                _ => Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid i32 array value: {:?}", value),
+                ))),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
         if vs.len() == 1 && vs[0].is_none() {
             values.push(None);
@@ -309,8 +333,13 @@ where
 
         match value {
             Float::Value(n) => values.push(Some(Value::Float(n))),
-            Float::Missing => values.push(None),
-            _ => todo!("unhandled f32 value: {:?}", value),
+            Float::Missing | Float::EndOfVector => values.push(None),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid f32 value: {:?}", value),
+                ))
+            }
         }
     }
 
@@ -335,12 +364,15 @@ where
             .into_iter()
             .map(Float::from)
             .filter_map(|value| match value {
-                Float::Value(n) => Some(Some(n)),
-                Float::Missing => Some(None),
+                Float::Value(n) => Some(Ok(Some(n))),
+                Float::Missing => Some(Ok(None)),
                 Float::EndOfVector => None,
-                _ => todo!("unhandled f32 array value: {:?}", value),
+                _ => Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid f32 array value: {:?}", value),
+                ))),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
         if vs.len() == 1 && vs[0].is_none() {
             values.push(None);
@@ -390,41 +422,96 @@ where
     let mut values = Vec::with_capacity(sample_count);
 
     match read_type(reader)? {
-        Some(Type::Int8(len)) => match len {
-            0 => values.push(None),
-            1 => {
-                for _ in 0..sample_count {
-                    let value = reader
-                        .read_i8()
-                        .map(|v| parse_genotype_genotype_field_values(&[v]))
-                        .map(Value::String)?;
-
-                    values.push(Some(value));
-                }
+        Some(Type::Int8(len)) => {
+            let mut buf = vec![0; len];
+
+            for _ in 0..sample_count {
+                reader.read_i8_into(&mut buf)?;
+
+                let vs = buf
+                    .iter()
+                    .map(|&n| match Int8::from(n) {
+                        Int8::Value(n) => Ok(Some(i32::from(n))),
+                        Int8::Missing => Ok(Some(0)),
+                        Int8::EndOfVector => Ok(None),
+                        value => Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid genotype value: {:?}", value),
+                        )),
+                    })
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                values.push(parse_genotype_genotype_field_values(&vs));
             }
-            _ => {
-                for _ in 0..sample_count {
-                    let mut buf = vec![0; len];
-                    reader.read_i8_into(&mut buf)?;
-                    let value = Value::String(parse_genotype_genotype_field_values(&buf));
-                    values.push(Some(value));
-                }
+        }
+        Some(Type::Int16(len)) => {
+            let mut buf = vec![0; len];
+
+            for _ in 0..sample_count {
+                reader.read_i16_into::<LittleEndian>(&mut buf)?;
+
+                let vs = buf
+                    .iter()
+                    .map(|&n| match Int16::from(n) {
+                        Int16::Value(n) => Ok(Some(i32::from(n))),
+                        Int16::Missing => Ok(Some(0)),
+                        Int16::EndOfVector => Ok(None),
+                        value => Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid genotype value: {:?}", value),
+                        )),
+                    })
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                values.push(parse_genotype_genotype_field_values(&vs));
             }
-        },
-        ty => todo!("unhandled type: {:?}", ty),
+        }
+        Some(Type::Int32(len)) => {
+            let mut buf = vec![0; len];
+
+            for _ in 0..sample_count {
+                reader.read_i32_into::<LittleEndian>(&mut buf)?;
+
+                let vs = buf
+                    .iter()
+                    .map(|&n| match Int32::from(n) {
+                        Int32::Value(n) => Ok(Some(n)),
+                        Int32::Missing => Ok(Some(0)),
+                        Int32::EndOfVector => Ok(None),
+                        value => Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid genotype value: {:?}", value),
+                        )),
+                    })
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                values.push(parse_genotype_genotype_field_values(&vs));
+            }
+        }
+        None => values.resize(sample_count, None),
+        ty => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid genotype type: {:?}", ty),
+            ))
+        }
     }
 
     Ok(values)
 }
 
-fn parse_genotype_genotype_field_values(values: &[i8]) -> String {
+// Each value is an allele index packed with its phasing, i.e., `(i + 1) << 1 | phased`. A packed
+// value of 0 is a missing allele, and `None` is the end of the vector. A genotype with no alleles
+// is missing.
+fn parse_genotype_genotype_field_values(values: &[Option<i32>]) -> Option<Value> {
     let mut genotype = String::new();
 
-    for (i, &value) in values.iter().enumerate() {
-        if let Int8::EndOfVector = Int8::from(value) {
-            break;
-        }
-
+    for (i, value) in values
+        .iter()
+        .take_while(|value| value.is_some())
+        .flatten()
+        .enumerate()
+    {
         let j = (value >> 1) - 1;
         let is_phased = value & 0x01 == 1;
 
@@ -443,7 +530,11 @@ fn parse_genotype_genotype_field_values(values: &[i8]) -> String {
         }
     }
 
-    genotype
+    if genotype.is_empty() {
+        None
+    } else {
+        Some(Value::String(genotype))
+    }
 }
 
 #[cfg(test)]
@@ -465,6 +556,13 @@ mod tests {
 
         assert_eq!(actual, expected);
 
+        let data = [
+            0x11, // Some(Type::Int8(1))
+            0x82, // Reserved(-126)
+        ];
+        let mut reader = &data[..];
+        assert!(read_genotype_field_values(&mut reader, 1).is_err());
+
         Ok(())
     }
 
@@ -641,27 +739,87 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_genotype_genotype_field_values() {
-        // Examples from § 6.3.3 Type encoding (2021-05-13)
+    fn test_read_genotype_genotype_field_values() -> io::Result<()> {
+        let data = [
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x05, // 0|1
+            0x04, 0x81, // 1
+            0x00, 0x00, // ./.
+        ];
+        let mut reader = &data[..];
 
-        assert_eq!(parse_genotype_genotype_field_values(&[0x02, 0x02]), "0/0");
-        assert_eq!(parse_genotype_genotype_field_values(&[0x02, 0x04]), "0/1");
-        assert_eq!(parse_genotype_genotype_field_values(&[0x04, 0x04]), "1/1");
-        assert_eq!(parse_genotype_genotype_field_values(&[0x02, 0x05]), "0|1");
-        assert_eq!(parse_genotype_genotype_field_values(&[0x00, 0x00]), "./.");
-        assert_eq!(parse_genotype_genotype_field_values(&[0x02]), "0");
-        assert_eq!(parse_genotype_genotype_field_values(&[0x04]), "1");
-        assert_eq!(
-            parse_genotype_genotype_field_values(&[0x02, 0x04, 0x06]),
-            "0/1/2"
-        );
+        let actual = read_genotype_genotype_field_values(&mut reader, 3)?;
+        let expected = vec![
+            Some(Value::String(String::from("0|1"))),
+            Some(Value::String(String::from("1"))),
+            Some(Value::String(String::from("./."))),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let data = [
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0x93, 0x01, // 0|200
+            0x01, 0x80, 0x01, 0x80, // None
+        ];
+        let mut reader = &data[..];
+
+        let actual = read_genotype_genotype_field_values(&mut reader, 2)?;
+        let expected = vec![Some(Value::String(String::from("0|200"))), None];
+
+        assert_eq!(actual, expected);
+
+        let data = [
+            0x13, // Some(Type::Int32(1))
+            0x4a, 0x9c, 0x00, 0x00, // 20004
+        ];
+        let mut reader = &data[..];
+
+        let actual = read_genotype_genotype_field_values(&mut reader, 1)?;
+        let expected = vec![Some(Value::String(String::from("20004")))];
+
+        assert_eq!(actual, expected);
+
+        let data = [0x01]; // Some(Type::Int8(0))
+        let mut reader = &data[..];
         assert_eq!(
-            parse_genotype_genotype_field_values(&[0x02, 0x04, 0x07]),
-            "0/1|2"
+            read_genotype_genotype_field_values(&mut reader, 2)?,
+            vec![None, None]
         );
+
+        let data = [0x15, 0x00, 0x00, 0x00, 0x00]; // Some(Type::Float(1))
+        let mut reader = &data[..];
+        assert!(read_genotype_genotype_field_values(&mut reader, 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_genotype_genotype_field_values() {
+        // Examples from § 6.3.3 Type encoding (2021-05-13)
+
+        fn t(values: &[i32], expected: &str) {
+            let values: Vec<_> = values.iter().copied().map(Some).collect();
+            assert_eq!(
+                parse_genotype_genotype_field_values(&values),
+                Some(Value::String(expected.into()))
+            );
+        }
+
+        t(&[0x02, 0x02], "0/0");
+        t(&[0x02, 0x04], "0/1");
+        t(&[0x04, 0x04], "1/1");
+        t(&[0x02, 0x05], "0|1");
+        t(&[0x00, 0x00], "./.");
+        t(&[0x02], "0");
+        t(&[0x04], "1");
+        t(&[0x02, 0x04, 0x06], "0/1/2");
+        t(&[0x02, 0x04, 0x07], "0/1|2");
+
         assert_eq!(
-            parse_genotype_genotype_field_values(&[0x02, i8::from(Int8::EndOfVector)]),
-            "0"
+            parse_genotype_genotype_field_values(&[Some(0x02), None]),
+            Some(Value::String(String::from("0")))
         );
+        assert_eq!(parse_genotype_genotype_field_values(&[None, None]), None);
     }
 }
@@ -14,7 +14,7 @@ use crate::{
     record::{value::Float, Value},
 };
 
-use self::info::read_info;
+pub use self::info::read_info;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Site {
@@ -75,7 +75,7 @@ where
     })
 }
 
-fn read_id<R>(reader: &mut R) -> io::Result<Ids>
+pub fn read_id<R>(reader: &mut R) -> io::Result<Ids>
 where
     R: Read,
 {
@@ -91,7 +91,7 @@ where
     }
 }
 
-fn read_ref_alt<R>(reader: &mut R, len: usize) -> io::Result<Vec<String>>
+pub fn read_ref_alt<R>(reader: &mut R, len: usize) -> io::Result<Vec<String>>
 where
     R: Read,
 {
@@ -113,7 +113,7 @@ where
     Ok(alleles)
 }
 
-fn read_filter<R>(reader: &mut R, string_map: &StringMap) -> io::Result<Filters>
+pub fn read_filter<R>(reader: &mut R, string_map: &StringMap) -> io::Result<Filters>
 where
     R: Read,
 {
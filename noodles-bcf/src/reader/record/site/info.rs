@@ -91,16 +91,42 @@ where
             vcf::record::info::field::Value::Integer(i32::from(n))
         }
         Some(Value::Int8Array(values)) => vcf::record::info::field::Value::IntegerArray(
-            values.into_iter().map(i32::from).collect(),
+            values
+                .into_iter()
+                .map(Int8::from)
+                .filter_map(|value| match value {
+                    Int8::Value(n) => Some(Some(i32::from(n))),
+                    Int8::Missing => Some(None),
+                    _ => None,
+                })
+                .collect(),
         ),
         Some(Value::Int16(Some(Int16::Value(n)))) => {
             vcf::record::info::field::Value::Integer(i32::from(n))
         }
         Some(Value::Int16Array(values)) => vcf::record::info::field::Value::IntegerArray(
-            values.into_iter().map(i32::from).collect(),
+            values
+                .into_iter()
+                .map(Int16::from)
+                .filter_map(|value| match value {
+                    Int16::Value(n) => Some(Some(i32::from(n))),
+                    Int16::Missing => Some(None),
+                    _ => None,
+                })
+                .collect(),
         ),
         Some(Value::Int32(Some(Int32::Value(n)))) => vcf::record::info::field::Value::Integer(n),
-        Some(Value::Int32Array(values)) => vcf::record::info::field::Value::IntegerArray(values),
+        Some(Value::Int32Array(values)) => vcf::record::info::field::Value::IntegerArray(
+            values
+                .into_iter()
+                .map(Int32::from)
+                .filter_map(|value| match value {
+                    Int32::Value(n) => Some(Some(n)),
+                    Int32::Missing => Some(None),
+                    _ => None,
+                })
+                .collect(),
+        ),
         v => return Err(type_mismatch_error(v, Type::Integer)),
     };
 
@@ -123,7 +149,20 @@ where
 {
     match read_value(reader)? {
         Some(Value::Float(Some(Float::Value(n)))) => Ok(vcf::record::info::field::Value::Float(n)),
-        Some(Value::FloatArray(values)) => Ok(vcf::record::info::field::Value::FloatArray(values)),
+        Some(Value::FloatArray(values)) => values
+            .into_iter()
+            .map(Float::from)
+            .filter_map(|value| match value {
+                Float::Value(n) => Some(Ok(Some(n))),
+                Float::Missing => Some(Ok(None)),
+                Float::EndOfVector => None,
+                _ => Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid f32 array value: {:?}", value),
+                ))),
+            })
+            .collect::<io::Result<_>>()
+            .map(vcf::record::info::field::Value::FloatArray),
         v => Err(type_mismatch_error(v, Type::Float)),
     }
 }
@@ -143,7 +182,7 @@ where
                     io::Error::new(io::ErrorKind::InvalidData, "INFO character value missing")
                 })?,
             _ => Ok(vcf::record::info::field::Value::CharacterArray(
-                s.chars().collect(),
+                s.chars().map(Some).collect(),
             )),
         },
         v => Err(type_mismatch_error(v, Type::Character)),
@@ -221,19 +260,22 @@ mod tests {
             String::default(),
         ));
 
-        let value = Value::IntegerArray(vec![8, 13]);
+        let value = Value::IntegerArray(vec![Some(8), Some(13)]);
         t(&[0x21, 0x08, 0x0d], &info, &value)?;
 
-        let value = Value::IntegerArray(vec![21, 34]);
+        let value = Value::IntegerArray(vec![Some(21), Some(34)]);
         t(&[0x22, 0x15, 0x00, 0x22, 0x00], &info, &value)?;
 
-        let value = Value::IntegerArray(vec![55, 89]);
+        let value = Value::IntegerArray(vec![Some(55), Some(89)]);
         t(
             &[0x23, 0x37, 0x00, 0x00, 0x00, 0x59, 0x00, 0x00, 0x00],
             &info,
             &value,
         )?;
 
+        let value = Value::IntegerArray(vec![Some(8), None]);
+        t(&[0x31, 0x08, 0x80, 0x81], &info, &value)?;
+
         Ok(())
     }
 
@@ -300,13 +342,51 @@ mod tests {
         ));
 
         let actual = read_info_field_value(&mut reader, &info)?;
-        let expected = vcf::record::info::field::Value::FloatArray(vec![0.0, 1.0]);
+        let expected = vcf::record::info::field::Value::FloatArray(vec![Some(0.0), Some(1.0)]);
 
         assert_eq!(actual, expected);
 
         Ok(())
     }
 
+    #[test]
+    fn test_read_info_field_value_with_float_array_value_with_missing_values() -> io::Result<()> {
+        let info = vcf::header::Info::from(Key::Other(
+            String::from("F32"),
+            Number::Count(3),
+            Type::Float,
+            String::default(),
+        ));
+
+        // Some(Value::FloatArray([0.0, Float::Missing, Float::EndOfVector]))
+        let data = [
+            0x35, // Some(Type::Float(3))
+            0x00, 0x00, 0x00, 0x00, // 0.0
+            0x01, 0x00, 0x80, 0x7f, // Float::Missing
+            0x02, 0x00, 0x80, 0x7f, // Float::EndOfVector
+        ];
+        let mut reader = &data[..];
+
+        let actual = read_info_field_value(&mut reader, &info)?;
+        let expected = vcf::record::info::field::Value::FloatArray(vec![Some(0.0), None]);
+        assert_eq!(actual, expected);
+
+        // Some(Value::FloatArray([0.0, Float::Reserved]))
+        let data = [
+            0x25, // Some(Type::Float(2))
+            0x00, 0x00, 0x00, 0x00, // 0.0
+            0x03, 0x00, 0x80, 0x7f, // Float::Reserved
+        ];
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            read_info_field_value(&mut reader, &info),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_info_field_value_with_character_value() -> io::Result<()> {
         // Some(Value::String(Some(String::from("n"))))
@@ -342,7 +422,7 @@ mod tests {
         ));
 
         let actual = read_info_field_value(&mut reader, &info)?;
-        let expected = vcf::record::info::field::Value::CharacterArray(vec!['n', 'd']);
+        let expected = vcf::record::info::field::Value::CharacterArray(vec![Some('n'), Some('d')]);
 
         assert_eq!(actual, expected);
 
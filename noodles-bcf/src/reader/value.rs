@@ -11,6 +11,25 @@ use crate::record::{
     Value,
 };
 
+/// Reads a BCF record typed value.
+///
+/// This reads the type descriptor and its data. A missing type (`0x00`) is returned as `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{read_value, Int8, Value};
+///
+/// let data = [0x11, 0x05];
+/// let mut reader = &data[..];
+/// assert_eq!(read_value(&mut reader)?, Some(Value::Int8(Some(Int8::Value(5)))));
+///
+/// let data = [0x47, b'n', b'd', b'l', b's'];
+/// let mut reader = &data[..];
+/// assert_eq!(read_value(&mut reader)?, Some(Value::String(Some(String::from("ndls")))));
+/// # Ok::<(), io::Error>(())
+/// ```
 pub fn read_value<R>(reader: &mut R) -> io::Result<Option<Value>>
 where
     R: Read,
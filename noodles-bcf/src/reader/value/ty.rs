@@ -9,6 +9,26 @@ use crate::record::{value::Type, Value};
 
 use super::read_value;
 
+/// Reads a BCF record typed value type descriptor.
+///
+/// A missing type (`0x00`) is returned as `None`. Lengths of 15 or more are read from the
+/// following typed integer.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{read_type, Type};
+///
+/// let data = [0x35];
+/// let mut reader = &data[..];
+/// assert_eq!(read_type(&mut reader)?, Some(Type::Float(3)));
+///
+/// let data = [0xf1, 0x11, 0x15];
+/// let mut reader = &data[..];
+/// assert_eq!(read_type(&mut reader)?, Some(Type::Int8(21)));
+/// # Ok::<(), io::Error>(())
+/// ```
 pub fn read_type<R>(reader: &mut R) -> io::Result<Option<Type>>
 where
     R: Read,
@@ -0,0 +1,56 @@
+use std::io::{self, Read};
+
+use noodles_vcf as vcf;
+
+use crate::{header::StringMap, Record};
+
+use super::Reader;
+
+/// An iterator over records of a BCF reader converted to VCF records.
+///
+/// This is created by calling [`Reader::vcf_records`].
+pub struct VcfRecords<'r, 'h, R>
+where
+    R: Read,
+{
+    reader: &'r mut Reader<R>,
+    header: &'h vcf::Header,
+    string_map: &'h StringMap,
+    record: Record,
+}
+
+impl<'r, 'h, R> VcfRecords<'r, 'h, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(
+        reader: &'r mut Reader<R>,
+        header: &'h vcf::Header,
+        string_map: &'h StringMap,
+    ) -> Self {
+        Self {
+            reader,
+            header,
+            string_map,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for VcfRecords<'r, 'h, R>
+where
+    R: Read,
+{
+    type Item = io::Result<vcf::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(0) => None,
+            Ok(_) => Some(
+                self.record
+                    .try_into_vcf_record(self.header, self.string_map),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
@@ -1,27 +1,93 @@
 //! BCF record and fields.
 
+pub mod builder;
 mod convert;
 pub mod value;
 
-pub use self::value::Value;
+pub use self::{builder::Builder, value::Value};
 
 use std::{
     convert::{TryFrom, TryInto},
     io,
-    ops::{Deref, DerefMut},
 };
 
-use noodles_vcf as vcf;
+use noodles_vcf::{self as vcf, record::Genotype};
+
+use crate::{
+    header::StringMap,
+    reader::record::{read_filter, read_genotypes, read_id, read_info, read_ref_alt},
+};
+
+use self::value::Float;
+
+// The byte length of the fixed-width site fields, i.e., `CHROM`, `POS`, `rlen`, `QUAL`, `n_info`,
+// `n_allele`, and `n_fmt_sample`.
+const SITE_FIXED_FIELDS_LEN: usize = 24;
 
 /// A BCF record.
 ///
-/// A `bcf::Record` wraps a raw byte buffer, and the fields should be considered immutable.
-#[derive(Clone, Default, Eq, PartialEq)]
-pub struct Record(Vec<u8>);
+/// A `bcf::Record` wraps the raw shared (site) and per-sample (genotypes) data as separate
+/// buffers, and the fields should be considered immutable.
+///
+/// Fields are decoded lazily. Site fields can be read individually, e.g., [`Self::position`] or
+/// [`Self::filters`]. These only decode the shared data and never touch the per-sample genotypes,
+/// which are only decoded by [`Self::genotypes`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Record {
+    shared: Vec<u8>,
+    indiv: Vec<u8>,
+}
 
 impl Record {
-    pub(crate) fn resize(&mut self, new_len: usize) {
-        self.0.resize(new_len, Default::default());
+    /// Returns a builder to create a record from each of its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::Record::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    pub(crate) fn new(shared: Vec<u8>, indiv: Vec<u8>) -> Self {
+        Self { shared, indiv }
+    }
+
+    pub(crate) fn resize(&mut self, l_shared: usize, l_indiv: usize) {
+        self.shared.resize(l_shared, Default::default());
+        self.indiv.resize(l_indiv, Default::default());
+    }
+
+    pub(crate) fn buffers_mut(&mut self) -> (&mut [u8], &mut [u8]) {
+        (&mut self.shared, &mut self.indiv)
+    }
+
+    /// Returns the raw shared (site) data of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let record = bcf::Record::from(vec![0x08, 0x00, 0x00, 0x00]);
+    /// assert_eq!(record.shared(), [0x08, 0x00, 0x00, 0x00]);
+    /// ```
+    pub fn shared(&self) -> &[u8] {
+        &self.shared
+    }
+
+    /// Returns the raw per-sample (genotypes) data of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let record = bcf::Record::from(vec![0x08, 0x00, 0x00, 0x00]);
+    /// assert!(record.indiv().is_empty());
+    /// ```
+    pub fn indiv(&self) -> &[u8] {
+        &self.indiv
     }
 
     /// Returns the chromosome ID of the record.
@@ -45,7 +111,7 @@ impl Record {
     pub fn chromosome_id(&self) -> io::Result<i32> {
         const OFFSET: usize = 0;
 
-        let data = &self.0[OFFSET..OFFSET + 4];
+        let data = &self.shared[OFFSET..OFFSET + 4];
 
         data.try_into()
             .map(i32::from_le_bytes)
@@ -77,7 +143,7 @@ impl Record {
 
         const OFFSET: usize = 4;
 
-        let data = &self.0[OFFSET..OFFSET + 4];
+        let data = &self.shared[OFFSET..OFFSET + 4];
 
         data.try_into()
             .map(i32::from_le_bytes)
@@ -91,7 +157,7 @@ impl Record {
     fn rlen(&self) -> io::Result<i32> {
         const OFFSET: usize = 8;
 
-        let data = &self.0[OFFSET..OFFSET + 4];
+        let data = &self.shared[OFFSET..OFFSET + 4];
 
         data.try_into()
             .map(i32::from_le_bytes)
@@ -128,24 +194,397 @@ impl Record {
 
         Position::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     }
-}
 
-impl Deref for Record {
-    type Target = [u8];
+    /// Returns the quality score of this record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let record = bcf::Record::from(vec![
+    ///     0x08, 0x00, 0x00, 0x00, // CHROM
+    ///     0x0c, 0x00, 0x00, 0x00, // POS
+    ///     0x05, 0x00, 0x00, 0x00, // rlen
+    ///     0x00, 0x00, 0x00, 0x42, // QUAL = 32.0
+    ///     // ...
+    /// ]);
+    ///
+    /// assert_eq!(*record.quality_score()?, Some(32.0));
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn quality_score(&self) -> io::Result<vcf::record::QualityScore> {
+        use vcf::record::QualityScore;
+
+        const OFFSET: usize = 12;
 
-    fn deref(&self) -> &[u8] {
-        &self.0
+        let data = &self.shared[OFFSET..OFFSET + 4];
+
+        let value = data
+            .try_into()
+            .map(f32::from_le_bytes)
+            .map(Float::from)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        match value {
+            Float::Value(n) => {
+                QualityScore::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Float::Missing => Ok(QualityScore::default()),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid qual: {:?}", value),
+            )),
+        }
     }
-}
 
-impl DerefMut for Record {
-    fn deref_mut(&mut self) -> &mut [u8] {
-        &mut self.0
+    fn n_info(&self) -> io::Result<u16> {
+        const OFFSET: usize = 16;
+
+        let data = &self.shared[OFFSET..OFFSET + 2];
+
+        data.try_into()
+            .map(u16::from_le_bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn n_allele(&self) -> io::Result<u16> {
+        const OFFSET: usize = 18;
+
+        let data = &self.shared[OFFSET..OFFSET + 2];
+
+        data.try_into()
+            .map(u16::from_le_bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn n_fmt_sample(&self) -> io::Result<(usize, usize)> {
+        const OFFSET: usize = 20;
+
+        let data = &self.shared[OFFSET..OFFSET + 4];
+
+        let n_fmt_sample = data
+            .try_into()
+            .map(u32::from_le_bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let n_fmt = (n_fmt_sample >> 24) as usize;
+        let n_sample = (n_fmt_sample & 0xffffff) as usize;
+
+        Ok((n_fmt, n_sample))
+    }
+
+    // Returns a reader positioned at the start of the variable-length site fields (`ID`).
+    fn variable_site_fields(&self) -> io::Result<&[u8]> {
+        self.shared.get(SITE_FIXED_FIELDS_LEN..).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "unexpected end of site fields",
+            )
+        })
+    }
+
+    /// Returns the IDs of this record.
+    ///
+    /// Only the site fields are read. The genotypes are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let record = bcf::Record::from(vec![
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = 0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x00, 0x00, // n_info = 0
+    ///     0x01, 0x00, // n_allele = 1
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x37, b'n', b'd', b'l', // id = ndl
+    ///     // ...
+    /// ]);
+    ///
+    /// assert_eq!(record.ids()?.to_string(), "ndl");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn ids(&self) -> io::Result<vcf::record::Ids> {
+        let mut reader = self.variable_site_fields()?;
+        read_id(&mut reader)
+    }
+
+    fn read_ref_alt(&self) -> io::Result<Vec<String>> {
+        let n_allele = self.n_allele().map(usize::from)?;
+
+        let mut reader = self.variable_site_fields()?;
+        read_id(&mut reader)?;
+        read_ref_alt(&mut reader, n_allele)
+    }
+
+    /// Returns the reference bases of this record.
+    ///
+    /// Only the site fields are read. The genotypes are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let record = bcf::Record::from(vec![
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = 0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x00, 0x00, // n_info = 0
+    ///     0x02, 0x00, // n_allele = 2
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x07, // id = [missing]
+    ///     0x17, b'A', // ref = A
+    ///     0x17, b'C', // alt = C
+    ///     // ...
+    /// ]);
+    ///
+    /// assert_eq!(record.reference_bases()?.to_string(), "A");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn reference_bases(&self) -> io::Result<vcf::record::ReferenceBases> {
+        self.read_ref_alt().and_then(|alleles| {
+            alleles
+                .first()
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "missing reference bases")
+                })
+                .and_then(|s| {
+                    s.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+        })
+    }
+
+    /// Returns the alternate bases of this record.
+    ///
+    /// Only the site fields are read. The genotypes are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let record = bcf::Record::from(vec![
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = 0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x00, 0x00, // n_info = 0
+    ///     0x02, 0x00, // n_allele = 2
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x07, // id = [missing]
+    ///     0x17, b'A', // ref = A
+    ///     0x17, b'C', // alt = C
+    ///     // ...
+    /// ]);
+    ///
+    /// assert_eq!(record.alternate_bases()?.to_string(), "C");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn alternate_bases(&self) -> io::Result<vcf::record::AlternateBases> {
+        use vcf::record::AlternateBases;
+
+        let alleles = self.read_ref_alt()?;
+
+        alleles
+            .iter()
+            .skip(1)
+            .map(|s| {
+                s.parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect::<io::Result<Vec<_>>>()
+            .map(AlternateBases::from)
+    }
+
+    /// Returns the filters of this record.
+    ///
+    /// Only the site fields are read. The genotypes are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// let string_map: StringMap = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    /// )
+    /// .parse()?;
+    ///
+    /// let record = bcf::Record::from(vec![
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = 0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x00, 0x00, // n_info = 0
+    ///     0x01, 0x00, // n_allele = 1
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x07, // id = [missing]
+    ///     0x17, b'A', // ref = A
+    ///     0x11, 0x00, // filter = [PASS]
+    ///     // ...
+    /// ]);
+    ///
+    /// assert_eq!(record.filters(&string_map)?, Filters::Pass);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn filters(&self, string_map: &StringMap) -> io::Result<vcf::record::Filters> {
+        let n_allele = self.n_allele().map(usize::from)?;
+
+        let mut reader = self.variable_site_fields()?;
+        read_id(&mut reader)?;
+        read_ref_alt(&mut reader, n_allele)?;
+        read_filter(&mut reader, string_map)
+    }
+
+    /// Returns the info fields of this record.
+    ///
+    /// Only the site fields are read. The genotypes are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let raw_header = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+    ///     "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    /// );
+    /// let header: vcf::Header = raw_header.parse()?;
+    /// let string_map: StringMap = raw_header.parse()?;
+    ///
+    /// let record = bcf::Record::from(vec![
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = 0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x01, 0x00, // n_info = 1
+    ///     0x01, 0x00, // n_allele = 1
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x07, // id = [missing]
+    ///     0x17, b'A', // ref = A
+    ///     0x00, // filter = []
+    ///     0x11, 0x01, 0x11, 0x02, // info = NS=2
+    ///     // ...
+    /// ]);
+    ///
+    /// assert_eq!(record.info(&header, &string_map)?.to_string(), "NS=2");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn info(
+        &self,
+        header: &vcf::Header,
+        string_map: &StringMap,
+    ) -> io::Result<vcf::record::Info> {
+        let n_info = self.n_info().map(usize::from)?;
+        let n_allele = self.n_allele().map(usize::from)?;
+
+        let mut reader = self.variable_site_fields()?;
+        read_id(&mut reader)?;
+        read_ref_alt(&mut reader, n_allele)?;
+        read_filter(&mut reader, string_map)?;
+        read_info(&mut reader, header.infos(), string_map, n_info)
+    }
+
+    /// Returns the genotypes of this record.
+    ///
+    /// This decodes the per-sample data, which can be expensive for records with many samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let raw_header = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+    ///     "##contig=<ID=sq0>\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\n",
+    /// );
+    /// let header: vcf::Header = raw_header.parse()?;
+    /// let string_map: StringMap = raw_header.parse()?;
+    ///
+    /// let vcf_record = vcf::Record::try_from_str("sq0\t8\t.\tA\tC\t.\tPASS\t.\tGT\t0|1", &header)?;
+    /// let record = bcf::Record::try_from_vcf_record(&header, &string_map, &vcf_record)?;
+    ///
+    /// assert_eq!(record.genotypes(&string_map)?, vcf_record.genotypes());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn genotypes(&self, string_map: &StringMap) -> io::Result<Vec<Genotype>> {
+        let (n_fmt, n_sample) = self.n_fmt_sample()?;
+
+        if n_sample == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut reader = &self.indiv[..];
+        read_genotypes(&mut reader, string_map, n_sample, n_fmt)
     }
 }
 
 impl From<Vec<u8>> for Record {
-    fn from(data: Vec<u8>) -> Self {
-        Self(data)
+    /// Creates a record from raw shared (site) data with no per-sample data.
+    fn from(shared: Vec<u8>) -> Self {
+        Self::new(shared, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_site_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+            "##FILTER=<ID=q10,Description=\"Quality below 10\">\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+            "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let vcf_record =
+            vcf::Record::try_from_str("sq0\t8\tr0;r1\tAC\tG,T\t5\tq10\tNS=1\tGT\t1|2", &header)?;
+        let record = Record::try_from_vcf_record(&header, &string_map, &vcf_record)?;
+
+        assert_eq!(record.chromosome_id()?, 0);
+        assert_eq!(record.position()?, vcf_record.position());
+        assert_eq!(record.end()?, vcf_record.end()?);
+        assert_eq!(record.ids()?, *vcf_record.ids());
+        assert_eq!(record.reference_bases()?, *vcf_record.reference_bases());
+        assert_eq!(record.alternate_bases()?, *vcf_record.alternate_bases());
+        assert_eq!(record.quality_score()?, vcf_record.quality_score());
+        assert_eq!(record.filters(&string_map)?, *vcf_record.filters());
+        assert_eq!(record.info(&header, &string_map)?, *vcf_record.info());
+        assert_eq!(record.genotypes(&string_map)?, vcf_record.genotypes());
+
+        Ok(())
     }
 }
@@ -0,0 +1,552 @@
+//! BCF record builder.
+
+use std::{convert::TryFrom, io};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use noodles_vcf::{
+    self as vcf,
+    record::{
+        AlternateBases, Chromosome, Filters, Format, Genotype, Ids, Info, Position, QualityScore,
+        ReferenceBases,
+    },
+};
+
+use crate::{
+    header::StringMap,
+    writer::record::{
+        calculate_rlen, write_filter, write_genotypes, write_id, write_info, write_n_fmt_sample,
+        write_pos, write_qual, write_ref_alt,
+    },
+};
+
+use super::Record;
+
+/// A BCF record builder.
+///
+/// This encodes typed fields directly into the shared and per-sample buffers of a BCF record,
+/// without first building a [`vcf::Record`].
+#[derive(Debug, Default, PartialEq)]
+pub struct Builder {
+    chromosome_id: Option<usize>,
+    position: Option<Position>,
+    ids: Ids,
+    reference_bases: Option<ReferenceBases>,
+    alternate_bases: AlternateBases,
+    quality_score: QualityScore,
+    filters: Filters,
+    info: Info,
+    format: Option<Format>,
+    genotypes: Vec<Genotype>,
+}
+
+impl Builder {
+    /// Sets the chromosome ID.
+    ///
+    /// This is the index of the contig in the VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(1)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build(&StringMap::default())?;
+    ///
+    /// assert_eq!(record.chromosome_id()?, 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_chromosome_id(mut self, chromosome_id: usize) -> Self {
+        self.chromosome_id = Some(chromosome_id);
+        self
+    }
+
+    /// Sets the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build(&StringMap::default())?;
+    ///
+    /// assert_eq!(i32::from(record.position()?), 8);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Sets a list of IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{Ids, Position};
+    ///
+    /// let ids: Ids = "nd0".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_ids(ids.clone())
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build(&StringMap::default())?;
+    ///
+    /// assert_eq!(record.ids()?, ids);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_ids(mut self, ids: Ids) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{Position, ReferenceBases};
+    ///
+    /// let reference_bases: ReferenceBases = "AC".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases(reference_bases.clone())
+    ///     .build(&StringMap::default())?;
+    ///
+    /// assert_eq!(record.reference_bases()?, reference_bases);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_bases(mut self, reference_bases: ReferenceBases) -> Self {
+        self.reference_bases = Some(reference_bases);
+        self
+    }
+
+    /// Sets the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{AlternateBases, Position};
+    ///
+    /// let alternate_bases: AlternateBases = "C".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases(alternate_bases.clone())
+    ///     .build(&StringMap::default())?;
+    ///
+    /// assert_eq!(record.alternate_bases()?, alternate_bases);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_alternate_bases(mut self, alternate_bases: AlternateBases) -> Self {
+        self.alternate_bases = alternate_bases;
+        self
+    }
+
+    /// Sets the quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{Position, QualityScore};
+    ///
+    /// let quality_score = QualityScore::try_from(13.0)?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_quality_score(quality_score)
+    ///     .build(&StringMap::default())?;
+    ///
+    /// assert_eq!(record.quality_score()?, quality_score);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_quality_score(mut self, quality_score: QualityScore) -> Self {
+        self.quality_score = quality_score;
+        self
+    }
+
+    /// Sets the filter status.
+    ///
+    /// Failed filter IDs are encoded as indices in the string map given to [`Self::build`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{Filters, Position};
+    ///
+    /// let string_map = StringMap::default();
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_filters(Filters::Pass)
+    ///     .build(&string_map)?;
+    ///
+    /// assert_eq!(record.filters(&string_map)?, Filters::Pass);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_filters(mut self, filters: Filters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Sets the addition information.
+    ///
+    /// If the info contains an `END` field, it is used to calculate the reference length
+    /// (`rlen`). Otherwise, the length of the reference bases is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{
+    ///     info::{field::{Key, Value}, Field},
+    ///     Info, Position,
+    /// };
+    ///
+    /// let string_map: StringMap = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##INFO=<ID=END,Number=1,Type=Integer,Description=\"End position\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    /// ).parse()?;
+    ///
+    /// let info = Info::try_from(vec![Field::new(Key::EndPosition, Value::Integer(13))])?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_info(info)
+    ///     .build(&string_map)?;
+    ///
+    /// assert_eq!(i32::from(record.end()?), 13);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_info(mut self, info: Info) -> Self {
+        self.info = info;
+        self
+    }
+
+    /// Sets the format of the genotypes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{Format, Genotype, Position};
+    ///
+    /// let string_map: StringMap = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\n",
+    /// ).parse()?;
+    ///
+    /// let format: Format = "GT".parse()?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_format(format.clone())
+    ///     .set_genotypes(vec![Genotype::from_str_format("0|1", &format)?])
+    ///     .build(&string_map)?;
+    ///
+    /// assert_eq!(record.genotypes(&string_map)?.len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the list of genotypes.
+    ///
+    /// The number of genotypes is the sample count of the record. A format is required when
+    /// genotypes are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::{Format, Genotype, Position};
+    ///
+    /// let string_map: StringMap = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\n",
+    /// ).parse()?;
+    ///
+    /// let format: Format = "GT".parse()?;
+    /// let genotypes = vec![
+    ///     Genotype::from_str_format("0|1", &format)?,
+    ///     Genotype::from_str_format("1|1", &format)?,
+    /// ];
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(8)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_format(format)
+    ///     .set_genotypes(genotypes.clone())
+    ///     .build(&string_map)?;
+    ///
+    /// assert_eq!(record.genotypes(&string_map)?, genotypes);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_genotypes(mut self, genotypes: Vec<Genotype>) -> Self {
+        self.genotypes = genotypes;
+        self
+    }
+
+    /// Builds and encodes a BCF record.
+    ///
+    /// The string map is used to encode the filter, info, and format keys. The chromosome ID,
+    /// position, and reference bases are required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf::{self as bcf, header::StringMap};
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_chromosome_id(0)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build(&StringMap::default())?;
+    ///
+    /// let expected = [
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = 0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x00, 0x00, // n_info = 0
+    ///     0x01, 0x00, // n_allele = 1
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x07, // id = [missing]
+    ///     0x17, 0x41, // ref = A
+    ///     0x00, // filter = []
+    /// ];
+    ///
+    /// assert_eq!(record.shared(), &expected[..]);
+    /// assert!(record.indiv().is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self, string_map: &StringMap) -> io::Result<Record> {
+        let chromosome_id = self
+            .chromosome_id
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing chromosome ID"))
+            .and_then(|n| {
+                i32::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })?;
+
+        let position = self
+            .position
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing position"))?;
+
+        let reference_bases = self.reference_bases.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "missing reference bases")
+        })?;
+
+        if self.format.is_none() && !self.genotypes.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing format",
+            ));
+        }
+
+        let mut shared = Vec::new();
+
+        shared.write_i32::<LittleEndian>(chromosome_id)?;
+        write_pos(&mut shared, position)?;
+
+        let rlen = calculate_rlen_from_fields(position, &reference_bases, &self.info)?;
+        shared.write_i32::<LittleEndian>(rlen)?;
+
+        write_qual(&mut shared, self.quality_score)?;
+
+        let n_info = u16::try_from(self.info.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        shared.write_u16::<LittleEndian>(n_info)?;
+
+        let n_allele = u16::try_from(1 + self.alternate_bases.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        shared.write_u16::<LittleEndian>(n_allele)?;
+
+        let n_fmt = self.format.as_ref().map(|format| format.len()).unwrap_or(0);
+        write_n_fmt_sample(&mut shared, n_fmt, self.genotypes.len())?;
+
+        write_id(&mut shared, &self.ids)?;
+        write_ref_alt(&mut shared, &reference_bases, &self.alternate_bases)?;
+        write_filter(&mut shared, string_map, &self.filters)?;
+        write_info(&mut shared, string_map, &self.info)?;
+
+        let mut indiv = Vec::new();
+
+        if let Some(format) = self.format.as_ref() {
+            write_genotypes(&mut indiv, string_map, format, &self.genotypes)?;
+        }
+
+        Ok(Record::new(shared, indiv))
+    }
+}
+
+// The end position is calculated by a VCF record with only the fields it depends on. The
+// chromosome is not used and is left empty.
+fn calculate_rlen_from_fields(
+    position: Position,
+    reference_bases: &ReferenceBases,
+    info: &Info,
+) -> io::Result<i32> {
+    use vcf::record::info::field::Key;
+
+    let mut builder = vcf::Record::builder()
+        .set_chromosome(Chromosome::Name(String::new()))
+        .set_position(position)
+        .set_reference_bases(reference_bases.clone());
+
+    if let Some(field) = info.get(&Key::EndPosition) {
+        let info = Info::try_from(vec![field.clone()])
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        builder = builder.set_info(info);
+    }
+
+    let record = builder
+        .build()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    calculate_rlen(&record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+            "##FILTER=<ID=q10,Description=\"Quality below 10\">\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+            "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+            "##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">\n",
+            "##contig=<ID=sq0>\n",
+            "##contig=<ID=sq1>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let vcf_record = vcf::Record::try_from_str(
+            "sq1\t8\tnd0\tAC\tG,T\t13\tq10\tNS=2\tGT:GQ\t0|1:300\t./.:.",
+            &header,
+        )?;
+
+        let actual = Record::builder()
+            .set_chromosome_id(1)
+            .set_position(vcf_record.position())
+            .set_ids(vcf_record.ids().clone())
+            .set_reference_bases(vcf_record.reference_bases().clone())
+            .set_alternate_bases(vcf_record.alternate_bases().clone())
+            .set_quality_score(vcf_record.quality_score())
+            .set_filters(vcf_record.filters().clone())
+            .set_info(vcf_record.info().clone())
+            .set_format(vcf_record.format().cloned().expect("missing format"))
+            .set_genotypes(vcf_record.genotypes().to_vec())
+            .build(&string_map)?;
+
+        let expected = Record::try_from_vcf_record(&header, &string_map, &vcf_record)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.try_into_vcf_record(&header, &string_map)?,
+            vcf_record
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_rlen_from_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let position = Position::try_from(8)?;
+        let reference_bases: ReferenceBases = "ACGT".parse()?;
+
+        assert_eq!(
+            calculate_rlen_from_fields(position, &reference_bases, &Info::default())?,
+            4
+        );
+
+        let info: Info = "END=13".parse()?;
+        assert_eq!(
+            calculate_rlen_from_fields(position, &reference_bases, &info)?,
+            6
+        );
+
+        let info: Info = "END=5".parse()?;
+        assert!(calculate_rlen_from_fields(position, &reference_bases, &info).is_err());
+
+        let info = Info::try_from(vec![vcf::record::info::Field::new(
+            vcf::record::info::field::Key::EndPosition,
+            vcf::record::info::field::Value::String(String::from("ndls")),
+        )])?;
+        assert!(calculate_rlen_from_fields(position, &reference_bases, &info).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_missing_fields() {
+        let string_map = StringMap::default();
+
+        assert!(Builder::default().build(&string_map).is_err());
+
+        let builder = Builder::default()
+            .set_chromosome_id(0)
+            .set_position(Position::try_from(1).unwrap());
+        assert!(builder.build(&string_map).is_err());
+
+        let format: Format = "GT".parse().unwrap();
+        let builder = Builder::default()
+            .set_chromosome_id(0)
+            .set_position(Position::try_from(1).unwrap())
+            .set_reference_bases("A".parse().unwrap())
+            .set_genotypes(vec![Genotype::from_str_format("0|1", &format).unwrap()]);
+        assert!(builder.build(&string_map).is_err());
+    }
+}
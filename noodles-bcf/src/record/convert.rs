@@ -1,14 +1,81 @@
-use std::{convert::TryFrom, io};
+use std::{
+    convert::TryFrom,
+    io::{self, Read},
+};
 
 use noodles_vcf::{self as vcf, record::Position};
 use vcf::record::{AlternateBases, Format, QualityScore};
 
-use crate::{header::StringMap, reader::record::read_record};
+use crate::{
+    header::StringMap,
+    reader::record::read_record,
+    writer::record::{write_genotypes, write_site},
+};
 
 use super::{value::Float, Record};
 
 impl Record {
-    /// Converts a VCF record to a BCF record.
+    /// Encodes a VCF record as a BCF record.
+    ///
+    /// This encodes the site (shared) and genotypes (per-sample) data of the BCF record, i.e.,
+    /// everything except the `l_shared` and `l_indiv` lengths. Integer values are encoded with
+    /// the smallest type that fits, and genotype (`GT`) values are packed into integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let raw_header = "##fileformat=VCFv4.3\n##contig=<ID=sq0>\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+    /// let header: vcf::Header = raw_header.parse()?;
+    /// let string_map = raw_header.parse()?;
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// let actual = bcf::Record::try_from_vcf_record(&header, &string_map, &record)?;
+    ///
+    /// let expected = [
+    ///     0x00, 0x00, 0x00, 0x00, // chrom = sq0
+    ///     0x00, 0x00, 0x00, 0x00, // pos = 0 (base 0)
+    ///     0x01, 0x00, 0x00, 0x00, // rlen = 1
+    ///     0x01, 0x00, 0x80, 0x7f, // qual = Float::Missing
+    ///     0x00, 0x00, // n_info = 0
+    ///     0x01, 0x00, // n_allele = 1
+    ///     0x00, 0x00, 0x00, // n_sample = 0
+    ///     0x00, // n_fmt = 0
+    ///     0x07, // id = [missing]
+    ///     0x17, 0x41, // ref = A
+    ///     0x00, // filter = []
+    /// ];
+    ///
+    /// assert_eq!(actual.shared(), &expected[..]);
+    /// assert!(actual.indiv().is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_vcf_record(
+        header: &vcf::Header,
+        string_map: &StringMap,
+        record: &vcf::Record,
+    ) -> io::Result<Self> {
+        let mut shared = Vec::new();
+        write_site(&mut shared, header, string_map, record)?;
+
+        let mut indiv = Vec::new();
+
+        if let Some(format) = record.format() {
+            write_genotypes(&mut indiv, string_map, format, record.genotypes())?;
+        }
+
+        Ok(Self::new(shared, indiv))
+    }
+
+    /// Converts a BCF record to a VCF record.
     ///
     /// # Examples
     ///
@@ -51,7 +118,7 @@ impl Record {
         header: &vcf::Header,
         string_map: &StringMap,
     ) -> io::Result<vcf::Record> {
-        let mut reader = &self[..];
+        let mut reader = self.shared().chain(self.indiv());
         let (site, genotypes) = read_record(&mut reader, header, string_map)?;
 
         let (_, contig) = usize::try_from(site.chrom)
@@ -130,3 +197,43 @@ impl Record {
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_vcf_record() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##FILTER=<ID=PASS,Description=\"All filters passed\">\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+            "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+            "##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let record = vcf::Record::try_from_str(
+            "sq0\t8\t.\tA\tG\t13\tPASS\tNS=2\tGT:GQ\t0|1:300\t./.:.",
+            &header,
+        )?;
+
+        let actual = Record::try_from_vcf_record(&header, &string_map, &record)?;
+
+        let expected_genotypes = [
+            0x11, 0x02, // GT
+            0x21, 0x02, 0x05, 0x00, 0x00, // [0|1, ./.]
+            0x11, 0x03, // GQ
+            0x12, 0x2c, 0x01, 0x00, 0x80, // [300, .]
+        ];
+
+        assert_eq!(actual.indiv(), &expected_genotypes[..]);
+        assert_eq!(actual.try_into_vcf_record(&header, &string_map)?, record);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,12 @@
+//! BCF record typed values.
+//!
+//! A typed value is a type descriptor followed by its data, i.e., a missing value, an integer, a
+//! float, a string, or a vector of integers or floats. This is the encoding used for the site
+//! (`INFO`) and per-sample (`FORMAT`) fields of a BCF record as well as for string map indices.
+//!
+//! [`read_value`] and [`write_value`] decode and encode typed values, and [`read_type`] and
+//! [`write_type`] decode and encode only the type descriptors.
+
 mod float;
 mod int16;
 mod int32;
@@ -6,15 +15,33 @@ mod ty;
 
 pub use self::{float::Float, int16::Int16, int32::Int32, int8::Int8, ty::Type};
 
+pub use crate::{
+    reader::value::{read_type, read_value},
+    writer::value::{write_type, write_value},
+};
+
+/// A BCF record typed value.
+///
+/// A scalar value of `None` is an empty value of the given type, i.e., a type descriptor with a
+/// length of 0.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
+    /// An 8-bit integer.
     Int8(Option<Int8>),
+    /// An array of 8-bit integers.
     Int8Array(Vec<i8>),
+    /// A 16-bit integer.
     Int16(Option<Int16>),
+    /// An array of 16-bit integers.
     Int16Array(Vec<i16>),
+    /// A 32-bit integer.
     Int32(Option<Int32>),
+    /// An array of 32-bit integers.
     Int32Array(Vec<i32>),
+    /// A single-precision floating-point.
     Float(Option<Float>),
+    /// An array of single-precision floating-points.
     FloatArray(Vec<f32>),
+    /// A string.
     String(Option<String>),
 }
@@ -1,9 +1,17 @@
+/// A BCF record single-precision floating-point value.
+///
+/// Missing (`0x7f800001`), end-of-vector (`0x7f800002`), and reserved values are signaling NaN
+/// bit patterns. Other NaN values are kept as [`Self::Value`].
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum Float {
+    /// A single-precision floating-point.
     Value(f32),
+    /// A missing value.
     Missing,
+    /// The end of a vector, used to pad vectors shorter than the maximum length.
     EndOfVector,
+    /// A reserved value.
     Reserved(f32),
 }
 
@@ -1,9 +1,14 @@
+/// A BCF record 32-bit integer value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int32 {
+    /// A 32-bit integer.
     Value(i32),
+    /// A missing value.
     Missing,
+    /// The end of a vector, used to pad vectors shorter than the maximum length.
     EndOfVector,
+    /// A reserved value.
     Reserved(i32),
 }
 
@@ -1,9 +1,14 @@
+/// A BCF record 8-bit integer value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int8 {
+    /// A 8-bit integer.
     Value(i8),
+    /// A missing value.
     Missing,
+    /// The end of a vector, used to pad vectors shorter than the maximum length.
     EndOfVector,
+    /// A reserved value.
     Reserved(i8),
 }
 
@@ -1,8 +1,16 @@
+/// A BCF record typed value type descriptor.
+///
+/// Each variant holds the number of values, e.g., the length of an array or string.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Type {
+    /// An 8-bit integer.
     Int8(usize),
+    /// A 16-bit integer.
     Int16(usize),
+    /// A 32-bit integer.
     Int32(usize),
+    /// A single-precision floating-point.
     Float(usize),
+    /// A character string.
     String(usize),
 }
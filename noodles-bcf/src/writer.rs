@@ -1,18 +1,19 @@
-mod record;
+pub(crate) mod record;
 mod string_map;
-mod value;
+pub mod value;
 
 use std::{
     convert::TryFrom,
     ffi::CString,
-    io::{self, Write},
+    io::{self, BufWriter, Write},
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
-use noodles_bgzf as bgzf;
+use noodles_bgzf::{self as bgzf, index::Chunk};
+use noodles_csi as csi;
 use noodles_vcf as vcf;
 
-use super::{header::StringMap, MAGIC_NUMBER};
+use super::{header::StringMap, Record, MAGIC_NUMBER};
 
 const MAJOR: u8 = 2;
 const MINOR: u8 = 2;
@@ -22,7 +23,35 @@ pub struct Writer<W>
 where
     W: Write,
 {
-    inner: bgzf::Writer<W>,
+    inner: Inner<W>,
+    indexer: Option<csi::index::Indexer>,
+}
+
+enum Inner<W>
+where
+    W: Write,
+{
+    Bgzf(bgzf::Writer<W>),
+    Raw(BufWriter<W>),
+}
+
+impl<W> Write for Inner<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Bgzf(writer) => writer.write(buf),
+            Self::Raw(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Bgzf(writer) => writer.flush(),
+            Self::Raw(writer) => writer.flush(),
+        }
+    }
 }
 
 impl<W> Writer<W>
@@ -41,7 +70,49 @@ where
     /// ```
     pub fn new(writer: W) -> Self {
         Self {
-            inner: bgzf::Writer::new(writer),
+            inner: Inner::Bgzf(bgzf::Writer::new(writer)),
+            indexer: None,
+        }
+    }
+
+    /// Creates a BCF writer that writes an uncompressed stream.
+    ///
+    /// The output is not wrapped in a BGZF container. This avoids compression costs, e.g., when
+    /// piping data between processes, but the output cannot be indexed. Writes to the given stream
+    /// are buffered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::Writer::new_uncompressed(Vec::new());
+    /// ```
+    pub fn new_uncompressed(writer: W) -> Self {
+        Self {
+            inner: Inner::Raw(BufWriter::new(writer)),
+            indexer: None,
+        }
+    }
+
+    /// Creates a BCF writer that builds a coordinate-sorted index (CSI) while writing records.
+    ///
+    /// Each record is added to the index using its chromosome ID, position, end position, and the
+    /// chunk of virtual positions it was written to. This avoids a second pass to index the
+    /// output. Records must be written in coordinate-sorted order.
+    ///
+    /// The built index is returned by [`Self::finish`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    /// let writer = bcf::Writer::with_indexer(Vec::new(), csi::Index::indexer());
+    /// ```
+    pub fn with_indexer(writer: W, indexer: csi::index::Indexer) -> Self {
+        Self {
+            inner: Inner::Bgzf(bgzf::Writer::new(writer)),
+            indexer: Some(indexer),
         }
     }
 
@@ -55,7 +126,10 @@ where
     /// assert!(writer.get_ref().is_empty());
     /// ```
     pub fn get_ref(&self) -> &W {
-        self.inner.get_ref()
+        match &self.inner {
+            Inner::Bgzf(writer) => writer.get_ref(),
+            Inner::Raw(writer) => writer.get_ref(),
+        }
     }
 
     /// Attempts to finish the output stream.
@@ -73,7 +147,35 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn try_finish(&mut self) -> io::Result<()> {
-        self.inner.try_finish()
+        match &mut self.inner {
+            Inner::Bgzf(writer) => writer.try_finish(),
+            Inner::Raw(writer) => writer.flush(),
+        }
+    }
+
+    /// Finishes the output stream and returns the underlying writer and, if the writer was
+    /// created with an indexer, the built index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    ///
+    /// let writer = bcf::Writer::with_indexer(Vec::new(), csi::Index::indexer());
+    /// let (data, index) = writer.finish()?;
+    /// assert!(index.is_some());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, Option<csi::Index>)> {
+        let inner = match self.inner {
+            Inner::Bgzf(writer) => writer.finish()?,
+            Inner::Raw(writer) => writer.into_inner()?,
+        };
+
+        let index = self.indexer.map(|indexer| indexer.build());
+        Ok((inner, index))
     }
 
     /// Writes a BCF file format.
@@ -173,17 +275,51 @@ where
         let l_indiv = u32::try_from(genotypes_buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
+        let start_position = if self.indexer.is_some() {
+            Some(self.virtual_position()?)
+        } else {
+            None
+        };
+
         self.inner.write_u32::<LittleEndian>(l_shared)?;
         self.inner.write_u32::<LittleEndian>(l_indiv)?;
         self.inner.write_all(&site_buf)?;
         self.inner.write_all(&genotypes_buf)?;
 
+        if let Some(start_position) = start_position {
+            let chunk = Chunk::new(start_position, self.virtual_position()?);
+
+            // The site data is a partial BCF record, which is enough to read its position.
+            let site = Record::from(site_buf);
+
+            let chromosome_id = usize::try_from(site.chromosome_id()?)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            let start = site.position().map(i32::from)?;
+            let end = site.end().map(i32::from)?;
+
+            if let Some(indexer) = self.indexer.as_mut() {
+                indexer.add_record(chromosome_id, i64::from(start), i64::from(end), chunk);
+            }
+        }
+
         Ok(())
     }
+
+    fn virtual_position(&self) -> io::Result<bgzf::VirtualPosition> {
+        match &self.inner {
+            Inner::Bgzf(writer) => writer.virtual_position(),
+            Inner::Raw(_) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "uncompressed streams do not have virtual positions",
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::Read;
+
     use crate::Reader;
 
     use super::*;
@@ -192,12 +328,13 @@ mod tests {
     fn test_write_file_format() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
         writer.write_file_format()?;
+        writer.write_header(&vcf::Header::default())?;
         writer.try_finish()?;
 
         let mut reader = Reader::new(writer.get_ref().as_slice());
-        let file_format = reader.read_file_format()?;
+        let header = reader.read_header()?;
 
-        assert_eq!(file_format, (2, 2));
+        assert_eq!(header.file_format(), (2, 2));
 
         Ok(())
     }
@@ -212,13 +349,145 @@ mod tests {
 
         writer.try_finish()?;
 
+        let mut reader = bgzf::Reader::new(writer.get_ref().as_slice());
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual)?;
+
+        let raw_header = "##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+
+        let mut expected = b"BCF\x02\x02".to_vec();
+        expected.extend_from_slice(&(raw_header.len() as u32 + 1).to_le_bytes());
+        expected.extend_from_slice(raw_header.as_bytes());
+        expected.push(b'\0');
+
+        assert_eq!(actual, expected);
+
         let mut reader = Reader::new(writer.get_ref().as_slice());
-        reader.read_file_format()?;
         let actual = reader.read_header()?;
 
-        let expected = "##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+        assert_eq!(actual.vcf_header(), &header);
 
-        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_vcf_record_uncompressed() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let mut writer = Writer::new_uncompressed(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        let record = vcf::Record::try_from_str("sq0\t8\t.\tA\t.\t.\tPASS\t.", &header)?;
+        writer.write_vcf_record(&header, &string_map, &record)?;
+
+        let (data, index) = writer.finish()?;
+
+        assert!(data.starts_with(b"BCF\x02\x02"));
+        assert!(index.is_none());
+
+        let mut reader = Reader::detect(&data[..])?;
+        let actual_header = reader.read_header()?;
+        assert_eq!(actual_header.vcf_header(), &header);
+
+        let records = reader
+            .vcf_records(&header, &string_map)
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(records, [record]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_vcf_record_with_indexer() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Region;
+
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##contig=<ID=sq0>\n",
+            "##contig=<ID=sq1>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let mut writer = Writer::with_indexer(Vec::new(), csi::Index::indexer());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for line in [
+            "sq0\t8\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t13\t.\tACGT\t.\t.\tPASS\t.",
+            "sq1\t5\t.\tA\t.\t.\tPASS\t.",
+        ]
+        .iter()
+        {
+            let record = vcf::Record::try_from_str(line, &header)?;
+            writer.write_vcf_record(&header, &string_map, &record)?;
+        }
+
+        let (data, index) = writer.finish()?;
+        let index = index.expect("missing index");
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+        assert_eq!(
+            reference_sequences[0]
+                .metadata()
+                .map(|metadata| metadata.mapped_record_count()),
+            Some(2)
+        );
+
+        let mut reader = Reader::new(std::io::Cursor::new(data));
+        reader.read_header()?;
+
+        let region = Region::mapped("sq0", 14, 21);
+        let positions = reader
+            .query(header.contigs(), &index, &region)?
+            .map(|result| result.and_then(|record| record.position().map(i32::from)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(positions, [13]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_vcf_record_with_indexer_at_position_0() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let raw_header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        );
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_map: StringMap = raw_header.parse()?;
+
+        let mut writer = Writer::with_indexer(Vec::new(), csi::Index::indexer());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        let record = vcf::Record::try_from_str("sq0\t0\t.\tN\t.\t.\tPASS\t.", &header)?;
+        writer.write_vcf_record(&header, &string_map, &record)?;
+
+        let (_, index) = writer.finish()?;
+        let index = index.expect("missing index");
+
+        assert_eq!(
+            index.reference_sequences()[0]
+                .metadata()
+                .map(|metadata| metadata.mapped_record_count()),
+            Some(1)
+        );
 
         Ok(())
     }
@@ -1,4 +1,10 @@
 mod genotypes;
 mod site;
 
-pub use self::{genotypes::write_genotypes, site::write_site};
+pub use self::{
+    genotypes::write_genotypes,
+    site::{
+        calculate_rlen, write_filter, write_id, write_info, write_n_fmt_sample, write_pos,
+        write_qual, write_ref_alt, write_site,
+    },
+};
@@ -80,6 +80,10 @@ where
 {
     use vcf::header::{format, Number};
 
+    if key == &Key::Genotype {
+        return write_genotype_genotype_field_values(writer, values);
+    }
+
     match key.ty() {
         format::Type::Integer => match key.number() {
             Number::Count(1) => write_genotype_field_integer_values(writer, values),
@@ -674,12 +678,150 @@ where
     Ok(())
 }
 
+// Each allele is packed as `(i + 1) << 1 | is_phased`, where `i` is the allele position. A
+// missing allele has the position -1.
+fn write_genotype_genotype_field_values<W>(
+    writer: &mut W,
+    values: &[Option<&Value>],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    use vcf::record::genotype::field::value::{genotype::allele::Phasing, Genotype};
+
+    let mut genotypes = Vec::with_capacity(values.len());
+
+    for value in values {
+        let genotype: Genotype = match value {
+            Some(Value::String(s)) => s.parse(),
+            None => MISSING_VALUE.to_string().parse(),
+            Some(v) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("type mismatch: expected String, got {:?}", v),
+                ))
+            }
+        }
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let alleles = genotype
+            .iter()
+            .map(|allele| {
+                let i = match allele.position() {
+                    Some(position) => i32::try_from(position)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+                    None => -1,
+                };
+
+                let is_phased = allele.phasing() == Some(Phasing::Phased);
+
+                Ok((i + 1) << 1 | i32::from(is_phased))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        genotypes.push(alleles);
+    }
+
+    let max_len = genotypes
+        .iter()
+        .map(|alleles| alleles.len())
+        .max()
+        .unwrap_or(1);
+    let max = genotypes
+        .iter()
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or_default();
+
+    if max <= i32::from(Int8::MAX_VALUE) {
+        write_type(writer, Some(Type::Int8(max_len)))?;
+
+        for alleles in &genotypes {
+            for &n in alleles {
+                writer.write_i8(n as i8)?;
+            }
+
+            for _ in alleles.len()..max_len {
+                writer.write_i8(i8::from(Int8::EndOfVector))?;
+            }
+        }
+    } else if max <= i32::from(Int16::MAX_VALUE) {
+        write_type(writer, Some(Type::Int16(max_len)))?;
+
+        for alleles in &genotypes {
+            for &n in alleles {
+                writer.write_i16::<LittleEndian>(n as i16)?;
+            }
+
+            for _ in alleles.len()..max_len {
+                writer.write_i16::<LittleEndian>(i16::from(Int16::EndOfVector))?;
+            }
+        }
+    } else {
+        write_type(writer, Some(Type::Int32(max_len)))?;
+
+        for alleles in &genotypes {
+            for &n in alleles {
+                writer.write_i32::<LittleEndian>(n)?;
+            }
+
+            for _ in alleles.len()..max_len {
+                writer.write_i32::<LittleEndian>(i32::from(Int32::EndOfVector))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use noodles_vcf::header::{format, Number};
 
     use super::*;
 
+    #[test]
+    fn test_write_genotype_field_values_with_genotype_values() -> io::Result<()> {
+        let mut buf = Vec::new();
+
+        let values = [
+            Some(&Value::String(String::from("0/1"))),
+            Some(&Value::String(String::from("1|2"))),
+            Some(&Value::String(String::from("./."))),
+            Some(&Value::String(String::from("1"))),
+            None,
+        ];
+
+        write_genotype_field_values(&mut buf, &Key::Genotype, &values)?;
+
+        let expected = [
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // 0/1
+            0x04, 0x07, // 1|2
+            0x00, 0x00, // ./.
+            0x04, 0x81, // 1
+            0x00, 0x81, // .
+        ];
+
+        assert_eq!(buf, expected);
+
+        buf.clear();
+        let values = [Some(&Value::String(String::from("0/63")))];
+        write_genotype_field_values(&mut buf, &Key::Genotype, &values)?;
+        let expected = [
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0x80, 0x00, // 0/63
+        ];
+        assert_eq!(buf, expected);
+
+        buf.clear();
+        let values = [Some(&Value::Integer(0))];
+        assert!(write_genotype_field_values(&mut buf, &Key::Genotype, &values).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_genotype_field_values_with_integer_values() -> io::Result<()> {
         fn t(
@@ -14,7 +14,7 @@ use crate::{
     writer::value::write_value,
 };
 
-use self::info::write_info;
+pub use self::info::write_info;
 
 const MAX_SAMPLE_NAME_COUNT: u32 = (1 << 24) - 1;
 
@@ -30,12 +30,7 @@ where
     write_chrom(writer, header.contigs(), record.chromosome())?;
     write_pos(writer, record.position())?;
 
-    let start = i32::from(record.position());
-    let end = record
-        .end()
-        .map(i32::from)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-    let rlen = end - start + 1;
+    let rlen = calculate_rlen(record)?;
     writer.write_i32::<LittleEndian>(rlen)?;
 
     write_qual(writer, record.quality_score())?;
@@ -44,19 +39,50 @@ where
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     writer.write_u16::<LittleEndian>(n_info)?;
 
-    let alternate_bases_len = if record.alternate_bases().is_empty() {
-        1
-    } else {
-        record.alternate_bases().len()
-    };
-
-    let n_allele = u16::try_from(1 + alternate_bases_len)
+    let n_allele = u16::try_from(1 + record.alternate_bases().len())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     writer.write_u16::<LittleEndian>(n_allele)?;
 
-    let n_sample = u32::try_from(header.sample_names().len())
+    let n_fmt = record.format().map(|format| format.len()).unwrap_or(0);
+    write_n_fmt_sample(writer, n_fmt, header.sample_names().len())?;
+
+    write_id(writer, record.ids())?;
+    write_ref_alt(writer, record.reference_bases(), record.alternate_bases())?;
+    write_filter(writer, string_map, record.filters())?;
+    write_info(writer, string_map, record.info())?;
+
+    Ok(())
+}
+
+// Calculates the length of the reference sequence covered by the record, i.e., `end - start + 1`.
+pub fn calculate_rlen(record: &vcf::Record) -> io::Result<i32> {
+    let start = i32::from(record.position());
+    let end = record
+        .end()
+        .map(i32::from)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
+    end.checked_sub(start)
+        .and_then(|n| n.checked_add(1))
+        .filter(|&rlen| rlen >= 0)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid end position ({}) for start position ({})",
+                    end, start
+                ),
+            )
+        })
+}
+
+pub fn write_n_fmt_sample<W>(writer: &mut W, n_fmt: usize, n_sample: usize) -> io::Result<()>
+where
+    W: Write,
+{
+    let n_sample =
+        u32::try_from(n_sample).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
     if n_sample > MAX_SAMPLE_NAME_COUNT {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
@@ -64,22 +90,10 @@ where
         ));
     }
 
-    let n_fmt = record
-        .format()
-        .map(|format| {
-            u8::try_from(format.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-        })
-        .unwrap_or(Ok(0))?;
+    let n_fmt = u8::try_from(n_fmt).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
     let n_fmt_sample = u32::from(n_fmt) << 24 | n_sample;
-    writer.write_u32::<LittleEndian>(n_fmt_sample)?;
-
-    write_id(writer, record.ids())?;
-    write_ref_alt(writer, record.reference_bases(), record.alternate_bases())?;
-    write_filter(writer, string_map, record.filters())?;
-    write_info(writer, string_map, record.info())?;
-
-    Ok(())
+    writer.write_u32::<LittleEndian>(n_fmt_sample)
 }
 
 fn write_chrom<W>(
@@ -110,7 +124,7 @@ where
     writer.write_i32::<LittleEndian>(chrom)
 }
 
-fn write_pos<W>(writer: &mut W, position: vcf::record::Position) -> io::Result<()>
+pub fn write_pos<W>(writer: &mut W, position: vcf::record::Position) -> io::Result<()>
 where
     W: Write,
 {
@@ -118,7 +132,7 @@ where
     writer.write_i32::<LittleEndian>(pos)
 }
 
-fn write_qual<W>(writer: &mut W, quality_score: vcf::record::QualityScore) -> io::Result<()>
+pub fn write_qual<W>(writer: &mut W, quality_score: vcf::record::QualityScore) -> io::Result<()>
 where
     W: Write,
 {
@@ -126,7 +140,7 @@ where
     writer.write_f32::<LittleEndian>(f32::from(float))
 }
 
-fn write_id<W>(writer: &mut W, ids: &vcf::record::Ids) -> io::Result<()>
+pub fn write_id<W>(writer: &mut W, ids: &vcf::record::Ids) -> io::Result<()>
 where
     W: Write,
 {
@@ -139,7 +153,7 @@ where
     write_value(writer, value)
 }
 
-fn write_ref_alt<W>(
+pub fn write_ref_alt<W>(
     writer: &mut W,
     reference_bases: &vcf::record::ReferenceBases,
     alternate_bases: &vcf::record::AlternateBases,
@@ -151,19 +165,16 @@ where
     let ref_value = Some(Value::String(Some(r#ref)));
     write_value(writer, ref_value)?;
 
-    if alternate_bases.is_empty() {
-        write_value(writer, Some(Value::String(None)))?;
-    } else {
-        for allele in alternate_bases.iter() {
-            let alt_value = Some(Value::String(Some(allele.to_string())));
-            write_value(writer, alt_value)?;
-        }
+    // A missing ALT (`.`) has no alternate alleles, i.e., `n_allele` = 1.
+    for allele in alternate_bases.iter() {
+        let alt_value = Some(Value::String(Some(allele.to_string())));
+        write_value(writer, alt_value)?;
     }
 
     Ok(())
 }
 
-fn write_filter<W>(
+pub fn write_filter<W>(
     writer: &mut W,
     string_map: &StringMap,
     filters: &vcf::record::Filters,
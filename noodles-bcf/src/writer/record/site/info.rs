@@ -12,6 +12,8 @@ use crate::{
     writer::{string_map::write_string_map_index, value::write_value},
 };
 
+const MISSING_VALUE: char = '.';
+
 pub fn write_info<W>(
     writer: &mut W,
     string_map: &StringMap,
@@ -125,7 +127,7 @@ where
     write_value(writer, Some(Value::String(Some(s.into()))))
 }
 
-fn write_info_field_integer_array_value<W>(writer: &mut W, values: &[i32]) -> io::Result<()>
+fn write_info_field_integer_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
 where
     W: Write,
 {
@@ -138,7 +140,7 @@ where
 
     let (mut min, mut max) = (i32::MAX, i32::MIN);
 
-    for &n in values {
+    for &n in values.iter().flatten() {
         min = cmp::min(min, n);
         max = cmp::max(max, n);
     }
@@ -167,66 +169,102 @@ where
     }
 }
 
-fn write_info_field_int8_array_value<W>(writer: &mut W, values: &[i32]) -> io::Result<()>
+fn write_info_field_int8_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
 where
     W: Write,
 {
     let v = values
         .iter()
-        .map(|&n| i8::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e)))
+        .map(|value| match value {
+            Some(n) => i8::try_from(*n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e)),
+            None => Ok(i8::from(Int8::Missing)),
+        })
         .collect::<Result<_, _>>()?;
 
     write_value(writer, Some(Value::Int8Array(v)))
 }
 
-fn write_info_field_int16_array_value<W>(writer: &mut W, values: &[i32]) -> io::Result<()>
+fn write_info_field_int16_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
 where
     W: Write,
 {
     let v = values
         .iter()
-        .map(|&n| i16::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e)))
+        .map(|value| match value {
+            Some(n) => {
+                i16::try_from(*n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            }
+            None => Ok(i16::from(Int16::Missing)),
+        })
         .collect::<Result<_, _>>()?;
 
     write_value(writer, Some(Value::Int16Array(v)))
 }
 
-fn write_info_field_int32_array_value<W>(writer: &mut W, values: &[i32]) -> io::Result<()>
+fn write_info_field_int32_array_value<W>(writer: &mut W, values: &[Option<i32>]) -> io::Result<()>
 where
     W: Write,
 {
-    write_value(writer, Some(Value::Int32Array(values.into())))
+    let v = values
+        .iter()
+        .map(|value| value.unwrap_or_else(|| i32::from(Int32::Missing)))
+        .collect();
+
+    write_value(writer, Some(Value::Int32Array(v)))
 }
 
-fn write_info_field_float_array_value<W>(writer: &mut W, values: &[f32]) -> io::Result<()>
+fn write_info_field_float_array_value<W>(writer: &mut W, values: &[Option<f32>]) -> io::Result<()>
 where
     W: Write,
 {
-    write_value(writer, Some(Value::FloatArray(values.into())))
+    let v = values
+        .iter()
+        .map(|value| value.unwrap_or_else(|| f32::from(Float::Missing)))
+        .collect();
+
+    write_value(writer, Some(Value::FloatArray(v)))
 }
 
-fn write_info_field_character_array_value<W>(writer: &mut W, values: &[char]) -> io::Result<()>
+fn write_info_field_character_array_value<W>(
+    writer: &mut W,
+    values: &[Option<char>],
+) -> io::Result<()>
 where
     W: Write,
 {
     let mut s = String::new();
 
-    for (i, &c) in values.iter().enumerate() {
+    for (i, value) in values.iter().enumerate() {
         if i > 0 {
             s.push(',');
         }
 
-        s.push(c);
+        s.push(value.unwrap_or(MISSING_VALUE));
     }
 
     write_value(writer, Some(Value::String(Some(s))))
 }
 
-fn write_info_field_string_array_value<W>(writer: &mut W, values: &[String]) -> io::Result<()>
+fn write_info_field_string_array_value<W>(
+    writer: &mut W,
+    values: &[Option<String>],
+) -> io::Result<()>
 where
     W: Write,
 {
-    let s = values.join(",");
+    let mut s = String::new();
+
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+
+        match value {
+            Some(t) => s.push_str(t),
+            None => s.push(MISSING_VALUE),
+        }
+    }
+
     write_value(writer, Some(Value::String(Some(s))))
 }
 
@@ -363,62 +401,68 @@ mod test {
 
         let mut buf = Vec::new();
 
-        let value = field::Value::IntegerArray(vec![-2147483641, -2147483640]);
+        let value = field::Value::IntegerArray(vec![Some(-2147483641), Some(-2147483640)]);
         buf.clear();
         assert!(matches!(
             write_info_field_value(&mut buf, &value),
             Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
         ));
 
-        let value = field::Value::IntegerArray(vec![-2147483640, -2147483639]);
+        let value = field::Value::IntegerArray(vec![Some(-2147483640), Some(-2147483639)]);
         t(
             &mut buf,
             &value,
             &[0x23, 0x08, 0x00, 0x00, 0x80, 0x09, 0x00, 0x00, 0x80],
         )?;
 
-        let value = field::Value::IntegerArray(vec![-32761, -32760]);
+        let value = field::Value::IntegerArray(vec![Some(-32761), Some(-32760)]);
         t(
             &mut buf,
             &value,
             &[0x23, 0x07, 0x80, 0xff, 0xff, 0x08, 0x80, 0xff, 0xff],
         )?;
 
-        let value = field::Value::IntegerArray(vec![-32760, -32759]);
+        let value = field::Value::IntegerArray(vec![Some(-32760), Some(-32759)]);
         t(&mut buf, &value, &[0x22, 0x08, 0x80, 0x09, 0x80])?;
 
-        let value = field::Value::IntegerArray(vec![-121, -120]);
+        let value = field::Value::IntegerArray(vec![Some(-121), Some(-120)]);
         t(&mut buf, &value, &[0x22, 0x87, 0xff, 0x88, 0xff])?;
 
-        let value = field::Value::IntegerArray(vec![-120, -119]);
+        let value = field::Value::IntegerArray(vec![Some(-120), Some(-119)]);
         t(&mut buf, &value, &[0x21, 0x88, 0x89])?;
 
-        let value = field::Value::IntegerArray(vec![-1, 0, 1]);
+        let value = field::Value::IntegerArray(vec![Some(-1), Some(0), Some(1)]);
         t(&mut buf, &value, &[0x31, 0xff, 0x00, 0x01])?;
 
-        let value = field::Value::IntegerArray(vec![126, 127]);
+        let value = field::Value::IntegerArray(vec![Some(126), Some(127)]);
         t(&mut buf, &value, &[0x21, 0x7e, 0x7f])?;
 
-        let value = field::Value::IntegerArray(vec![127, 128]);
+        let value = field::Value::IntegerArray(vec![Some(127), Some(128)]);
         t(&mut buf, &value, &[0x22, 0x7f, 0x00, 0x80, 0x00])?;
 
-        let value = field::Value::IntegerArray(vec![32766, 32767]);
+        let value = field::Value::IntegerArray(vec![Some(32766), Some(32767)]);
         t(&mut buf, &value, &[0x22, 0xfe, 0x7f, 0xff, 0x7f])?;
 
-        let value = field::Value::IntegerArray(vec![32767, 32768]);
+        let value = field::Value::IntegerArray(vec![Some(32767), Some(32768)]);
         t(
             &mut buf,
             &value,
             &[0x23, 0xff, 0x7f, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00],
         )?;
 
-        let value = field::Value::IntegerArray(vec![2147483646, 2147483647]);
+        let value = field::Value::IntegerArray(vec![Some(2147483646), Some(2147483647)]);
         t(
             &mut buf,
             &value,
             &[0x23, 0xfe, 0xff, 0xff, 0x7f, 0xff, 0xff, 0xff, 0x7f],
         )?;
 
+        let value = field::Value::IntegerArray(vec![Some(8), None]);
+        t(&mut buf, &value, &[0x21, 0x08, 0x80])?;
+
+        let value = field::Value::IntegerArray(vec![Some(128), None]);
+        t(&mut buf, &value, &[0x22, 0x80, 0x00, 0x00, 0x80])?;
+
         Ok(())
     }
 
@@ -427,13 +471,21 @@ mod test {
         use vcf::record::info::field;
 
         let mut buf = Vec::new();
-        let value = field::Value::FloatArray(vec![0.0, 1.0]);
+        let value = field::Value::FloatArray(vec![Some(0.0), Some(1.0)]);
         write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3f];
 
         assert_eq!(buf, expected);
 
+        buf.clear();
+        let value = field::Value::FloatArray(vec![Some(0.0), None]);
+        write_info_field_value(&mut buf, &value)?;
+
+        let expected = [0x25, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x80, 0x7f];
+
+        assert_eq!(buf, expected);
+
         Ok(())
     }
 
@@ -442,7 +494,7 @@ mod test {
         use vcf::record::info::field;
 
         let mut buf = Vec::new();
-        let value = field::Value::CharacterArray(vec!['n', 'd', 'l', 's']);
+        let value = field::Value::CharacterArray(vec![Some('n'), Some('d'), Some('l'), Some('s')]);
         write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x77, 0x6e, 0x2c, 0x64, 0x2c, 0x6c, 0x2c, 0x73];
@@ -457,13 +509,22 @@ mod test {
         use vcf::record::info::field;
 
         let mut buf = Vec::new();
-        let value = field::Value::StringArray(vec![String::from("nd"), String::from("ls")]);
+        let value =
+            field::Value::StringArray(vec![Some(String::from("nd")), Some(String::from("ls"))]);
         write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x57, 0x6e, 0x64, 0x2c, 0x6c, 0x73];
 
         assert_eq!(buf, expected);
 
+        buf.clear();
+        let value = field::Value::StringArray(vec![Some(String::from("nd")), None]);
+        write_info_field_value(&mut buf, &value)?;
+
+        let expected = [0x47, 0x6e, 0x64, 0x2c, 0x2e];
+
+        assert_eq!(buf, expected);
+
         Ok(())
     }
 }
@@ -8,7 +8,21 @@ use byteorder::{LittleEndian, WriteBytesExt};
 
 use crate::record::value::{Float, Int16, Int32, Int8, Type, Value};
 
-#[allow(dead_code)]
+/// Writes a BCF record typed value.
+///
+/// This writes the type descriptor and its data. `None` is written as a missing type (`0x00`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{write_value, Value};
+///
+/// let mut buf = Vec::new();
+/// write_value(&mut buf, Some(Value::String(Some(String::from("ndls")))))?;
+/// assert_eq!(buf, [0x47, b'n', b'd', b'l', b's']);
+/// # Ok::<(), io::Error>(())
+/// ```
 pub fn write_value<W>(writer: &mut W, value: Option<Value>) -> io::Result<()>
 where
     W: Write,
@@ -12,6 +12,26 @@ use super::write_value;
 // § 6.3.3 Type encoding (2021-01-13)
 const MAX_TYPE_LEN: usize = 0x0f;
 
+/// Writes a BCF record typed value type descriptor.
+///
+/// `None` is written as a missing type (`0x00`). Lengths of 15 or more are written as a following
+/// typed integer.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{write_type, Type};
+///
+/// let mut buf = Vec::new();
+/// write_type(&mut buf, Some(Type::Float(3)))?;
+/// assert_eq!(buf, [0x35]);
+///
+/// let mut buf = Vec::new();
+/// write_type(&mut buf, Some(Type::Int8(21)))?;
+/// assert_eq!(buf, [0xf1, 0x11, 0x15]);
+/// # Ok::<(), io::Error>(())
+/// ```
 pub fn write_type<W>(writer: &mut W, ty: Option<Type>) -> io::Result<()>
 where
     W: Write,
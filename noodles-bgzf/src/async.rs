@@ -0,0 +1,5 @@
+//! Async BGZF.
+
+mod reader;
+
+pub use self::reader::Reader;
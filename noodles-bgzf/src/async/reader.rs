@@ -0,0 +1,367 @@
+use std::{
+    cmp,
+    io::{self, SeekFrom},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use byteorder::{ByteOrder, LittleEndian};
+use futures::future::poll_fn;
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncSeekExt, ReadBuf};
+
+use crate::{gz, reader::inflate_data, Block, VirtualPosition, BGZF_HEADER_SIZE};
+
+/// An async BGZF reader.
+///
+/// This is the async analog of [`crate::Reader`]. It implements both [`tokio::io::AsyncRead`] and
+/// [`tokio::io::AsyncBufRead`], consuming compressed data and emitting uncompressed data. It is
+/// internally buffered by a single block and, like the synchronous reader, _cannot_ be double
+/// buffered.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// use noodles_bgzf as bgzf;
+/// use tokio::{fs::File, io::AsyncReadExt};
+///
+/// let mut reader = File::open("data.gz").await.map(bgzf::AsyncReader::new)?;
+///
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Reader<R> {
+    inner: R,
+    position: u64,
+    buf: Vec<u8>,
+    buf_len: usize,
+    block: Block,
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates an async BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::AsyncReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            position: 0,
+            buf: Vec::new(),
+            buf_len: 0,
+            block: Block::default(),
+        }
+    }
+
+    /// Returns the current position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::AsyncReader::new(&data[..]);
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::AsyncReader::new(&data[..]);
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.block.virtual_position()
+    }
+
+    // Reads and decompresses the next block.
+    //
+    // The block header is read first to determine the size of the rest of the block. Partially
+    // read data is kept in `buf` across pending polls.
+    //
+    // If successful, the block size is returned. If a block size of 0 is returned, the stream
+    // reached EOF.
+    fn poll_read_block(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<usize>> {
+        if self.buf.is_empty() {
+            self.buf.resize(BGZF_HEADER_SIZE, 0);
+            self.buf_len = 0;
+        }
+
+        loop {
+            while self.buf_len < self.buf.len() {
+                let mut read_buf = ReadBuf::new(&mut self.buf[self.buf_len..]);
+
+                match Pin::new(&mut self.inner).poll_read(cx, &mut read_buf) {
+                    Poll::Ready(Ok(())) => {}
+                    Poll::Ready(Err(e)) => {
+                        self.buf.clear();
+                        return Poll::Ready(Err(e));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+
+                let n = read_buf.filled().len();
+
+                if n == 0 {
+                    let is_at_block_start = self.buf_len == 0 && self.buf.len() == BGZF_HEADER_SIZE;
+                    self.buf.clear();
+
+                    if is_at_block_start {
+                        return Poll::Ready(Ok(0));
+                    } else {
+                        return Poll::Ready(Err(io::Error::from(io::ErrorKind::UnexpectedEof)));
+                    }
+                }
+
+                self.buf_len += n;
+            }
+
+            if self.buf.len() == BGZF_HEADER_SIZE {
+                // Add 1 because BSIZE is "total Block SIZE minus 1".
+                let clen = usize::from(LittleEndian::read_u16(&self.buf[16..])) + 1;
+
+                if clen < BGZF_HEADER_SIZE + gz::TRAILER_SIZE {
+                    self.buf.clear();
+
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "expected clen >= {}, got {}",
+                            BGZF_HEADER_SIZE + gz::TRAILER_SIZE,
+                            clen
+                        ),
+                    )));
+                }
+
+                self.buf.resize(clen, 0);
+            } else {
+                let clen = self.buf.len();
+                let result = inflate_block(&self.buf[BGZF_HEADER_SIZE..], &mut self.block);
+                self.buf.clear();
+                return Poll::Ready(result.map(|_| clen));
+            }
+        }
+    }
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    /// Seeks the stream to the given virtual position.
+    ///
+    /// The underlying stream's cursor is first moved the the compressed position. A block is read,
+    /// decompressed, and has its own cursor moved to the uncompressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::{self, Cursor};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::AsyncReader::new(Cursor::new(Vec::new()));
+    /// let virtual_position = bgzf::VirtualPosition::from(102334155);
+    /// reader.seek(virtual_position).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        let (cpos, upos) = pos.into();
+
+        self.inner.seek(SeekFrom::Start(cpos)).await?;
+        self.position = cpos;
+        self.buf.clear();
+
+        let clen = poll_fn(|cx| self.poll_read_block(cx)).await?;
+
+        if clen > 0 {
+            self.block.set_cpos(cpos);
+            self.position += clen as u64;
+        }
+
+        self.block.set_upos(u32::from(upos));
+
+        Ok(pos)
+    }
+}
+
+impl<R> AsyncRead for Reader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let src = match self.as_mut().poll_fill_buf(cx) {
+            Poll::Ready(Ok(src)) => src,
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        };
+
+        let amt = cmp::min(src.len(), buf.remaining());
+        buf.put_slice(&src[..amt]);
+        self.consume(amt);
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<R> AsyncBufRead for Reader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+
+        if this.block.is_eof() {
+            match this.poll_read_block(cx) {
+                Poll::Ready(Ok(0)) => {}
+                Poll::Ready(Ok(clen)) => {
+                    this.block.set_cpos(this.position);
+                    this.position += clen as u64;
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(Ok(this.block.fill_buf()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        let amt = cmp::min(amt, crate::block::MAX_UNCOMPRESSED_DATA_LENGTH);
+        let upos = cmp::min(this.block.ulen(), this.block.upos() + amt as u32);
+        this.block.set_upos(upos);
+    }
+}
+
+// Decompresses the body of a block, i.e., the compressed data followed by the gzip trailer.
+fn inflate_block(src: &[u8], block: &mut Block) -> io::Result<()> {
+    let (cdata, trailer) = src.split_at(src.len() - gz::TRAILER_SIZE);
+    let r#isize = LittleEndian::read_u32(&trailer[4..]);
+
+    block.set_clen((BGZF_HEADER_SIZE + src.len()) as u64);
+    block.set_upos(0);
+
+    let udata = block.data_mut();
+    udata.clear();
+
+    inflate_data(cdata, udata)?;
+
+    if udata.len() != r#isize as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "BGZF block length not equal to isize",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, io::Write};
+
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+    use crate::{writer::BGZF_EOF, Writer};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_virtual_positions() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles\nbgzf\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut line = String::new();
+
+        reader.read_line(&mut line).await?;
+        assert_eq!(line, "noodles\n");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((0, 8)).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_seek() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.virtual_position()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(io::Cursor::new(data));
+        reader.seek(position).await?;
+        assert_eq!(reader.virtual_position(), position);
+
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"bgzf");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_invalid_block_size() {
+        let data = {
+            let mut eof = BGZF_EOF.to_vec();
+            // BSIZE = 0
+            eof[16] = 0x00;
+            eof[17] = 0x00;
+            eof
+        };
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        assert!(reader.read_to_end(&mut buf).await.is_err());
+    }
+}
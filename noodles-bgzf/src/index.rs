@@ -1,8 +1,9 @@
 //! BGZF index structures.
 
 mod chunk;
+mod chunk_reader;
 
-pub use self::chunk::Chunk;
+pub use self::{chunk::Chunk, chunk_reader::ChunkReader};
 
 use super::VirtualPosition;
 
@@ -0,0 +1,176 @@
+use std::io::{self, BufRead, Read, Seek};
+
+use super::Chunk;
+use crate::{Reader, VirtualPosition};
+
+enum State {
+    Seek,
+    Read(VirtualPosition),
+    Done,
+}
+
+/// A BGZF reader over a list of chunks.
+///
+/// This seeks to the start of each chunk and reads until its end before moving to the next. The
+/// chunks are read in the given order, so they are typically first merged, e.g., using
+/// [`super::optimize_chunks`].
+///
+/// Reads stop at the first virtual position at or after the end of a chunk. Chunk ends are
+/// expected to be at record boundaries, so a record that starts in a chunk is read whole.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufRead}};
+/// use noodles_bgzf::{self as bgzf, index::{Chunk, ChunkReader}};
+///
+/// let mut reader = File::open("sample.gz").map(bgzf::Reader::new)?;
+///
+/// let chunks = vec![Chunk::new(
+///     bgzf::VirtualPosition::from(0),
+///     bgzf::VirtualPosition::from(21),
+/// )];
+///
+/// let chunk_reader = ChunkReader::new(&mut reader, chunks);
+///
+/// for result in chunk_reader.lines() {
+///     let line = result?;
+///     println!("{}", line);
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct ChunkReader<'r, R> {
+    inner: &'r mut Reader<R>,
+    chunks: Vec<Chunk>,
+    i: usize,
+    state: State,
+}
+
+impl<'r, R> ChunkReader<'r, R>
+where
+    R: Read + Seek,
+{
+    /// Creates a BGZF reader over a list of chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_bgzf::{self as bgzf, index::ChunkReader};
+    ///
+    /// let mut reader = bgzf::Reader::new(io::Cursor::new(Vec::new()));
+    /// let chunk_reader = ChunkReader::new(&mut reader, Vec::new());
+    /// ```
+    pub fn new(inner: &'r mut Reader<R>, chunks: Vec<Chunk>) -> Self {
+        Self {
+            inner,
+            chunks,
+            i: 0,
+            state: State::Seek,
+        }
+    }
+
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_bgzf::{self as bgzf, index::ChunkReader};
+    ///
+    /// let mut reader = bgzf::Reader::new(io::Cursor::new(Vec::new()));
+    /// let chunk_reader = ChunkReader::new(&mut reader, Vec::new());
+    ///
+    /// assert_eq!(chunk_reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<'r, R> Read for ChunkReader<'r, R>
+where
+    R: Read + Seek,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = {
+            let mut remaining = self.fill_buf()?;
+            remaining.read(buf)?
+        };
+
+        self.consume(bytes_read);
+
+        Ok(bytes_read)
+    }
+}
+
+impl<'r, R> BufRead for ChunkReader<'r, R>
+where
+    R: Read + Seek,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        loop {
+            match self.state {
+                State::Seek => {
+                    self.state = match self.chunks.get(self.i) {
+                        Some(chunk) => {
+                            self.inner.seek(chunk.start())?;
+                            self.i += 1;
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    }
+                }
+                State::Read(chunk_end) => {
+                    if self.inner.virtual_position() < chunk_end {
+                        return self.inner.fill_buf();
+                    }
+
+                    self.state = State::Seek;
+                }
+                State::Done => return Ok(&[]),
+            }
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use crate::Writer;
+
+    use super::*;
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles\n")?;
+        writer.flush()?;
+        let start = writer.virtual_position()?;
+        writer.write_all(b"bgzf\n")?;
+        writer.flush()?;
+        let end = writer.virtual_position()?;
+        writer.write_all(b"chunk\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+
+        let chunks = vec![
+            Chunk::new(start, end),
+            Chunk::new(VirtualPosition::from(0), VirtualPosition::from(8)),
+        ];
+
+        let mut chunk_reader = ChunkReader::new(&mut reader, chunks);
+        let mut buf = String::new();
+        chunk_reader.read_to_string(&mut buf)?;
+
+        assert_eq!(buf, "bgzf\nnoodles\n");
+
+        Ok(())
+    }
+}
@@ -34,14 +34,24 @@
 //! # Ok::<(), io::Error>(())
 //! ```
 
+#[cfg(feature = "async")]
+mod r#async;
+
 mod block;
 mod gz;
 pub mod index;
+mod multithreaded_reader;
 mod reader;
 pub mod virtual_position;
 mod writer;
 
-pub use self::{reader::Reader, virtual_position::VirtualPosition, writer::Writer};
+pub use self::{
+    multithreaded_reader::MultithreadedReader, reader::Reader, virtual_position::VirtualPosition,
+    writer::Writer,
+};
+
+#[cfg(feature = "async")]
+pub use self::r#async::Reader as AsyncReader;
 
 use self::block::Block;
 
@@ -123,4 +133,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let virtual_position = writer.virtual_position()?;
+        writer.write_all(b"bgzf")?;
+
+        let data = writer.finish()?;
+        let mut reader = Reader::new(io::Cursor::new(data));
+
+        reader.seek(virtual_position)?;
+        assert_eq!(reader.virtual_position(), virtual_position);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"bgzf");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,331 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+    sync::{mpsc, Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use super::{
+    reader::{inflate_block, read_compressed_block},
+    Block, VirtualPosition,
+};
+
+// The number of blocks queued per worker.
+const QUEUE_DEPTH_PER_WORKER: usize = 2;
+
+type BlockResult = io::Result<Block>;
+
+struct Request {
+    cdata: Vec<u8>,
+    ulen: u32,
+    cpos: u64,
+    clen: u64,
+    tx: mpsc::SyncSender<BlockResult>,
+}
+
+/// A multithreaded BGZF reader.
+///
+/// This is a BGZF reader that decompresses blocks in parallel using a pool of worker threads.
+/// Compressed blocks are read from the underlying stream on the calling thread and are inflated
+/// ahead of the current position, in order.
+///
+/// Like [`crate::Reader`], it implements both [`std::io::Read`] and [`std::io::BufRead`] and
+/// tracks virtual positions, so it _cannot_ be double buffered.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, Read}, num::NonZeroUsize};
+/// use noodles_bgzf as bgzf;
+///
+/// let worker_count = NonZeroUsize::new(4).unwrap();
+/// let mut reader = File::open("data.gz")
+///     .map(|f| bgzf::MultithreadedReader::with_worker_count(worker_count, f))?;
+///
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct MultithreadedReader<R> {
+    inner: R,
+    position: u64,
+    is_eof: bool,
+    queue_capacity: usize,
+    queue: VecDeque<mpsc::Receiver<BlockResult>>,
+    request_tx: Option<mpsc::Sender<Request>>,
+    handles: Vec<JoinHandle<()>>,
+    block: Block,
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read,
+{
+    /// Creates a multithreaded BGZF reader with the given number of workers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let reader = bgzf::MultithreadedReader::with_worker_count(worker_count, &data[..]);
+    /// ```
+    pub fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
+        let (request_tx, request_rx) = mpsc::channel();
+        let request_rx = Arc::new(Mutex::new(request_rx));
+
+        let handles = (0..worker_count.get())
+            .map(|_| spawn_worker(Arc::clone(&request_rx)))
+            .collect();
+
+        Self {
+            inner,
+            position: 0,
+            is_eof: false,
+            queue_capacity: worker_count.get() * QUEUE_DEPTH_PER_WORKER,
+            queue: VecDeque::new(),
+            request_tx: Some(request_tx),
+            handles,
+            block: Block::default(),
+        }
+    }
+
+    /// Returns the current virtual position of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let worker_count = NonZeroUsize::new(2).unwrap();
+    /// let reader = bgzf::MultithreadedReader::with_worker_count(worker_count, &data[..]);
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::from(0));
+    /// ```
+    pub fn virtual_position(&self) -> VirtualPosition {
+        self.block.virtual_position()
+    }
+
+    fn fill_queue(&mut self) -> io::Result<()> {
+        while !self.is_eof && self.queue.len() < self.queue_capacity {
+            let mut cdata = Vec::new();
+
+            let (clen, ulen) = match read_compressed_block(&mut self.inner, &mut cdata)? {
+                Some(lens) => lens,
+                None => {
+                    self.is_eof = true;
+                    break;
+                }
+            };
+
+            let (tx, rx) = mpsc::sync_channel(1);
+
+            let request = Request {
+                cdata,
+                ulen,
+                cpos: self.position,
+                clen: clen as u64,
+                tx,
+            };
+
+            self.request_tx
+                .as_ref()
+                .expect("missing request sender")
+                .send(request)
+                .map_err(|e| io::Error::new(io::ErrorKind::BrokenPipe, e.to_string()))?;
+
+            self.queue.push_back(rx);
+            self.position += clen as u64;
+        }
+
+        Ok(())
+    }
+
+    fn next_block(&mut self) -> io::Result<Option<Block>> {
+        self.fill_queue()?;
+
+        match self.queue.pop_front() {
+            Some(rx) => rx
+                .recv()
+                .map_err(|e| io::Error::new(io::ErrorKind::BrokenPipe, e))?
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Seeks the stream to the given virtual position.
+    ///
+    /// Any blocks that were read ahead are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{io::{self, Cursor}, num::NonZeroUsize};
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::MultithreadedReader::with_worker_count(
+    ///     NonZeroUsize::new(2).unwrap(),
+    ///     Cursor::new(Vec::new()),
+    /// );
+    /// let virtual_position = bgzf::VirtualPosition::from(102334155);
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        let (cpos, upos) = pos.into();
+
+        self.queue.clear();
+        self.inner.seek(SeekFrom::Start(cpos))?;
+        self.position = cpos;
+        self.is_eof = false;
+
+        self.block = self.next_block()?.unwrap_or_default();
+        self.block.set_upos(u32::from(upos));
+
+        Ok(pos)
+    }
+}
+
+impl<R> Read for MultithreadedReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = {
+            let mut remaining = self.fill_buf()?;
+            remaining.read(buf)?
+        };
+
+        self.consume(bytes_read);
+
+        Ok(bytes_read)
+    }
+}
+
+impl<R> BufRead for MultithreadedReader<R>
+where
+    R: Read,
+{
+    fn consume(&mut self, amt: usize) {
+        let upos = self.block.upos() as usize + amt;
+        let upos = upos.min(self.block.ulen() as usize);
+        self.block.set_upos(upos as u32);
+    }
+
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.block.is_eof() {
+            if let Some(block) = self.next_block()? {
+                self.block = block;
+            }
+        }
+
+        Ok(self.block.fill_buf())
+    }
+}
+
+impl<R> Drop for MultithreadedReader<R> {
+    fn drop(&mut self) {
+        // Closing the request channel stops the workers.
+        self.request_tx.take();
+        self.queue.clear();
+
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn spawn_worker(request_rx: Arc<Mutex<mpsc::Receiver<Request>>>) -> JoinHandle<()> {
+    thread::spawn(move || loop {
+        let request = match request_rx.lock() {
+            Ok(rx) => match rx.recv() {
+                Ok(request) => request,
+                Err(_) => break,
+            },
+            Err(_) => break,
+        };
+
+        let mut block = Block::default();
+        block.set_cpos(request.cpos);
+        block.set_clen(request.clen);
+
+        let result = inflate_block(&request.cdata, request.ulen, &mut block).map(|_| block);
+
+        // The receiver is dropped when the reader seeks or is dropped.
+        let _ = request.tx.send(result);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use crate::{Reader, Writer};
+
+    use super::*;
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+
+        for i in 0..(1 << 16) {
+            writeln!(writer, "noodles-bgzf {}", i)?;
+        }
+
+        writer.finish()
+    }
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut expected_reader = Reader::new(&data[..]);
+        let mut expected = Vec::new();
+        expected_reader.read_to_end(&mut expected)?;
+
+        let worker_count = NonZeroUsize::new(3).unwrap();
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, &data[..]);
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual)?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            reader.virtual_position(),
+            expected_reader.virtual_position()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = vec![0; 200000];
+        reader.read_exact(&mut buf)?;
+        let virtual_position = reader.virtual_position();
+
+        let mut expected = Vec::new();
+        reader.read_to_end(&mut expected)?;
+
+        let worker_count = NonZeroUsize::new(2).unwrap();
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, Cursor::new(&data));
+        reader.read_exact(&mut buf[..8])?;
+        reader.seek(virtual_position)?;
+        assert_eq!(reader.virtual_position(), virtual_position);
+
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -111,7 +111,9 @@ where
         self.inner.seek(SeekFrom::Start(cpos))?;
         self.position = cpos;
 
-        read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+        let bs = read_block(&mut self.inner, &mut self.cdata, &mut self.block)?;
+        self.block.set_cpos(self.position);
+        self.position += bs as u64;
 
         self.block.set_upos(u32::from(upos));
 
@@ -200,7 +202,7 @@ where
     Ok(r#isize)
 }
 
-fn inflate_data<R>(reader: R, writer: &mut Vec<u8>) -> io::Result<usize>
+pub(crate) fn inflate_data<R>(reader: R, writer: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
 {
@@ -209,11 +211,35 @@ where
 }
 
 fn read_block<R>(reader: &mut R, cdata: &mut Vec<u8>, block: &mut Block) -> io::Result<usize>
+where
+    R: Read,
+{
+    let (clen, ulen) = match read_compressed_block(reader, cdata)? {
+        Some(lens) => lens,
+        None => return Ok(0),
+    };
+
+    block.set_clen(clen as u64);
+    inflate_block(cdata, ulen, block)?;
+
+    Ok(clen)
+}
+
+/// Reads the compressed data of a BGZF block.
+///
+/// The position of the stream is expected to be at the start of a block.
+///
+/// If successful, the block size and the length of the uncompressed data (`ISIZE`) are returned.
+/// If `None` is returned, the stream reached EOF.
+pub(crate) fn read_compressed_block<R>(
+    reader: &mut R,
+    cdata: &mut Vec<u8>,
+) -> io::Result<Option<(usize, u32)>>
 where
     R: Read,
 {
     let clen = match read_header(reader) {
-        Ok(0) => return Ok(0),
+        Ok(0) => return Ok(None),
         Ok(bs) => bs as usize,
         Err(e) => return Err(e),
     };
@@ -235,13 +261,19 @@ where
 
     let ulen = read_trailer(reader)?;
 
-    block.set_clen(clen as u64);
+    Ok(Some((clen, ulen)))
+}
+
+/// Inflates compressed block data into the given block.
+///
+/// The cursor of the block is reset to the start of the uncompressed data.
+pub(crate) fn inflate_block(cdata: &[u8], ulen: u32, block: &mut Block) -> io::Result<()> {
     block.set_upos(0);
 
     let udata = block.data_mut();
     udata.clear();
 
-    inflate_data(&cdata[..], udata)?;
+    inflate_data(cdata, udata)?;
 
     if udata.len() != ulen as usize {
         return Err(io::Error::new(
@@ -250,7 +282,7 @@ where
         ));
     }
 
-    Ok(clen)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1,12 +1,13 @@
 use std::{
     cmp,
+    convert::TryFrom,
     io::{self, Write},
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use flate2::{write::DeflateEncoder, Compression, Crc};
 
-use super::{block, gz, BGZF_HEADER_SIZE};
+use super::{block, gz, VirtualPosition, BGZF_HEADER_SIZE};
 
 const BGZF_FLG: u8 = 0x04; // FEXTRA
 const BGZF_XFL: u8 = 0x00; // none
@@ -37,6 +38,11 @@ pub(crate) static BGZF_EOF: &[u8] = &[
 ///
 /// This implements [`std::io::Write`], consuming uncompressed data and emitting compressed data.
 ///
+/// A block is written to the underlying writer as soon as it holds the maximum amount of
+/// uncompressed data, i.e., during the [`Write::write`] call that fills it. This means a write
+/// may perform I/O on the underlying writer and return an error from it, but it never returns
+/// [`std::io::ErrorKind::Interrupted`] when a block is full.
+///
 /// # Examples
 ///
 /// ```
@@ -55,6 +61,7 @@ where
     W: Write,
 {
     inner: Option<W>,
+    position: u64,
     encoder: DeflateEncoder<Vec<u8>>,
     crc: Crc,
 }
@@ -74,6 +81,7 @@ where
     pub fn new(inner: W) -> Self {
         Self {
             inner: Some(inner),
+            position: 0,
             encoder: DeflateEncoder::new(Vec::new(), Compression::default()),
             crc: Crc::new(),
         }
@@ -92,6 +100,43 @@ where
         self.inner.as_ref().unwrap()
     }
 
+    /// Returns the current virtual position of the writer.
+    ///
+    /// The compressed position is the start of the current block, i.e., the number of compressed
+    /// bytes written to the underlying writer; and the uncompressed position, the number of
+    /// uncompressed bytes written to the current block.
+    ///
+    /// This returns an error if the compressed position exceeds the maximum representable
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// assert_eq!(writer.virtual_position()?, bgzf::VirtualPosition::from(0));
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// assert_eq!(writer.virtual_position()?, bgzf::VirtualPosition::from(7));
+    ///
+    /// writer.flush()?;
+    /// assert_eq!(
+    ///     writer.virtual_position()?,
+    ///     bgzf::VirtualPosition::from((writer.get_ref().len() as u64) << 16),
+    /// );
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn virtual_position(&self) -> io::Result<VirtualPosition> {
+        // Full blocks are flushed on write, so the uncompressed position always fits in a u16.
+        let uncompressed_position = u16::try_from(self.crc.amount())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        VirtualPosition::try_from((self.position, uncompressed_position))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
     fn flush_block(&mut self) -> io::Result<()> {
         self.encoder.try_finish()?;
 
@@ -102,6 +147,8 @@ where
         inner.write_all(&data[..])?;
         write_trailer(inner, self.crc.sum(), self.crc.amount())?;
 
+        self.position += (BGZF_HEADER_SIZE + data.len() + gz::TRAILER_SIZE) as u64;
+
         self.encoder.reset(Vec::new())?;
         self.crc.reset();
 
@@ -171,13 +218,6 @@ where
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         let total_uncompressed_bytes_written = self.crc.amount() as usize;
 
-        // Only the uncompressed size is tracked, and the assumption is that the uncompressed size
-        // will always be less than the compressed size.
-        if total_uncompressed_bytes_written >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
-            self.flush()?;
-            return Err(io::Error::from(io::ErrorKind::Interrupted));
-        }
-
         let bytes_to_be_written = cmp::min(
             (block::MAX_UNCOMPRESSED_DATA_LENGTH - total_uncompressed_bytes_written) as usize,
             buf.len(),
@@ -185,6 +225,15 @@ where
         let bytes_written = self.encoder.write(&buf[..bytes_to_be_written])?;
         self.crc.update(&buf[..bytes_written]);
 
+        // Only the uncompressed size is tracked, and the assumption is that the uncompressed size
+        // will always be less than the compressed size.
+        //
+        // The block is flushed as soon as it is full so that the virtual position always points
+        // into an open block.
+        if self.crc.amount() as usize >= block::MAX_UNCOMPRESSED_DATA_LENGTH {
+            self.flush_block()?;
+        }
+
         Ok(bytes_written)
     }
 
@@ -244,4 +293,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_virtual_position() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        assert_eq!(writer.virtual_position()?, VirtualPosition::from(0));
+
+        writer.write_all(b"noodles")?;
+        assert_eq!(writer.virtual_position()?, VirtualPosition::from(7));
+
+        let data = vec![0; block::MAX_UNCOMPRESSED_DATA_LENGTH - 7];
+        writer.write_all(&data)?;
+
+        let compressed_position = writer.get_ref().len() as u64;
+        assert!(compressed_position > 0);
+        assert_eq!(
+            writer.virtual_position()?,
+            VirtualPosition::try_from((compressed_position, 0)).unwrap()
+        );
+
+        writer.position = crate::virtual_position::MAX_COMPRESSED_POSITION + 1;
+        assert!(writer.virtual_position().is_err());
+
+        Ok(())
+    }
 }
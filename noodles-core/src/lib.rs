@@ -2,6 +2,8 @@
 
 //! **noodles-core** contains shared structures and behavior among noodles libraries.
 
+pub mod path;
+pub mod position;
 pub mod region;
 
-pub use self::region::Region;
+pub use self::{position::Position, region::Region};
@@ -0,0 +1,50 @@
+//! Path utilities.
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+/// Appends an extension to a path.
+///
+/// Unlike [`Path::with_extension`], this does not replace an existing extension. This is typically
+/// used to build the path of an associated index, e.g., `sample.vcf.gz` -> `sample.vcf.gz.tbi`.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use noodles_core::path::push_extension;
+///
+/// assert_eq!(
+///     push_extension(Path::new("sample.fa"), "fai"),
+///     PathBuf::from("sample.fa.fai")
+/// );
+/// ```
+pub fn push_extension<P>(src: P, extension: &str) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let mut s = OsString::from(src.as_ref());
+    s.push(".");
+    s.push(extension);
+    PathBuf::from(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_extension() {
+        assert_eq!(
+            push_extension(Path::new("sample.fastq"), "fai"),
+            PathBuf::from("sample.fastq.fai")
+        );
+
+        assert_eq!(
+            push_extension(Path::new("sample.vcf.gz"), "tbi"),
+            PathBuf::from("sample.vcf.gz.tbi")
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! 1-based position.
+
+use std::{
+    convert::TryFrom,
+    error, fmt,
+    num::{self, NonZeroUsize},
+    str::FromStr,
+};
+
+/// A 1-based position.
+///
+/// A position is guaranteed to be non-zero, i.e., >= 1. This is used by region intervals (see
+/// [`crate::region::Interval`]) and index queries. Format-specific positions that allow 0, e.g.,
+/// a VCF record position, are kept as separate types with checked conversions to this type.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Position(NonZeroUsize);
+
+impl Position {
+    /// Creates a position if the given value is non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// assert!(Position::new(8).is_some());
+    /// assert!(Position::new(0).is_none());
+    /// ```
+    pub fn new(n: usize) -> Option<Self> {
+        NonZeroUsize::new(n).map(Self)
+    }
+
+    /// Returns the position as a primitive value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::new(8).unwrap();
+    /// assert_eq!(position.get(), 8);
+    /// ```
+    pub fn get(&self) -> usize {
+        self.0.get()
+    }
+
+    /// Adds an unsigned value to the position, returning `None` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::new(8).unwrap();
+    /// assert_eq!(position.checked_add(5), Position::new(13));
+    /// assert!(position.checked_add(usize::MAX).is_none());
+    /// ```
+    pub fn checked_add(self, n: usize) -> Option<Self> {
+        self.get().checked_add(n).and_then(Self::new)
+    }
+
+    /// Subtracts an unsigned value from the position, returning `None` if the result is < 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::new(8).unwrap();
+    /// assert_eq!(position.checked_sub(5), Position::new(3));
+    /// assert!(position.checked_sub(8).is_none());
+    /// ```
+    pub fn checked_sub(self, n: usize) -> Option<Self> {
+        self.get().checked_sub(n).and_then(Self::new)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// An error returned when a raw position fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input failed to parse as an integer.
+    Parse(num::ParseIntError),
+    /// The input is invalid.
+    Invalid(TryFromIntError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "{}", e),
+            Self::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n: usize = s.parse().map_err(ParseError::Parse)?;
+        Self::try_from(n).map_err(ParseError::Invalid)
+    }
+}
+
+/// An error returned when an integer fails to convert to a position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TryFromIntError(i64);
+
+impl error::Error for TryFromIntError {}
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid position: expected >= 1, got {}", self.0)
+    }
+}
+
+impl TryFrom<usize> for Position {
+    type Error = TryFromIntError;
+
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        Self::new(n).ok_or(TryFromIntError(0))
+    }
+}
+
+impl TryFrom<i32> for Position {
+    type Error = TryFromIntError;
+
+    fn try_from(n: i32) -> Result<Self, Self::Error> {
+        usize::try_from(n)
+            .ok()
+            .and_then(Self::new)
+            .ok_or_else(|| TryFromIntError(i64::from(n)))
+    }
+}
+
+impl From<Position> for usize {
+    fn from(position: Position) -> Self {
+        position.get()
+    }
+}
+
+impl TryFrom<Position> for i32 {
+    type Error = num::TryFromIntError;
+
+    fn try_from(position: Position) -> Result<Self, Self::Error> {
+        i32::try_from(position.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(
+            Position::new(1).map(|p| p.to_string()),
+            Some(String::from("1"))
+        );
+        assert_eq!(
+            Position::new(13).map(|p| p.to_string()),
+            Some(String::from("13"))
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("8".parse(), Ok(Position::new(8).unwrap()));
+
+        assert!(matches!("".parse::<Position>(), Err(ParseError::Parse(_))));
+        assert!(matches!(
+            "-1".parse::<Position>(),
+            Err(ParseError::Parse(_))
+        ));
+        assert_eq!(
+            "0".parse::<Position>(),
+            Err(ParseError::Invalid(TryFromIntError(0)))
+        );
+    }
+
+    #[test]
+    fn test_try_from_i32_for_position() {
+        assert_eq!(Position::try_from(1i32), Ok(Position::new(1).unwrap()));
+        assert_eq!(Position::try_from(0i32), Err(TryFromIntError(0)));
+        assert_eq!(Position::try_from(-8i32), Err(TryFromIntError(-8)));
+    }
+
+    #[test]
+    fn test_try_from_position_for_i32() {
+        assert_eq!(i32::try_from(Position::new(1).unwrap()), Ok(1));
+        assert!(i32::try_from(Position::new(usize::MAX).unwrap()).is_err());
+    }
+}
@@ -1,9 +1,15 @@
 //! Genomic region.
 
-use std::{error, fmt, num, str::FromStr};
+pub mod interval;
+
+pub use self::interval::Interval;
+
+use std::{convert::TryFrom, error, fmt, num, str::FromStr};
 
 use noodles_sam::header::ReferenceSequences;
 
+use super::Position;
+
 // Position coordinates are 1-based.
 const MIN_POSITION: i32 = 1;
 const MAX_POSITION: i32 = i32::MAX;
@@ -129,6 +135,35 @@ impl Region {
             Self::All => ALL_NAME,
         }
     }
+
+    /// Returns the interval of the region.
+    ///
+    /// This returns `None` if the region is not mapped or if its start and end positions do not
+    /// form a valid interval, e.g., the start position is < 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let region = Region::mapped("sq0", 8, 13);
+    /// let interval = region.interval().unwrap();
+    /// assert_eq!(interval.start(), Position::new(8).unwrap());
+    /// assert_eq!(interval.end(), Position::new(13).unwrap());
+    ///
+    /// assert!(Region::mapped("sq0", 0, 13).interval().is_none());
+    /// assert!(Region::All.interval().is_none());
+    /// ```
+    pub fn interval(&self) -> Option<Interval> {
+        match self {
+            Self::Mapped { start, end, .. } => {
+                let start = Position::try_from(*start).ok()?;
+                let end = Position::try_from(*end).ok()?;
+                Interval::new(start, end)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Region {
@@ -0,0 +1,140 @@
+//! Genomic region interval.
+
+use std::fmt;
+
+use crate::Position;
+
+/// A closed interval of 1-based positions.
+///
+/// An interval is guaranteed to have a start position <= its end position.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Interval {
+    start: Position,
+    end: Position,
+}
+
+impl Interval {
+    /// Creates an interval if the start position is <= the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let start = Position::new(8).unwrap();
+    /// let end = Position::new(13).unwrap();
+    ///
+    /// assert!(Interval::new(start, end).is_some());
+    /// assert!(Interval::new(end, start).is_none());
+    /// ```
+    pub fn new(start: Position, end: Position) -> Option<Self> {
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let start = Position::new(8).unwrap();
+    /// let end = Position::new(13).unwrap();
+    /// let interval = Interval::new(start, end).unwrap();
+    ///
+    /// assert_eq!(interval.start(), start);
+    /// ```
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let start = Position::new(8).unwrap();
+    /// let end = Position::new(13).unwrap();
+    /// let interval = Interval::new(start, end).unwrap();
+    ///
+    /// assert_eq!(interval.end(), end);
+    /// ```
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns whether the interval contains the given position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let interval = Interval::new(Position::new(8).unwrap(), Position::new(13).unwrap()).unwrap();
+    ///
+    /// assert!(interval.contains(Position::new(8).unwrap()));
+    /// assert!(interval.contains(Position::new(13).unwrap()));
+    /// assert!(!interval.contains(Position::new(21).unwrap()));
+    /// ```
+    pub fn contains(&self, position: Position) -> bool {
+        self.start <= position && position <= self.end
+    }
+
+    /// Returns whether two intervals overlap by at least one position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::new(Position::new(8).unwrap(), Position::new(13).unwrap()).unwrap();
+    /// let b = Interval::new(Position::new(13).unwrap(), Position::new(21).unwrap()).unwrap();
+    /// let c = Interval::new(Position::new(21).unwrap(), Position::new(34).unwrap()).unwrap();
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_interval(start: usize, end: usize) -> Interval {
+        let start = Position::new(start).unwrap();
+        let end = Position::new(end).unwrap();
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_fmt() {
+        let interval = build_interval(8, 13);
+        assert_eq!(interval.to_string(), "8-13");
+    }
+
+    #[test]
+    fn test_intersects() {
+        let a = build_interval(8, 13);
+
+        assert!(a.intersects(&build_interval(1, 8)));
+        assert!(a.intersects(&build_interval(10, 11)));
+        assert!(a.intersects(&build_interval(1, 21)));
+        assert!(!a.intersects(&build_interval(1, 7)));
+        assert!(!a.intersects(&build_interval(14, 21)));
+    }
+}
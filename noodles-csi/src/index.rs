@@ -1,9 +1,10 @@
 //! Coordinate-sorted index and fields.
 
 mod builder;
+mod indexer;
 pub mod reference_sequence;
 
-pub use self::{builder::Builder, reference_sequence::ReferenceSequence};
+pub use self::{builder::Builder, indexer::Indexer, reference_sequence::ReferenceSequence};
 
 /// A coordinate-sorted index (CSI).
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -28,6 +29,19 @@ impl Index {
         Builder::default()
     }
 
+    /// Returns a record indexer with the default binning parameters (a min shift of 14 and a
+    /// depth of 5).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::Index::indexer();
+    /// ```
+    pub fn indexer() -> Indexer {
+        Indexer::default()
+    }
+
     /// Returns the number of bits for the minimum interval.
     ///
     /// # Examples
@@ -0,0 +1,124 @@
+use noodles_bgzf::index::Chunk;
+
+use super::{reference_sequence, Index};
+
+const DEFAULT_MIN_SHIFT: i32 = 14;
+const DEFAULT_DEPTH: i32 = 5;
+
+/// A coordinate-sorted index (CSI) indexer.
+///
+/// Records are expected to be added in coordinate-sorted order.
+#[derive(Debug)]
+pub struct Indexer {
+    min_shift: i32,
+    depth: i32,
+    reference_sequence_builders: Vec<reference_sequence::Builder>,
+}
+
+impl Indexer {
+    /// Creates a CSI indexer with the given binning parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::index::Indexer;
+    /// let indexer = Indexer::new(14, 5);
+    /// ```
+    pub fn new(min_shift: i32, depth: i32) -> Self {
+        Self {
+            min_shift,
+            depth,
+            reference_sequence_builders: Vec::new(),
+        }
+    }
+
+    /// Adds a record.
+    ///
+    /// `start` and `end` are 1-based, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, index::Chunk};
+    /// use noodles_csi as csi;
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    ///
+    /// indexer.add_record(0, 8, 13, Chunk::new(
+    ///     bgzf::VirtualPosition::from(144),
+    ///     bgzf::VirtualPosition::from(233),
+    /// ));
+    /// ```
+    pub fn add_record(&mut self, reference_sequence_id: usize, start: i64, end: i64, chunk: Chunk) {
+        if reference_sequence_id >= self.reference_sequence_builders.len() {
+            let builder = reference_sequence::Builder::new(self.min_shift, self.depth);
+            self.reference_sequence_builders
+                .resize(reference_sequence_id + 1, builder);
+        }
+
+        self.reference_sequence_builders[reference_sequence_id].add_record(start, end, chunk);
+    }
+
+    /// Builds a coordinate-sorted index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::Index::indexer();
+    /// let index = indexer.build();
+    /// ```
+    pub fn build(self) -> Index {
+        let reference_sequences = self
+            .reference_sequence_builders
+            .into_iter()
+            .map(|b| b.build())
+            .collect();
+
+        Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth)
+            .set_reference_sequences(reference_sequences)
+            .build()
+    }
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_SHIFT, DEFAULT_DEPTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            1,
+            8,
+            13,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        );
+
+        let index = indexer.build();
+
+        assert_eq!(index.min_shift(), 14);
+        assert_eq!(index.depth(), 5);
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+        assert!(reference_sequences[0].bins().is_empty());
+        assert!(reference_sequences[0].metadata().is_none());
+        assert_eq!(reference_sequences[1].bins().len(), 1);
+        assert_eq!(reference_sequences[1].query(14, 5, 8, 13).len(), 1);
+    }
+}
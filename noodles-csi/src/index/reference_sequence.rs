@@ -1,10 +1,13 @@
 //! Coordinate-sorted index (CSI) reference sequence and fields.
 
 mod bin;
+mod builder;
 mod metadata;
 
 pub use self::{bin::Bin, metadata::Metadata};
 
+pub(crate) use self::builder::Builder;
+
 use bit_vec::BitVec;
 
 /// A CSI reference sequence.
@@ -79,7 +82,8 @@ impl ReferenceSequence {
         let max_bin_id = Bin::max_id(depth);
         let mut region_bins = BitVec::from_elem(max_bin_id as usize, false);
 
-        reg2bins(start, end, min_shift, depth, &mut region_bins);
+        // 0-based, [beg, end]
+        reg2bins(start - 1, end - 1, min_shift, depth, &mut region_bins);
 
         self.bins()
             .iter()
@@ -108,3 +112,30 @@ fn reg2bins(beg: i64, end: i64, min_shift: i32, depth: i32, bins: &mut BitVec) {
         l += 1;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf::{self as bgzf, index::Chunk};
+
+    use super::*;
+
+    #[test]
+    fn test_query() {
+        let chunks = vec![Chunk::new(
+            bgzf::VirtualPosition::from(8),
+            bgzf::VirtualPosition::from(13),
+        )];
+
+        // bin 4681 = [1, 16384]
+        let reference_sequence = ReferenceSequence::new(
+            vec![Bin::new(4681, bgzf::VirtualPosition::default(), chunks)],
+            None,
+        );
+
+        let query_bins = reference_sequence.query(14, 5, 16384, 16384);
+        assert_eq!(query_bins.len(), 1);
+
+        let query_bins = reference_sequence.query(14, 5, 16385, 32768);
+        assert!(query_bins.is_empty());
+    }
+}
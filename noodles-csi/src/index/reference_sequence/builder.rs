@@ -0,0 +1,286 @@
+use std::{cmp, collections::HashMap};
+
+use noodles_bgzf::{self as bgzf, index::Chunk};
+
+use super::{Bin, Metadata, ReferenceSequence};
+
+#[derive(Clone, Debug)]
+pub struct Builder {
+    min_shift: i32,
+    depth: i32,
+    bins: HashMap<u32, Vec<Chunk>>,
+    intervals: Vec<Option<bgzf::VirtualPosition>>,
+    start_position: Option<bgzf::VirtualPosition>,
+    end_position: bgzf::VirtualPosition,
+    mapped_record_count: u64,
+}
+
+impl Builder {
+    pub fn new(min_shift: i32, depth: i32) -> Self {
+        Self {
+            min_shift,
+            depth,
+            bins: HashMap::new(),
+            intervals: Vec::new(),
+            start_position: None,
+            end_position: bgzf::VirtualPosition::default(),
+            mapped_record_count: 0,
+        }
+    }
+
+    // `start` and `end` are 1-based, inclusive.
+    pub fn add_record(&mut self, start: i64, end: i64, chunk: Chunk) -> &mut Self {
+        // 0-based, [beg, end)
+        //
+        // Like htslib, positions before the start of the reference sequence (e.g., a telomeric
+        // VCF record at POS 0) are clamped to the first base.
+        let beg = cmp::max(start - 1, 0);
+        let end = cmp::max(end, 1);
+
+        self.update_bins(beg, end, chunk);
+        self.update_linear_index(beg, end, chunk);
+        self.update_metadata(chunk);
+
+        self
+    }
+
+    pub fn build(self) -> ReferenceSequence {
+        let start_position = match self.start_position {
+            Some(position) => position,
+            None => return ReferenceSequence::new(Vec::new(), None),
+        };
+
+        // Windows before the first record take the position of the first record, and gaps take
+        // the position of the previous window.
+        let mut intervals = Vec::with_capacity(self.intervals.len());
+        let mut last_position = start_position;
+
+        for interval in self.intervals {
+            if let Some(position) = interval {
+                last_position = position;
+            }
+
+            intervals.push(last_position);
+        }
+
+        let depth = self.depth;
+
+        let mut bins: Vec<_> = self
+            .bins
+            .into_iter()
+            .map(|(id, chunks)| {
+                let loffset = intervals
+                    .get(first_window(id, depth))
+                    .copied()
+                    .unwrap_or_default();
+
+                Bin::new(id, loffset, chunks)
+            })
+            .collect();
+
+        bins.sort_unstable_by_key(|bin| bin.id());
+
+        let metadata = Metadata::new(
+            start_position,
+            self.end_position,
+            self.mapped_record_count,
+            0,
+        );
+
+        ReferenceSequence::new(bins, Some(metadata))
+    }
+
+    fn update_bins(&mut self, beg: i64, end: i64, chunk: Chunk) {
+        let id = region_to_bin(beg, end, self.min_shift, self.depth);
+        let chunks = self.bins.entry(id).or_default();
+
+        if let Some(last_chunk) = chunks.last_mut() {
+            if chunk.start() <= last_chunk.end() {
+                *last_chunk = Chunk::new(last_chunk.start(), chunk.end());
+                return;
+            }
+        }
+
+        chunks.push(chunk);
+    }
+
+    fn update_linear_index(&mut self, beg: i64, end: i64, chunk: Chunk) {
+        let start_offset = (beg >> self.min_shift) as usize;
+        let end_offset = ((end - 1) >> self.min_shift) as usize;
+
+        if end_offset >= self.intervals.len() {
+            self.intervals.resize(end_offset + 1, None);
+        }
+
+        for interval in &mut self.intervals[start_offset..=end_offset] {
+            interval.get_or_insert(chunk.start());
+        }
+    }
+
+    fn update_metadata(&mut self, chunk: Chunk) {
+        self.mapped_record_count += 1;
+
+        self.start_position = Some(
+            self.start_position
+                .map(|position| cmp::min(position, chunk.start()))
+                .unwrap_or_else(|| chunk.start()),
+        );
+
+        self.end_position = cmp::max(self.end_position, chunk.end());
+    }
+}
+
+// `CSIv1.pdf` (2020-07-21)
+//
+// 0-based, [beg, end)
+fn region_to_bin(beg: i64, mut end: i64, min_shift: i32, depth: i32) -> u32 {
+    end -= 1;
+
+    let mut l = depth;
+    let mut s = min_shift;
+    let mut t = ((1 << (depth * 3)) - 1) / 7;
+
+    while l > 0 {
+        if beg >> s == end >> s {
+            return (t + (beg >> s)) as u32;
+        }
+
+        l -= 1;
+        s += 3;
+        t -= 1 << (l * 3);
+    }
+
+    0
+}
+
+// Returns the index of the first linear index window (of size 2^min_shift) covered by a bin.
+fn first_window(id: u32, depth: i32) -> usize {
+    let mut level = 0;
+    let mut first_id = 0;
+
+    while level < depth {
+        let next_first_id = first_id + (1 << (level * 3));
+
+        if id < next_first_id {
+            break;
+        }
+
+        first_id = next_first_id;
+        level += 1;
+    }
+
+    ((id - first_id) as usize) << ((depth - level) * 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let mut builder = Builder::new(14, 5);
+
+        builder.add_record(
+            8,
+            13,
+            Chunk::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(13),
+            ),
+        );
+
+        builder.add_record(
+            16,
+            21,
+            Chunk::new(
+                bgzf::VirtualPosition::from(13),
+                bgzf::VirtualPosition::from(21),
+            ),
+        );
+
+        builder.add_record(
+            121393,
+            196418,
+            Chunk::new(
+                bgzf::VirtualPosition::from(21),
+                bgzf::VirtualPosition::from(34),
+            ),
+        );
+
+        let actual = builder.build();
+
+        let expected = ReferenceSequence::new(
+            vec![
+                Bin::new(
+                    73,
+                    bgzf::VirtualPosition::from(5),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(21),
+                        bgzf::VirtualPosition::from(34),
+                    )],
+                ),
+                Bin::new(
+                    4681,
+                    bgzf::VirtualPosition::from(5),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(5),
+                        bgzf::VirtualPosition::from(21),
+                    )],
+                ),
+            ],
+            Some(Metadata::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(34),
+                3,
+                0,
+            )),
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_build_with_no_records() {
+        let reference_sequence = Builder::new(14, 5).build();
+        assert_eq!(reference_sequence, ReferenceSequence::new(Vec::new(), None));
+    }
+
+    #[test]
+    fn test_region_to_bin() {
+        // [8, 13]
+        assert_eq!(region_to_bin(7, 13, 14, 5), 4681);
+        // [63245986, 63245986]
+        assert_eq!(region_to_bin(63245985, 63255986, 14, 5), 8541);
+        // [1, 2^29]
+        assert_eq!(region_to_bin(0, 1 << 29, 14, 5), 0);
+    }
+
+    #[test]
+    fn test_first_window() {
+        assert_eq!(first_window(0, 5), 0);
+        assert_eq!(first_window(1, 5), 0);
+        assert_eq!(first_window(2, 5), 4096);
+        assert_eq!(first_window(73, 5), 0);
+        assert_eq!(first_window(4681, 5), 0);
+        assert_eq!(first_window(4682, 5), 1);
+    }
+
+    #[test]
+    fn test_build_with_position_0() {
+        let mut builder = Builder::new(14, 5);
+
+        builder.add_record(
+            0,
+            0,
+            Chunk::new(
+                bgzf::VirtualPosition::from(5),
+                bgzf::VirtualPosition::from(13),
+            ),
+        );
+
+        let reference_sequence = builder.build();
+        let bins = reference_sequence.bins();
+        assert_eq!(bins.len(), 1);
+        assert_eq!(bins[0].id(), 4681);
+    }
+}
@@ -0,0 +1,29 @@
+//! Queries an indexed FASTA file with a given region.
+//!
+//! The input FASTA must have an index (`<src>.fai`) in the same directory.
+//!
+//! This is similar to the output of `samtools faidx <src> <region>`.
+
+use std::{env, io};
+
+use noodles_fasta as fasta;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args();
+
+    let src = args.nth(1).expect("missing src");
+    let raw_region = args.next().expect("missing region");
+
+    let mut reader = fasta::IndexedReader::from_path(src)?;
+
+    let region = raw_region.parse()?;
+    let record = reader.query(&region)?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = fasta::Writer::new(handle);
+
+    writer.write_record(&record)?;
+
+    Ok(())
+}
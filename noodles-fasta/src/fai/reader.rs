@@ -1,5 +1,7 @@
 use std::io::{self, BufRead};
 
+use super::Index;
+
 /// A FASTA index reader.
 pub struct Reader<R> {
     inner: R,
@@ -53,4 +55,45 @@ where
         buf.pop();
         result
     }
+
+    /// Reads a FASTA index.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::fai;
+    ///
+    /// let data = b"sq0\t13\t5\t80\t81\nsq1\t21\t19\t80\t81\n";
+    /// let mut reader = fai::Reader::new(&data[..]);
+    /// let index = reader.read_index()?;
+    ///
+    /// assert_eq!(index, vec![
+    ///     fai::Record::new(String::from("sq0"), 13, 5, 80, 81),
+    ///     fai::Record::new(String::from("sq1"), 21, 19, 80, 81),
+    /// ]);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_index(&mut self) -> io::Result<Index> {
+        let mut buf = String::new();
+        let mut index = Vec::new();
+
+        loop {
+            buf.clear();
+
+            if self.read_record(&mut buf)? == 0 {
+                break;
+            }
+
+            let record = buf
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            index.push(record);
+        }
+
+        Ok(index)
+    }
 }
@@ -0,0 +1,404 @@
+//! Indexed FASTA reader.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use noodles_core::{path::push_extension, Region};
+
+use super::{
+    fai,
+    reader::{Reader, Records, NEWLINE},
+    record::{Definition, Sequence},
+    Record,
+};
+
+const FAI_EXTENSION: &str = "fai";
+const CARRIAGE_RETURN: u8 = b'\r';
+
+/// A FASTA reader with an associated FASTA index (FAI).
+///
+/// The index allows random access to subsequences of a FASTA file, similar to `samtools faidx`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_core::Region;
+/// use noodles_fasta as fasta;
+///
+/// let mut reader = fasta::IndexedReader::from_path("reference.fa")?;
+///
+/// let region = Region::mapped("sq0", 8, 13);
+/// let record = reader.query(&region)?;
+///
+/// println!("{}", record.reference_sequence_name());
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: fai::Index,
+}
+
+impl IndexedReader<BufReader<File>> {
+    /// Opens a FASTA file and its index.
+    ///
+    /// The index is discovered by appending `.fai` to the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let reader = fasta::IndexedReader::from_path("reference.fa")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_path<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index = fai::read(push_extension(src, FAI_EXTENSION))?;
+        let file = File::open(src).map(BufReader::new)?;
+
+        Ok(Self::new(file, index))
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an indexed FASTA reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let reader = fasta::IndexedReader::new(&data[..], index);
+    /// ```
+    pub fn new(inner: R, index: fai::Index) -> Self {
+        Self {
+            inner: Reader::new(inner),
+            index,
+        }
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let reader = fasta::IndexedReader::new(&data[..], index.clone());
+    ///
+    /// assert_eq!(reader.index(), &index[..]);
+    /// ```
+    pub fn index(&self) -> &[fai::Record] {
+        &self.index
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// See [`Reader::records`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let mut reader = fasta::IndexedReader::new(&data[..], index);
+    ///
+    /// assert_eq!(reader.records().count(), 1);
+    /// ```
+    pub fn records(&mut self) -> Records<'_, R> {
+        self.inner.records()
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead + Seek,
+{
+    /// Returns the subsequence of the given region.
+    ///
+    /// This seeks to the start of the region using the index offset and line widths and only
+    /// reads the requested bases. The name of the returned record is the region, e.g., `sq0:2-3`.
+    /// The region end is clamped to the length of the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_core::Region;
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+    ///     fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+    /// ];
+    ///
+    /// let mut reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let record = reader.query(&Region::mapped("sq0", 2, 3))?;
+    /// assert_eq!(record.reference_sequence_name(), "sq0:2-3");
+    /// assert_eq!(&record.sequence()[..], b"CG");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Record> {
+        let (index_record, start, end) = resolve_region(&self.index, region)?;
+
+        let mut sequence = Vec::new();
+        read_interval(&mut self.inner, index_record, start, end, &mut sequence)?;
+
+        let name = format!(
+            "{}:{}-{}",
+            index_record.reference_sequence_name(),
+            start,
+            end
+        );
+        let definition = Definition::new(name, None);
+
+        Ok(Record::new(definition, Sequence::from(sequence)))
+    }
+
+    /// Reads the bases of the given region into a buffer.
+    ///
+    /// The bases are appended to the buffer. Like [`Self::query`], only the bytes covering the
+    /// requested interval are read from the underlying stream, so chromosome-scale sequences are
+    /// never loaded in full. The region end is clamped to the length of the reference sequence.
+    ///
+    /// If successful, this returns the number of bases read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_core::Region;
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+    ///     fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+    /// ];
+    ///
+    /// let mut reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let mut buf = Vec::new();
+    /// reader.read_region(&Region::mapped("sq0", 3, 4), &mut buf)?;
+    /// reader.read_region(&Region::mapped("sq1", 4, 5), &mut buf)?;
+    ///
+    /// assert_eq!(buf, b"GTNN");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_region(&mut self, region: &Region, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let (index_record, start, end) = resolve_region(&self.index, region)?;
+        read_interval(&mut self.inner, index_record, start, end, buf)
+    }
+}
+
+// Returns the index record and the 1-based, closed interval of the given region, clamping the end
+// to the reference sequence length.
+fn resolve_region<'a>(
+    index: &'a [fai::Record],
+    region: &Region,
+) -> io::Result<(&'a fai::Record, u64, u64)> {
+    let (name, start, end) = match region {
+        Region::Mapped { name, start, end } => (name, *start, *end),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported region: {}", region),
+            ))
+        }
+    };
+
+    let index_record = index
+        .iter()
+        .find(|record| record.reference_sequence_name() == name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid reference sequence name: {}", name),
+            )
+        })?;
+
+    if start < 1 || end < start || start as u64 > index_record.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid region: {}", region),
+        ));
+    }
+
+    let start = start as u64;
+    let end = (end as u64).min(index_record.len());
+
+    Ok((index_record, start, end))
+}
+
+// Reads the bases of a 1-based, closed interval.
+//
+// This seeks to the byte offset of the start position and reads only the bytes up to and including
+// the end position, as calculated from the line bases and line width of the index record.
+fn read_interval<R>(
+    reader: &mut Reader<R>,
+    index_record: &fai::Record,
+    start: u64,
+    end: u64,
+    buf: &mut Vec<u8>,
+) -> io::Result<usize>
+where
+    R: BufRead + Seek,
+{
+    let start_pos = seek_position(index_record, start - 1)?;
+    let end_pos = seek_position(index_record, end - 1)? + 1;
+
+    reader.seek(SeekFrom::Start(start_pos))?;
+
+    let base_count = (end - start + 1) as usize;
+    buf.reserve(base_count);
+
+    let mut limited_reader = reader.get_mut().take(end_pos - start_pos);
+    let bases_read = read_bases(&mut limited_reader, buf)?;
+
+    if bases_read != base_count {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!("expected {} bases, read {} bases", base_count, bases_read),
+        ));
+    }
+
+    Ok(bases_read)
+}
+
+// Returns the byte offset of the given 0-based position in a sequence.
+fn seek_position(record: &fai::Record, pos: u64) -> io::Result<u64> {
+    let line_bases = record.line_bases();
+
+    if line_bases == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid index record: line bases = 0",
+        ));
+    }
+
+    Ok(record.offset() + pos / line_bases * record.line_width() + pos % line_bases)
+}
+
+// Reads all bases from the reader into the buffer, skipping line breaks.
+//
+// If successful, this returns the number of bases read.
+fn read_bases<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut bases_read = 0;
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            break;
+        }
+
+        for line in src.split(|&b| b == NEWLINE || b == CARRIAGE_RETURN) {
+            buf.extend_from_slice(line);
+            bases_read += line.len();
+        }
+
+        let len = src.len();
+        reader.consume(len);
+    }
+
+    Ok(bases_read)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_query() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\r\nNNNN\r\nACGT\r\nNN\r\n";
+
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 10, 16, 4, 6),
+        ];
+
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let record = reader.query(&Region::mapped("sq0", 1, 4))?;
+        assert_eq!(record.reference_sequence_name(), "sq0:1-4");
+        assert_eq!(&record.sequence()[..], b"ACGT");
+
+        let record = reader.query(&Region::mapped("sq1", 3, 10))?;
+        assert_eq!(record.reference_sequence_name(), "sq1:3-10");
+        assert_eq!(&record.sequence()[..], b"NNACGTNN");
+
+        let record = reader.query(&Region::mapped("sq1", 9, 13))?;
+        assert_eq!(record.reference_sequence_name(), "sq1:9-10");
+        assert_eq!(&record.sequence()[..], b"NN");
+
+        assert!(reader.query(&Region::mapped("sq2", 1, 4)).is_err());
+        assert!(reader.query(&Region::mapped("sq0", 5, 8)).is_err());
+        assert!(reader.query(&Region::All).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_region_only_reads_the_interval() -> io::Result<()> {
+        let data = b">sq0\nACGT\nACGT\nAC\n";
+        let index = vec![fai::Record::new(String::from("sq0"), 10, 5, 4, 5)];
+
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let mut buf = Vec::new();
+        let bases_read = reader.read_region(&Region::mapped("sq0", 3, 6), &mut buf)?;
+
+        assert_eq!(bases_read, 4);
+        assert_eq!(buf, b"GTAC");
+        assert_eq!(reader.inner.get_mut().position(), 12);
+
+        // The index claims more bases than the file has.
+        let index = vec![fai::Record::new(String::from("sq0"), 16, 5, 4, 5)];
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+        buf.clear();
+        assert!(reader
+            .read_region(&Region::mapped("sq0", 9, 16), &mut buf)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_position() -> io::Result<()> {
+        let record = fai::Record::new(String::from("sq0"), 10, 5, 4, 5);
+        assert_eq!(seek_position(&record, 0)?, 5);
+        assert_eq!(seek_position(&record, 3)?, 8);
+        assert_eq!(seek_position(&record, 4)?, 10);
+        assert_eq!(seek_position(&record, 9)?, 16);
+
+        let record = fai::Record::new(String::from("sq0"), 10, 5, 0, 0);
+        assert!(seek_position(&record, 0).is_err());
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 //! FASTA indexer.
 
 use std::{
+    collections::HashSet,
     error::Error,
     fmt,
     io::{self, BufRead},
@@ -14,11 +15,51 @@ use super::{
     record::definition::{Definition, ParseError},
 };
 
+const CARRIAGE_RETURN: u8 = b'\r';
+
 /// A FASTA indexer.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{fai, indexer::Indexer};
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+/// let mut indexer = Indexer::new(&data[..]);
+///
+/// let mut index = Vec::new();
+///
+/// while let Some(record) = indexer.index_record()? {
+///     index.push(record);
+/// }
+///
+/// assert_eq!(index, [
+///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+///     fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+/// ]);
+/// # Ok::<(), io::Error>(())
+/// ```
 pub struct Indexer<R> {
     inner: R,
     offset: u64,
+    line_number: u64,
     line_buf: Vec<u8>,
+    names: HashSet<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Line {
+    number: u64,
+    width: usize,
+    bases: usize,
+    ending: Option<LineEnding>,
 }
 
 impl<R> Indexer<R>
@@ -26,20 +67,30 @@ where
     R: BufRead,
 {
     /// Creates a FASTA indexer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::indexer::Indexer;
+    /// let data = b">sq0\nACGT\n";
+    /// let indexer = Indexer::new(&data[..]);
+    /// ```
     pub fn new(inner: R) -> Self {
         Self {
             inner,
             offset: 0,
+            line_number: 0,
             line_buf: Vec::new(),
+            names: HashSet::new(),
         }
     }
 
     /// Consumes a single sequence line.
     ///
-    /// If successful, this returns the number of bytes read from the stream (i.e., the line width)
-    /// and the number of bases in the line. If the number of bytes read is 0, the entire sequence
-    /// of the current record was read.
-    fn consume_sequence_line(&mut self) -> io::Result<(usize, usize)> {
+    /// If successful, this returns the line, which includes the number of bytes read from the
+    /// stream (i.e., the line width) and the number of bases in the line. If the number of bytes
+    /// read is 0, the entire sequence of the current record was read.
+    fn consume_sequence_line(&mut self) -> io::Result<Line> {
         self.line_buf.clear();
 
         let mut bytes_read = 0;
@@ -69,9 +120,24 @@ where
             bytes_read += len;
         }
 
-        let base_count = len_with_right_trim(&self.line_buf);
+        if bytes_read > 0 {
+            self.line_number += 1;
+        }
+
+        let ending = if self.line_buf.ends_with(&[CARRIAGE_RETURN, NEWLINE]) {
+            Some(LineEnding::CrLf)
+        } else if self.line_buf.ends_with(&[NEWLINE]) {
+            Some(LineEnding::Lf)
+        } else {
+            None
+        };
 
-        Ok((bytes_read, base_count))
+        Ok(Line {
+            number: self.line_number,
+            width: bytes_read,
+            bases: len_with_right_trim(&self.line_buf),
+            ending,
+        })
     }
 
     /// Indexes a raw FASTA record.
@@ -84,69 +150,181 @@ where
     /// An error is returned if the record fails to be completely read. This includes when
     ///
     ///   * the stream is not at the start of a definition;
+    ///   * the reference sequence name was already indexed;
     ///   * the record is missing a sequence;
-    ///   * the sequence lines have a different number of bases, excluding the last line;
+    ///   * the sequence has a blank line that is followed by more sequence lines;
+    ///   * the sequence lines mix LF and CRLF line endings;
+    ///   * the sequence lines have a different number of bases, excluding the last line, or the
+    ///     last line has more bases than the others;
     ///   * or the sequence lines are not the same length, excluding the last line.
+    ///
+    /// Errors include the reference sequence name and the 1-based line number where the problem
+    /// was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::indexer::{IndexError, Indexer};
+    ///
+    /// let data = b">sq0\nACGT\nACG\nACGT\n";
+    /// let mut indexer = Indexer::new(&data[..]);
+    ///
+    /// match indexer.index_record() {
+    ///     Err(IndexError::InvalidLineBases { name, line_number, expected, actual }) => {
+    ///         assert_eq!(name, "sq0");
+    ///         assert_eq!(line_number, 3);
+    ///         assert_eq!((expected, actual), (4, 3));
+    ///     }
+    ///     _ => panic!("expected invalid line bases"),
+    /// }
+    /// ```
     pub fn index_record(&mut self) -> Result<Option<Record>, IndexError> {
-        let definition = match self.read_definition() {
-            Ok(None) => return Ok(None),
-            Ok(Some(d)) => d,
-            Err(e) => return Err(e.into()),
+        let definition = match self.read_definition()? {
+            Some(d) => d,
+            None => return Ok(None),
         };
 
+        let name = definition.reference_sequence_name().to_string();
+
+        if !self.names.insert(name.clone()) {
+            return Err(IndexError::DuplicateName {
+                name,
+                line_number: self.line_number,
+            });
+        }
+
         let offset = self.offset;
         let mut length = 0;
 
-        let (line_width, line_bases) = self.consume_sequence_line()?;
-        let (mut prev_line_width, mut prev_line_bases) = (line_width, line_bases);
+        let mut first_line: Option<Line> = None;
+        let mut prev_line: Option<Line> = None;
+        let mut blank_line: Option<Line> = None;
 
         loop {
-            self.offset += prev_line_width as u64;
-            length += prev_line_bases;
-
-            match self.consume_sequence_line() {
-                Ok((0, _)) => break,
-                Ok((bytes_read, base_count)) => {
-                    if line_bases != prev_line_bases {
-                        return Err(IndexError::InvalidLineBases(line_bases, prev_line_bases));
-                    } else if line_width != prev_line_width {
-                        return Err(IndexError::InvalidLineWidth(line_width, prev_line_width));
-                    }
-
-                    prev_line_width = bytes_read;
-                    prev_line_bases = base_count;
+            let line = self.consume_sequence_line()?;
+
+            if line.width == 0 {
+                break;
+            }
+
+            self.offset += line.width as u64;
+
+            // Blank lines are allowed at the end of a record but not between sequence lines.
+            if line.bases == 0 {
+                blank_line.get_or_insert(line);
+                continue;
+            } else if let Some(blank_line) = blank_line {
+                return Err(IndexError::BlankLine {
+                    name,
+                    line_number: blank_line.number,
+                });
+            }
+
+            length += line.bases;
+
+            match (first_line, prev_line) {
+                (None, _) => first_line = Some(line),
+                (Some(first_line), Some(prev_line)) => {
+                    validate_line(&name, &first_line, &prev_line)?
                 }
-                Err(e) => return Err(IndexError::IoError(e)),
+                (Some(_), None) => {}
             }
+
+            prev_line = Some(line);
         }
 
-        if length == 0 {
-            return Err(IndexError::EmptySequence(self.offset));
+        let first_line = match first_line {
+            Some(line) => line,
+            None => {
+                return Err(IndexError::EmptySequence {
+                    name,
+                    offset: self.offset,
+                })
+            }
+        };
+
+        if let Some(last_line) = prev_line {
+            validate_last_line(&name, &first_line, &last_line)?;
         }
 
         let record = Record::new(
-            definition.reference_sequence_name().into(),
+            name,
             length as u64,
             offset,
-            line_bases as u64,
-            line_width as u64,
+            first_line.bases as u64,
+            first_line.width as u64,
         );
 
         Ok(Some(record))
     }
 
-    fn read_definition(&mut self) -> io::Result<Option<Definition>> {
+    fn read_definition(&mut self) -> Result<Option<Definition>, IndexError> {
         let mut buf = String::new();
 
-        match read_line(&mut self.inner, &mut buf) {
-            Ok(0) => return Ok(None),
-            Ok(n) => self.offset += n as u64,
-            Err(e) => return Err(e),
+        match read_line(&mut self.inner, &mut buf)? {
+            0 => return Ok(None),
+            n => {
+                self.offset += n as u64;
+                self.line_number += 1;
+            }
         }
 
         buf.parse()
             .map(Some)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            .map_err(|e| IndexError::InvalidDefinition {
+                line_number: self.line_number,
+                source: e,
+            })
+    }
+}
+
+// Validates a line that is not the last line of a sequence against the first line.
+fn validate_line(name: &str, first_line: &Line, line: &Line) -> Result<(), IndexError> {
+    validate_line_ending(name, first_line, line)?;
+
+    if line.bases != first_line.bases {
+        Err(IndexError::InvalidLineBases {
+            name: name.into(),
+            line_number: line.number,
+            expected: first_line.bases,
+            actual: line.bases,
+        })
+    } else if line.width != first_line.width {
+        Err(IndexError::InvalidLineWidth {
+            name: name.into(),
+            line_number: line.number,
+            expected: first_line.width,
+            actual: line.width,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// Validates the last line of a sequence against the first line. The last line may be shorter and
+// may be missing a line ending.
+fn validate_last_line(name: &str, first_line: &Line, line: &Line) -> Result<(), IndexError> {
+    validate_line_ending(name, first_line, line)?;
+
+    if line.bases > first_line.bases {
+        Err(IndexError::InvalidLineBases {
+            name: name.into(),
+            line_number: line.number,
+            expected: first_line.bases,
+            actual: line.bases,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_line_ending(name: &str, first_line: &Line, line: &Line) -> Result<(), IndexError> {
+    match (first_line.ending, line.ending) {
+        (Some(expected), Some(actual)) if expected != actual => Err(IndexError::MixedLineEndings {
+            name: name.into(),
+            line_number: line.number,
+        }),
+        _ => Ok(()),
     }
 }
 
@@ -157,23 +335,78 @@ fn len_with_right_trim(vec: &[u8]) -> usize {
     }
 }
 
+/// An error returned when a FASTA record fails to be indexed.
+///
+/// Line numbers are 1-based and count all lines read by the indexer, including definitions.
 #[derive(Debug)]
 pub enum IndexError {
-    EmptySequence(u64),
-    InvalidDefinition(ParseError),
-    InvalidLineBases(usize, usize),
-    InvalidLineWidth(usize, usize),
+    /// The reference sequence name was already indexed.
+    DuplicateName {
+        /// The reference sequence name.
+        name: String,
+        /// The line number of the duplicate definition.
+        line_number: u64,
+    },
+    /// The record is missing a sequence.
+    EmptySequence {
+        /// The reference sequence name.
+        name: String,
+        /// The offset of the stream after the record.
+        offset: u64,
+    },
+    /// The definition is invalid.
+    InvalidDefinition {
+        /// The line number of the definition.
+        line_number: u64,
+        /// The parse error.
+        source: ParseError,
+    },
+    /// The sequence has a blank line followed by more sequence lines.
+    BlankLine {
+        /// The reference sequence name.
+        name: String,
+        /// The line number of the blank line.
+        line_number: u64,
+    },
+    /// A sequence line has a line ending (LF or CRLF) that differs from the first line.
+    MixedLineEndings {
+        /// The reference sequence name.
+        name: String,
+        /// The line number of the offending line.
+        line_number: u64,
+    },
+    /// A sequence line has a different number of bases than the first line.
+    InvalidLineBases {
+        /// The reference sequence name.
+        name: String,
+        /// The line number of the offending line.
+        line_number: u64,
+        /// The number of bases in the first line.
+        expected: usize,
+        /// The number of bases in the offending line.
+        actual: usize,
+    },
+    /// A sequence line has a different width than the first line.
+    InvalidLineWidth {
+        /// The reference sequence name.
+        name: String,
+        /// The line number of the offending line.
+        line_number: u64,
+        /// The width of the first line.
+        expected: usize,
+        /// The width of the offending line.
+        actual: usize,
+    },
+    /// An I/O error.
     IoError(io::Error),
 }
 
 impl Error for IndexError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::EmptySequence(_) => None,
-            Self::InvalidDefinition(e) => Some(e),
-            Self::InvalidLineBases(..) => None,
-            Self::InvalidLineWidth(..) => None,
+            Self::InvalidDefinition { source, .. } => Some(source),
             Self::IoError(e) => Some(e),
+            _ => None,
         }
     }
 }
@@ -181,17 +414,45 @@ impl Error for IndexError {
 impl fmt::Display for IndexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::EmptySequence(offset) => write!(f, "empty sequence at offset {}", offset),
-            Self::InvalidDefinition(e) => e.fmt(f),
-            Self::InvalidLineBases(expected, actual) => write!(
+            Self::DuplicateName { name, line_number } => write!(
                 f,
-                "invalid line bases: expected {}, got {}",
-                expected, actual
+                "line {}: duplicate reference sequence name: {}",
+                line_number, name
             ),
-            Self::InvalidLineWidth(expected, actual) => write!(
+            Self::EmptySequence { name, offset } => {
+                write!(f, "{}: empty sequence at offset {}", name, offset)
+            }
+            Self::InvalidDefinition {
+                line_number,
+                source,
+            } => write!(f, "line {}: {}", line_number, source),
+            Self::BlankLine { name, line_number } => {
+                write!(f, "{}: line {}: blank line in sequence", name, line_number)
+            }
+            Self::MixedLineEndings { name, line_number } => write!(
+                f,
+                "{}: line {}: mixed line endings (LF and CRLF)",
+                name, line_number
+            ),
+            Self::InvalidLineBases {
+                name,
+                line_number,
+                expected,
+                actual,
+            } => write!(
                 f,
-                "invalid line width: expected {}, got {}",
-                expected, actual
+                "{}: line {}: invalid line bases: expected {}, got {}",
+                name, line_number, expected, actual
+            ),
+            Self::InvalidLineWidth {
+                name,
+                line_number,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}: line {}: invalid line width: expected {}, got {}",
+                name, line_number, expected, actual
             ),
             Self::IoError(e) => e.fmt(f),
         }
@@ -204,12 +465,6 @@ impl From<io::Error> for IndexError {
     }
 }
 
-impl From<ParseError> for IndexError {
-    fn from(error: ParseError) -> Self {
-        Self::InvalidDefinition(error)
-    }
-}
-
 impl From<IndexError> for io::Error {
     fn from(error: IndexError) -> Self {
         match error {
@@ -225,12 +480,20 @@ mod tests {
 
     #[test]
     fn test_consume_sequence_line() -> io::Result<()> {
-        let data = b"ACGT\nNNNN\n";
+        let data = b"ACGT\nNNNN\r\n";
         let mut indexer = Indexer::new(&data[..]);
 
-        let (bytes_read, base_count) = indexer.consume_sequence_line()?;
-        assert_eq!(bytes_read, 5);
-        assert_eq!(base_count, 4);
+        let line = indexer.consume_sequence_line()?;
+        assert_eq!(line.number, 1);
+        assert_eq!(line.width, 5);
+        assert_eq!(line.bases, 4);
+        assert_eq!(line.ending, Some(LineEnding::Lf));
+
+        let line = indexer.consume_sequence_line()?;
+        assert_eq!(line.number, 2);
+        assert_eq!(line.width, 6);
+        assert_eq!(line.bases, 4);
+        assert_eq!(line.ending, Some(LineEnding::CrLf));
 
         Ok(())
     }
@@ -251,6 +514,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_index_record_with_crlf_line_endings() -> Result<(), IndexError> {
+        let data = b">sq0\r\nACGT\r\nAC\r\n>sq1\r\nNNNN";
+        let mut indexer = Indexer::new(&data[..]);
+
+        let record = indexer.index_record()?;
+        assert_eq!(record, Some(Record::new(String::from("sq0"), 6, 6, 4, 6)));
+
+        let record = indexer.index_record()?;
+        assert_eq!(record, Some(Record::new(String::from("sq1"), 4, 22, 4, 4)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_record_with_duplicate_name() {
+        let data = b">sq0\nACGT\n>sq1\nACGT\n>sq0\nACGT\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        assert!(indexer.index_record().is_ok());
+        assert!(indexer.index_record().is_ok());
+
+        assert!(matches!(
+            indexer.index_record(),
+            Err(IndexError::DuplicateName { name, line_number: 5 }) if name == "sq0"
+        ));
+    }
+
+    #[test]
+    fn test_index_record_with_invalid_definition() {
+        let data = b"ACGT\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        assert!(matches!(
+            indexer.index_record(),
+            Err(IndexError::InvalidDefinition { line_number: 1, .. })
+        ));
+    }
+
     #[test]
     fn test_index_record_with_invalid_line_bases() {
         let data = b">sq0\nACGT\nACG\nACGT\nAC\n";
@@ -258,7 +560,25 @@ mod tests {
 
         assert!(matches!(
             indexer.index_record(),
-            Err(IndexError::InvalidLineBases(4, 3))
+            Err(IndexError::InvalidLineBases {
+                name,
+                line_number: 3,
+                expected: 4,
+                actual: 3,
+            }) if name == "sq0"
+        ));
+
+        let data = b">sq0\nACGT\nACGTA\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        assert!(matches!(
+            indexer.index_record(),
+            Err(IndexError::InvalidLineBases {
+                line_number: 3,
+                expected: 4,
+                actual: 5,
+                ..
+            })
         ));
     }
 
@@ -269,10 +589,50 @@ mod tests {
 
         assert!(matches!(
             indexer.index_record(),
-            Err(IndexError::InvalidLineWidth(5, 6))
+            Err(IndexError::InvalidLineWidth {
+                name,
+                line_number: 3,
+                expected: 5,
+                actual: 6,
+            }) if name == "sq0"
+        ));
+    }
+
+    #[test]
+    fn test_index_record_with_mixed_line_endings() {
+        let data = b">sq0\nACGT\nACGT\r\nAC\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        assert!(matches!(
+            indexer.index_record(),
+            Err(IndexError::MixedLineEndings { name, line_number: 3 }) if name == "sq0"
         ));
     }
 
+    #[test]
+    fn test_index_record_with_blank_line() -> Result<(), IndexError> {
+        let data = b">sq0\nACGT\nAC\n\n>sq1\nACGT\n\n\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        let record = indexer.index_record()?;
+        assert_eq!(record, Some(Record::new(String::from("sq0"), 6, 5, 4, 5)));
+
+        let record = indexer.index_record()?;
+        assert_eq!(record, Some(Record::new(String::from("sq1"), 4, 19, 4, 5)));
+
+        assert!(indexer.index_record()?.is_none());
+
+        let data = b">sq0\nACGT\n\nAC\n";
+        let mut indexer = Indexer::new(&data[..]);
+
+        assert!(matches!(
+            indexer.index_record(),
+            Err(IndexError::BlankLine { name, line_number: 3 }) if name == "sq0"
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_index_record_with_empty_seqeunce() {
         let data = b">sq0\n";
@@ -280,7 +640,7 @@ mod tests {
 
         assert!(matches!(
             indexer.index_record(),
-            Err(IndexError::EmptySequence(5))
+            Err(IndexError::EmptySequence { name, offset: 5 }) if name == "sq0"
         ));
     }
 
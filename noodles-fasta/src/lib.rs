@@ -53,16 +53,25 @@
 //! ```
 
 pub mod fai;
-mod indexer;
+mod indexed_reader;
+pub mod indexer;
 pub mod reader;
 pub mod record;
-mod writer;
+pub mod repository;
+#[cfg(feature = "sequence-dictionary")]
+pub mod sequence_dictionary;
+#[cfg(feature = "verify")]
+pub mod verify;
+pub mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, reader::Reader, record::Record, repository::Repository,
+    writer::Writer,
+};
 
 use std::{
     fs::File,
-    io::{self, BufReader},
+    io::{self, BufRead, BufReader},
     path::Path,
 };
 
@@ -82,7 +91,34 @@ pub fn index<P>(src: P) -> io::Result<fai::Index>
 where
     P: AsRef<Path>,
 {
-    let mut indexer = File::open(src).map(BufReader::new).map(Indexer::new)?;
+    File::open(src).map(BufReader::new).and_then(index_reader)
+}
+
+/// Indexes FASTA records from a reader.
+///
+/// This is useful for indexing in-memory or streamed references. Use [`indexer::Indexer`]
+/// directly to set indexing options.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, fai};
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+/// let index = fasta::index_reader(&data[..])?;
+///
+/// assert_eq!(index, [
+///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+///     fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+/// ]);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index_reader<R>(reader: R) -> io::Result<fai::Index>
+where
+    R: BufRead,
+{
+    let mut indexer = Indexer::new(reader);
     let mut index = Vec::new();
 
     while let Some(i) = indexer.index_record()? {
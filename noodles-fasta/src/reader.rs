@@ -36,6 +36,10 @@ where
         Self { inner }
     }
 
+    pub(crate) fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
     /// Reads a raw definition line.
     ///
     /// The given buffer will not include the trailing newline. It can subsequently be parsed as a
@@ -130,6 +134,82 @@ where
         Ok(bytes_read)
     }
 
+    /// Reads a chunk of a sequence.
+    ///
+    /// This fills the given buffer with the next bases of the current sequence, without newlines,
+    /// stopping when the buffer is full or when another definition or EOF is reached. Unlike
+    /// [`Self::read_sequence`], this allows a sequence to be processed in fixed-size pieces
+    /// without holding the entire sequence in memory.
+    ///
+    /// The position of the stream is expected to be at the start of a sequence, which is directly
+    /// after a definition, or directly after a previously read chunk.
+    ///
+    /// If successful, this returns the number of bases written to the buffer. If the number of
+    /// bases is 0 (and the buffer is not empty), the end of the sequence was reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let data = b">sq0\nNNNN\nNNNN\nNN\n>sq1\nACGT\n";
+    /// let mut reader = fasta::Reader::new(&data[..]);
+    /// reader.read_definition(&mut String::new())?;
+    ///
+    /// let mut buf = [0; 3];
+    /// let mut len = 0;
+    ///
+    /// loop {
+    ///     match reader.read_sequence_chunk(&mut buf)? {
+    ///         0 => break,
+    ///         n => len += n,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(len, 10);
+    ///
+    /// let mut definition = String::new();
+    /// reader.read_definition(&mut definition)?;
+    /// assert_eq!(definition, ">sq1");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_sequence_chunk(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut len = 0;
+
+        while len < buf.len() {
+            let reader_buf = self.inner.fill_buf()?;
+
+            if reader_buf.is_empty() || reader_buf[0] == DEFINITION_PREFIX {
+                break;
+            }
+
+            let (line, has_newline) = match memchr(NEWLINE, reader_buf) {
+                Some(i) => (&reader_buf[..i], true),
+                None => (reader_buf, false),
+            };
+
+            let mut i = 0;
+
+            while i < line.len() && len < buf.len() {
+                if line[i] != CARRIAGE_RETURN as u8 {
+                    buf[len] = line[i];
+                    len += 1;
+                }
+
+                i += 1;
+            }
+
+            if i == line.len() && has_newline {
+                i += 1;
+            }
+
+            self.inner.consume(i);
+        }
+
+        Ok(len)
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The position of the stream is expected to be at the start or at the start of another
@@ -146,12 +226,12 @@ where
     ///
     /// assert_eq!(records.next().transpose()?, Some(fasta::Record::new(
     ///     fasta::record::Definition::new(String::from("sq0"), None),
-    ///     b"ACGT".to_vec(),
+    ///     fasta::record::Sequence::from(b"ACGT".to_vec()),
     /// )));
     ///
     /// assert_eq!(records.next().transpose()?, Some(fasta::Record::new(
     ///     fasta::record::Definition::new(String::from("sq1"), None),
-    ///     b"NNNNNNNNNN".to_vec(),
+    ///     fasta::record::Sequence::from(b"NNNNNNNNNN".to_vec()),
     /// )));
     ///
     /// assert!(records.next().is_none());
@@ -284,6 +364,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_sequence_chunk() -> io::Result<()> {
+        let data = b"NNNN\r\nACGT\r\nNN\r\n>sq1\nACGT";
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = [0; 4];
+
+        assert_eq!(reader.read_sequence_chunk(&mut buf)?, 4);
+        assert_eq!(&buf[..], b"NNNN");
+
+        assert_eq!(reader.read_sequence_chunk(&mut buf[..3])?, 3);
+        assert_eq!(&buf[..3], b"ACG");
+
+        assert_eq!(reader.read_sequence_chunk(&mut buf)?, 3);
+        assert_eq!(&buf[..3], b"TNN");
+
+        assert_eq!(reader.read_sequence_chunk(&mut buf)?, 0);
+
+        let mut definition = String::new();
+        reader.read_definition(&mut definition)?;
+        assert_eq!(definition, ">sq1");
+
+        assert_eq!(reader.read_sequence_chunk(&mut buf)?, 4);
+        assert_eq!(&buf[..], b"ACGT");
+        assert_eq!(reader.read_sequence_chunk(&mut buf)?, 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_sequence_after_seek() {
         let data = b">sq0\nACGT\n>sq1\nNNNN\n";
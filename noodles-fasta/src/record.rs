@@ -1,17 +1,35 @@
-//! FASTA record and definition.
+//! FASTA record, definition, and sequence.
 
+mod builder;
 pub mod definition;
+pub mod sequence;
 
-pub use self::definition::Definition;
+pub use self::{
+    builder::{BuildError, Builder},
+    definition::Definition,
+    sequence::Sequence,
+};
 
 /// A FASTA record.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Record {
     definition: Definition,
-    sequence: Vec<u8>,
+    sequence: Sequence,
 }
 
 impl Record {
+    /// Returns a builder to create a record from each of its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::Record::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Creates a FASTA record from a definition and sequence.
     ///
     /// # Examples
@@ -19,10 +37,10 @@ impl Record {
     /// ```
     /// use noodles_fasta as fasta;
     /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
-    /// let sequence = b"ACGT".to_vec();
+    /// let sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
     /// let record = fasta::Record::new(definition, sequence);
     /// ```
-    pub fn new(definition: Definition, sequence: Vec<u8>) -> Self {
+    pub fn new(definition: Definition, sequence: Sequence) -> Self {
         Self {
             definition,
             sequence,
@@ -37,7 +55,7 @@ impl Record {
     /// use noodles_fasta as fasta;
     ///
     /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
-    /// let sequence = b"ACGT".to_vec();
+    /// let sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
     /// let record = fasta::Record::new(definition.clone(), sequence);
     ///
     /// assert_eq!(record.definition(), &definition);
@@ -54,7 +72,7 @@ impl Record {
     /// use noodles_fasta as fasta;
     ///
     /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
-    /// let sequence = b"ACGT".to_vec();
+    /// let sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
     /// let record = fasta::Record::new(definition, sequence);
     ///
     /// assert_eq!(record.reference_sequence_name(), "sq0");
@@ -63,6 +81,27 @@ impl Record {
         self.definition.reference_sequence_name()
     }
 
+    /// Returns the name.
+    ///
+    /// This is the first word of the definition line, i.e., the reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_fasta::record::definition::ParseError;
+    /// use noodles_fasta::{self as fasta, record::Sequence};
+    ///
+    /// let definition = ">chr1 AC:CM000663.2 LN:248956422".parse()?;
+    /// let record = fasta::Record::new(definition, Sequence::from(b"ACGT".to_vec()));
+    ///
+    /// assert_eq!(record.name(), "chr1");
+    /// assert_eq!(record.description(), Some("AC:CM000663.2 LN:248956422"));
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.definition.name()
+    }
+
     /// Returns the description.
     ///
     /// # Examples
@@ -75,7 +114,7 @@ impl Record {
     ///     Some(String::from("LN:4"))
     /// );
     ///
-    /// let sequence = b"ACGT".to_vec();
+    /// let sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
     /// let record = fasta::Record::new(definition, sequence);
     ///
     /// assert_eq!(record.description(), Some("LN:4"));
@@ -92,12 +131,16 @@ impl Record {
     /// use noodles_fasta as fasta;
     ///
     /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
-    /// let sequence = b"ACGT".to_vec();
+    /// let sequence = fasta::record::Sequence::from(b"ACGT".to_vec());
     /// let record = fasta::Record::new(definition, sequence);
     ///
-    /// assert_eq!(record.sequence(), b"ACGT");
+    /// assert_eq!(&record.sequence()[..], b"ACGT");
     /// ```
-    pub fn sequence(&self) -> &[u8] {
+    pub fn sequence(&self) -> &Sequence {
         &self.sequence
     }
+
+    pub(crate) fn into_sequence(self) -> Sequence {
+        self.sequence
+    }
 }
@@ -0,0 +1,160 @@
+use std::{error, fmt};
+
+use super::{Definition, Record, Sequence};
+
+/// A FASTA record builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    name: Option<String>,
+    description: Option<String>,
+    sequence: Sequence,
+}
+
+/// An error returned when a FASTA record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The name is missing.
+    MissingName,
+    /// The name is invalid.
+    ///
+    /// A name cannot be empty or contain whitespace.
+    InvalidName(String),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingName => f.write_str("missing name"),
+            Self::InvalidName(name) => write!(f, "invalid name: {:?}", name),
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let record = fasta::Record::builder().set_name("sq0").build()?;
+    /// assert_eq!(record.name(), "sq0");
+    /// # Ok::<(), fasta::record::BuildError>(())
+    /// ```
+    pub fn set_name<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    ///
+    /// let record = fasta::Record::builder()
+    ///     .set_name("sq0")
+    ///     .set_description("LN:4")
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.description(), Some("LN:4"));
+    /// # Ok::<(), fasta::record::BuildError>(())
+    /// ```
+    pub fn set_description<D>(mut self, description: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::Sequence};
+    ///
+    /// let sequence = Sequence::from(b"ACGT".to_vec());
+    ///
+    /// let record = fasta::Record::builder()
+    ///     .set_name("sq0")
+    ///     .set_sequence(sequence.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.sequence(), &sequence);
+    /// # Ok::<(), fasta::record::BuildError>(())
+    /// ```
+    pub fn set_sequence(mut self, sequence: Sequence) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Builds a FASTA record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    ///
+    /// let record = fasta::Record::builder().set_name("sq0").build()?;
+    /// assert_eq!(record.definition().raw(), ">sq0");
+    ///
+    /// assert_eq!(
+    ///     fasta::Record::builder().build(),
+    ///     Err(fasta::record::BuildError::MissingName)
+    /// );
+    /// # Ok::<(), fasta::record::BuildError>(())
+    /// ```
+    pub fn build(self) -> Result<Record, BuildError> {
+        let name = self.name.ok_or(BuildError::MissingName)?;
+
+        if name.is_empty() || name.contains(|c: char| c.is_ascii_whitespace()) {
+            return Err(BuildError::InvalidName(name));
+        }
+
+        let definition = Definition::new(name, self.description);
+
+        Ok(Record::new(definition, self.sequence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let record = Builder::default()
+            .set_name("sq0")
+            .set_description("LN:4")
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .build();
+
+        assert_eq!(
+            record,
+            Ok(Record::new(
+                Definition::new(String::from("sq0"), Some(String::from("LN:4"))),
+                Sequence::from(b"ACGT".to_vec()),
+            ))
+        );
+
+        assert_eq!(Builder::default().build(), Err(BuildError::MissingName));
+
+        assert_eq!(
+            Builder::default().set_name("").build(),
+            Err(BuildError::InvalidName(String::new()))
+        );
+
+        assert_eq!(
+            Builder::default().set_name("sq 0").build(),
+            Err(BuildError::InvalidName(String::from("sq 0")))
+        );
+    }
+}
@@ -1,17 +1,23 @@
 //! FASTA record definition and components.
 
-use std::{error, fmt, str::FromStr};
+use std::{error, fmt, ops::Range, str::FromStr};
 
 const PREFIX: char = '>';
 
 /// A FASTA record definition.
 ///
 /// A definition represents a definition line, i.e, a reference sequence name and, optionally, a
-/// description.
-#[derive(Clone, Debug, Eq, PartialEq)]
+/// description. For example, the definition `>sq0 LN:13` has the name `sq0` and the description
+/// `LN:13`.
+///
+/// The raw definition line is kept as is, which allows it to be written back out unchanged.
+/// Definitions are compared by their name and description, not their raw lines, e.g.,
+/// `>sq0  LN:13` equals `>sq0 LN:13`.
+#[derive(Clone, Debug)]
 pub struct Definition {
-    reference_sequence_name: String,
-    description: Option<String>,
+    raw: String,
+    name_end: usize,
+    description: Option<Range<usize>>,
 }
 
 impl Definition {
@@ -24,8 +30,22 @@ impl Definition {
     /// let definition = Definition::new(String::from("sq0"), None);
     /// ```
     pub fn new(reference_sequence_name: String, description: Option<String>) -> Self {
+        let mut raw = String::with_capacity(1 + reference_sequence_name.len());
+        raw.push(PREFIX);
+        raw.push_str(&reference_sequence_name);
+
+        let name_end = raw.len();
+
+        let description = description.map(|d| {
+            raw.push(' ');
+            let start = raw.len();
+            raw.push_str(&d);
+            start..raw.len()
+        });
+
         Self {
-            reference_sequence_name,
+            raw,
+            name_end,
             description,
         }
     }
@@ -40,14 +60,35 @@ impl Definition {
     /// assert_eq!(definition.reference_sequence_name(), "sq0");
     /// ```
     pub fn reference_sequence_name(&self) -> &str {
-        &self.reference_sequence_name
+        &self.raw[PREFIX.len_utf8()..self.name_end]
+    }
+
+    /// Returns the name.
+    ///
+    /// This is the first word of the definition line, i.e., the reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_fasta::record::definition::ParseError;
+    /// use noodles_fasta::record::Definition;
+    /// let definition: Definition = ">chr1 AC:CM000663.2 LN:248956422".parse()?;
+    /// assert_eq!(definition.name(), "chr1");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.reference_sequence_name()
     }
 
     /// Returns the description if it is set.
     ///
+    /// The description is the remainder of the definition line after the name, with surrounding
+    /// whitespace removed.
+    ///
     /// # Examples
     ///
     /// ```
+    /// # use noodles_fasta::record::definition::ParseError;
     /// use noodles_fasta::record::Definition;
     ///
     /// let definition = Definition::new(String::from("sq0"), None);
@@ -55,21 +96,49 @@ impl Definition {
     ///
     /// let definition = Definition::new(String::from("sq0"), Some(String::from("LN:13")));
     /// assert_eq!(definition.description(), Some("LN:13"));
+    ///
+    /// let definition: Definition = ">chr1 AC:CM000663.2 LN:248956422".parse()?;
+    /// assert_eq!(definition.description(), Some("AC:CM000663.2 LN:248956422"));
+    /// # Ok::<(), ParseError>(())
     /// ```
     pub fn description(&self) -> Option<&str> {
-        self.description.as_deref()
+        self.description.clone().map(|range| &self.raw[range])
+    }
+
+    /// Returns the raw definition line.
+    ///
+    /// This includes the prefix (`>`) but not the trailing newline. For a parsed definition, it is
+    /// the line as read, including any extra whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_fasta::record::definition::ParseError;
+    /// use noodles_fasta::record::Definition;
+    ///
+    /// let definition = Definition::new(String::from("sq0"), Some(String::from("LN:13")));
+    /// assert_eq!(definition.raw(), ">sq0 LN:13");
+    ///
+    /// let definition: Definition = ">sq0\tLN:13".parse()?;
+    /// assert_eq!(definition.raw(), ">sq0\tLN:13");
+    /// # Ok::<(), ParseError>(())
+    /// ```
+    pub fn raw(&self) -> &str {
+        &self.raw
     }
 }
 
-impl fmt::Display for Definition {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", PREFIX, self.reference_sequence_name())?;
+impl PartialEq for Definition {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.description() == other.description()
+    }
+}
 
-        if let Some(description) = self.description() {
-            write!(f, " {}", description)?;
-        }
+impl Eq for Definition {}
 
-        Ok(())
+impl fmt::Display for Definition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.raw())
     }
 }
 
@@ -106,18 +175,31 @@ impl FromStr for Definition {
             return Err(ParseError::MissingPrefix);
         }
 
-        let line = &s[1..];
-        let mut components = line.splitn(2, |c: char| c.is_ascii_whitespace());
+        let line = &s[PREFIX.len_utf8()..];
+
+        let name_len = line
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(line.len());
 
-        let reference_sequence_name = components
-            .next()
-            .and_then(|s| if s.is_empty() { None } else { Some(s.into()) })
-            .ok_or(ParseError::MissingReferenceSequenceName)?;
+        if name_len == 0 {
+            return Err(ParseError::MissingReferenceSequenceName);
+        }
+
+        let name_end = PREFIX.len_utf8() + name_len;
 
-        let description = components.next().map(|s| s.trim().into());
+        let description = if name_end < s.len() {
+            let rest = &s[name_end..];
+            let trimmed = rest.trim_start();
+            let start = s.len() - trimmed.len();
+            let end = start + trimmed.trim_end().len();
+            Some(start..end)
+        } else {
+            None
+        };
 
         Ok(Self {
-            reference_sequence_name,
+            raw: s.into(),
+            name_end,
             description,
         })
     }
@@ -134,6 +216,29 @@ mod tests {
 
         let definition = Definition::new(String::from("sq0"), Some(String::from("LN:13")));
         assert_eq!(definition.to_string(), ">sq0 LN:13");
+
+        let definition: Definition = ">sq0\tLN:13".parse().unwrap();
+        assert_eq!(definition.to_string(), ">sq0\tLN:13");
+    }
+
+    #[test]
+    fn test_eq() -> Result<(), ParseError> {
+        assert_eq!(
+            ">sq0  LN:13".parse::<Definition>()?,
+            Definition::new(String::from("sq0"), Some(String::from("LN:13")))
+        );
+
+        assert_eq!(
+            ">sq0".parse::<Definition>()?,
+            Definition::new(String::from("sq0"), None)
+        );
+
+        assert_ne!(
+            ">sq0 LN:13".parse::<Definition>()?,
+            Definition::new(String::from("sq0"), None)
+        );
+
+        Ok(())
     }
 
     #[test]
@@ -145,6 +250,11 @@ mod tests {
         let definition: Definition = ">sq0  LN:13".parse()?;
         assert_eq!(definition.reference_sequence_name(), "sq0");
         assert_eq!(definition.description(), Some("LN:13"));
+        assert_eq!(definition.raw(), ">sq0  LN:13");
+
+        let definition: Definition = ">sq0 ".parse()?;
+        assert_eq!(definition.name(), "sq0");
+        assert_eq!(definition.description(), Some(""));
 
         assert_eq!("".parse::<Definition>(), Err(ParseError::Empty));
         assert_eq!("sq0".parse::<Definition>(), Err(ParseError::MissingPrefix));
@@ -0,0 +1,427 @@
+//! FASTA record sequence.
+
+use std::{error, fmt, ops::Deref};
+
+/// A FASTA record sequence.
+///
+/// A sequence is a list of raw bases. Bases are not validated on construction, as FASTA files
+/// commonly include soft-masked (lowercase) and ambiguous bases. Use [`Self::validate`] to check
+/// that a sequence only contains IUPAC nucleotide codes.
+///
+/// The sequence dereferences to a byte slice, which provides length helpers, e.g., `len` and
+/// `is_empty`, and 0-based indexing.
+///
+/// Lowercase bases denote soft-masked regions, e.g., repeats. Sequence operations, e.g.,
+/// [`Self::slice`] and [`Self::reverse_complement`], preserve case, and soft masking can be
+/// changed explicitly using [`Self::mask`] and [`Self::unmask`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sequence(Vec<u8>);
+
+/// An error returned when a sequence has an invalid base.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidBaseError {
+    position: usize,
+    base: u8,
+}
+
+impl InvalidBaseError {
+    /// Returns the 1-based position of the invalid base.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the invalid base.
+    pub fn base(&self) -> u8 {
+        self.base
+    }
+}
+
+impl error::Error for InvalidBaseError {}
+
+impl fmt::Display for InvalidBaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid base at position {}: {:?}",
+            self.position,
+            char::from(self.base)
+        )
+    }
+}
+
+/// An error returned when an interval is out of bounds of a sequence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidIntervalError {
+    start: usize,
+    end: usize,
+}
+
+impl InvalidIntervalError {
+    /// Returns the 1-based start position of the invalid interval.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the 1-based end position of the invalid interval.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+impl error::Error for InvalidIntervalError {}
+
+impl fmt::Display for InvalidIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid interval: {}-{}", self.start, self.end)
+    }
+}
+
+impl Sequence {
+    /// Validates the bases of the sequence.
+    ///
+    /// Valid bases are IUPAC nucleotide codes (`ACGTUNRYKMSWBDHV`), in either case, and the gap
+    /// characters `-` and `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACGTNacgtn".to_vec());
+    /// assert!(sequence.validate().is_ok());
+    ///
+    /// let sequence = Sequence::from(b"AC!T".to_vec());
+    /// let e = sequence.validate().unwrap_err();
+    /// assert_eq!(e.position(), 3);
+    /// assert_eq!(e.base(), b'!');
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidBaseError> {
+        for (i, &base) in self.0.iter().enumerate() {
+            if !is_valid_base(base) {
+                return Err(InvalidBaseError {
+                    position: i + 1,
+                    base,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the subsequence of the given 1-based, closed interval.
+    ///
+    /// This returns `None` if the interval is empty or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACGT".to_vec());
+    ///
+    /// assert_eq!(sequence.slice(2, 3), Some(Sequence::from(b"CG".to_vec())));
+    /// assert_eq!(sequence.slice(0, 2), None);
+    /// assert_eq!(sequence.slice(3, 5), None);
+    /// ```
+    pub fn slice(&self, start: usize, end: usize) -> Option<Sequence> {
+        if start < 1 || end < start {
+            return None;
+        }
+
+        self.0.get(start - 1..end).map(|bases| Self(bases.to_vec()))
+    }
+
+    /// Soft masks the bases in the given 1-based, closed interval.
+    ///
+    /// Masked bases are converted to lowercase. All other bases are left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_fasta::record::sequence::InvalidIntervalError;
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let mut sequence = Sequence::from(b"ACGTACGT".to_vec());
+    /// sequence.mask(3, 6)?;
+    /// assert_eq!(sequence, Sequence::from(b"ACgtacGT".to_vec()));
+    ///
+    /// assert!(sequence.mask(7, 9).is_err());
+    /// # Ok::<(), InvalidIntervalError>(())
+    /// ```
+    pub fn mask(&mut self, start: usize, end: usize) -> Result<(), InvalidIntervalError> {
+        let bases = if start < 1 || end < start {
+            None
+        } else {
+            self.0.get_mut(start - 1..end)
+        };
+
+        bases
+            .map(|bases| bases.make_ascii_lowercase())
+            .ok_or(InvalidIntervalError { start, end })
+    }
+
+    /// Removes all soft masking from the sequence.
+    ///
+    /// All bases are converted to uppercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let mut sequence = Sequence::from(b"ACgtnN".to_vec());
+    /// sequence.unmask();
+    /// assert_eq!(sequence, Sequence::from(b"ACGTNN".to_vec()));
+    /// ```
+    pub fn unmask(&mut self) {
+        self.0.make_ascii_uppercase();
+    }
+
+    /// Returns whether the base at the given 1-based position is soft masked.
+    ///
+    /// A base is soft masked if it is lowercase. This returns `None` if the position is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACgt".to_vec());
+    ///
+    /// assert_eq!(sequence.is_soft_masked(2), Some(false));
+    /// assert_eq!(sequence.is_soft_masked(3), Some(true));
+    /// assert_eq!(sequence.is_soft_masked(5), None);
+    /// ```
+    pub fn is_soft_masked(&self, position: usize) -> Option<bool> {
+        position
+            .checked_sub(1)
+            .and_then(|i| self.0.get(i))
+            .map(|base| base.is_ascii_lowercase())
+    }
+
+    /// Returns the complement of the sequence.
+    ///
+    /// IUPAC nucleotide codes are complemented, preserving case. All other bases, e.g., `N` or
+    /// gaps, are left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"ACGTNacgtn".to_vec());
+    /// assert_eq!(sequence.complement(), Sequence::from(b"TGCANtgcan".to_vec()));
+    /// ```
+    pub fn complement(&self) -> Sequence {
+        self.0.iter().copied().map(complement_base).collect()
+    }
+
+    /// Returns the reverse complement of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"AACGTN".to_vec());
+    /// assert_eq!(sequence.reverse_complement(), Sequence::from(b"NACGTT".to_vec()));
+    /// ```
+    pub fn reverse_complement(&self) -> Sequence {
+        self.0.iter().rev().copied().map(complement_base).collect()
+    }
+
+    /// Returns the GC content of the sequence.
+    ///
+    /// This is the fraction of `G` and `C` bases over all unambiguous (`A`, `C`, `G`, `T`) bases,
+    /// in either case. If the sequence has no unambiguous bases, this returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACGgNN".to_vec());
+    /// assert_eq!(sequence.gc_content(), Some(0.75));
+    ///
+    /// let sequence = Sequence::from(b"NN".to_vec());
+    /// assert_eq!(sequence.gc_content(), None);
+    /// ```
+    pub fn gc_content(&self) -> Option<f64> {
+        let mut gc_count = 0;
+        let mut base_count = 0;
+
+        for base in &self.0 {
+            match base.to_ascii_uppercase() {
+                b'G' | b'C' => {
+                    gc_count += 1;
+                    base_count += 1;
+                }
+                b'A' | b'T' => base_count += 1,
+                _ => {}
+            }
+        }
+
+        if base_count == 0 {
+            None
+        } else {
+            Some(gc_count as f64 / base_count as f64)
+        }
+    }
+}
+
+impl Deref for Sequence {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Sequence {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Sequence {
+    fn from(bases: Vec<u8>) -> Self {
+        Self(bases)
+    }
+}
+
+impl From<Sequence> for Vec<u8> {
+    fn from(sequence: Sequence) -> Self {
+        sequence.0
+    }
+}
+
+impl std::iter::FromIterator<u8> for Sequence {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+fn is_valid_base(base: u8) -> bool {
+    matches!(
+        base.to_ascii_uppercase(),
+        b'A' | b'C'
+            | b'G'
+            | b'T'
+            | b'U'
+            | b'N'
+            | b'R'
+            | b'Y'
+            | b'K'
+            | b'M'
+            | b'S'
+            | b'W'
+            | b'B'
+            | b'D'
+            | b'H'
+            | b'V'
+            | b'-'
+            | b'*'
+    )
+}
+
+fn complement_base(base: u8) -> u8 {
+    let complement = match base.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' | b'U' => b'A',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'K' => b'M',
+        b'M' => b'K',
+        b'B' => b'V',
+        b'V' => b'B',
+        b'D' => b'H',
+        b'H' => b'D',
+        _ => return base,
+    };
+
+    if base.is_ascii_lowercase() {
+        complement.to_ascii_lowercase()
+    } else {
+        complement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(Sequence::default().validate().is_ok());
+        assert!(Sequence::from(b"ACGTURYKMSWBDHVN-*".to_vec())
+            .validate()
+            .is_ok());
+        assert!(Sequence::from(b"acgturykmswbdhvn".to_vec())
+            .validate()
+            .is_ok());
+
+        assert_eq!(
+            Sequence::from(b"ACXT".to_vec()).validate(),
+            Err(InvalidBaseError {
+                position: 3,
+                base: b'X'
+            })
+        );
+    }
+
+    #[test]
+    fn test_complement_base() {
+        for (base, expected) in b"ACGTURYKMSWBDHVN-".iter().zip(b"TGCAAYRMKSWVHDBN-") {
+            assert_eq!(complement_base(*base), *expected);
+            assert_eq!(
+                complement_base(base.to_ascii_lowercase()),
+                expected.to_ascii_lowercase()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mask() {
+        let mut sequence = Sequence::from(b"ACGTN".to_vec());
+
+        assert!(sequence.mask(1, 1).is_ok());
+        assert!(sequence.mask(4, 5).is_ok());
+        assert_eq!(sequence, Sequence::from(b"aCGtn".to_vec()));
+
+        assert_eq!(
+            sequence.mask(0, 1),
+            Err(InvalidIntervalError { start: 0, end: 1 })
+        );
+        assert_eq!(
+            sequence.mask(3, 2),
+            Err(InvalidIntervalError { start: 3, end: 2 })
+        );
+        assert_eq!(
+            sequence.mask(5, 6),
+            Err(InvalidIntervalError { start: 5, end: 6 })
+        );
+        assert_eq!(sequence, Sequence::from(b"aCGtn".to_vec()));
+
+        assert_eq!(sequence.is_soft_masked(0), None);
+        assert_eq!(sequence.is_soft_masked(1), Some(true));
+
+        let reverse_complement = sequence.reverse_complement();
+        assert_eq!(reverse_complement, Sequence::from(b"naCGt".to_vec()));
+
+        sequence.unmask();
+        assert_eq!(sequence, Sequence::from(b"ACGTN".to_vec()));
+    }
+
+    #[test]
+    fn test_slice() {
+        let sequence = Sequence::from(b"ACGT".to_vec());
+
+        assert_eq!(sequence.slice(1, 4), Some(sequence.clone()));
+        assert_eq!(sequence.slice(4, 4), Some(Sequence::from(b"T".to_vec())));
+        assert_eq!(sequence.slice(3, 2), None);
+        assert_eq!(sequence.slice(5, 5), None);
+    }
+}
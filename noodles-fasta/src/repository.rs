@@ -0,0 +1,447 @@
+//! FASTA reference sequence repository.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::TryFrom,
+    io::{self, BufRead, Seek},
+    sync::Arc,
+};
+
+use noodles_core::Region;
+
+use super::{record::Sequence, IndexedReader, Record};
+
+/// A reference sequence repository.
+///
+/// A repository provides sequences by reference sequence name. It is the common interface for
+/// consumers that need reference bases, e.g., CRAM decoding or MD tag calculation, regardless of
+/// whether the sequences are held in memory or read on demand.
+pub trait Repository {
+    /// Returns the sequence of the given reference sequence name.
+    ///
+    /// This returns `None` if the repository does not have the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{
+    ///     self as fasta,
+    ///     record::{Definition, Sequence},
+    ///     repository::InMemoryRepository,
+    ///     Repository,
+    /// };
+    ///
+    /// let mut repository = InMemoryRepository::new(vec![fasta::Record::new(
+    ///     Definition::new(String::from("sq0"), None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )]);
+    ///
+    /// let sequence = repository.get("sq0").transpose()?;
+    /// assert_eq!(sequence.as_deref(), Some(&Sequence::from(b"ACGT".to_vec())));
+    ///
+    /// assert!(repository.get("sq1").is_none());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    fn get(&mut self, name: &str) -> Option<io::Result<Arc<Sequence>>>;
+
+    /// Returns the subsequence of the given reference sequence name and 1-based, closed interval.
+    ///
+    /// This returns `None` if the repository does not have the reference sequence. It returns an
+    /// error if the interval is out of bounds.
+    ///
+    /// The default implementation slices the sequence returned by [`Self::get`]. Repositories that
+    /// read sequences on demand should override it to read only the requested interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{
+    ///     self as fasta,
+    ///     record::{Definition, Sequence},
+    ///     repository::InMemoryRepository,
+    ///     Repository,
+    /// };
+    ///
+    /// let mut repository = InMemoryRepository::new(vec![fasta::Record::new(
+    ///     Definition::new(String::from("sq0"), None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// )]);
+    ///
+    /// let subsequence = repository.get_subsequence("sq0", 2, 3).transpose()?;
+    /// assert_eq!(subsequence, Some(Sequence::from(b"CG".to_vec())));
+    ///
+    /// assert!(repository.get_subsequence("sq0", 3, 5).transpose().is_err());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    fn get_subsequence(
+        &mut self,
+        name: &str,
+        start: usize,
+        end: usize,
+    ) -> Option<io::Result<Sequence>> {
+        self.get(name).map(|result| {
+            result.and_then(|sequence| {
+                sequence.slice(start, end).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid interval: {}:{}-{}", name, start, end),
+                    )
+                })
+            })
+        })
+    }
+}
+
+/// A repository of sequences held in memory.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{
+///     self as fasta,
+///     record::{Definition, Sequence},
+///     repository::InMemoryRepository,
+///     Repository,
+/// };
+///
+/// let mut repository = InMemoryRepository::new(vec![fasta::Record::new(
+///     Definition::new(String::from("sq0"), None),
+///     Sequence::from(b"ACGT".to_vec()),
+/// )]);
+///
+/// assert!(repository.get("sq0").is_some());
+/// # Ok::<(), io::Error>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryRepository {
+    sequences: HashMap<String, Arc<Sequence>>,
+}
+
+impl InMemoryRepository {
+    /// Creates an in-memory repository from a list of records.
+    ///
+    /// If there are multiple records with the same reference sequence name, the last one is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::InMemoryRepository;
+    /// let repository = InMemoryRepository::new(Vec::new());
+    /// ```
+    pub fn new(records: Vec<Record>) -> Self {
+        let sequences = records
+            .into_iter()
+            .map(|record| {
+                let name = record.reference_sequence_name().into();
+                (name, Arc::new(record.into_sequence()))
+            })
+            .collect();
+
+        Self { sequences }
+    }
+}
+
+impl From<Vec<Record>> for InMemoryRepository {
+    fn from(records: Vec<Record>) -> Self {
+        Self::new(records)
+    }
+}
+
+impl Repository for InMemoryRepository {
+    fn get(&mut self, name: &str) -> Option<io::Result<Arc<Sequence>>> {
+        self.sequences.get(name).cloned().map(Ok)
+    }
+}
+
+/// A repository that reads sequences from an indexed FASTA reader and caches them in memory.
+///
+/// A whole reference sequence is read from the underlying reader on the first call to
+/// [`Repository::get`], and subsequent requests for the same reference sequence are served from
+/// the cache. Subsequences of uncached reference sequences are read directly from the reader and
+/// are not cached.
+///
+/// By default, the cache is unbounded. Use [`Self::with_cache_limit`] to limit the number of
+/// cached sequences.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, repository::CachingRepository, Repository};
+///
+/// let reader = fasta::IndexedReader::from_path("reference.fa")?;
+/// let mut repository = CachingRepository::new(reader);
+///
+/// if let Some(sequence) = repository.get("sq0").transpose()? {
+///     println!("{}", sequence.len());
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct CachingRepository<R> {
+    reader: IndexedReader<R>,
+    cache: HashMap<String, Arc<Sequence>>,
+    cache_order: VecDeque<String>,
+    cache_limit: Option<usize>,
+}
+
+impl<R> CachingRepository<R>
+where
+    R: BufRead + Seek,
+{
+    /// Creates a caching repository over an indexed FASTA reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use noodles_fasta::{self as fasta, fai, repository::CachingRepository};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let repository = CachingRepository::new(reader);
+    /// ```
+    pub fn new(reader: IndexedReader<R>) -> Self {
+        Self {
+            reader,
+            cache: HashMap::new(),
+            cache_order: VecDeque::new(),
+            cache_limit: None,
+        }
+    }
+
+    /// Creates a caching repository that holds at most the given number of sequences.
+    ///
+    /// When the cache is full, the sequence that was cached first is evicted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use noodles_fasta::{self as fasta, fai, repository::CachingRepository};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let repository = CachingRepository::with_cache_limit(reader, 1);
+    /// ```
+    pub fn with_cache_limit(reader: IndexedReader<R>, cache_limit: usize) -> Self {
+        Self {
+            cache_limit: Some(cache_limit),
+            ..Self::new(reader)
+        }
+    }
+
+    /// Removes all cached sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fasta::{self as fasta, fai, repository::CachingRepository, Repository};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+    /// let reader = fasta::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let mut repository = CachingRepository::new(reader);
+    /// repository.get("sq0").transpose()?;
+    /// repository.clear();
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.cache_order.clear();
+    }
+
+    fn insert(&mut self, name: &str, sequence: Arc<Sequence>) {
+        if let Some(limit) = self.cache_limit {
+            if limit == 0 {
+                return;
+            }
+
+            while self.cache.len() >= limit {
+                match self.cache_order.pop_front() {
+                    Some(evicted_name) => {
+                        self.cache.remove(&evicted_name);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        self.cache.insert(name.into(), sequence);
+        self.cache_order.push_back(name.into());
+    }
+
+    fn reference_sequence_len(&self, name: &str) -> Option<u64> {
+        self.reader
+            .index()
+            .iter()
+            .find(|record| record.reference_sequence_name() == name)
+            .map(|record| record.len())
+    }
+}
+
+impl<R> Repository for CachingRepository<R>
+where
+    R: BufRead + Seek,
+{
+    fn get(&mut self, name: &str) -> Option<io::Result<Arc<Sequence>>> {
+        if let Some(sequence) = self.cache.get(name) {
+            return Some(Ok(Arc::clone(sequence)));
+        }
+
+        let len = self.reference_sequence_len(name)?;
+
+        if len == 0 {
+            let sequence = Arc::new(Sequence::default());
+            self.insert(name, Arc::clone(&sequence));
+            return Some(Ok(sequence));
+        }
+
+        let end = match i32::try_from(len) {
+            Ok(n) => n,
+            Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+        };
+
+        let region = Region::mapped(name, 1, end);
+
+        let result = self.reader.query(&region).map(|record| {
+            let sequence = Arc::new(record.into_sequence());
+            self.insert(name, Arc::clone(&sequence));
+            sequence
+        });
+
+        Some(result)
+    }
+
+    fn get_subsequence(
+        &mut self,
+        name: &str,
+        start: usize,
+        end: usize,
+    ) -> Option<io::Result<Sequence>> {
+        let invalid_interval = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid interval: {}:{}-{}", name, start, end),
+            )
+        };
+
+        if let Some(sequence) = self.cache.get(name) {
+            return Some(sequence.slice(start, end).ok_or_else(invalid_interval));
+        }
+
+        let len = self.reference_sequence_len(name)?;
+
+        if start < 1 || end < start || end as u64 > len {
+            return Some(Err(invalid_interval()));
+        }
+
+        let (start, end) = match (i32::try_from(start), i32::try_from(end)) {
+            (Ok(start), Ok(end)) => (start, end),
+            _ => return Some(Err(invalid_interval())),
+        };
+
+        let region = Region::mapped(name, start, end);
+        let result = self
+            .reader
+            .query(&region)
+            .map(|record| record.into_sequence());
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::fai;
+
+    use super::*;
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+        ];
+        let reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let mut repository = CachingRepository::new(reader);
+
+        let sequence = repository.get("sq1").transpose()?;
+        assert_eq!(
+            sequence.as_deref(),
+            Some(&Sequence::from(b"NNNNNNNNNN".to_vec()))
+        );
+        assert_eq!(repository.cache.len(), 1);
+
+        let subsequence = repository.get_subsequence("sq0", 2, 4).transpose()?;
+        assert_eq!(subsequence, Some(Sequence::from(b"CGT".to_vec())));
+        assert_eq!(repository.cache.len(), 1);
+
+        let subsequence = repository.get_subsequence("sq1", 4, 6).transpose()?;
+        assert_eq!(subsequence, Some(Sequence::from(b"NNN".to_vec())));
+
+        assert!(repository.get_subsequence("sq0", 3, 5).transpose().is_err());
+        assert!(repository
+            .get_subsequence("sq1", 8, 11)
+            .transpose()
+            .is_err());
+
+        assert!(repository.get("sq2").is_none());
+
+        repository.clear();
+        assert!(repository.cache.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_cache_limit() -> io::Result<()> {
+        let data = b">sq0\nACGT\n>sq1\nNNNN\n";
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+            fai::Record::new(String::from("sq1"), 4, 15, 4, 5),
+        ];
+        let reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        let mut repository = CachingRepository::with_cache_limit(reader, 1);
+
+        repository.get("sq0").transpose()?;
+        assert!(repository.cache.contains_key("sq0"));
+
+        repository.get("sq1").transpose()?;
+        assert_eq!(repository.cache.len(), 1);
+        assert!(repository.cache.contains_key("sq1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_memory_repository_get() -> io::Result<()> {
+        let mut repository = InMemoryRepository::new(vec![Record::new(
+            crate::record::Definition::new(String::from("sq0"), None),
+            Sequence::from(b"ACGT".to_vec()),
+        )]);
+
+        let sequence = repository.get("sq0").transpose()?;
+        assert_eq!(sequence.as_deref(), Some(&Sequence::from(b"ACGT".to_vec())));
+
+        let subsequence = repository.get_subsequence("sq0", 2, 3).transpose()?;
+        assert_eq!(subsequence, Some(Sequence::from(b"CG".to_vec())));
+
+        assert!(repository.get("sq1").is_none());
+
+        Ok(())
+    }
+}
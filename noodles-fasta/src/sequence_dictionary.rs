@@ -0,0 +1,267 @@
+//! SAM sequence dictionary generation.
+//!
+//! A sequence dictionary is a list of SAM header reference sequences (`@SQ`) that describe the
+//! reference sequences in a FASTA, i.e., their names, lengths, and MD5 checksums. It is commonly
+//! stored as a SAM header in a `.dict` file next to the FASTA.
+//!
+//! This module requires the `sequence-dictionary` feature.
+
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+use md5::{Digest, Md5};
+use noodles_sam::{
+    self as sam,
+    header::{reference_sequence::Md5Checksum, ReferenceSequence, ReferenceSequences},
+};
+
+use super::{record::Definition, Reader};
+
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// A SAM sequence dictionary builder.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::sequence_dictionary::Builder;
+///
+/// let data = b">sq0\nacgt\n>sq1\nNNNN\nNN\n";
+///
+/// let reference_sequences = Builder::default()
+///     .set_uri("file:/tmp/reference.fa")
+///     .build_from_reader(&data[..])?;
+///
+/// let reference_sequence = &reference_sequences["sq0"];
+/// assert_eq!(reference_sequence.len(), 4);
+/// assert_eq!(
+///     reference_sequence.md5_checksum().map(|checksum| checksum.to_string()),
+///     Some(String::from("f1f8f4bf413b16ad135722aa4591043e"))
+/// );
+/// assert_eq!(reference_sequence.uri(), Some("file:/tmp/reference.fa"));
+///
+/// assert_eq!(reference_sequences["sq1"].len(), 6);
+/// # Ok::<(), io::Error>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Builder {
+    uri: Option<String>,
+}
+
+impl Builder {
+    /// Sets the URI of the FASTA.
+    ///
+    /// When set, each reference sequence includes the URI (`UR`) field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::sequence_dictionary::Builder;
+    /// let builder = Builder::default().set_uri("file:/tmp/reference.fa");
+    /// ```
+    pub fn set_uri<I>(mut self, uri: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Builds a sequence dictionary by scanning a FASTA file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_fasta::sequence_dictionary::Builder;
+    /// let reference_sequences = Builder::default().build_from_path("reference.fa")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<ReferenceSequences>
+    where
+        P: AsRef<Path>,
+    {
+        File::open(src)
+            .map(BufReader::new)
+            .and_then(|reader| self.build_from_reader(reader))
+    }
+
+    /// Builds a sequence dictionary by scanning FASTA records from a reader.
+    ///
+    /// Sequences are read in chunks, so they are never held entirely in memory. The MD5 checksum
+    /// of each sequence is calculated over its uppercase bases, as defined by the SAM
+    /// specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::sequence_dictionary::Builder;
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let reference_sequences = Builder::default().build_from_reader(&data[..])?;
+    ///
+    /// assert_eq!(reference_sequences.len(), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> io::Result<ReferenceSequences>
+    where
+        R: BufRead,
+    {
+        let mut reader = Reader::new(reader);
+        let mut reference_sequences = ReferenceSequences::new();
+
+        let mut definition_buf = String::new();
+        let mut buf = vec![0; CHUNK_SIZE];
+
+        loop {
+            definition_buf.clear();
+
+            if reader.read_definition(&mut definition_buf)? == 0 {
+                break;
+            }
+
+            let definition: Definition = definition_buf
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let name = definition.name();
+
+            if reference_sequences.contains_key(name) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("duplicate reference sequence name: {}", name),
+                ));
+            }
+
+            let mut hasher = Md5Hasher::default();
+            let mut len = 0;
+
+            loop {
+                let n = reader.read_sequence_chunk(&mut buf)?;
+
+                if n == 0 {
+                    break;
+                }
+
+                hasher.update(&mut buf[..n]);
+                len += n;
+            }
+
+            let len = i32::try_from(len).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid reference sequence length: {}: {}", name, e),
+                )
+            })?;
+
+            let md5_checksum = hasher.finalize();
+
+            let mut builder = ReferenceSequence::builder()
+                .set_name(name)
+                .set_length(len)
+                .set_md5_checksum(md5_checksum);
+
+            if let Some(uri) = self.uri.as_deref() {
+                builder = builder.set_uri(uri);
+            }
+
+            reference_sequences.insert(name.into(), builder.build());
+        }
+
+        Ok(reference_sequences)
+    }
+}
+
+// Calculates the MD5 checksum of a sequence over its uppercase bases, as defined by the SAM
+// specification.
+#[derive(Default)]
+pub(crate) struct Md5Hasher(Md5);
+
+impl Md5Hasher {
+    // Updates the hasher with a chunk of the sequence. The chunk is uppercased in place.
+    pub(crate) fn update(&mut self, chunk: &mut [u8]) {
+        chunk.make_ascii_uppercase();
+        self.0.update(&chunk);
+    }
+
+    pub(crate) fn finalize(self) -> Md5Checksum {
+        Md5Checksum::from(<[u8; 16]>::from(self.0.finalize()))
+    }
+}
+
+/// Writes a sequence dictionary in the Picard `.dict` format.
+///
+/// A `.dict` file is a SAM header with a header (`@HD`) line followed by the reference sequence
+/// (`@SQ`) lines.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::sequence_dictionary::{self, Builder};
+///
+/// let data = b">sq0\nACGT\n";
+/// let reference_sequences = Builder::default().build_from_reader(&data[..])?;
+///
+/// let mut buf = Vec::new();
+/// sequence_dictionary::write_dict(&mut buf, &reference_sequences)?;
+///
+/// assert_eq!(
+///     String::from_utf8_lossy(&buf),
+///     "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:4\tM5:f1f8f4bf413b16ad135722aa4591043e\n"
+/// );
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn write_dict<W>(writer: &mut W, reference_sequences: &ReferenceSequences) -> io::Result<()>
+where
+    W: Write,
+{
+    writeln!(writer, "{}", sam::header::header::Header::default())?;
+
+    for reference_sequence in reference_sequences.values() {
+        writeln!(writer, "{}", reference_sequence)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_from_reader() -> io::Result<()> {
+        let data = b">sq0 LN:4\nAC\r\ngt\r\n>sq1\nNNNN\nNNNN\nNN\n";
+        let reference_sequences = Builder::default().build_from_reader(&data[..])?;
+
+        let expected: Vec<_> = vec![
+            ReferenceSequence::builder()
+                .set_name("sq0")
+                .set_length(4)
+                .set_md5_checksum("f1f8f4bf413b16ad135722aa4591043e".parse().unwrap())
+                .build(),
+            ReferenceSequence::builder()
+                .set_name("sq1")
+                .set_length(10)
+                .set_md5_checksum("3f5d4e86f68b8d8a388971469d2d1287".parse().unwrap())
+                .build(),
+        ];
+
+        let actual: Vec<_> = reference_sequences.values().cloned().collect();
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_duplicate_name() {
+        let data = b">sq0\nACGT\n>sq0\nACGT\n";
+        assert!(Builder::default().build_from_reader(&data[..]).is_err());
+    }
+}
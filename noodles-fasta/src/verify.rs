@@ -0,0 +1,364 @@
+//! Reference sequence verification against SAM and VCF headers.
+//!
+//! Headers declare the reference sequences they were created with, typically with lengths and
+//! MD5 checksums. Using a different reference (or a different version of it) than declared can
+//! silently corrupt downstream results. The functions in this module compare declared reference
+//! sequences to the sequences in a FASTA [`Repository`] and return a [`Report`] of mismatches.
+//!
+//! This module requires the `verify` feature.
+
+use std::{convert::TryFrom, fmt, io};
+
+use noodles_sam as sam;
+use noodles_vcf as vcf;
+
+use super::{record::Sequence, sequence_dictionary::Md5Hasher, Repository};
+
+const CHUNK_SIZE: usize = 4096;
+
+/// A mismatch between a declared reference sequence and its FASTA sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mismatch {
+    /// The reference sequence is missing from the FASTA.
+    MissingReferenceSequence {
+        /// The reference sequence name.
+        name: String,
+    },
+    /// The declared length differs from the sequence length.
+    Length {
+        /// The reference sequence name.
+        name: String,
+        /// The declared length.
+        expected: i32,
+        /// The sequence length.
+        actual: usize,
+    },
+    /// The declared MD5 checksum differs from the checksum of the sequence.
+    Md5Checksum {
+        /// The reference sequence name.
+        name: String,
+        /// The declared MD5 checksum, as a hex string.
+        expected: String,
+        /// The MD5 checksum of the sequence, as a lowercase hex string.
+        actual: String,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequence { name } => {
+                write!(f, "{}: missing reference sequence", name)
+            }
+            Self::Length {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}: length mismatch: expected {}, got {}",
+                name, expected, actual
+            ),
+            Self::Md5Checksum {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}: MD5 checksum mismatch: expected {}, got {}",
+                name, expected, actual
+            ),
+        }
+    }
+}
+
+/// A reference sequence verification report.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    verified_count: usize,
+    mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    /// Returns whether all declared reference sequences match their FASTA sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::verify::Report;
+    /// let report = Report::default();
+    /// assert!(report.is_ok());
+    /// ```
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Returns the number of declared reference sequences that match their FASTA sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::verify::Report;
+    /// let report = Report::default();
+    /// assert_eq!(report.verified_count(), 0);
+    /// ```
+    pub fn verified_count(&self) -> usize {
+        self.verified_count
+    }
+
+    /// Returns the list of mismatches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::verify::Report;
+    /// let report = Report::default();
+    /// assert!(report.mismatches().is_empty());
+    /// ```
+    pub fn mismatches(&self) -> &[Mismatch] {
+        &self.mismatches
+    }
+}
+
+/// Verifies SAM header reference sequences against the sequences in a FASTA repository.
+///
+/// Each reference sequence length is checked, and if the reference sequence has an MD5 checksum
+/// (`M5`), it is compared to the checksum of the uppercase sequence.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, repository::InMemoryRepository, verify::{self, Mismatch}};
+/// use noodles_sam::header::{ReferenceSequence, ReferenceSequences};
+///
+/// let mut repository = InMemoryRepository::new(vec![
+///     fasta::Record::builder()
+///         .set_name("sq0")
+///         .set_sequence(fasta::record::Sequence::from(b"ACGT".to_vec()))
+///         .build()
+///         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+/// ]);
+///
+/// let mut reference_sequences = ReferenceSequences::new();
+/// reference_sequences.insert(String::from("sq0"), ReferenceSequence::new("sq0", 8));
+/// reference_sequences.insert(String::from("sq1"), ReferenceSequence::new("sq1", 13));
+///
+/// let report = verify::verify_sam_reference_sequences(&mut repository, &reference_sequences)?;
+///
+/// assert_eq!(report.mismatches(), [
+///     Mismatch::Length { name: String::from("sq0"), expected: 8, actual: 4 },
+///     Mismatch::MissingReferenceSequence { name: String::from("sq1") },
+/// ]);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn verify_sam_reference_sequences<R>(
+    repository: &mut R,
+    reference_sequences: &sam::header::ReferenceSequences,
+) -> io::Result<Report>
+where
+    R: Repository,
+{
+    let declarations = reference_sequences.values().map(|reference_sequence| {
+        (
+            reference_sequence.name(),
+            Some(reference_sequence.len()),
+            reference_sequence
+                .md5_checksum()
+                .map(|checksum| checksum.to_string()),
+        )
+    });
+
+    verify(repository, declarations)
+}
+
+/// Verifies VCF header contigs against the sequences in a FASTA repository.
+///
+/// If a contig has a length or an MD5 checksum, it is compared to the length or checksum of the
+/// uppercase sequence, respectively.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, repository::InMemoryRepository, verify};
+/// use noodles_vcf as vcf;
+///
+/// let mut repository = InMemoryRepository::new(vec![
+///     fasta::Record::builder()
+///         .set_name("sq0")
+///         .set_sequence(fasta::record::Sequence::from(b"ACGT".to_vec()))
+///         .build()
+///         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+/// ]);
+///
+/// let header = vcf::Header::builder().add_contig_with_len("sq0", 4).build();
+/// let report = verify::verify_vcf_contigs(&mut repository, header.contigs())?;
+///
+/// assert!(report.is_ok());
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn verify_vcf_contigs<R>(
+    repository: &mut R,
+    contigs: &vcf::header::Contigs,
+) -> io::Result<Report>
+where
+    R: Repository,
+{
+    let declarations = contigs
+        .values()
+        .map(|contig| (contig.id(), contig.len(), contig.md5().map(String::from)));
+
+    verify(repository, declarations)
+}
+
+fn verify<'a, R, I>(repository: &mut R, declarations: I) -> io::Result<Report>
+where
+    R: Repository,
+    I: Iterator<Item = (&'a str, Option<i32>, Option<String>)>,
+{
+    let mut report = Report::default();
+
+    for (name, len, md5_checksum) in declarations {
+        let mismatch_count = report.mismatches.len();
+
+        let sequence = match repository.get(name).transpose()? {
+            Some(sequence) => sequence,
+            None => {
+                report
+                    .mismatches
+                    .push(Mismatch::MissingReferenceSequence { name: name.into() });
+
+                continue;
+            }
+        };
+
+        if let Some(expected) = len {
+            let actual = sequence.len();
+
+            if usize::try_from(expected).ok() != Some(actual) {
+                report.mismatches.push(Mismatch::Length {
+                    name: name.into(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        if let Some(expected) = md5_checksum {
+            let actual = calculate_md5_checksum(&sequence);
+
+            if !expected.eq_ignore_ascii_case(&actual) {
+                report.mismatches.push(Mismatch::Md5Checksum {
+                    name: name.into(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        if report.mismatches.len() == mismatch_count {
+            report.verified_count += 1;
+        }
+    }
+
+    Ok(report)
+}
+
+// Calculates the MD5 checksum of the uppercase sequence as a lowercase hex string.
+fn calculate_md5_checksum(sequence: &Sequence) -> String {
+    let mut hasher = Md5Hasher::default();
+    let mut buf = [0; CHUNK_SIZE];
+
+    for chunk in sequence.chunks(CHUNK_SIZE) {
+        let buf = &mut buf[..chunk.len()];
+        buf.copy_from_slice(chunk);
+        hasher.update(buf);
+    }
+
+    hasher.finalize().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{repository::InMemoryRepository, Record};
+
+    use super::*;
+
+    #[test]
+    fn test_verify_sam_reference_sequences() -> io::Result<()> {
+        let mut repository = InMemoryRepository::new(vec![
+            Record::builder()
+                .set_name("sq0")
+                .set_sequence(Sequence::from(b"acgt".to_vec()))
+                .build()
+                .unwrap(),
+            Record::builder()
+                .set_name("sq1")
+                .set_sequence(Sequence::from(b"NNNN".to_vec()))
+                .build()
+                .unwrap(),
+        ]);
+
+        let mut reference_sequences = sam::header::ReferenceSequences::new();
+        reference_sequences.insert(
+            String::from("sq0"),
+            sam::header::ReferenceSequence::builder()
+                .set_name("sq0")
+                .set_length(4)
+                .set_md5_checksum("f1f8f4bf413b16ad135722aa4591043e".parse().unwrap())
+                .build(),
+        );
+        reference_sequences.insert(
+            String::from("sq1"),
+            sam::header::ReferenceSequence::builder()
+                .set_name("sq1")
+                .set_length(4)
+                .set_md5_checksum("f1f8f4bf413b16ad135722aa4591043e".parse().unwrap())
+                .build(),
+        );
+
+        let report = verify_sam_reference_sequences(&mut repository, &reference_sequences)?;
+
+        assert_eq!(report.verified_count(), 1);
+        assert_eq!(
+            report.mismatches(),
+            [Mismatch::Md5Checksum {
+                name: String::from("sq1"),
+                expected: String::from("f1f8f4bf413b16ad135722aa4591043e"),
+                actual: String::from("ef95bc05180af51bfd945e93b2bbba8e"),
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_vcf_contigs() -> io::Result<()> {
+        let mut repository = InMemoryRepository::new(vec![Record::builder()
+            .set_name("sq0")
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .build()
+            .unwrap()]);
+
+        let mut contig = vcf::header::Contig::new(String::from("sq0"));
+        *contig.md5_mut() = Some(String::from("F1F8F4BF413B16AD135722AA4591043E"));
+
+        let header = vcf::Header::builder()
+            .add_contig(contig)
+            .add_contig(vcf::header::Contig::new(String::from("sq1")))
+            .build();
+
+        let report = verify_vcf_contigs(&mut repository, header.contigs())?;
+
+        assert_eq!(report.verified_count(), 1);
+        assert_eq!(
+            report.mismatches(),
+            [Mismatch::MissingReferenceSequence {
+                name: String::from("sq1")
+            }]
+        );
+
+        Ok(())
+    }
+}
@@ -1,15 +1,25 @@
+//! FASTA writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
-    cmp,
     io::{self, Write},
+    num::NonZeroUsize,
 };
 
-use super::Record;
+use super::{reader::DEFINITION_PREFIX, Record};
 
-const LINE_BASES: u64 = 80;
+// The size of the buffer used to uppercase sequence bases before they are written.
+const UPPERCASE_BUF_SIZE: usize = 4096;
 
 /// A FASTA writer.
 pub struct Writer<W> {
     inner: W,
+    line_bases: Option<NonZeroUsize>,
+    write_descriptions: bool,
+    uppercase_sequences: bool,
 }
 
 impl<W> Writer<W>
@@ -18,6 +28,8 @@ where
 {
     /// Creates a FASTA writer.
     ///
+    /// Sequence lines are hard wrapped at 80 bases. Use a [`Builder`] to configure the output.
+    ///
     /// # Examples
     ///
     /// ```
@@ -25,7 +37,7 @@ where
     /// let writer = fasta::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Builder::default().build_with_writer(inner)
     }
 
     /// Returns a reference to the underlying writer.
@@ -43,7 +55,8 @@ where
 
     /// Writes a FASTA record.
     ///
-    /// Sequence lines are hard wrapped at 80 bases.
+    /// By default, the definition includes the description, and sequence lines are hard wrapped at
+    /// 80 bases.
     ///
     /// # Examples
     ///
@@ -54,7 +67,7 @@ where
     /// let mut writer = fasta::Writer::new(Vec::new());
     ///
     /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
-    /// let record = fasta::Record::new(definition, b"ACGT".to_vec());
+    /// let record = fasta::Record::new(definition, fasta::record::Sequence::from(b"ACGT".to_vec()));
     ///
     /// writer.write_record(&record)?;
     ///
@@ -62,26 +75,58 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        writeln!(self.inner, "{}", record.definition())?;
-        write_record_sequence(&mut self.inner, record.sequence(), LINE_BASES as usize)?;
-        Ok(())
+        if self.write_descriptions {
+            writeln!(self.inner, "{}", record.definition())?;
+        } else {
+            writeln!(
+                self.inner,
+                "{}{}",
+                char::from(DEFINITION_PREFIX),
+                record.reference_sequence_name()
+            )?;
+        }
+
+        write_record_sequence(
+            &mut self.inner,
+            record.sequence(),
+            self.line_bases,
+            self.uppercase_sequences,
+        )
     }
 }
 
-fn write_record_sequence<W>(writer: &mut W, sequence: &[u8], line_bases: usize) -> io::Result<()>
+// Writes a sequence hard wrapped at the given number of bases. If `line_bases` is `None`, the
+// sequence is written on a single line.
+fn write_record_sequence<W>(
+    writer: &mut W,
+    sequence: &[u8],
+    line_bases: Option<NonZeroUsize>,
+    uppercase: bool,
+) -> io::Result<()>
 where
     W: Write,
 {
-    let mut start = 0;
+    let mut buf = [0; UPPERCASE_BUF_SIZE];
+
+    let line_bases = match line_bases {
+        Some(n) => n.get(),
+        None => sequence.len().max(1),
+    };
+
+    for line in sequence.chunks(line_bases) {
+        if uppercase {
+            // Bases are uppercased through a fixed buffer rather than copying the sequence.
+            for chunk in line.chunks(buf.len()) {
+                let buf = &mut buf[..chunk.len()];
+                buf.copy_from_slice(chunk);
+                buf.make_ascii_uppercase();
+                writer.write_all(buf)?;
+            }
+        } else {
+            writer.write_all(line)?;
+        }
 
-    while start < sequence.len() {
-        let end = cmp::min(start + line_bases, sequence.len());
-        let line = &sequence[start..end];
-
-        writer.write_all(line)?;
         writeln!(writer)?;
-
-        start = end;
     }
 
     Ok(())
@@ -89,26 +134,60 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::record::{Definition, Sequence};
+
     use super::*;
 
+    #[test]
+    fn test_write_record() -> io::Result<()> {
+        let definition = Definition::new(String::from("sq0"), Some(String::from("LN:6")));
+        let record = Record::new(definition, Sequence::from(b"acgtNN".to_vec()));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&record)?;
+        assert_eq!(writer.get_ref(), b">sq0 LN:6\nacgtNN\n");
+
+        let mut writer = Builder::default()
+            .set_line_bases(NonZeroUsize::new(4))
+            .set_write_descriptions(false)
+            .set_uppercase_sequences(true)
+            .build_with_writer(Vec::new());
+        writer.write_record(&record)?;
+        assert_eq!(writer.get_ref(), b">sq0\nACGT\nNN\n");
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_sequence() -> io::Result<()> {
         let mut writer = Vec::new();
-        write_record_sequence(&mut writer, b"AC", 4)?;
+        write_record_sequence(&mut writer, b"AC", NonZeroUsize::new(4), false)?;
         assert_eq!(writer, b"AC\n");
 
         writer.clear();
-        write_record_sequence(&mut writer, b"ACGT", 4)?;
+        write_record_sequence(&mut writer, b"ACGT", NonZeroUsize::new(4), false)?;
         assert_eq!(writer, b"ACGT\n");
 
         writer.clear();
-        write_record_sequence(&mut writer, b"ACGTACGT", 4)?;
+        write_record_sequence(&mut writer, b"ACGTACGT", NonZeroUsize::new(4), false)?;
         assert_eq!(writer, b"ACGT\nACGT\n");
 
         writer.clear();
-        write_record_sequence(&mut writer, b"ACGTACGTAC", 4)?;
+        write_record_sequence(&mut writer, b"ACGTACGTAC", NonZeroUsize::new(4), false)?;
         assert_eq!(writer, b"ACGT\nACGT\nAC\n");
 
+        writer.clear();
+        write_record_sequence(&mut writer, b"ACGTACGTAC", None, false)?;
+        assert_eq!(writer, b"ACGTACGTAC\n");
+
+        writer.clear();
+        write_record_sequence(&mut writer, b"acgtNNacgt", NonZeroUsize::new(4), true)?;
+        assert_eq!(writer, b"ACGT\nNNAC\nGT\n");
+
+        writer.clear();
+        write_record_sequence(&mut writer, b"", None, false)?;
+        assert!(writer.is_empty());
+
         Ok(())
     }
 }
@@ -0,0 +1,127 @@
+use std::{io::Write, num::NonZeroUsize};
+
+use super::Writer;
+
+const DEFAULT_LINE_BASES: usize = 80;
+
+/// A FASTA writer builder.
+#[derive(Debug)]
+pub struct Builder {
+    line_bases: Option<NonZeroUsize>,
+    write_descriptions: bool,
+    uppercase_sequences: bool,
+}
+
+impl Builder {
+    /// Sets the number of bases per line.
+    ///
+    /// Sequences are hard wrapped at this width. `None` disables wrapping, i.e., each sequence is
+    /// written on a single line. The default is 80.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io, num::NonZeroUsize};
+    /// use noodles_fasta as fasta;
+    ///
+    /// let mut writer = fasta::writer::Builder::default()
+    ///     .set_line_bases(NonZeroUsize::new(2))
+    ///     .build_with_writer(Vec::new());
+    ///
+    /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
+    /// let record = fasta::Record::new(definition, fasta::record::Sequence::from(b"ACGT".to_vec()));
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b">sq0\nAC\nGT\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn set_line_bases(mut self, line_bases: Option<NonZeroUsize>) -> Self {
+        self.line_bases = line_bases;
+        self
+    }
+
+    /// Sets whether descriptions are written after reference sequence names.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let mut writer = fasta::writer::Builder::default()
+    ///     .set_write_descriptions(false)
+    ///     .build_with_writer(Vec::new());
+    ///
+    /// let definition = fasta::record::Definition::new(
+    ///     String::from("sq0"),
+    ///     Some(String::from("LN:4")),
+    /// );
+    /// let record = fasta::Record::new(definition, fasta::record::Sequence::from(b"ACGT".to_vec()));
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b">sq0\nACGT\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn set_write_descriptions(mut self, write_descriptions: bool) -> Self {
+        self.write_descriptions = write_descriptions;
+        self
+    }
+
+    /// Sets whether sequences are converted to uppercase when written.
+    ///
+    /// The default is `false`, i.e., sequences are written as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let mut writer = fasta::writer::Builder::default()
+    ///     .set_uppercase_sequences(true)
+    ///     .build_with_writer(Vec::new());
+    ///
+    /// let definition = fasta::record::Definition::new(String::from("sq0"), None);
+    /// let record = fasta::Record::new(definition, fasta::record::Sequence::from(b"acgt".to_vec()));
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b">sq0\nACGT\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn set_uppercase_sequences(mut self, uppercase_sequences: bool) -> Self {
+        self.uppercase_sequences = uppercase_sequences;
+        self
+    }
+
+    /// Builds a FASTA writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::writer::Builder::default().build_with_writer(Vec::new());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        Writer {
+            inner: writer,
+            line_bases: self.line_bases,
+            write_descriptions: self.write_descriptions,
+            uppercase_sequences: self.uppercase_sequences,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            line_bases: NonZeroUsize::new(DEFAULT_LINE_BASES),
+            write_descriptions: true,
+            uppercase_sequences: false,
+        }
+    }
+}
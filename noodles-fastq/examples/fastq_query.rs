@@ -0,0 +1,33 @@
+//! Extracts records by read name from an indexed FASTQ file.
+//!
+//! The input FASTQ must have an index (`<src>.fai`) in the same directory.
+//!
+//! This is similar to the output of `samtools fqidx <src> <read-name>...`.
+
+use std::{env, io};
+
+use noodles_fastq as fastq;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let mut reader = fastq::IndexedReader::from_path(src)?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = fastq::Writer::new(handle);
+
+    for read_name in args {
+        let record = reader.get(&read_name).unwrap_or_else(|| {
+            Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("missing read name: {}", read_name),
+            ))
+        })?;
+
+        writer.write_record(&record)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+//! Async FASTQ.
+
+mod reader;
+
+pub use self::reader::Reader;
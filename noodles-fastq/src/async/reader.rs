@@ -0,0 +1,241 @@
+use std::io;
+
+use futures::{stream, Stream};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+use crate::Record;
+
+const READ_NAME_PREFIX: u8 = b'@';
+const LINE_FEED: u8 = b'\n';
+const CARRIAGE_RETURN: u8 = b'\r';
+
+/// An async FASTQ reader.
+///
+/// This is the async analog of [`crate::Reader`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// use futures::TryStreamExt;
+/// use noodles_fastq as fastq;
+/// use tokio::{fs::File, io::BufReader};
+///
+/// let mut reader = File::open("sample.fastq")
+///     .await
+///     .map(BufReader::new)
+///     .map(fastq::AsyncReader::new)?;
+///
+/// let mut records = reader.records();
+///
+/// while let Some(record) = records.try_next().await? {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Reader<R> {
+    inner: R,
+    line_buf: Vec<u8>,
+}
+
+impl<R> Reader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// Creates an async FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let reader = fastq::AsyncReader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            line_buf: Vec::new(),
+        }
+    }
+
+    /// Reads a FASTQ record.
+    ///
+    /// This reads from the underlying stream until four lines are read: the read name, the
+    /// sequence, the plus line, and the quality scores. Each line omits the trailing newline.
+    ///
+    /// The stream is expected to be at the start of a record.
+    ///
+    /// It is more ergonomic to read records using a stream (see [`Self::records`]), but using this
+    /// method directly allows the reuse of a single [`Record`] buffer.
+    ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
+    /// stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let mut reader = fastq::AsyncReader::new(&data[..]);
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record).await?;
+    ///
+    /// assert_eq!(record, fastq::Record::new("r0", "ATCG", "NDLS"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        record.clear();
+
+        let mut len = match read_read_name(&mut self.inner, record.read_name_mut()).await? {
+            0 => return Ok(0),
+            n => n,
+        };
+
+        len += read_line(&mut self.inner, record.sequence_mut()).await?;
+
+        self.line_buf.clear();
+        len += read_line(&mut self.inner, &mut self.line_buf).await?;
+
+        len += read_line(&mut self.inner, record.quality_scores_mut()).await?;
+
+        Ok(len)
+    }
+
+    /// Returns a stream over records starting from the current stream position.
+    ///
+    /// The stream is expected to be at the start of a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let mut reader = fastq::AsyncReader::new(&data[..]);
+    ///
+    /// let mut records = reader.records();
+    ///
+    /// assert_eq!(
+    ///     records.try_next().await?,
+    ///     Some(fastq::Record::new("r0", "ATCG", "NDLS"))
+    /// );
+    ///
+    /// assert!(records.try_next().await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + Unpin + '_ {
+        Box::pin(stream::try_unfold(
+            (self, Record::default()),
+            |(reader, mut record)| async {
+                match reader.read_record(&mut record).await? {
+                    0 => Ok(None),
+                    _ => Ok(Some((record.clone(), (reader, record)))),
+                }
+            },
+        ))
+    }
+}
+
+async fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    match reader.read_until(LINE_FEED, buf).await? {
+        0 => Ok(0),
+        n => {
+            if buf.ends_with(&[LINE_FEED]) {
+                buf.pop();
+
+                if buf.ends_with(&[CARRIAGE_RETURN]) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+    }
+}
+
+async fn read_read_name<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let prefix = match reader.read_u8().await {
+        Ok(b) => b,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+        Err(e) => return Err(e),
+    };
+
+    if prefix != READ_NAME_PREFIX {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "read name missing @ prefix",
+        ));
+    }
+
+    read_line(reader, buf).await.map(|n| n + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_record() -> io::Result<()> {
+        let data = b"\
+@noodles:1/1
+AGCT
++
+abcd
+@noodles:2/1\r
+TCGA\r
++\r
+dcba\r
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut record = Record::default();
+
+        let len = reader.read_record(&mut record).await?;
+        assert_eq!(len, 25);
+        assert_eq!(record, Record::new("noodles:1/1", "AGCT", "abcd"));
+
+        let len = reader.read_record(&mut record).await?;
+        assert_eq!(len, 29);
+        assert_eq!(record, Record::new("noodles:2/1", "TCGA", "dcba"));
+
+        let len = reader.read_record(&mut record).await?;
+        assert_eq!(len, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_record_with_missing_read_name_prefix() {
+        let data = b"r0\nAGCT\n+\nabcd\n";
+        let mut reader = Reader::new(&data[..]);
+        let mut record = Record::default();
+
+        assert!(matches!(
+            reader.read_record(&mut record).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
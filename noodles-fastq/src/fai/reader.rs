@@ -1,5 +1,7 @@
 use std::io::{self, BufRead};
 
+use super::Index;
+
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
 
@@ -55,6 +57,47 @@ where
     pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
         read_line(&mut self.inner, buf)
     }
+
+    /// Reads a FASTQ index.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::fai;
+    ///
+    /// let data = b"r0\t4\t4\t4\t5\t11\nr1\t10\t20\t10\t11\t33\n";
+    /// let mut reader = fai::Reader::new(&data[..]);
+    /// let index = reader.read_index()?;
+    ///
+    /// assert_eq!(index, vec![
+    ///     fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11),
+    ///     fai::Record::new(String::from("r1"), 10, 20, 10, 11, 33),
+    /// ]);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_index(&mut self) -> io::Result<Index> {
+        let mut buf = String::new();
+        let mut index = Vec::new();
+
+        loop {
+            buf.clear();
+
+            if self.read_record(&mut buf)? == 0 {
+                break;
+            }
+
+            let record = buf
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            index.push(record);
+        }
+
+        Ok(index)
+    }
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
@@ -0,0 +1,312 @@
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fs::File,
+    io::{self, BufReader, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use noodles_core::path::push_extension;
+
+use super::{fai, Record};
+
+const FAI_EXTENSION: &str = "fai";
+
+/// A FASTQ reader with an associated FASTQ index (FAI).
+///
+/// The index allows random access to records by read name or by record number, similar to
+/// `samtools fqidx`. This makes it possible to extract a subset of reads without scanning the
+/// entire file. Sequences and quality scores may be line-wrapped, as described by the line bases
+/// and line width of each index record.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_fastq as fastq;
+///
+/// let mut reader = fastq::IndexedReader::from_path("sample.fastq")?;
+///
+/// for read_name in &["r0", "r8", "r13"] {
+///     if let Some(record) = reader.get(read_name).transpose()? {
+///         print!("{}", record);
+///     }
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: R,
+    index: fai::Index,
+    read_names: HashMap<String, usize>,
+}
+
+impl IndexedReader<BufReader<File>> {
+    /// Opens a FASTQ file and its index.
+    ///
+    /// The index is discovered by appending `.fai` to the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::IndexedReader::from_path("sample.fastq")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_path<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index = fai::read(push_extension(src, FAI_EXTENSION))?;
+        let file = File::open(src).map(BufReader::new)?;
+
+        Ok(Self::new(file, index))
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Creates an indexed FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n";
+    /// let index = vec![fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11)];
+    /// let reader = fastq::IndexedReader::new(Cursor::new(&data[..]), index);
+    /// ```
+    pub fn new(inner: R, index: fai::Index) -> Self {
+        let read_names = index
+            .iter()
+            .enumerate()
+            .map(|(i, record)| (record.read_name().into(), i))
+            .collect();
+
+        Self {
+            inner,
+            index,
+            read_names,
+        }
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n";
+    /// let index = vec![fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11)];
+    /// let reader = fastq::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// assert_eq!(reader.index().len(), 1);
+    /// ```
+    pub fn index(&self) -> &[fai::Record] {
+        &self.index
+    }
+
+    /// Reads the record with the given read name.
+    ///
+    /// This returns `None` if the index does not have the read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nNNNN\n+\nABCD\n";
+    /// let index = vec![
+    ///     fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11),
+    ///     fai::Record::new(String::from("r1"), 4, 20, 4, 5, 27),
+    /// ];
+    /// let mut reader = fastq::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let record = reader.get("r1").transpose()?;
+    /// assert_eq!(record, Some(fastq::Record::new("r1", "NNNN", "ABCD")));
+    ///
+    /// assert!(reader.get("r2").is_none());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn get(&mut self, read_name: &str) -> Option<io::Result<Record>> {
+        let i = *self.read_names.get(read_name)?;
+        self.get_by_index(i)
+    }
+
+    /// Reads the record at the given 0-based record number.
+    ///
+    /// This returns `None` if the record number is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n";
+    /// let index = vec![fai::Record::new(String::from("r0"), 4, 4, 4, 5, 11)];
+    /// let mut reader = fastq::IndexedReader::new(Cursor::new(&data[..]), index);
+    ///
+    /// let record = reader.get_by_index(0).transpose()?;
+    /// assert_eq!(record, Some(fastq::Record::new("r0", "ACGT", "NDLS")));
+    ///
+    /// assert!(reader.get_by_index(1).is_none());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn get_by_index(&mut self, i: usize) -> Option<io::Result<Record>> {
+        let index_record = self.index.get(i)?;
+        Some(read_record(&mut self.inner, index_record))
+    }
+}
+
+fn read_record<R>(reader: &mut R, index_record: &fai::Record) -> io::Result<Record>
+where
+    R: Read + Seek,
+{
+    let sequence = read_wrapped(reader, index_record, index_record.sequence_offset())?;
+    let quality_scores = read_wrapped(reader, index_record, index_record.quality_scores_offset())?;
+
+    Ok(Record::new(
+        index_record.read_name(),
+        sequence,
+        quality_scores,
+    ))
+}
+
+// Reads a line-wrapped sequence or quality scores starting at the given offset.
+//
+// Each line has `line_bases` bases and is `line_width` bytes long, including the line
+// terminator. Only the last line may have fewer bases.
+fn read_wrapped<R>(reader: &mut R, index_record: &fai::Record, offset: u64) -> io::Result<Vec<u8>>
+where
+    R: Read + Seek,
+{
+    let len = index_record.len();
+
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+
+    let line_bases = index_record.line_bases();
+    let line_width = index_record.line_width();
+
+    if line_bases == 0 || line_width < line_bases {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid line layout: line bases = {}, line width = {}",
+                line_bases, line_width
+            ),
+        ));
+    }
+
+    // The number of lines before the last line, which are all full.
+    let full_line_count = (len - 1) / line_bases;
+
+    let raw_len = full_line_count
+        .checked_mul(line_width)
+        .and_then(|n| n.checked_add(len - full_line_count * line_bases))
+        .and_then(|n| usize::try_from(n).ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid record length"))?;
+
+    let mut raw = vec![0; raw_len];
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(&mut raw)?;
+
+    if line_bases == line_width {
+        return Ok(raw);
+    }
+
+    // Both are bounded by `raw_len`.
+    let line_bases = line_bases as usize;
+    let line_width = line_width as usize;
+
+    let mut buf = Vec::with_capacity(raw_len);
+
+    for line in raw.chunks(line_width) {
+        let end = line.len().min(line_bases);
+        buf.extend_from_slice(&line[..end]);
+    }
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::Indexer;
+
+    use super::*;
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let data = b"@r0\nACGT\n+\nNDLS\n@r1\nNNNNNNNNNN\n+\nNDLSNDLSND\n@r2\nTG\n+\nAB\n";
+
+        let mut indexer = Indexer::new(&data[..]);
+        let mut index = Vec::new();
+
+        while let Some(record) = indexer.index_record()? {
+            index.push(record);
+        }
+
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        assert_eq!(
+            reader.get("r2").transpose()?,
+            Some(Record::new("r2", "TG", "AB"))
+        );
+        assert_eq!(
+            reader.get("r1").transpose()?,
+            Some(Record::new("r1", "NNNNNNNNNN", "NDLSNDLSND"))
+        );
+        assert_eq!(
+            reader.get_by_index(0).transpose()?,
+            Some(Record::new("r0", "ACGT", "NDLS"))
+        );
+
+        assert!(reader.get("r3").is_none());
+        assert!(reader.get_by_index(3).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_wrapped_lines() -> io::Result<()> {
+        let data = b"@r0\nACGT\nNN\n+\nNDLS\nAB\n@r1\nACGT\n+\nNDLS\n";
+
+        let index = vec![
+            fai::Record::new(String::from("r0"), 6, 4, 4, 5, 14),
+            fai::Record::new(String::from("r1"), 4, 26, 4, 5, 33),
+        ];
+
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+
+        assert_eq!(
+            reader.get("r0").transpose()?,
+            Some(Record::new("r0", "ACGTNN", "NDLSAB"))
+        );
+        assert_eq!(
+            reader.get("r1").transpose()?,
+            Some(Record::new("r1", "ACGT", "NDLS"))
+        );
+
+        let index = vec![fai::Record::new(String::from("r0"), 6, 4, 0, 0, 14)];
+        let mut reader = IndexedReader::new(Cursor::new(&data[..]), index);
+        assert!(matches!(
+            reader.get("r0"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -28,13 +28,30 @@
 //! # Ok::<(), io::Error>(())
 //! ```
 
+#[cfg(feature = "async")]
+mod r#async;
+
 pub mod fai;
+mod indexed_reader;
 mod indexer;
+mod paired_reader;
 mod reader;
 mod record;
+#[cfg(feature = "unmapped")]
+pub mod unmapped;
 mod writer;
 
-pub use self::{indexer::Indexer, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader,
+    indexer::Indexer,
+    paired_reader::PairedReader,
+    reader::{ParsingMode, Reader},
+    record::Record,
+    writer::Writer,
+};
+
+#[cfg(feature = "async")]
+pub use self::r#async::Reader as AsyncReader;
 
 use std::{
     fs::File,
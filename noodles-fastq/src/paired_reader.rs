@@ -0,0 +1,218 @@
+mod records;
+
+pub use self::records::Records;
+
+use std::io::{self, BufRead};
+
+use super::{Reader, Record};
+
+const READ_NAME_SEPARATOR: u8 = b'/';
+
+/// A paired-end FASTQ reader.
+///
+/// This reads records from two FASTQ streams (R1 and R2) in lockstep. Each record pair is
+/// validated to have the same read name, ignoring the read number.
+///
+/// Read names are compared up to the first whitespace, which discards descriptions, e.g.,
+/// `r0 1:N:0:ATCACG`. A read number suffix, i.e., `/1` for R1 and `/2` for R2, is also ignored.
+pub struct PairedReader<R, S> {
+    r1: Reader<R>,
+    r2: Reader<S>,
+}
+
+impl<R, S> PairedReader<R, S>
+where
+    R: BufRead,
+    S: BufRead,
+{
+    /// Creates a paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    /// let reader = fastq::PairedReader::new(&r1[..], &r2[..]);
+    /// ```
+    pub fn new(r1: R, r2: S) -> Self {
+        Self {
+            r1: Reader::new(r1),
+            r2: Reader::new(r2),
+        }
+    }
+
+    /// Reads a pair of FASTQ records.
+    ///
+    /// This reads a record from each of the R1 and R2 streams.
+    ///
+    /// If successful, the total number of bytes read is returned. If the number of bytes read is
+    /// 0, both streams reached EOF.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if one stream reaches EOF before the other or if the read names of
+    /// the records differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0/1\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::PairedReader::new(&r1[..], &r2[..]);
+    ///
+    /// let mut record1 = fastq::Record::default();
+    /// let mut record2 = fastq::Record::default();
+    /// reader.read_record_pair(&mut record1, &mut record2)?;
+    ///
+    /// assert_eq!(record1.sequence(), b"ATCG");
+    /// assert_eq!(record2.sequence(), b"CGAT");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_record_pair(&mut self, r1: &mut Record, r2: &mut Record) -> io::Result<usize> {
+        let r1_len = self.r1.read_record(r1)?;
+        let r2_len = self.r2.read_record(r2)?;
+
+        match (r1_len, r2_len) {
+            (0, 0) => return Ok(0),
+            (0, _) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "R1 ended before R2 (at R2 read name {})",
+                        String::from_utf8_lossy(r2.read_name())
+                    ),
+                ))
+            }
+            (_, 0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "R2 ended before R1 (at R1 read name {})",
+                        String::from_utf8_lossy(r1.read_name())
+                    ),
+                ))
+            }
+            _ => {}
+        }
+
+        let r1_name = normalize_read_name(r1.read_name(), b'1');
+        let r2_name = normalize_read_name(r2.read_name(), b'2');
+
+        if r1_name != r2_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "read name mismatch: R1 = {}, R2 = {}",
+                    String::from_utf8_lossy(r1.read_name()),
+                    String::from_utf8_lossy(r2.read_name())
+                ),
+            ));
+        }
+
+        Ok(r1_len + r2_len)
+    }
+
+    /// Returns an iterator over record pairs starting from the current stream positions.
+    ///
+    /// Both streams are expected to be at the start of a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = b"@r0 1:N:0:ATCACG\nATCG\n+\nNDLS\n";
+    /// let r2 = b"@r0 2:N:0:ATCACG\nCGAT\n+\nSLDN\n";
+    /// let mut reader = fastq::PairedReader::new(&r1[..], &r2[..]);
+    ///
+    /// let mut records = reader.records();
+    ///
+    /// assert_eq!(
+    ///     records.next().transpose()?,
+    ///     Some((
+    ///         fastq::Record::new("r0 1:N:0:ATCACG", "ATCG", "NDLS"),
+    ///         fastq::Record::new("r0 2:N:0:ATCACG", "CGAT", "SLDN"),
+    ///     ))
+    /// );
+    ///
+    /// assert!(records.next().is_none());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records(&mut self) -> Records<'_, R, S> {
+        Records::new(self)
+    }
+}
+
+// Returns the read name up to the first whitespace and without the given read number suffix.
+pub(crate) fn normalize_read_name(read_name: &[u8], read_number: u8) -> &[u8] {
+    let end = read_name
+        .iter()
+        .position(|b| b.is_ascii_whitespace())
+        .unwrap_or(read_name.len());
+
+    let name = &read_name[..end];
+
+    if name.ends_with(&[READ_NAME_SEPARATOR, read_number]) {
+        &name[..name.len() - 2]
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_record_pair() -> io::Result<()> {
+        let r1 = b"@r0/1\nATCG\n+\nNDLS\n@r1/1\nATCG\n+\nNDLS\n";
+        let r2 = b"@r0/2\nCGAT\n+\nSLDN\n@r2/2\nCGAT\n+\nSLDN\n";
+        let mut reader = PairedReader::new(&r1[..], &r2[..]);
+
+        let mut record1 = Record::default();
+        let mut record2 = Record::default();
+
+        assert_eq!(reader.read_record_pair(&mut record1, &mut record2)?, 36);
+
+        let e = reader
+            .read_record_pair(&mut record1, &mut record2)
+            .unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_pair_with_desynchronized_streams() -> io::Result<()> {
+        let r1 = b"@r0/1\nATCG\n+\nNDLS\n@r1/1\nATCG\n+\nNDLS\n";
+        let r2 = b"@r0/2\nCGAT\n+\nSLDN\n";
+        let mut reader = PairedReader::new(&r1[..], &r2[..]);
+
+        let mut record1 = Record::default();
+        let mut record2 = Record::default();
+
+        reader.read_record_pair(&mut record1, &mut record2)?;
+
+        let e = reader
+            .read_record_pair(&mut record1, &mut record2)
+            .unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_read_name() {
+        assert_eq!(normalize_read_name(b"r0", b'1'), b"r0");
+        assert_eq!(normalize_read_name(b"r0/1", b'1'), b"r0");
+        assert_eq!(normalize_read_name(b"r0/2", b'2'), b"r0");
+        assert_eq!(normalize_read_name(b"r0/2", b'1'), b"r0/2");
+        assert_eq!(normalize_read_name(b"r0 1:N:0:ATCACG", b'1'), b"r0");
+        assert_eq!(normalize_read_name(b"r0/1\t1:N:0", b'1'), b"r0");
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::{self, BufRead};
+
+use crate::Record;
+
+use super::PairedReader;
+
+/// An iterator over record pairs of a paired-end FASTQ reader.
+///
+/// This is created by calling [`PairedReader::records`].
+pub struct Records<'a, R, S> {
+    inner: &'a mut PairedReader<R, S>,
+    r1: Record,
+    r2: Record,
+}
+
+impl<'a, R, S> Records<'a, R, S>
+where
+    R: BufRead,
+    S: BufRead,
+{
+    pub(crate) fn new(inner: &'a mut PairedReader<R, S>) -> Self {
+        Self {
+            inner,
+            r1: Record::default(),
+            r2: Record::default(),
+        }
+    }
+}
+
+impl<'a, R, S> Iterator for Records<'a, R, S>
+where
+    R: BufRead,
+    S: BufRead,
+{
+    type Item = io::Result<(Record, Record)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.read_record_pair(&mut self.r1, &mut self.r2) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok((self.r1.clone(), self.r2.clone()))),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
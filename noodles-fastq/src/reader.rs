@@ -1,12 +1,14 @@
+mod parsing_mode;
 mod records;
 
-pub use self::records::Records;
+pub use self::{parsing_mode::ParsingMode, records::Records};
 
 use std::io::{self, BufRead, Read};
 
 use super::Record;
 
 const READ_NAME_PREFIX: u8 = b'@';
+const PLUS_LINE_PREFIX: u8 = b'+';
 const LINE_FEED: u8 = b'\n';
 const CARRIAGE_RETURN: u8 = b'\r';
 
@@ -14,6 +16,7 @@ const CARRIAGE_RETURN: u8 = b'\r';
 pub struct Reader<R> {
     inner: R,
     line_buf: Vec<u8>,
+    parsing_mode: ParsingMode,
 }
 
 impl<R> Reader<R>
@@ -33,9 +36,36 @@ where
         Self {
             inner,
             line_buf: Vec::new(),
+            parsing_mode: ParsingMode::default(),
         }
     }
 
+    /// Sets the parsing mode.
+    ///
+    /// By default, each record is read as four lines (see [`ParsingMode::Standard`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, ParsingMode};
+    ///
+    /// let data = b"@r0\nAT\nCG\n+r0\nND\nLS\n";
+    ///
+    /// let mut reader = fastq::Reader::new(&data[..]).set_parsing_mode(ParsingMode::Lenient);
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record, fastq::Record::new("r0", "ATCG", "NDLS"));
+    ///
+    /// let mut reader = fastq::Reader::new(&data[..]).set_parsing_mode(ParsingMode::Strict);
+    /// assert!(reader.read_record(&mut record).is_err());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn set_parsing_mode(mut self, parsing_mode: ParsingMode) -> Self {
+        self.parsing_mode = parsing_mode;
+        self
+    }
+
     /// Reads a FASTQ record.
     ///
     /// This reads from the underlying stream until four lines are read: the read name, the
@@ -43,6 +73,10 @@ where
     ///
     /// The stream is expected to be at the start of a record.
     ///
+    /// In lenient mode, the sequence and quality scores may span multiple lines. Sequence lines
+    /// are read until the plus line, and quality score lines are read until there are at least as
+    /// many quality scores as bases.
+    ///
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
@@ -66,20 +100,15 @@ where
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
         record.clear();
 
-        let mut len = match read_read_name(&mut self.inner, record.read_name_mut()) {
-            Ok(0) => return Ok(0),
-            Ok(n) => n,
-            Err(e) => return Err(e),
-        };
-
-        len += read_line(&mut self.inner, record.sequence_mut())?;
-
-        self.line_buf.clear();
-        len += read_line(&mut self.inner, &mut self.line_buf)?;
-
-        len += read_line(&mut self.inner, record.quality_scores_mut())?;
-
-        Ok(len)
+        match self.parsing_mode {
+            ParsingMode::Standard => {
+                read_record_standard(&mut self.inner, record, &mut self.line_buf)
+            }
+            ParsingMode::Strict => read_record_strict(&mut self.inner, record, &mut self.line_buf),
+            ParsingMode::Lenient => {
+                read_record_lenient(&mut self.inner, record, &mut self.line_buf)
+            }
+        }
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -110,6 +139,150 @@ where
     }
 }
 
+fn read_record_standard<R>(
+    reader: &mut R,
+    record: &mut Record,
+    line_buf: &mut Vec<u8>,
+) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut len = match read_read_name(reader, record.read_name_mut())? {
+        0 => return Ok(0),
+        n => n,
+    };
+
+    len += read_line(reader, record.sequence_mut())?;
+
+    line_buf.clear();
+    len += read_line(reader, line_buf)?;
+
+    len += read_line(reader, record.quality_scores_mut())?;
+
+    Ok(len)
+}
+
+fn read_record_strict<R>(
+    reader: &mut R,
+    record: &mut Record,
+    line_buf: &mut Vec<u8>,
+) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut len = match consume_byte(reader, READ_NAME_PREFIX) {
+        Ok(n) => n,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+        Err(e) => return Err(e),
+    };
+
+    len += read_line_strict(reader, record.read_name_mut())?;
+    len += read_line_strict(reader, record.sequence_mut())?;
+
+    line_buf.clear();
+    len += read_line_strict(reader, line_buf)?;
+
+    match line_buf.split_first() {
+        Some((&PLUS_LINE_PREFIX, rest)) if rest.is_empty() || rest == record.read_name() => {}
+        Some((&PLUS_LINE_PREFIX, _)) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "plus line read name mismatch",
+            ))
+        }
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "plus line missing + prefix",
+            ))
+        }
+    }
+
+    len += read_line_strict(reader, record.quality_scores_mut())?;
+
+    if record.quality_scores().len() != record.sequence().len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    Ok(len)
+}
+
+fn read_record_lenient<R>(
+    reader: &mut R,
+    record: &mut Record,
+    line_buf: &mut Vec<u8>,
+) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut len = match read_read_name(reader, record.read_name_mut())? {
+        0 => return Ok(0),
+        n => n,
+    };
+
+    loop {
+        match reader.fill_buf()?.first() {
+            Some(&PLUS_LINE_PREFIX) => break,
+            Some(_) => len += read_line(reader, record.sequence_mut())?,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "unexpected EOF before plus line",
+                ))
+            }
+        }
+    }
+
+    line_buf.clear();
+    len += read_line(reader, line_buf)?;
+
+    loop {
+        match read_line(reader, record.quality_scores_mut())? {
+            0 => break,
+            n => len += n,
+        }
+
+        if record.quality_scores().len() >= record.sequence().len() {
+            break;
+        }
+    }
+
+    if record.quality_scores().len() != record.sequence().len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    Ok(len)
+}
+
+fn read_line_strict<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    match reader.read_until(LINE_FEED, buf)? {
+        0 => Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+        n => {
+            if buf.ends_with(&[LINE_FEED]) {
+                buf.pop();
+
+                if buf.ends_with(&[CARRIAGE_RETURN]) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unexpected CRLF line ending",
+                    ));
+                }
+            }
+
+            Ok(n)
+        }
+    }
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
@@ -191,6 +364,102 @@ dcba
         assert_eq!(len, 0);
     }
 
+    #[test]
+    fn test_read_record_with_lenient_parsing_mode() -> io::Result<()> {
+        let data = b"\
+@r0 LN:8\r
+ACGT\r
+acgt\r
++r0 LN:8\r
+@DLS\r
+NDLS\r
+@r1
+
++
+
+@r2
+TG
++
++@
+";
+
+        let mut reader = Reader::new(&data[..]).set_parsing_mode(ParsingMode::Lenient);
+        let mut record = Record::default();
+
+        assert_eq!(reader.read_record(&mut record)?, 44);
+        assert_eq!(record, Record::new("r0 LN:8", "ACGTacgt", "@DLSNDLS"));
+
+        assert_eq!(reader.read_record(&mut record)?, 8);
+        assert_eq!(record, Record::new("r1", "", ""));
+
+        assert_eq!(reader.read_record(&mut record)?, 12);
+        assert_eq!(record, Record::new("r2", "TG", "+@"));
+
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        let data = b"@r0\nACGT\nACGT\n";
+        let mut reader = Reader::new(&data[..]).set_parsing_mode(ParsingMode::Lenient);
+
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        for data in &[
+            &b"@r0\nACGT\n+\nNDL\n"[..],
+            &b"@r0\nACGT\n+\nND\nLSND\n"[..],
+        ] {
+            let mut reader = Reader::new(*data).set_parsing_mode(ParsingMode::Lenient);
+
+            assert!(matches!(
+                reader.read_record(&mut record),
+                Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_strict_parsing_mode() -> io::Result<()> {
+        fn read_record(data: &[u8]) -> io::Result<Record> {
+            let mut reader = Reader::new(data).set_parsing_mode(ParsingMode::Strict);
+            let mut record = Record::default();
+            reader.read_record(&mut record).map(|_| record)
+        }
+
+        assert_eq!(
+            read_record(b"@r0\nACGT\n+\nNDLS\n")?,
+            Record::new("r0", "ACGT", "NDLS")
+        );
+        assert_eq!(
+            read_record(b"@r0 LN:4\nACGT\n+r0 LN:4\nNDLS")?,
+            Record::new("r0 LN:4", "ACGT", "NDLS")
+        );
+
+        let mut reader = Reader::new(&b""[..]).set_parsing_mode(ParsingMode::Strict);
+        assert_eq!(reader.read_record(&mut Record::default())?, 0);
+
+        for data in &[
+            &b"@r0\r\nACGT\r\n+\r\nNDLS\r\n"[..],
+            &b"@r0\nACGT\n+r1\nNDLS\n"[..],
+            &b"@r0\nAC\nGT\n+\nND\nLS\n"[..],
+            &b"@r0\nACGT\n+\nNDL\n"[..],
+        ] {
+            assert!(matches!(
+                read_record(data),
+                Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        assert!(matches!(
+            read_record(b"@r0\nACGT\n"),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = Vec::new();
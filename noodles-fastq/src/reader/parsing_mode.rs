@@ -0,0 +1,31 @@
+/// A FASTQ reader parsing mode.
+///
+/// The parsing mode determines how the reader handles common deviations from the conventional
+/// four-line FASTQ record format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParsingMode {
+    /// Reads each record as four lines without validating them.
+    ///
+    /// This accepts LF and CRLF line endings and ignores the contents of the plus line. This is
+    /// the default parsing mode.
+    Standard,
+    /// Rejects deviations from the conventional format.
+    ///
+    /// Each record must be exactly four lines with LF line endings. The plus line may only repeat
+    /// the read name, and the quality scores must be the same length as the sequence.
+    Strict,
+    /// Tolerates common deviations from the conventional format.
+    ///
+    /// This accepts CRLF line endings, ignores the contents of the plus line, and allows sequences
+    /// and quality scores to span multiple lines. The quality scores must be the same length as
+    /// the sequence.
+    Lenient,
+}
+
+// `#[default]` on enum variants is not used to keep the minimum supported Rust version.
+#[allow(clippy::derivable_impls)]
+impl Default for ParsingMode {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
@@ -0,0 +1,390 @@
+//! FASTQ to unmapped SAM record conversion.
+//!
+//! Unmapped SAM/BAM files (uBAMs) store raw reads with metadata, e.g., read groups, barcodes, and
+//! UMIs, before alignment.
+
+use std::{convert::TryFrom, io, str};
+
+use noodles_sam::{
+    self as sam,
+    record::{
+        data::{
+            field::{Tag, Value},
+            Field,
+        },
+        Data, Flags, MappingQuality,
+    },
+};
+
+use super::{paired_reader::normalize_read_name, Record};
+
+const ILLUMINA_READ_NAME_FIELD_COUNT: usize = 8;
+const ILLUMINA_DESCRIPTION_FIELD_COUNT: usize = 4;
+const ILLUMINA_FIELD_DELIMITER: char = ':';
+
+/// A FASTQ to unmapped SAM record converter builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    read_group_id: Option<String>,
+    barcodes_from_description: bool,
+    umis_from_read_name: bool,
+}
+
+impl Builder {
+    /// Sets the read group ID.
+    ///
+    /// When set, each record includes a read group (`RG`) data field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::unmapped::Builder;
+    /// let converter = Builder::default().set_read_group_id("rg0").build();
+    /// ```
+    pub fn set_read_group_id<I>(mut self, read_group_id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.read_group_id = Some(read_group_id.into());
+        self
+    }
+
+    /// Sets whether to set sample barcodes from the read descriptions.
+    ///
+    /// When enabled, the index sequence of an Illumina (CASAVA 1.8+) read description, e.g.,
+    /// `ACGT` in `1:N:0:ACGT`, is added as a sample barcode sequence (`BC`) data field. Dual index
+    /// sequences (`ACGT+TGCA`) are separated by a hyphen (`ACGT-TGCA`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::unmapped::Builder;
+    /// let converter = Builder::default().set_barcodes_from_description(true).build();
+    /// ```
+    pub fn set_barcodes_from_description(mut self, barcodes_from_description: bool) -> Self {
+        self.barcodes_from_description = barcodes_from_description;
+        self
+    }
+
+    /// Sets whether to set UMIs from the read names.
+    ///
+    /// When enabled, the UMI of an Illumina read name, i.e., the eighth colon-separated field,
+    /// e.g., `ACGT` in `M0:1:FC0:1:1101:1000:2000:ACGT`, is added as a UMI sequence (`RX`) data
+    /// field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::unmapped::Builder;
+    /// let converter = Builder::default().set_umis_from_read_name(true).build();
+    /// ```
+    pub fn set_umis_from_read_name(mut self, umis_from_read_name: bool) -> Self {
+        self.umis_from_read_name = umis_from_read_name;
+        self
+    }
+
+    /// Builds a FASTQ to unmapped SAM record converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::unmapped::Builder;
+    /// let converter = Builder::default().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        Converter {
+            read_group_id: self.read_group_id,
+            barcodes_from_description: self.barcodes_from_description,
+            umis_from_read_name: self.umis_from_read_name,
+        }
+    }
+}
+
+/// A FASTQ to unmapped SAM record converter.
+///
+/// The read name is the FASTQ read name up to the first whitespace, without a read number suffix
+/// (`/1` or `/2`). Quality scores are expected to be Phred scores offset by 33.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fastq::{self as fastq, unmapped::Builder};
+///
+/// let converter = Builder::default()
+///     .set_read_group_id("rg0")
+///     .set_barcodes_from_description(true)
+///     .build();
+///
+/// let record = fastq::Record::new("r0 1:N:0:ACGT", "ATCG", "NDLS");
+/// let sam_record = converter.convert(&record)?;
+///
+/// assert_eq!(
+///     sam_record.to_string(),
+///     "r0\t4\t*\t0\t0\t*\t*\t0\t0\tATCG\tNDLS\tRG:Z:rg0\tBC:Z:ACGT"
+/// );
+/// # Ok::<(), io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Converter {
+    read_group_id: Option<String>,
+    barcodes_from_description: bool,
+    umis_from_read_name: bool,
+}
+
+impl Converter {
+    /// Returns a builder to create a converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::unmapped::Converter;
+    /// let builder = Converter::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Converts a single-end FASTQ record to an unmapped SAM record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, unmapped::Converter};
+    /// use noodles_sam::record::Flags;
+    ///
+    /// let converter = Converter::builder().build();
+    ///
+    /// let record = fastq::Record::new("r0", "ATCG", "NDLS");
+    /// let sam_record = converter.convert(&record)?;
+    ///
+    /// assert_eq!(sam_record.flags(), Flags::UNMAPPED);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn convert(&self, record: &Record) -> io::Result<sam::Record> {
+        self.convert_with_flags(record, b'1', Flags::UNMAPPED)
+    }
+
+    /// Converts a pair of FASTQ records to unmapped SAM records.
+    ///
+    /// Both records are flagged as paired and unmapped, with their respective read (first or
+    /// last segment) flags set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, unmapped::Converter};
+    /// use noodles_sam::record::Flags;
+    ///
+    /// let converter = Converter::builder().build();
+    ///
+    /// let r1 = fastq::Record::new("r0/1", "ATCG", "NDLS");
+    /// let r2 = fastq::Record::new("r0/2", "CGAT", "SLDN");
+    /// let (sam_r1, sam_r2) = converter.convert_pair(&r1, &r2)?;
+    ///
+    /// assert_eq!(sam_r1.read_name().map(|name| name.as_str()), Some("r0"));
+    /// assert_eq!(
+    ///     sam_r1.flags(),
+    ///     Flags::PAIRED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::READ_1
+    /// );
+    ///
+    /// assert_eq!(sam_r2.read_name().map(|name| name.as_str()), Some("r0"));
+    /// assert_eq!(
+    ///     sam_r2.flags(),
+    ///     Flags::PAIRED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::READ_2
+    /// );
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn convert_pair(&self, r1: &Record, r2: &Record) -> io::Result<(sam::Record, sam::Record)> {
+        let flags = Flags::PAIRED | Flags::UNMAPPED | Flags::MATE_UNMAPPED;
+
+        let sam_r1 = self.convert_with_flags(r1, b'1', flags | Flags::READ_1)?;
+        let sam_r2 = self.convert_with_flags(r2, b'2', flags | Flags::READ_2)?;
+
+        Ok((sam_r1, sam_r2))
+    }
+
+    fn convert_with_flags(
+        &self,
+        record: &Record,
+        read_number: u8,
+        flags: Flags,
+    ) -> io::Result<sam::Record> {
+        let raw_read_name = str::from_utf8(record.read_name())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let read_name = str::from_utf8(normalize_read_name(record.read_name(), read_number))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut builder = sam::Record::builder()
+            .set_read_name(
+                read_name
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            )
+            .set_flags(flags)
+            .set_mapping_quality(MappingQuality::from(0));
+
+        if !record.sequence().is_empty() {
+            let sequence = str::from_utf8(record.sequence())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                .and_then(|s| {
+                    s.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })?;
+
+            builder = builder.set_sequence(sequence);
+        }
+
+        if !record.quality_scores().is_empty() {
+            let quality_scores = str::from_utf8(record.quality_scores())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                .and_then(|s| {
+                    s.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })?;
+
+            builder = builder.set_quality_scores(quality_scores);
+        }
+
+        let mut fields = Vec::new();
+
+        if let Some(read_group_id) = self.read_group_id.as_deref() {
+            fields.push(Field::new(
+                Tag::ReadGroup,
+                Value::String(read_group_id.into()),
+            ));
+        }
+
+        if self.barcodes_from_description {
+            if let Some(barcode) = parse_illumina_barcode(raw_read_name) {
+                fields.push(Field::new(
+                    Tag::SampleBarcodeSequence,
+                    Value::String(barcode),
+                ));
+            }
+        }
+
+        if self.umis_from_read_name {
+            if let Some(umi) = parse_illumina_umi(read_name) {
+                fields.push(Field::new(Tag::UmiSequence, Value::String(umi)));
+            }
+        }
+
+        if !fields.is_empty() {
+            let data = Data::try_from(fields)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            builder = builder.set_data(data);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+// Returns the index sequence of an Illumina read description, e.g., `ACGT` in `r0 1:N:0:ACGT`.
+fn parse_illumina_barcode(raw_read_name: &str) -> Option<String> {
+    let (_, description) = split_once_whitespace(raw_read_name)?;
+    let fields: Vec<_> = description.trim().split(ILLUMINA_FIELD_DELIMITER).collect();
+
+    if fields.len() != ILLUMINA_DESCRIPTION_FIELD_COUNT {
+        return None;
+    }
+
+    normalize_sequence_list(fields[ILLUMINA_DESCRIPTION_FIELD_COUNT - 1])
+}
+
+// Returns the UMI of an Illumina read name, e.g., `ACGT` in `M0:1:FC0:1:1101:1000:2000:ACGT`.
+fn parse_illumina_umi(read_name: &str) -> Option<String> {
+    let fields: Vec<_> = read_name.split(ILLUMINA_FIELD_DELIMITER).collect();
+
+    if fields.len() != ILLUMINA_READ_NAME_FIELD_COUNT {
+        return None;
+    }
+
+    normalize_sequence_list(fields[ILLUMINA_READ_NAME_FIELD_COUNT - 1])
+}
+
+fn split_once_whitespace(s: &str) -> Option<(&str, &str)> {
+    let i = s.find(|c: char| c.is_ascii_whitespace())?;
+    Some((&s[..i], &s[i + 1..]))
+}
+
+// Validates a list of sequences delimited by `+` or `-`, e.g., `ACGT+TGCA`, and returns it using
+// the SAM delimiter (`-`).
+fn normalize_sequence_list(s: &str) -> Option<String> {
+    let is_valid = !s.is_empty()
+        && s.chars().any(|c| c.is_ascii_alphabetic())
+        && s.chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '+' || c == '-');
+
+    if is_valid {
+        Some(s.replace('+', "-"))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        let converter = Converter::builder()
+            .set_read_group_id("rg0")
+            .set_barcodes_from_description(true)
+            .set_umis_from_read_name(true)
+            .build();
+
+        let record = Record::new(
+            "M0:1:FC0:1:1101:1000:2000:ACGT+TTAA/1 1:N:0:GATC+CTAG",
+            "ATCG",
+            "NDLS",
+        );
+
+        let sam_record = converter.convert(&record)?;
+
+        assert_eq!(
+            sam_record.to_string(),
+            "M0:1:FC0:1:1101:1000:2000:ACGT+TTAA\t4\t*\t0\t0\t*\t*\t0\t0\tATCG\tNDLS\tRG:Z:rg0\tBC:Z:GATC-CTAG\tRX:Z:ACGT-TTAA"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_with_empty_sequence() -> io::Result<()> {
+        let converter = Converter::builder().build();
+        let record = Record::new("r0", "", "");
+        let sam_record = converter.convert(&record)?;
+        assert_eq!(sam_record.to_string(), "r0\t4\t*\t0\t0\t*\t*\t0\t0\t*\t*");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_illumina_barcode() {
+        assert_eq!(
+            parse_illumina_barcode("r0 1:N:0:ACGT"),
+            Some(String::from("ACGT"))
+        );
+        assert_eq!(
+            parse_illumina_barcode("r0 1:N:0:ACGT+TGCA"),
+            Some(String::from("ACGT-TGCA"))
+        );
+        assert_eq!(parse_illumina_barcode("r0 1:N:0:2"), None);
+        assert_eq!(parse_illumina_barcode("r0 length=4"), None);
+        assert_eq!(parse_illumina_barcode("r0"), None);
+    }
+
+    #[test]
+    fn test_parse_illumina_umi() {
+        assert_eq!(
+            parse_illumina_umi("M0:1:FC0:1:1101:1000:2000:ACGT"),
+            Some(String::from("ACGT"))
+        );
+        assert_eq!(parse_illumina_umi("M0:1:FC0:1:1101:1000:2000"), None);
+        assert_eq!(parse_illumina_umi("r0"), None);
+    }
+}
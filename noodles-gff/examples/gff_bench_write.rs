@@ -0,0 +1,42 @@
+//! Benchmarks writing GFF records.
+//!
+//! This writes a number of synthetic records (default: 1000000) to a sink and prints the elapsed
+//! time and throughput.
+
+use std::{
+    env,
+    io::{self, BufWriter},
+    time::Instant,
+};
+
+use noodles_gff as gff;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let n = match env::args().nth(1) {
+        Some(s) => s.parse()?,
+        None => 1_000_000,
+    };
+
+    let record: gff::Record = "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\t\
+        ID=exon0;Parent=mRNA0,mRNA1;Dbxref=EMBL:AA816246,NCBI_gi:10727410;Note=noodles%3B gff"
+        .parse()?;
+
+    let mut writer = gff::Writer::new(BufWriter::new(io::sink()));
+
+    let start = Instant::now();
+
+    for _ in 0..n {
+        writer.write_record(&record)?;
+    }
+
+    let elapsed = start.elapsed();
+
+    println!(
+        "wrote {} records in {:?} ({:.0} records/s)",
+        n,
+        elapsed,
+        n as f64 / elapsed.as_secs_f64()
+    );
+
+    Ok(())
+}
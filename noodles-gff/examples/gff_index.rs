@@ -0,0 +1,65 @@
+//! Builds and writes a tabix index from a bgzipped GFF file.
+//!
+//! The input is expected to be sorted by reference sequence name and position. Records are read
+//! lazily, so attributes are never parsed. This writes the output to stdout.
+
+use std::{convert::TryFrom, env, fs::File, io};
+
+use noodles_bgzf::{self as bgzf, index::Chunk};
+use noodles_gff::{self as gff, record::lazy};
+use noodles_tabix as tabix;
+
+fn invalid_data(e: gff::record::ParseError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src)
+        .map(bgzf::Reader::new)
+        .map(gff::Reader::new)?;
+
+    let mut indexer = tabix::Index::indexer();
+    indexer.set_header(tabix::index::header::Builder::gff().build());
+
+    let mut buf = String::new();
+    let mut start_position = reader.virtual_position();
+
+    loop {
+        buf.clear();
+
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+
+        if buf.starts_with('#') {
+            if buf == "##FASTA" {
+                break;
+            }
+        } else {
+            let record = lazy::Record::try_from(buf.as_str()).map_err(invalid_data)?;
+
+            let reference_sequence_name = record.reference_sequence_name().map_err(invalid_data)?;
+            let start = record.start().map_err(invalid_data)?;
+            let end = record.end().map_err(invalid_data)?;
+
+            let chunk = Chunk::new(start_position, end_position);
+            indexer.add_record(&reference_sequence_name, start, end, chunk)?;
+        }
+
+        start_position = end_position;
+    }
+
+    let index = indexer.build()?;
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = tabix::Writer::new(handle);
+
+    writer.write_index(&index)?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! Sorts a GFF file by reference sequence name and start position.
+//!
+//! The output is written to stdout and can be compressed with bgzip and indexed with tabix, unless
+//! the input has embedded sequences (`##FASTA`), which are written after the records.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_gff as gff;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src).map(BufReader::new).map(gff::Reader::new)?;
+    let mut sorter = gff::Sorter::new();
+
+    for result in reader.lines() {
+        let line = result?;
+        sorter.add_line(line);
+    }
+
+    for result in reader.fasta_reader().records() {
+        let record = result?;
+        sorter.add_fasta_record(record);
+    }
+
+    let stdout = io::stdout();
+    let handle = stdout.lock();
+    let mut writer = gff::Writer::new(handle);
+
+    sorter.write(&mut writer)
+}
@@ -3,9 +3,11 @@
 pub mod genome_build;
 pub mod gff_version;
 pub mod sequence_region;
+pub mod species;
 
 pub use self::{
     genome_build::GenomeBuild, gff_version::GffVersion, sequence_region::SequenceRegion,
+    species::Species,
 };
 
 use std::{error, fmt, str::FromStr};
@@ -28,7 +30,7 @@ pub enum Directive {
     /// The ontology used for the sources (`source-ontology`).
     SourceOntology(String),
     /// The species the annotations apply to (`species`).
-    Species(String),
+    Species(Species),
     /// The genome build used for the start and end positions (`genome-build`).
     GenomeBuild(GenomeBuild),
     /// A marker indicating that all forward references to feature IDs have been resolved (`#`).
@@ -46,7 +48,7 @@ impl fmt::Display for Directive {
             Self::FeatureOntology(uri) => write!(f, "{}feature-ontology {}", PREFIX, uri),
             Self::AttributeOntology(uri) => write!(f, "{}attribute-ontology {}", PREFIX, uri),
             Self::SourceOntology(uri) => write!(f, "{}source-ontology {}", PREFIX, uri),
-            Self::Species(uri) => write!(f, "{}species {}", PREFIX, uri),
+            Self::Species(species) => write!(f, "{}", species),
             Self::GenomeBuild(genome_build) => write!(f, "{}", genome_build),
             Self::ForwardReferencesAreResolved => write!(f, "{}#", PREFIX),
             Self::StartOfFasta => write!(f, "{}FASTA", PREFIX),
@@ -69,6 +71,8 @@ pub enum ParseError {
     InvalidGffVersion(gff_version::ParseError),
     /// A sequence region is invalid.
     InvalidSequenceRegion(sequence_region::ParseError),
+    /// A species is invalid.
+    InvalidSpecies(species::ParseError),
     /// A genome build is invalid.
     InvalidGenomeBuild(genome_build::ParseError),
 }
@@ -84,6 +88,7 @@ impl fmt::Display for ParseError {
             Self::MissingValue => f.write_str("directive value is missing"),
             Self::InvalidGffVersion(e) => write!(f, "{}", e),
             Self::InvalidSequenceRegion(e) => write!(f, "{}", e),
+            Self::InvalidSpecies(e) => write!(f, "{}", e),
             Self::InvalidGenomeBuild(e) => write!(f, "{}", e),
         }
     }
@@ -112,22 +117,14 @@ impl FromStr for Directive {
                 .ok_or(ParseError::MissingValue)
                 .and_then(|s| s.parse().map_err(ParseError::InvalidSequenceRegion))
                 .map(Self::SequenceRegion),
-            "feature-ontology" => components
-                .next()
-                .map(|s| Self::FeatureOntology(s.into()))
-                .ok_or(ParseError::MissingValue),
-            "attribute-ontology" => components
-                .next()
-                .map(|s| Self::AttributeOntology(s.into()))
-                .ok_or(ParseError::MissingValue),
-            "source-ontology" => components
-                .next()
-                .map(|s| Self::SourceOntology(s.into()))
-                .ok_or(ParseError::MissingValue),
+            "feature-ontology" => parse_uri(components.next()).map(Self::FeatureOntology),
+            "attribute-ontology" => parse_uri(components.next()).map(Self::AttributeOntology),
+            "source-ontology" => parse_uri(components.next()).map(Self::SourceOntology),
             "species" => components
                 .next()
-                .map(|s| Self::Species(s.into()))
-                .ok_or(ParseError::MissingValue),
+                .ok_or(ParseError::MissingValue)
+                .and_then(|s| s.parse().map_err(ParseError::InvalidSpecies))
+                .map(Self::Species),
             "genome-build" => components
                 .next()
                 .ok_or(ParseError::MissingValue)
@@ -140,6 +137,13 @@ impl FromStr for Directive {
     }
 }
 
+fn parse_uri(s: Option<&str>) -> Result<String, ParseError> {
+    s.map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.into())
+        .ok_or(ParseError::MissingValue)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,7 +174,10 @@ mod tests {
         );
 
         assert_eq!(
-            Directive::Species(String::from("https://example.com/species?id=1")).to_string(),
+            Directive::Species(Species::new(String::from(
+                "https://example.com/species?id=1"
+            )))
+            .to_string(),
             "##species https://example.com/species?id=1"
         );
 
@@ -181,4 +188,59 @@ mod tests {
         assert_eq!(Directive::ForwardReferencesAreResolved.to_string(), "###");
         assert_eq!(Directive::StartOfFasta.to_string(), "##FASTA");
     }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        assert_eq!(
+            "##gff-version 3".parse::<Directive>()?,
+            Directive::GffVersion(GffVersion::default())
+        );
+
+        assert_eq!(
+            "##sequence-region sq0 8 13".parse::<Directive>()?,
+            Directive::SequenceRegion(SequenceRegion::new(String::from("sq0"), 8, 13))
+        );
+
+        assert_eq!(
+            "##feature-ontology https://example.com/fo.obo".parse::<Directive>()?,
+            Directive::FeatureOntology(String::from("https://example.com/fo.obo"))
+        );
+
+        assert_eq!(
+            "##species https://example.com/species?id=1".parse::<Directive>()?,
+            Directive::Species(Species::new(String::from(
+                "https://example.com/species?id=1"
+            )))
+        );
+
+        assert_eq!(
+            "##genome-build NDLS r1".parse::<Directive>()?,
+            Directive::GenomeBuild(GenomeBuild::new(String::from("NDLS"), String::from("r1")))
+        );
+
+        assert_eq!(
+            "###".parse::<Directive>()?,
+            Directive::ForwardReferencesAreResolved
+        );
+        assert_eq!("##FASTA".parse::<Directive>()?, Directive::StartOfFasta);
+
+        assert_eq!(
+            "#gff-version 3".parse::<Directive>(),
+            Err(ParseError::MissingPrefix)
+        );
+        assert_eq!(
+            "##feature-ontology ".parse::<Directive>(),
+            Err(ParseError::MissingValue)
+        );
+        assert_eq!(
+            "##species ".parse::<Directive>(),
+            Err(ParseError::InvalidSpecies(species::ParseError::Empty))
+        );
+        assert_eq!(
+            "##noodles".parse::<Directive>(),
+            Err(ParseError::InvalidName(String::from("noodles")))
+        );
+
+        Ok(())
+    }
 }
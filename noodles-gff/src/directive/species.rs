@@ -0,0 +1,145 @@
+//! GFF directive species.
+
+use std::{error, fmt, str::FromStr};
+
+use super::PREFIX;
+
+const NCBI_TAXONOMY_ID_KEY: &str = "id";
+
+/// A GFF directive species.
+///
+/// The species is typically referenced by an NCBI Taxonomy URI, e.g.,
+/// `https://www.ncbi.nlm.nih.gov/Taxonomy/Browser/wwwtax.cgi?id=6239`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Species {
+    uri: String,
+}
+
+impl Species {
+    /// Creates a species directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::directive::Species;
+    /// let species = Species::new(String::from("https://example.com/species?id=1"));
+    /// ```
+    pub fn new(uri: String) -> Self {
+        Self { uri }
+    }
+
+    /// Returns the species URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::directive::Species;
+    /// let species = Species::new(String::from("https://example.com/species?id=1"));
+    /// assert_eq!(species.uri(), "https://example.com/species?id=1");
+    /// ```
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Returns the NCBI Taxonomy ID.
+    ///
+    /// This is read from the `id` query parameter of the URI. If the URI does not have a valid
+    /// `id` query parameter, this returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::directive::Species;
+    ///
+    /// let species = Species::new(String::from(
+    ///     "https://www.ncbi.nlm.nih.gov/Taxonomy/Browser/wwwtax.cgi?id=6239",
+    /// ));
+    /// assert_eq!(species.taxonomy_id(), Some(6239));
+    ///
+    /// let species = Species::new(String::from("https://example.com/species"));
+    /// assert!(species.taxonomy_id().is_none());
+    /// ```
+    pub fn taxonomy_id(&self) -> Option<u32> {
+        let (_, query) = self.uri.split_once('?')?;
+
+        query
+            .split('&')
+            .filter_map(|param| param.split_once('='))
+            .find(|(key, _)| *key == NCBI_TAXONOMY_ID_KEY)
+            .and_then(|(_, value)| value.parse().ok())
+    }
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}species {}", PREFIX, self.uri)
+    }
+}
+
+/// An error returned when a raw GFF species directive fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid species directive: ")?;
+
+        match self {
+            Self::Empty => f.write_str("empty input"),
+        }
+    }
+}
+
+impl FromStr for Species {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let uri = s.trim();
+
+        if uri.is_empty() {
+            Err(ParseError::Empty)
+        } else {
+            Ok(Self::new(uri.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let species = Species::new(String::from("https://example.com/species?id=1"));
+        assert_eq!(
+            species.to_string(),
+            "##species https://example.com/species?id=1"
+        );
+    }
+
+    #[test]
+    fn test_taxonomy_id() {
+        let species = Species::new(String::from("https://example.com/species?name=ndls&id=8"));
+        assert_eq!(species.taxonomy_id(), Some(8));
+
+        let species = Species::new(String::from("https://example.com/species?id=ndls"));
+        assert!(species.taxonomy_id().is_none());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "https://example.com/species?id=1".parse(),
+            Ok(Species::new(String::from(
+                "https://example.com/species?id=1"
+            )))
+        );
+
+        assert_eq!("".parse::<Species>(), Err(ParseError::Empty));
+    }
+}
@@ -0,0 +1,296 @@
+//! GFF feature hierarchy.
+//!
+//! Records are linked to their parents by the `Parent` attribute, which refers to the `ID` of
+//! another record, e.g., an exon is a part of a transcript, which is a part of a gene. A
+//! [`Feature`] is a record with its child features.
+
+use std::{collections::HashMap, error, fmt};
+
+use super::Record;
+
+/// A GFF feature.
+///
+/// A feature is a record with its child features, i.e., the features with a `Parent` attribute
+/// that refers to the `ID` of this feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature {
+    record: Record,
+    children: Vec<Feature>,
+}
+
+impl Feature {
+    /// Creates a GFF feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// ```
+    pub fn new(record: Record, children: Vec<Feature>) -> Self {
+        Self { record, children }
+    }
+
+    /// Returns the record of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// assert_eq!(feature.record(), &gff::Record::default());
+    /// ```
+    pub fn record(&self) -> &Record {
+        &self.record
+    }
+
+    /// Returns the child features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, feature::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// assert!(feature.children().is_empty());
+    /// ```
+    pub fn children(&self) -> &[Feature] {
+        &self.children
+    }
+}
+
+/// An error returned when a GFF feature hierarchy fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// A parent (`Parent`) is not defined by any record (`ID`).
+    MissingParent(String),
+    /// A feature is its own ancestor.
+    ///
+    /// This includes the ID of the feature.
+    CyclicReference(String),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingParent(id) => write!(f, "missing parent: {}", id),
+            Self::CyclicReference(id) => write!(f, "cyclic reference: {}", id),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Visit {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Builds feature hierarchies from a list of records.
+///
+/// Records without a parent are the roots of the returned features, which are in input order.
+/// A record with multiple parents is a child of each of them. When multiple records share an ID
+/// (e.g., the segments of a CDS), children are attached to the first record with the ID.
+///
+/// All parents must be defined in the given records, so this is typically called with records
+/// between `###` directives (see [`crate::Reader::record_groups`]).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gff::{self as gff, feature};
+///
+/// let records = [
+///     "sq0\tNOODLES\tgene\t8\t34\t.\t+\t.\tID=gene0",
+///     "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA0;Parent=gene0",
+///     "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=mRNA0",
+///     "sq0\tNOODLES\texon\t21\t34\t.\t+\t.\tParent=mRNA0",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<Vec<gff::Record>, _>>()?;
+///
+/// let features = feature::build(&records)?;
+/// assert_eq!(features.len(), 1);
+///
+/// let gene = &features[0];
+/// assert_eq!(gene.record().ty(), "gene");
+///
+/// let transcripts = gene.children();
+/// assert_eq!(transcripts.len(), 1);
+/// assert_eq!(transcripts[0].children().len(), 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn build(records: &[Record]) -> Result<Vec<Feature>, BuildError> {
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+
+    for (i, record) in records.iter().enumerate() {
+        if let Some(id) = record.attributes().id() {
+            ids.entry(id).or_insert(i);
+        }
+    }
+
+    let mut children = vec![Vec::new(); records.len()];
+    let mut roots = Vec::new();
+
+    for (i, record) in records.iter().enumerate() {
+        let mut parents = record.attributes().parent().peekable();
+
+        if parents.peek().is_none() {
+            roots.push(i);
+        }
+
+        for parent in parents {
+            let j = ids
+                .get(parent)
+                .copied()
+                .ok_or_else(|| BuildError::MissingParent(parent.into()))?;
+
+            children[j].push(i);
+        }
+    }
+
+    let mut visits = vec![Visit::Unvisited; records.len()];
+
+    let features = roots
+        .into_iter()
+        .map(|i| assemble(records, &children, &mut visits, i))
+        .collect::<Result<_, _>>()?;
+
+    // Records that are not reachable from a root are in a parent cycle.
+    if let Some(i) = visits.iter().position(|&visit| visit == Visit::Unvisited) {
+        return Err(cyclic_reference_error(&records[i]));
+    }
+
+    Ok(features)
+}
+
+fn assemble(
+    records: &[Record],
+    children: &[Vec<usize>],
+    visits: &mut [Visit],
+    i: usize,
+) -> Result<Feature, BuildError> {
+    if visits[i] == Visit::InProgress {
+        return Err(cyclic_reference_error(&records[i]));
+    }
+
+    visits[i] = Visit::InProgress;
+
+    let child_features = children[i]
+        .iter()
+        .map(|&j| assemble(records, children, visits, j))
+        .collect::<Result<_, _>>()?;
+
+    visits[i] = Visit::Done;
+
+    Ok(Feature::new(records[i].clone(), child_features))
+}
+
+fn cyclic_reference_error(record: &Record) -> BuildError {
+    let id = record.attributes().id().unwrap_or_default();
+    BuildError::CyclicReference(id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_records(lines: &[&str]) -> Vec<Record> {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_build() -> Result<(), BuildError> {
+        let records = parse_records(&[
+            "sq0\tNOODLES\tgene\t8\t34\t.\t+\t.\tID=gene0",
+            "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA0;Parent=gene0",
+            "sq0\tNOODLES\tmRNA\t8\t21\t.\t+\t.\tID=mRNA1;Parent=gene0",
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tID=exon0;Parent=mRNA0,mRNA1",
+            "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t0\tID=cds0;Parent=mRNA0",
+            "sq0\tNOODLES\tCDS\t21\t34\t.\t+\t1\tID=cds0;Parent=mRNA0",
+            "sq1\tNOODLES\tgene\t5\t8\t.\t-\t.\tID=gene1",
+        ]);
+
+        let features = build(&records)?;
+        assert_eq!(features.len(), 2);
+
+        let gene0 = &features[0];
+        assert_eq!(gene0.record(), &records[0]);
+
+        let transcripts = gene0.children();
+        assert_eq!(transcripts.len(), 2);
+
+        let mrna0_children: Vec<_> = transcripts[0]
+            .children()
+            .iter()
+            .map(|feature| feature.record())
+            .collect();
+        assert_eq!(mrna0_children, [&records[3], &records[4], &records[5]]);
+
+        let mrna1_children: Vec<_> = transcripts[1]
+            .children()
+            .iter()
+            .map(|feature| feature.record())
+            .collect();
+        assert_eq!(mrna1_children, [&records[3]]);
+
+        assert_eq!(features[1], Feature::new(records[6].clone(), Vec::new()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_repeated_parent_keys() -> Result<(), BuildError> {
+        let records = parse_records(&[
+            "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA0",
+            "sq0\tNOODLES\tmRNA\t8\t21\t.\t+\t.\tID=mRNA1",
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tID=exon0;Parent=mRNA0;Parent=mRNA1",
+        ]);
+
+        let features = build(&records)?;
+        assert_eq!(features.len(), 2);
+
+        for feature in &features {
+            let children: Vec<_> = feature.children().iter().map(|f| f.record()).collect();
+            assert_eq!(children, [&records[2]]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_missing_parent() {
+        let records = parse_records(&["sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA0;Parent=gene0"]);
+
+        assert_eq!(
+            build(&records),
+            Err(BuildError::MissingParent(String::from("gene0")))
+        );
+    }
+
+    #[test]
+    fn test_build_with_cyclic_reference() {
+        let records = parse_records(&[
+            "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA0;Parent=mRNA1",
+            "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA1;Parent=mRNA0",
+        ]);
+
+        assert_eq!(
+            build(&records),
+            Err(BuildError::CyclicReference(String::from("mRNA0")))
+        );
+
+        let records = parse_records(&[
+            "sq0\tNOODLES\tgene\t8\t34\t.\t+\t.\tID=gene0",
+            "sq0\tNOODLES\tmRNA\t8\t34\t.\t+\t.\tID=mRNA0;Parent=gene0,exon0",
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tID=exon0;Parent=mRNA0",
+        ]);
+
+        assert_eq!(
+            build(&records),
+            Err(BuildError::CyclicReference(String::from("mRNA0")))
+        );
+    }
+}
@@ -32,9 +32,15 @@
 //! ```
 
 pub mod directive;
+pub mod feature;
 pub mod line;
 pub mod reader;
 pub mod record;
+mod sorter;
+pub mod translation;
 mod writer;
 
-pub use self::{directive::Directive, line::Line, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    directive::Directive, line::Line, reader::Reader, record::Record, sorter::Sorter,
+    writer::Writer,
+};
@@ -15,6 +15,16 @@ pub enum Line {
     Record(Record),
 }
 
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directive(directive) => write!(f, "{}", directive),
+            Self::Comment(comment) => write!(f, "#{}", comment),
+            Self::Record(record) => write!(f, "{}", record),
+        }
+    }
+}
+
 /// An error returns when a raw GFF line fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -57,6 +67,18 @@ impl FromStr for Line {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() {
+        let line = Line::Directive(Directive::GffVersion(Default::default()));
+        assert_eq!(line.to_string(), "##gff-version 3");
+
+        let line = Line::Comment(String::from("format: gff3"));
+        assert_eq!(line.to_string(), "#format: gff3");
+
+        let line = Line::Record(Record::default());
+        assert_eq!(line.to_string(), ".\t.\t.\t1\t1\t.\t.\t.\t.");
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(
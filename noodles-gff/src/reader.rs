@@ -1,15 +1,31 @@
 //! GFF reader and iterators.
 
+mod features;
 mod lines;
+mod query;
+mod record_groups;
 mod records;
+mod records_in_region;
 
-pub use self::{lines::Lines, records::Records};
+pub use self::{
+    features::Features, lines::Lines, query::Query, record_groups::RecordGroups, records::Records,
+    records_in_region::RecordsInRegion,
+};
 
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_fasta as fasta;
+use noodles_tabix as tabix;
+
+const LINE_FEED: char = '\n';
+const CARRIAGE_RETURN: char = '\r';
 
 /// A GFF reader.
 pub struct Reader<R> {
     inner: R,
+    is_fasta_started: bool,
 }
 
 impl<R> Reader<R>
@@ -26,7 +42,10 @@ where
     /// let mut reader = gff::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            is_fasta_started: false,
+        }
     }
 
     /// Returns a reference to the underlying reader.
@@ -69,8 +88,8 @@ where
     /// Reads a raw GFF line.
     ///
     /// This reads from the underlying stream until a newline is reached and appends it to the
-    /// given buffer, sans the final newline character. The buffer can subsequently be parsed as a
-    /// [`crate::Line`].
+    /// given buffer, sans the final newline character (`\n` or `\r\n`). The buffer can
+    /// subsequently be parsed as a [`crate::Line`].
     ///
     /// It is more ergonomic to read records using an iterator (see [`Self::lines`]), but using
     /// this method allows control of the line buffer and whether the raw line should be parsed.
@@ -95,15 +114,14 @@ where
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
-        let result = self.inner.read_line(buf);
-        buf.pop();
-        result
+        read_line(&mut self.inner, buf)
     }
 
     /// Returns an iterator over lines starting from the current stream position.
     ///
-    /// When using this, the caller is responsible to stop reading at either EOF or when the
-    /// `FASTA` directive is read, whichever comes first.
+    /// This stops at either EOF or after the `FASTA` directive is read, whichever comes first.
+    /// Embedded sequences can then be read using [`Self::fasta_reader`]. Once the `FASTA` directive
+    /// is read, subsequent iterators return no lines.
     ///
     /// Unlike [`Self::read_line`], each line is parsed as a [`crate::Line`].
     ///
@@ -156,10 +174,305 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self.lines())
     }
+
+    /// Returns an iterator over records that intersect the given region, starting from the
+    /// current stream position.
+    ///
+    /// This does not require an index. All records are read and filtered by reference sequence
+    /// name and interval, using the same overlap semantics as [`Reader::query`]. It stops at
+    /// either EOF or when the `FASTA` directive is read, whichever comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_core::Region;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+    /// sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1
+    /// sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene2
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    ///
+    /// let region = Region::mapped("sq0", 13, 21);
+    /// let ids = reader
+    ///     .records_in_region(&region)
+    ///     .map(|result| result.map(|record| record.attributes().id().map(String::from)))
+    ///     .collect::<io::Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(ids, [Some(String::from("gene0")), Some(String::from("gene1"))]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_in_region(&mut self, region: &Region) -> RecordsInRegion<'_, R> {
+        RecordsInRegion::new(self.records(), region.clone())
+    }
+
+    /// Returns an iterator over groups of records starting from the current stream position.
+    ///
+    /// Records are grouped until all forward references are resolved, as indicated by the `###`
+    /// directive. The last group ends at either EOF or when the `FASTA` directive is read,
+    /// whichever comes first.
+    ///
+    /// Parent (`Parent`) and derives from (`Derives_from`) references must be defined in the
+    /// same group. Otherwise, the group fails with an invalid data error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+    /// sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=mRNA0;Parent=gene0\n###
+    /// sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    /// let mut groups = reader.record_groups();
+    ///
+    /// assert_eq!(groups.next().transpose()?.map(|records| records.len()), Some(2));
+    /// assert_eq!(groups.next().transpose()?.map(|records| records.len()), Some(1));
+    /// assert!(groups.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn record_groups(&mut self) -> RecordGroups<'_, R> {
+        RecordGroups::new(self.lines())
+    }
+
+    /// Returns an iterator over feature hierarchies starting from the current stream position.
+    ///
+    /// Features are built from each group of records (see [`Self::record_groups`]) using the
+    /// `ID` and `Parent` attributes. Only top-level features, i.e., records without a parent, are
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+    /// sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=mRNA0;Parent=gene0
+    /// sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=mRNA0\n###
+    /// sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    /// let mut features = reader.features();
+    ///
+    /// let gene = features.next().transpose()?.expect("missing feature");
+    /// assert_eq!(gene.record().attributes().id(), Some("gene0"));
+    /// assert_eq!(gene.children()[0].children().len(), 1);
+    ///
+    /// let gene = features.next().transpose()?.expect("missing feature");
+    /// assert_eq!(gene.record().attributes().id(), Some("gene1"));
+    ///
+    /// assert!(features.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn features(&mut self) -> Features<'_, R> {
+        Features::new(self.record_groups())
+    }
+
+    /// Returns a FASTA reader over the embedded reference sequences.
+    ///
+    /// The stream is expected to be directly after the `FASTA` directive, e.g., after reading all
+    /// lines or records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0\n##FASTA
+    /// >sq0
+    /// ACGT
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    ///
+    /// for result in reader.records() {
+    ///     let _record = result?;
+    /// }
+    ///
+    /// let mut fasta_reader = reader.fasta_reader();
+    /// let mut records = fasta_reader.records();
+    ///
+    /// assert_eq!(
+    ///     records.next().transpose()?,
+    ///     Some(fasta::Record::new(
+    ///         fasta::record::Definition::new(String::from("sq0"), None),
+    ///         fasta::record::Sequence::from(b"ACGT".to_vec()),
+    ///     ))
+    /// );
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn fasta_reader(&mut self) -> fasta::Reader<&mut R> {
+        fasta::Reader::new(&mut self.inner)
+    }
+}
+
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read,
+{
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// Together with [`Self::read_line`], this can be used to build a tabix index, where the
+    /// virtual positions before and after reading a record line form its chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = Vec::new();
+    /// let reader = gff::Reader::new(bgzf::Reader::new(&data[..]));
+    /// let virtual_position = reader.virtual_position();
+    ///
+    /// assert_eq!(virtual_position.compressed(), 0);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    /// ```
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf::{self as bgzf, index::Chunk};
+    /// use noodles_gff as gff;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"##gff-version 3\nsq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0\n")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = gff::Reader::new(bgzf::Reader::new(&data[..]));
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.set_header(tabix::index::header::Builder::gff().build());
+    ///
+    /// let mut buf = String::new();
+    /// let mut start_position = reader.virtual_position();
+    ///
+    /// while reader.read_line(&mut buf)? != 0 {
+    ///     let end_position = reader.virtual_position();
+    ///
+    ///     if let Ok(gff::Line::Record(record)) = buf.parse() {
+    ///         let chunk = Chunk::new(start_position, end_position);
+    ///         indexer.add_record(
+    ///             record.reference_sequence_name(),
+    ///             record.start(),
+    ///             record.end(),
+    ///             chunk,
+    ///         )?;
+    ///     }
+    ///
+    ///     buf.clear();
+    ///     start_position = end_position;
+    /// }
+    ///
+    /// let index = indexer.build()?;
+    /// assert_eq!(index.reference_sequence_names(), ["sq0"]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Seeks the underlying BGZF stream to the given virtual position.
+    ///
+    /// Virtual positions typically come from an associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut reader = File::open("annotations.gff3.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gff::Reader::new)?;
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::from(102334155);
+    /// reader.seek(virtual_position)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.is_fasta_started = false;
+        self.inner.seek(pos)
+    }
+
+    /// Returns an iterator over records that intersects the given region.
+    ///
+    /// The GFF is expected to be bgzipped and sorted, with an associated tabix index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Region;
+    /// use noodles_gff as gff;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut reader = File::open("annotations.gff3.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gff::Reader::new)?;
+    ///
+    /// let index = tabix::read("annotations.gff3.gz.tbi")?;
+    /// let region = Region::mapped("sq0", 8, 13);
+    /// let query = reader.query(&index, &region)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn query(&mut self, index: &tabix::Index, region: &Region) -> io::Result<Query<'_, R>> {
+        let chunks = index.query_region(region)?;
+        Ok(Query::new(self, chunks, region.clone()))
+    }
+}
+
+// Reads all bytes until a line feed ('\n') or EOF is reached.
+//
+// The buffer will not include the trailing newline ('\n' or '\r\n').
+fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    match reader.read_line(buf) {
+        Ok(0) => Ok(0),
+        Ok(n) => {
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+
+                if buf.ends_with(CARRIAGE_RETURN) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+        Err(e) => Err(e),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::{Directive, Line, Record};
+
     use super::*;
 
     #[test]
@@ -204,4 +517,167 @@ ACGT
 
         Ok(())
     }
+
+    #[test]
+    fn test_lines_with_fasta_directive() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+##FASTA
+>sq0
+ACGT
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let lines: Vec<_> = reader.lines().collect::<Result<_, _>>()?;
+
+        assert_eq!(
+            lines,
+            [
+                Line::Directive(Directive::GffVersion(Default::default())),
+                Line::Directive(Directive::StartOfFasta),
+            ]
+        );
+
+        assert!(reader.lines().next().is_none());
+        assert!(reader.records().next().is_none());
+
+        let records: Vec<_> = reader.fasta_reader().records().collect::<Result<_, _>>()?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name(), "sq0");
+        assert_eq!(records[0].sequence().as_ref(), b"ACGT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_bgzf::index::Chunk;
+
+        let lines = [
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0",
+            "sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1",
+            "sq1\tNOODLES\tgene\t5\t8\t.\t+\t.\tID=gene2",
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writeln!(writer, "##gff-version 3")?;
+
+        let mut indexer = tabix::Index::indexer();
+
+        for line in &lines {
+            let record: Record = line.parse()?;
+
+            let start_position = writer.virtual_position()?;
+            writeln!(writer, "{}", line)?;
+            let end_position = writer.virtual_position()?;
+
+            indexer.add_record(
+                record.reference_sequence_name(),
+                record.start(),
+                record.end(),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let region = Region::mapped("sq0", 13, 21);
+        let ids = reader
+            .query(&index, &region)?
+            .map(|result| result.map(|record| record.attributes().id().map(String::from)))
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(
+            ids,
+            [Some(String::from("gene0")), Some(String::from("gene1"))]
+        );
+
+        let region = Region::mapped("sq1", 1, 4);
+        assert_eq!(reader.query(&index, &region)?.count(), 0);
+
+        let region = Region::mapped("sq2", 1, 4);
+        assert!(reader.query(&index, &region).is_err());
+
+        assert!(matches!(
+            reader.query(&index, &Region::Unmapped),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_groups() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=mRNA0;Parent=gene0
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+###
+###
+sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1
+###
+##FASTA
+>sq0
+ACGT
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let groups: Vec<_> = reader.record_groups().collect::<Result<_, _>>()?;
+
+        let ids: Vec<Vec<_>> = groups
+            .iter()
+            .map(|records| {
+                records
+                    .iter()
+                    .filter_map(|record| record.attributes().id())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(ids, [vec!["mRNA0", "gene0"], vec!["gene1"]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_groups_with_unresolved_reference() {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+###
+sq0\tNOODLES\tmRNA\t8\t13\t.\t+\t.\tID=mRNA0;Parent=gene0
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut groups = reader.record_groups();
+
+        assert!(matches!(groups.next(), Some(Ok(_))));
+        assert!(matches!(
+            groups.next(),
+            Some(Err(ref e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_line() -> io::Result<()> {
+        fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
+            buf.clear();
+            read_line(&mut reader, buf)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = String::new();
+
+        t(&mut buf, b"##gff-version 3\n", "##gff-version 3")?;
+        t(&mut buf, b"##gff-version 3\r\n", "##gff-version 3")?;
+        t(&mut buf, b"##gff-version 3", "##gff-version 3")?;
+
+        Ok(())
+    }
 }
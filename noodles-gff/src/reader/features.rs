@@ -0,0 +1,55 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+};
+
+use crate::feature::{self, Feature};
+
+use super::RecordGroups;
+
+/// An iterator over features of a GFF reader.
+///
+/// Feature hierarchies are built from each group of records, i.e., records between `###`
+/// directives. It stops at either EOF or when the `FASTA` directive is read, whichever comes
+/// first.
+///
+/// This is created by calling [`crate::Reader::features`].
+pub struct Features<'a, R> {
+    record_groups: RecordGroups<'a, R>,
+    features: VecDeque<Feature>,
+}
+
+impl<'a, R> Features<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(record_groups: RecordGroups<'a, R>) -> Self {
+        Self {
+            record_groups,
+            features: VecDeque::new(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for Features<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Feature>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(feature) = self.features.pop_front() {
+                return Some(Ok(feature));
+            }
+
+            match self.record_groups.next()? {
+                Ok(records) => match feature::build(&records) {
+                    Ok(features) => self.features.extend(features),
+                    Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
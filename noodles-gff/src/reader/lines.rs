@@ -1,13 +1,14 @@
 use std::io::{self, BufRead};
 
-use crate::Line;
+use crate::{Directive, Line};
 
 use super::Reader;
 
 /// An iterator over lines of a GFF reader.
 ///
-/// When using this, the caller is responsible to stop reading at either EOF or when the `FASTA`
-/// directive is read, whichever comes first.
+/// This stops at either EOF or after the `FASTA` directive is read, whichever comes first. The
+/// `FASTA` directive is the last line returned, and it is tracked by the reader, so later
+/// iterators over the same reader also stop.
 ///
 /// This is created by calling [`Reader::lines`].
 pub struct Lines<'a, R> {
@@ -34,12 +35,22 @@ where
     type Item = io::Result<Line>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_fasta_started {
+            return None;
+        }
+
         self.line_buf.clear();
 
         match self.inner.read_line(&mut self.line_buf) {
             Ok(0) => None,
             Ok(_) => match self.line_buf.parse() {
-                Ok(line) => Some(Ok(line)),
+                Ok(line) => {
+                    if line == Line::Directive(Directive::StartOfFasta) {
+                        self.inner.is_fasta_started = true;
+                    }
+
+                    Some(Ok(line))
+                }
                 Err(e) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
             },
             Err(e) => Some(Err(e)),
@@ -0,0 +1,77 @@
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf::{
+    self as bgzf,
+    index::{Chunk, ChunkReader},
+};
+use noodles_core::Region;
+
+use crate::{Line, Record};
+
+use super::{records_in_region::intersects, Reader};
+
+/// An iterator over records of a GFF reader that intersects a given region.
+///
+/// This is created by calling [`Reader::query`].
+pub struct Query<'a, R>
+where
+    R: Read + Seek,
+{
+    reader: Reader<ChunkReader<'a, R>>,
+    region: Region,
+    line_buf: String,
+}
+
+impl<'a, R> Query<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(crate) fn new(
+        reader: &'a mut Reader<bgzf::Reader<R>>,
+        chunks: Vec<Chunk>,
+        region: Region,
+    ) -> Self {
+        reader.is_fasta_started = false;
+
+        Self {
+            reader: Reader::new(ChunkReader::new(&mut reader.inner, chunks)),
+            region,
+            line_buf: String::new(),
+        }
+    }
+
+    fn read_and_parse_line(&mut self) -> Option<io::Result<Line>> {
+        self.line_buf.clear();
+
+        match self.reader.read_line(&mut self.line_buf) {
+            Ok(0) => None,
+            Ok(_) => Some(
+                self.line_buf
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<'a, R> Iterator for Query<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.read_and_parse_line()? {
+                Ok(Line::Record(record)) => {
+                    if intersects(&record, &self.region) {
+                        return Some(Ok(record));
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
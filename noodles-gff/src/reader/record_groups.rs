@@ -0,0 +1,94 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufRead},
+};
+
+use crate::{Directive, Line, Record};
+
+use super::Lines;
+
+/// An iterator over groups of records of a GFF reader.
+///
+/// A group ends when all forward references are resolved, i.e., at the `###` directive, or at
+/// either EOF or the `FASTA` directive. Each parent (`Parent`) and derives from (`Derives_from`)
+/// reference in a group must be defined by a record (`ID`) in the same group.
+///
+/// This is created by calling [`crate::Reader::record_groups`].
+pub struct RecordGroups<'a, R> {
+    lines: Lines<'a, R>,
+    is_eof: bool,
+}
+
+impl<'a, R> RecordGroups<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(lines: Lines<'a, R>) -> Self {
+        Self {
+            lines,
+            is_eof: false,
+        }
+    }
+
+    fn read_group(&mut self) -> io::Result<Vec<Record>> {
+        let mut records = Vec::new();
+
+        for result in self.lines.by_ref() {
+            match result? {
+                Line::Directive(Directive::ForwardReferencesAreResolved) if !records.is_empty() => {
+                    return Ok(records);
+                }
+                Line::Directive(Directive::StartOfFasta) => break,
+                Line::Record(record) => records.push(record),
+                _ => {}
+            }
+        }
+
+        self.is_eof = true;
+
+        Ok(records)
+    }
+}
+
+impl<'a, R> Iterator for RecordGroups<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Vec<Record>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_eof {
+            return None;
+        }
+
+        match self.read_group() {
+            Ok(records) if records.is_empty() => None,
+            Ok(records) => Some(resolve_references(&records).map(|_| records)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn resolve_references(records: &[Record]) -> io::Result<()> {
+    let ids: HashSet<_> = records
+        .iter()
+        .filter_map(|record| record.attributes().id())
+        .collect();
+
+    for record in records {
+        let attributes = record.attributes();
+
+        let references = attributes.parent().chain(attributes.derives_from());
+
+        for reference in references {
+            if !ids.contains(reference) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unresolved reference: {}", reference),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
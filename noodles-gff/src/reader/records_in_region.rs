@@ -0,0 +1,81 @@
+use std::io::{self, BufRead};
+
+use noodles_core::Region;
+
+use crate::Record;
+
+use super::Records;
+
+/// An iterator over records of a GFF reader that intersect a given region.
+///
+/// Unlike [`super::Query`], this does not use an index and reads all records until the end of
+/// the stream (or the `FASTA` directive), so the input does not need to be sorted.
+///
+/// This is created by calling [`super::Reader::records_in_region`].
+pub struct RecordsInRegion<'a, R> {
+    records: Records<'a, R>,
+    region: Region,
+}
+
+impl<'a, R> RecordsInRegion<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(records: Records<'a, R>, region: Region) -> Self {
+        Self { records, region }
+    }
+}
+
+impl<'a, R> Iterator for RecordsInRegion<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next()? {
+                Ok(record) => {
+                    if intersects(&record, &self.region) {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+pub(crate) fn intersects(record: &Record, region: &Region) -> bool {
+    match region {
+        Region::Mapped { name, .. } => {
+            record.reference_sequence_name() == name
+                && match (record.interval(), region.interval()) {
+                    (Some(a), Some(b)) => a.intersects(&b),
+                    _ => false,
+                }
+        }
+        Region::Unmapped => false,
+        Region::All => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersects() -> Result<(), crate::record::ParseError> {
+        let record: Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0".parse()?;
+
+        assert!(intersects(&record, &Region::mapped("sq0", 13, 21)));
+        assert!(intersects(&record, &Region::mapped("sq0", 1, 8)));
+        assert!(!intersects(&record, &Region::mapped("sq0", 14, 21)));
+        assert!(!intersects(&record, &Region::mapped("sq1", 8, 13)));
+        assert!(!intersects(&record, &Region::mapped("sq0", 13, 8)));
+        assert!(!intersects(&record, &Region::Unmapped));
+        assert!(intersects(&record, &Region::All));
+
+        Ok(())
+    }
+}
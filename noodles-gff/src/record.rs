@@ -3,18 +3,52 @@
 pub mod attributes;
 mod builder;
 mod field;
+pub mod lazy;
+pub(crate) mod percent;
 mod phase;
 mod strand;
 
 pub use self::{
-    attributes::Attributes, builder::Builder, field::Field, phase::Phase, strand::Strand,
+    attributes::Attributes,
+    builder::{BuildError, Builder},
+    field::Field,
+    phase::Phase,
+    strand::Strand,
 };
 
-use std::{error, fmt, num, str::FromStr};
+use std::{
+    convert::TryFrom,
+    error, fmt, num,
+    str::{self, FromStr},
+};
 
-pub(crate) const NULL_FIELD: &str = ".";
+use noodles_core::{region::Interval, Position};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+
+use self::percent::percent_decode;
+
+// § 2.3 Column 1: "seqid" (2020-08-18): "IDs may contain any characters, but must escape any
+// characters not in the set [a-zA-Z0-9.:^*$@!+_?-|]."
+pub(crate) const REFERENCE_SEQUENCE_NAME_PERCENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'.')
+    .remove(b':')
+    .remove(b'^')
+    .remove(b'*')
+    .remove(b'$')
+    .remove(b'@')
+    .remove(b'!')
+    .remove(b'+')
+    .remove(b'_')
+    .remove(b'?')
+    .remove(b'-')
+    .remove(b'|');
+
+pub(crate) const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS.add(b'%');
+
+const NULL_FIELD: &str = ".";
 const FIELD_DELIMITER: char = '\t';
 const MAX_FIELDS: usize = 9;
+const CDS_TYPE: &str = "CDS";
 
 /// A GFF record.
 #[derive(Clone, Debug, PartialEq)]
@@ -40,9 +74,10 @@ impl Record {
     ///
     /// let record = gff::Record::builder()
     ///     .set_reference_sequence_name(String::from("sq0"))
-    ///     .build();
+    ///     .build()?;
     ///
     /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn builder() -> Builder {
         Builder::new()
@@ -117,6 +152,30 @@ impl Record {
         self.end
     }
 
+    /// Returns the interval of the record.
+    ///
+    /// This is the closed interval of the start and end positions. It returns `None` if the
+    /// positions do not form a valid interval, e.g., the start position is < 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff as gff;
+    ///
+    /// let record: gff::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0".parse()?;
+    /// let interval = record.interval().expect("invalid interval");
+    ///
+    /// assert_eq!(interval.start(), Position::new(8).unwrap());
+    /// assert_eq!(interval.end(), Position::new(13).unwrap());
+    /// # Ok::<_, gff::record::ParseError>(())
+    /// ```
+    pub fn interval(&self) -> Option<Interval> {
+        let start = Position::try_from(self.start).ok()?;
+        let end = Position::try_from(self.end).ok()?;
+        Interval::new(start, end)
+    }
+
     /// Returns the score of the record.
     ///
     /// # Examples
@@ -172,7 +231,55 @@ impl Record {
 
 impl Default for Record {
     fn default() -> Self {
-        Builder::new().build()
+        Self {
+            reference_sequence_name: NULL_FIELD.into(),
+            source: NULL_FIELD.into(),
+            ty: NULL_FIELD.into(),
+            start: 1,
+            end: 1,
+            score: None,
+            strand: Strand::default(),
+            phase: None,
+            attributes: Attributes::default(),
+        }
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{seqid}{delimiter}{source}{delimiter}{ty}{delimiter}{start}{delimiter}{end}",
+            delimiter = FIELD_DELIMITER,
+            seqid = utf8_percent_encode(
+                self.reference_sequence_name(),
+                REFERENCE_SEQUENCE_NAME_PERCENT_ENCODE_SET
+            ),
+            source = utf8_percent_encode(self.source(), PERCENT_ENCODE_SET),
+            ty = utf8_percent_encode(self.ty(), PERCENT_ENCODE_SET),
+            start = self.start(),
+            end = self.end(),
+        )?;
+
+        if let Some(score) = self.score() {
+            write!(f, "{}{}", FIELD_DELIMITER, score)?;
+        } else {
+            write!(f, "{}{}", FIELD_DELIMITER, NULL_FIELD)?;
+        }
+
+        write!(f, "{}{}", FIELD_DELIMITER, self.strand())?;
+
+        if let Some(phase) = self.phase() {
+            write!(f, "{}{}", FIELD_DELIMITER, phase)?;
+        } else {
+            write!(f, "{}{}", FIELD_DELIMITER, NULL_FIELD)?;
+        }
+
+        if self.attributes().is_empty() {
+            write!(f, "{}{}", FIELD_DELIMITER, NULL_FIELD)
+        } else {
+            write!(f, "{}{}", FIELD_DELIMITER, self.attributes())
+        }
     }
 }
 
@@ -185,6 +292,8 @@ pub enum ParseError {
     MissingField(Field),
     /// A field is empty.
     EmptyField(Field),
+    /// A field is not valid UTF-8 after percent-decoding.
+    InvalidField(Field, str::Utf8Error),
     /// The start is invalid.
     InvalidStart(num::ParseIntError),
     /// The end is invalid.
@@ -211,6 +320,7 @@ impl fmt::Display for ParseError {
             Self::Empty => write!(f, "empty input"),
             Self::MissingField(field) => write!(f, "missing field: {:?}", field),
             Self::EmptyField(field) => write!(f, "empty field: {:?}", field),
+            Self::InvalidField(field, e) => write!(f, "invalid field: {:?}: {}", field, e),
             Self::InvalidStart(e) => write!(f, "invalid start: {}", e),
             Self::InvalidEnd(e) => write!(f, "invalid end: {}", e),
             Self::InvalidScore(e) => write!(f, "invalid score: {}", e),
@@ -229,9 +339,9 @@ impl FromStr for Record {
         let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
 
         let reference_sequence_name =
-            parse_string(&mut fields, Field::ReferenceSequenceName).map(|s| s.into())?;
-        let source = parse_string(&mut fields, Field::Source).map(|s| s.into())?;
-        let ty = parse_string(&mut fields, Field::Type).map(|s| s.into())?;
+            parse_decoded_string(&mut fields, Field::ReferenceSequenceName)?;
+        let source = parse_decoded_string(&mut fields, Field::Source)?;
+        let ty = parse_decoded_string(&mut fields, Field::Type)?;
 
         let start = parse_string(&mut fields, Field::Start)
             .and_then(|s| s.parse().map_err(ParseError::InvalidStart))?;
@@ -239,33 +349,18 @@ impl FromStr for Record {
         let end = parse_string(&mut fields, Field::End)
             .and_then(|s| s.parse().map_err(ParseError::InvalidEnd))?;
 
-        let score = parse_string(&mut fields, Field::Score).and_then(|s| {
-            if s == NULL_FIELD {
-                Ok(None)
-            } else {
-                s.parse().map(Some).map_err(ParseError::InvalidScore)
-            }
-        })?;
+        let score = parse_string(&mut fields, Field::Score).and_then(parse_score)?;
 
         let strand = parse_string(&mut fields, Field::Strand)
             .and_then(|s| s.parse().map_err(ParseError::InvalidStrand))?;
 
-        let phase = parse_string(&mut fields, Field::Phase).and_then(|s| {
-            if s == NULL_FIELD {
-                if ty == "CDS" {
-                    Err(ParseError::MissingPhase)
-                } else {
-                    Ok(None)
-                }
-            } else {
-                s.parse().map(Some).map_err(ParseError::InvalidPhase)
-            }
-        })?;
-
-        let attributes = match fields.next() {
-            Some(s) => s.parse().map_err(ParseError::InvalidAttributes)?,
-            None => Attributes::default(),
-        };
+        let phase = parse_string(&mut fields, Field::Phase).and_then(|s| parse_phase(s, &ty))?;
+
+        let attributes = fields
+            .next()
+            .map(parse_attributes)
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(Self {
             reference_sequence_name,
@@ -288,10 +383,66 @@ where
     fields.next().ok_or(ParseError::MissingField(field))
 }
 
+fn parse_decoded_string<'a, I>(fields: &mut I, field: Field) -> Result<String, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    parse_string(fields, field).and_then(|s| {
+        percent_decode(s)
+            .map(|t| t.into_owned())
+            .map_err(|e| ParseError::InvalidField(field, e))
+    })
+}
+
+fn parse_score(s: &str) -> Result<Option<f32>, ParseError> {
+    if s == NULL_FIELD {
+        Ok(None)
+    } else {
+        s.parse().map(Some).map_err(ParseError::InvalidScore)
+    }
+}
+
+fn parse_phase(s: &str, ty: &str) -> Result<Option<Phase>, ParseError> {
+    if s == NULL_FIELD {
+        if ty == CDS_TYPE {
+            Err(ParseError::MissingPhase)
+        } else {
+            Ok(None)
+        }
+    } else {
+        s.parse().map(Some).map_err(ParseError::InvalidPhase)
+    }
+}
+
+fn parse_attributes(s: &str) -> Result<Attributes, ParseError> {
+    s.parse().map_err(ParseError::InvalidAttributes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_reference_sequence_name(String::from("sq 0"))
+            .set_source(String::from("NOODLES%"))
+            .set_type(String::from("gene"))
+            .set_start(8)
+            .set_end(13)
+            .set_strand(Strand::Forward)
+            .set_attributes("ID=gene0;Note=a%3Bb".parse()?)
+            .build()?;
+
+        let s = "sq%200\tNOODLES%25\tgene\t8\t13\t.\t+\t.\tID=gene0;Note=a%3Bb";
+        assert_eq!(record.to_string(), s);
+        assert_eq!(s.parse::<Record>()?, record);
+
+        assert_eq!(Record::default().to_string(), ".\t.\t.\t1\t1\t.\t.\t.\t.");
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), ParseError> {
         let s = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0";
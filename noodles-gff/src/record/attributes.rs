@@ -8,10 +8,253 @@ use std::{error, fmt, ops::Deref, str::FromStr};
 
 const DELIMITER: char = ';';
 
+const ID: &str = "ID";
+const NAME: &str = "Name";
+const ALIAS: &str = "Alias";
+const PARENT: &str = "Parent";
+const TARGET: &str = "Target";
+const GAP: &str = "Gap";
+const DERIVES_FROM: &str = "Derives_from";
+const NOTE: &str = "Note";
+const DBXREF: &str = "Dbxref";
+const ONTOLOGY_TERM: &str = "Ontology_term";
+const IS_CIRCULAR: &str = "Is_circular";
+
 /// GFF record attributes.
+///
+/// Attributes are an ordered multimap of entries. Entries are kept in insertion order, and
+/// duplicate keys are allowed, e.g., repeated `Dbxref` pairs, so parsed attributes are written in
+/// their original form.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Attributes(Vec<Entry>);
 
+impl Attributes {
+    /// Returns the first entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes = Attributes::from(vec![
+    ///     Entry::new(String::from("gene_id"), String::from("ndls0")),
+    /// ]);
+    ///
+    /// assert_eq!(attributes.get("gene_id").and_then(|entry| entry.value()), Some("ndls0"));
+    /// assert!(attributes.get("gene_name").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.iter().find(|entry| entry.key() == key)
+    }
+
+    /// Returns all entries with the given key, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes: Attributes = "ID=gene0;Dbxref=EMBL:AA816246;Dbxref=NCBI_gi:10727410".parse()?;
+    ///
+    /// let values: Vec<_> = attributes
+    ///     .get_all("Dbxref")
+    ///     .flat_map(|entry| entry.values())
+    ///     .collect();
+    ///
+    /// assert_eq!(values, ["EMBL:AA816246", "NCBI_gi:10727410"]);
+    /// assert_eq!(attributes.get_all("Note").count(), 0);
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Entry> + 'a {
+        self.iter().filter(move |entry| entry.key() == key)
+    }
+
+    /// Returns the ID (`ID`) of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "ID=gene0;Name=ndls".parse()?;
+    /// assert_eq!(attributes.id(), Some("gene0"));
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn id(&self) -> Option<&str> {
+        self.get_value(ID)
+    }
+
+    /// Returns the display name (`Name`) of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "ID=gene0;Name=ndls".parse()?;
+    /// assert_eq!(attributes.name(), Some("ndls"));
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.get_value(NAME)
+    }
+
+    /// Returns the secondary names (`Alias`) of the feature.
+    ///
+    /// Values are aggregated across all `Alias` entries, in order. The same is true for the other
+    /// multi-value accessors, e.g., [`Self::parent`] and [`Self::dbxref`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "ID=gene0;Alias=ndls0,ndls1".parse()?;
+    /// assert_eq!(attributes.alias().collect::<Vec<_>>(), ["ndls0", "ndls1"]);
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn alias(&self) -> impl Iterator<Item = &str> + '_ {
+        self.get_values(ALIAS)
+    }
+
+    /// Returns the IDs of the parent features (`Parent`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "ID=exon0;Parent=mRNA0,mRNA1".parse()?;
+    /// assert_eq!(attributes.parent().collect::<Vec<_>>(), ["mRNA0", "mRNA1"]);
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn parent(&self) -> impl Iterator<Item = &str> + '_ {
+        self.get_values(PARENT)
+    }
+
+    /// Returns the alignment target (`Target`) of the feature.
+    ///
+    /// This is the raw (decoded) target, i.e., `<target_id> <start> <end> [<strand>]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Target=EST23 1 21".parse()?;
+    /// assert_eq!(attributes.target(), Some("EST23 1 21"));
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn target(&self) -> Option<&str> {
+        self.get_value(TARGET)
+    }
+
+    /// Returns the alignment of the feature to the target (`Gap`) in CIGAR format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Target=EST23 1 21;Gap=M8 D3 M6 I1 M6".parse()?;
+    /// assert_eq!(attributes.gap(), Some("M8 D3 M6 I1 M6"));
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn gap(&self) -> Option<&str> {
+        self.get_value(GAP)
+    }
+
+    /// Returns the IDs of the features this feature derives from (`Derives_from`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "ID=polypeptide0;Derives_from=mRNA0".parse()?;
+    /// assert_eq!(attributes.derives_from().collect::<Vec<_>>(), ["mRNA0"]);
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn derives_from(&self) -> impl Iterator<Item = &str> + '_ {
+        self.get_values(DERIVES_FROM)
+    }
+
+    /// Returns the notes (`Note`) of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Note=noodles%3B gff".parse()?;
+    /// assert_eq!(attributes.note().collect::<Vec<_>>(), ["noodles; gff"]);
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn note(&self) -> impl Iterator<Item = &str> + '_ {
+        self.get_values(NOTE)
+    }
+
+    /// Returns the database cross references (`Dbxref`) of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes: Attributes = "Dbxref=EMBL:AA816246,NCBI_gi:10727410".parse()?;
+    /// assert_eq!(
+    ///     attributes.dbxref().collect::<Vec<_>>(),
+    ///     ["EMBL:AA816246", "NCBI_gi:10727410"]
+    /// );
+    ///
+    /// let attributes: Attributes = "Dbxref=EMBL:AA816246;Dbxref=NCBI_gi:10727410".parse()?;
+    /// assert_eq!(
+    ///     attributes.dbxref().collect::<Vec<_>>(),
+    ///     ["EMBL:AA816246", "NCBI_gi:10727410"]
+    /// );
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn dbxref(&self) -> impl Iterator<Item = &str> + '_ {
+        self.get_values(DBXREF)
+    }
+
+    /// Returns the ontology term cross references (`Ontology_term`) of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    /// let attributes: Attributes = "Ontology_term=GO:0046703".parse()?;
+    /// assert_eq!(attributes.ontology_term().collect::<Vec<_>>(), ["GO:0046703"]);
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn ontology_term(&self) -> impl Iterator<Item = &str> + '_ {
+        self.get_values(ONTOLOGY_TERM)
+    }
+
+    /// Returns whether the feature is circular (`Is_circular`).
+    ///
+    /// A feature is circular if the value is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes: Attributes = "ID=chrM;Is_circular=true".parse()?;
+    /// assert_eq!(attributes.is_circular(), Some(true));
+    ///
+    /// let attributes: Attributes = "ID=chr1".parse()?;
+    /// assert!(attributes.is_circular().is_none());
+    /// # Ok::<(), noodles_gff::record::attributes::ParseError>(())
+    /// ```
+    pub fn is_circular(&self) -> Option<bool> {
+        self.get_value(IS_CIRCULAR).map(|value| value == "true")
+    }
+
+    fn get_value(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(|entry| entry.value())
+    }
+
+    fn get_values<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.get_all(key)
+            .flat_map(|entry| entry.values())
+            .map(|value| value.as_str())
+    }
+}
+
 impl Deref for Attributes {
     type Target = [Entry];
 
@@ -115,6 +358,29 @@ mod tests {
         )]);
         assert_eq!(actual, expected);
 
+        let s = "ID=cds0;Parent=mRNA0,mRNA1;Note=a%2Cb%3Bc";
+        let actual = s.parse::<Attributes>()?;
+        let expected = Attributes::from(vec![
+            Entry::new(String::from("ID"), String::from("cds0")),
+            Entry::with_values(
+                String::from("Parent"),
+                vec![String::from("mRNA0"), String::from("mRNA1")],
+            ),
+            Entry::new(String::from("Note"), String::from("a,b;c")),
+        ]);
+        assert_eq!(actual, expected);
+        assert_eq!(actual.to_string(), s);
+
+        let s = "ID=gene0;Dbxref=EMBL:AA816246;Note=n0;Dbxref=NCBI_gi:10727410";
+        let actual = s.parse::<Attributes>()?;
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual.get_all("Dbxref").count(), 2);
+        assert_eq!(
+            actual.dbxref().collect::<Vec<_>>(),
+            ["EMBL:AA816246", "NCBI_gi:10727410"]
+        );
+        assert_eq!(actual.to_string(), s);
+
         let actual = "".parse::<Attributes>()?;
         let expected = Attributes::default();
         assert_eq!(actual, expected);
@@ -1,14 +1,15 @@
 //! GFF record attribute entry.
 
 use std::{
-    borrow::Cow,
     error, fmt,
     str::{self, FromStr},
 };
 
-use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use percent_encoding::{AsciiSet, CONTROLS};
 
-const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
+use crate::record::percent::{percent_decode, percent_encode};
+
+pub(crate) const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'\t')
     .add(b'\n')
     .add(b'\r')
@@ -19,12 +20,16 @@ const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b',');
 
 const SEPARATOR: char = '=';
+const VALUE_DELIMITER: char = ',';
 
 /// A GFF record attribute entry.
+///
+/// An entry has one or more values. In the raw format, multiple values are separated by commas
+/// (`,`), and keys and values are percent-encoded.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Entry {
     key: String,
-    value: String,
+    values: Vec<String>,
 }
 
 impl Entry {
@@ -37,7 +42,25 @@ impl Entry {
     /// let entry = Entry::new(String::from("gene_name"), String::from("gene0"));
     /// ```
     pub fn new(key: String, value: String) -> Self {
-        Self { key, value }
+        Self::with_values(key, vec![value])
+    }
+
+    /// Creates a GFF record attribute with multiple values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::Entry;
+    ///
+    /// let entry = Entry::with_values(
+    ///     String::from("Parent"),
+    ///     vec![String::from("mRNA0"), String::from("mRNA1")],
+    /// );
+    ///
+    /// assert_eq!(entry.to_string(), "Parent=mRNA0,mRNA1");
+    /// ```
+    pub fn with_values(key: String, values: Vec<String>) -> Self {
+        Self { key, values }
     }
 
     /// Returns the key of the entry.
@@ -55,15 +78,42 @@ impl Entry {
 
     /// Returns the value of the entry.
     ///
+    /// This returns `None` if the entry has multiple values. Use [`Self::values`] to read all
+    /// values.
+    ///
     /// # Examples
     ///
     /// ```
     /// use noodles_gff::record::attributes::Entry;
+    ///
     /// let entry = Entry::new(String::from("gene_name"), String::from("gene0"));
-    /// assert_eq!(entry.value(), "gene0");
+    /// assert_eq!(entry.value(), Some("gene0"));
+    ///
+    /// let entry: Entry = "Note=a,b".parse()?;
+    /// assert!(entry.value().is_none());
+    /// # Ok::<(), noodles_gff::record::attributes::entry::ParseError>(())
+    /// ```
+    pub fn value(&self) -> Option<&str> {
+        match self.values.as_slice() {
+            [value] => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the values of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::attributes::Entry;
+    ///
+    /// let entry: Entry = "Alias=gene%2C0,ndls0".parse()?;
+    ///
+    /// assert_eq!(entry.values(), [String::from("gene,0"), String::from("ndls0")]);
+    /// # Ok::<(), noodles_gff::record::attributes::entry::ParseError>(())
     /// ```
-    pub fn value(&self) -> &str {
-        &self.value
+    pub fn values(&self) -> &[String] {
+        &self.values
     }
 }
 
@@ -71,11 +121,20 @@ impl fmt::Display for Entry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}{}{}",
-            percent_encode(self.key()),
-            SEPARATOR,
-            percent_encode(self.value())
-        )
+            "{}{}",
+            percent_encode(self.key(), PERCENT_ENCODE_SET),
+            SEPARATOR
+        )?;
+
+        for (i, value) in self.values().iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", VALUE_DELIMITER)?;
+            }
+
+            write!(f, "{}", percent_encode(value, PERCENT_ENCODE_SET))?;
+        }
+
+        Ok(())
     }
 }
 
@@ -125,24 +184,23 @@ impl FromStr for Entry {
             .and_then(|t| percent_decode(t).map_err(ParseError::InvalidKey))
             .map(|t| t.into_owned())?;
 
-        let value = components
+        let values = components
             .next()
             .ok_or(ParseError::MissingValue)
-            .and_then(|t| percent_decode(t).map_err(ParseError::InvalidValue))
-            .map(|t| t.into_owned())?;
+            .and_then(|t| {
+                t.split(VALUE_DELIMITER)
+                    .map(|u| {
+                        percent_decode(u)
+                            .map(|v| v.into_owned())
+                            .map_err(ParseError::InvalidValue)
+                    })
+                    .collect::<Result<_, _>>()
+            })?;
 
-        Ok(Self::new(key, value))
+        Ok(Self::with_values(key, values))
     }
 }
 
-fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
-    percent_decode_str(s).decode_utf8()
-}
-
-fn percent_encode(s: &str) -> Cow<'_, str> {
-    utf8_percent_encode(s, PERCENT_ENCODE_SET).into()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,6 +212,23 @@ mod tests {
 
         let entry = Entry::new(String::from("%s"), String::from("13,21"));
         assert_eq!(entry.to_string(), "%25s=13%2C21");
+
+        let entry = Entry::with_values(
+            String::from("Note"),
+            vec![String::from("a;b"), String::from("c,d")],
+        );
+        assert_eq!(entry.to_string(), "Note=a%3Bb,c%2Cd");
+    }
+
+    #[test]
+    fn test_value() -> Result<(), ParseError> {
+        let entry: Entry = "Note=a%2Cb".parse()?;
+        assert_eq!(entry.value(), Some("a,b"));
+
+        let entry: Entry = "Note=a,b".parse()?;
+        assert!(entry.value().is_none());
+
+        Ok(())
     }
 
     #[test]
@@ -167,6 +242,14 @@ mod tests {
             Entry::new(String::from("%s"), String::from("13,21"))
         );
 
+        assert_eq!(
+            "Parent=mRNA0,mRNA%2C1".parse::<Entry>()?,
+            Entry::with_values(
+                String::from("Parent"),
+                vec![String::from("mRNA0"), String::from("mRNA,1")]
+            )
+        );
+
         assert_eq!("".parse::<Entry>(), Err(ParseError::Empty));
         assert_eq!("=gene0".parse::<Entry>(), Err(ParseError::MissingKey));
         assert_eq!("gene_name".parse::<Entry>(), Err(ParseError::MissingValue));
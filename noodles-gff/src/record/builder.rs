@@ -1,5 +1,9 @@
+use std::{error, fmt};
+
 use super::{Attributes, Phase, Record, Strand, NULL_FIELD};
 
+const CDS_TYPE: &str = "CDS";
+
 /// A GFF record builder.
 #[derive(Debug)]
 pub struct Builder {
@@ -14,6 +18,46 @@ pub struct Builder {
     attributes: Attributes,
 }
 
+/// An error returned when a GFF record fails to build.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// The start is invalid.
+    ///
+    /// The start must be >= 1.
+    InvalidStart(i32),
+    /// The end is invalid.
+    ///
+    /// The end must be >= the start.
+    InvalidEnd(i32),
+    /// The score is invalid.
+    ///
+    /// The score must be finite.
+    InvalidScore(f32),
+    /// The strand is invalid.
+    ///
+    /// A phase is relative to the strand, so a feature with a phase must be on the forward or
+    /// reverse strand.
+    InvalidStrand(Strand),
+    /// The phase is missing.
+    ///
+    /// The phase is required for CDS features.
+    MissingPhase,
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidStart(start) => write!(f, "invalid start: {}", start),
+            Self::InvalidEnd(end) => write!(f, "invalid end: {}", end),
+            Self::InvalidScore(score) => write!(f, "invalid score: {}", score),
+            Self::InvalidStrand(strand) => write!(f, "invalid strand: {}", strand),
+            Self::MissingPhase => f.write_str("missing phase"),
+        }
+    }
+}
+
 impl Builder {
     /// Creates a GFF record builder.
     ///
@@ -38,9 +82,10 @@ impl Builder {
     ///
     /// let record = gff::Record::builder()
     ///     .set_reference_sequence_name(String::from("sq0"))
-    ///     .build();
+    ///     .build()?;
     ///
     /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_reference_sequence_name(mut self, reference_sequence_name: String) -> Self {
         self.reference_sequence_name = reference_sequence_name;
@@ -56,9 +101,10 @@ impl Builder {
     ///
     /// let record = gff::Record::builder()
     ///     .set_source(String::from("NOODLES"))
-    ///     .build();
+    ///     .build()?;
     ///
     /// assert_eq!(record.source(), "NOODLES");
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_source(mut self, source: String) -> Self {
         self.source = source;
@@ -74,9 +120,10 @@ impl Builder {
     ///
     /// let record = gff::Record::builder()
     ///     .set_type(String::from("gene"))
-    ///     .build();
+    ///     .build()?;
     ///
     /// assert_eq!(record.ty(), "gene");
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_type(mut self, ty: String) -> Self {
         self.ty = ty;
@@ -89,8 +136,9 @@ impl Builder {
     ///
     /// ```
     /// use noodles_gff as gff;
-    /// let record = gff::Record::builder().set_start(8).build();
+    /// let record = gff::Record::builder().set_start(8).set_end(13).build()?;
     /// assert_eq!(record.start(), 8);
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_start(mut self, start: i32) -> Self {
         self.start = start;
@@ -103,8 +151,9 @@ impl Builder {
     ///
     /// ```
     /// use noodles_gff as gff;
-    /// let record = gff::Record::builder().set_end(13).build();
+    /// let record = gff::Record::builder().set_end(13).build()?;
     /// assert_eq!(record.end(), 13);
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_end(mut self, end: i32) -> Self {
         self.end = end;
@@ -117,8 +166,9 @@ impl Builder {
     ///
     /// ```
     /// use noodles_gff as gff;
-    /// let record = gff::Record::builder().set_score(21.0).build();
+    /// let record = gff::Record::builder().set_score(21.0).build()?;
     /// assert_eq!(record.score(), Some(21.0));
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_score(mut self, score: f32) -> Self {
         self.score = Some(score);
@@ -134,9 +184,10 @@ impl Builder {
     ///
     /// let record = gff::Record::builder()
     ///     .set_strand(Strand::Forward)
-    ///     .build();
+    ///     .build()?;
     ///
     /// assert_eq!(record.strand(), Strand::Forward);
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_strand(mut self, strand: Strand) -> Self {
         self.strand = strand;
@@ -148,9 +199,15 @@ impl Builder {
     /// # Examples
     ///
     /// ```
-    /// use noodles_gff::{self as gff, record::Phase};
-    /// let record = gff::Record::builder().set_phase(Phase::Zero).build();
+    /// use noodles_gff::{self as gff, record::{Phase, Strand}};
+    ///
+    /// let record = gff::Record::builder()
+    ///     .set_strand(Strand::Forward)
+    ///     .set_phase(Phase::Zero)
+    ///     .build()?;
+    ///
     /// assert_eq!(record.phase(), Some(Phase::Zero));
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_phase(mut self, phase: Phase) -> Self {
         self.phase = Some(phase);
@@ -173,9 +230,10 @@ impl Builder {
     ///
     /// let record = gff::Record::builder()
     ///     .set_attributes(attributes.clone())
-    ///     .build();
+    ///     .build()?;
     ///
     /// assert_eq!(record.attributes(), &attributes);
+    /// # Ok::<_, gff::record::BuildError>(())
     /// ```
     pub fn set_attributes(mut self, attributes: Attributes) -> Self {
         self.attributes = attributes;
@@ -184,14 +242,51 @@ impl Builder {
 
     /// Builds a GFF record.
     ///
+    /// This validates that the start is >= 1 and <= the end, the score is finite, CDS features
+    /// have a phase, and features with a phase are on the forward or reverse strand.
+    ///
     /// # Example
     ///
     /// ```
-    /// use noodles_gff as gff;
-    /// let record = gff::Record::builder().build();
+    /// use noodles_gff::{self as gff, record::BuildError};
+    ///
+    /// let record = gff::Record::builder().set_start(8).set_end(13).build()?;
+    ///
+    /// assert_eq!(
+    ///     gff::Record::builder().set_start(13).set_end(8).build(),
+    ///     Err(BuildError::InvalidEnd(8))
+    /// );
+    /// # Ok::<_, BuildError>(())
     /// ```
-    pub fn build(self) -> Record {
-        Record {
+    pub fn build(self) -> Result<Record, BuildError> {
+        if self.start < 1 {
+            return Err(BuildError::InvalidStart(self.start));
+        }
+
+        if self.end < self.start {
+            return Err(BuildError::InvalidEnd(self.end));
+        }
+
+        if let Some(score) = self.score {
+            if !score.is_finite() {
+                return Err(BuildError::InvalidScore(score));
+            }
+        }
+
+        match self.phase {
+            Some(_) => {
+                if !matches!(self.strand, Strand::Forward | Strand::Reverse) {
+                    return Err(BuildError::InvalidStrand(self.strand));
+                }
+            }
+            None => {
+                if self.ty == CDS_TYPE {
+                    return Err(BuildError::MissingPhase);
+                }
+            }
+        }
+
+        Ok(Record {
             reference_sequence_name: self.reference_sequence_name,
             source: self.source,
             ty: self.ty,
@@ -201,7 +296,7 @@ impl Builder {
             strand: self.strand,
             phase: self.phase,
             attributes: self.attributes,
-        }
+        })
     }
 }
 
@@ -228,8 +323,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_default() {
-        let record = Builder::default().build();
+    fn test_default() -> Result<(), BuildError> {
+        let record = Builder::default().build()?;
 
         assert_eq!(record.reference_sequence_name(), ".");
         assert_eq!(record.source(), ".");
@@ -240,10 +335,12 @@ mod tests {
         assert_eq!(record.strand(), Strand::default());
         assert!(record.phase().is_none());
         assert!(record.attributes().is_empty());
+
+        Ok(())
     }
 
     #[test]
-    fn test_build() {
+    fn test_build() -> Result<(), BuildError> {
         let attributes = Attributes::from(vec![Entry::new(
             String::from("gene_id"),
             String::from("ndls0"),
@@ -259,7 +356,7 @@ mod tests {
             .set_strand(Strand::Forward)
             .set_phase(Phase::Zero)
             .set_attributes(attributes.clone())
-            .build();
+            .build()?;
 
         assert_eq!(record.reference_sequence_name(), "sq0");
         assert_eq!(record.source(), "NOODLES");
@@ -270,5 +367,38 @@ mod tests {
         assert_eq!(record.strand(), Strand::Forward);
         assert_eq!(record.phase(), Some(Phase::Zero));
         assert_eq!(record.attributes(), &attributes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_invalid_fields() {
+        assert_eq!(
+            Builder::new().set_start(0).build(),
+            Err(BuildError::InvalidStart(0))
+        );
+
+        assert_eq!(
+            Builder::new().set_start(13).set_end(8).build(),
+            Err(BuildError::InvalidEnd(8))
+        );
+
+        assert!(matches!(
+            Builder::new().set_score(f32::NAN).build(),
+            Err(BuildError::InvalidScore(_))
+        ));
+
+        assert_eq!(
+            Builder::new().set_phase(Phase::Zero).build(),
+            Err(BuildError::InvalidStrand(Strand::None))
+        );
+
+        assert_eq!(
+            Builder::new()
+                .set_type(String::from("CDS"))
+                .set_strand(Strand::Forward)
+                .build(),
+            Err(BuildError::MissingPhase)
+        );
     }
 }
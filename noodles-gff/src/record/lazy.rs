@@ -0,0 +1,362 @@
+//! Lazily-evaluated GFF record.
+
+use std::{borrow::Cow, convert::TryFrom};
+
+use noodles_core::{region::Interval, Position};
+
+use super::{
+    parse_attributes, parse_phase, parse_score, percent::percent_decode, Attributes, Field,
+    ParseError, Phase, Strand, FIELD_DELIMITER, MAX_FIELDS,
+};
+
+/// A lazily-evaluated GFF record.
+///
+/// This borrows a raw GFF record line and only splits it into its fields. Each field is parsed
+/// when it is requested, which avoids the cost of parsing, e.g., the attributes when only the
+/// reference sequence name and positions are needed.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use noodles_gff::record::lazy;
+///
+/// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+///
+/// assert_eq!(record.reference_sequence_name()?, "sq0");
+/// assert_eq!(record.start()?, 8);
+/// assert_eq!(record.end()?, 13);
+/// # Ok::<(), noodles_gff::record::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record<'a> {
+    line: &'a str,
+    reference_sequence_name: &'a str,
+    source: &'a str,
+    ty: &'a str,
+    start: &'a str,
+    end: &'a str,
+    score: &'a str,
+    strand: &'a str,
+    phase: &'a str,
+    attributes: Option<&'a str>,
+}
+
+impl<'a> Record<'a> {
+    /// Returns the raw record line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let s = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0";
+    /// let record = lazy::Record::try_from(s)?;
+    /// assert_eq!(record.as_str(), s);
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.line
+    }
+
+    /// Parses the reference sequence name.
+    ///
+    /// The name is percent-decoded. It is only copied if it has escaped characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq%200\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.reference_sequence_name()?, "sq 0");
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn reference_sequence_name(&self) -> Result<Cow<'a, str>, ParseError> {
+        decode_field(Field::ReferenceSequenceName, self.reference_sequence_name)
+    }
+
+    /// Parses the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.source()?, "NOODLES");
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn source(&self) -> Result<Cow<'a, str>, ParseError> {
+        decode_field(Field::Source, self.source)
+    }
+
+    /// Parses the feature type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.ty()?, "gene");
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn ty(&self) -> Result<Cow<'a, str>, ParseError> {
+        decode_field(Field::Type, self.ty)
+    }
+
+    /// Parses the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.start()?, 8);
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn start(&self) -> Result<i32, ParseError> {
+        self.start.parse().map_err(ParseError::InvalidStart)
+    }
+
+    /// Parses the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.end()?, 13);
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn end(&self) -> Result<i32, ParseError> {
+        self.end.parse().map_err(ParseError::InvalidEnd)
+    }
+
+    /// Parses the start and end positions as an interval.
+    ///
+    /// This returns `Ok(None)` if the positions do not form a valid interval. See
+    /// [`super::Record::interval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_core::Position;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// let interval = record.interval()?.expect("invalid interval");
+    /// assert_eq!(interval.start(), Position::new(8).unwrap());
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn interval(&self) -> Result<Option<Interval>, ParseError> {
+        let start = self.start()?;
+        let end = self.end()?;
+
+        Ok(Position::try_from(start)
+            .ok()
+            .zip(Position::try_from(end).ok())
+            .and_then(|(start, end)| Interval::new(start, end)))
+    }
+
+    /// Parses the score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t5.0\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.score()?, Some(5.0));
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn score(&self) -> Result<Option<f32>, ParseError> {
+        parse_score(self.score)
+    }
+
+    /// Parses the strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::{lazy, Strand};
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.strand()?, Strand::Forward);
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn strand(&self) -> Result<Strand, ParseError> {
+        self.strand.parse().map_err(ParseError::InvalidStrand)
+    }
+
+    /// Parses the phase.
+    ///
+    /// As with [`super::Record`], a CDS feature must have a phase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::{lazy, Phase};
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tCDS\t8\t13\t.\t+\t0\tParent=mRNA0")?;
+    /// assert_eq!(record.phase()?, Some(Phase::Zero));
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn phase(&self) -> Result<Option<Phase>, ParseError> {
+        let ty = self.ty()?;
+        parse_phase(self.phase, &ty)
+    }
+
+    /// Parses the attributes.
+    ///
+    /// If the record has no attributes field, this returns empty attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_gff::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0")?;
+    /// assert_eq!(record.attributes()?.id(), Some("gene0"));
+    /// # Ok::<(), noodles_gff::record::ParseError>(())
+    /// ```
+    pub fn attributes(&self) -> Result<Attributes, ParseError> {
+        self.attributes
+            .map(parse_attributes)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+fn decode_field(field: Field, s: &str) -> Result<Cow<'_, str>, ParseError> {
+    percent_decode(s).map_err(|e| ParseError::InvalidField(field, e))
+}
+
+impl<'a> TryFrom<&'a str> for Record<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut components = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
+
+        let mut next_field = |field| components.next().ok_or(ParseError::MissingField(field));
+
+        Ok(Self {
+            line: s,
+            reference_sequence_name: next_field(Field::ReferenceSequenceName)?,
+            source: next_field(Field::Source)?,
+            ty: next_field(Field::Type)?,
+            start: next_field(Field::Start)?,
+            end: next_field(Field::End)?,
+            score: next_field(Field::Score)?,
+            strand: next_field(Field::Strand)?,
+            phase: next_field(Field::Phase)?,
+            attributes: components.next(),
+        })
+    }
+}
+
+impl<'a> TryFrom<Record<'a>> for super::Record {
+    type Error = ParseError;
+
+    fn try_from(record: Record<'a>) -> Result<Self, Self::Error> {
+        record.line.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_str_for_record() {
+        assert_eq!(
+            Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+"),
+            Err(ParseError::MissingField(Field::Phase))
+        );
+    }
+
+    #[test]
+    fn test_fields() -> Result<(), ParseError> {
+        let record = Record::try_from("sq%200\tNOODLES\tgene\t8\t13\t5.0\t-\t.\tID=gene0")?;
+
+        assert_eq!(record.reference_sequence_name()?, "sq 0");
+        assert_eq!(record.source()?, "NOODLES");
+        assert_eq!(record.ty()?, "gene");
+        assert_eq!(record.start()?, 8);
+        assert_eq!(record.end()?, 13);
+        assert_eq!(
+            record.interval()?,
+            Interval::new(Position::new(8).unwrap(), Position::new(13).unwrap())
+        );
+        assert_eq!(record.score()?, Some(5.0));
+        assert_eq!(record.strand()?, Strand::Reverse);
+        assert_eq!(record.phase()?, None);
+
+        let record = Record::try_from("sq0\tNOODLES\tgene\t13\t8\t.\t.\t.")?;
+        assert_eq!(record.interval()?, None);
+        assert_eq!(record.score()?, None);
+        assert_eq!(record.strand()?, Strand::None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fields_with_invalid_values() -> Result<(), ParseError> {
+        let record = Record::try_from("sq%ff\tNOODLES\tgene\tx\ty\tz\t!\t.")?;
+
+        assert!(matches!(
+            record.reference_sequence_name(),
+            Err(ParseError::InvalidField(Field::ReferenceSequenceName, _))
+        ));
+        assert!(matches!(record.start(), Err(ParseError::InvalidStart(_))));
+        assert!(matches!(record.end(), Err(ParseError::InvalidEnd(_))));
+        assert!(matches!(
+            record.interval(),
+            Err(ParseError::InvalidStart(_))
+        ));
+        assert!(matches!(record.score(), Err(ParseError::InvalidScore(_))));
+        assert!(matches!(record.strand(), Err(ParseError::InvalidStrand(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_phase() -> Result<(), ParseError> {
+        let record = Record::try_from("sq0\tNOODLES\tCDS\t8\t13\t.\t+\t.\tParent=mRNA0")?;
+        assert_eq!(record.phase(), Err(ParseError::MissingPhase));
+
+        let record = Record::try_from("sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tParent=mRNA0")?;
+        assert_eq!(record.phase(), Ok(None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attributes() -> Result<(), ParseError> {
+        let record = Record::try_from("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.")?;
+        assert!(record.attributes()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_lazy_record_for_record() -> Result<(), ParseError> {
+        let s = "sq0\tNOODLES\tCDS\t8\t13\t5.0\t-\t2\tID=cds0;Parent=mRNA0,mRNA1";
+        let record = Record::try_from(s)?;
+        assert_eq!(super::super::Record::try_from(record), s.parse());
+        Ok(())
+    }
+}
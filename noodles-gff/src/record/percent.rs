@@ -0,0 +1,38 @@
+//! GFF record percent-encoding.
+
+use std::{borrow::Cow, str};
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet};
+
+/// Decodes a percent-encoded string.
+///
+/// The string is only copied if it has escaped characters.
+pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
+    percent_decode_str(s).decode_utf8()
+}
+
+/// Percent-encodes the characters of a string that are in the given set.
+pub(crate) fn percent_encode<'a>(s: &'a str, ascii_set: &'static AsciiSet) -> Cow<'a, str> {
+    utf8_percent_encode(s, ascii_set).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use percent_encoding::CONTROLS;
+
+    use super::*;
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("ndls"), Ok(Cow::from("ndls")));
+        assert_eq!(percent_decode("nd%3Bls"), Ok(Cow::from("nd;ls")));
+        assert!(percent_decode("nd%FFls").is_err());
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        const SET: &AsciiSet = &CONTROLS.add(b';');
+        assert_eq!(percent_encode("ndls", SET), "ndls");
+        assert_eq!(percent_encode("nd;ls", SET), "nd%3Bls");
+    }
+}
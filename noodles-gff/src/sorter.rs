@@ -0,0 +1,349 @@
+use std::io::{self, Write};
+
+use noodles_fasta as fasta;
+
+use super::{directive::SequenceRegion, Directive, Line, Record, Writer};
+
+/// A GFF sorter.
+///
+/// A sorter orders lines so that the output can be compressed with bgzip and indexed with tabix.
+/// Records are sorted by reference sequence name (lexicographically) and start position, with
+/// ties broken by end position. The sort is stable, i.e., records with the same reference
+/// sequence name, start, and end keep their input order.
+///
+/// Directives and comments are written before the records:
+///
+/// 1. the GFF version (`gff-version`) directive, if any, is hoisted to the first line;
+/// 2. sequence region (`sequence-region`) directives are kept together as a single block,
+///    ordered by reference sequence name; and
+/// 3. all other directives and comments follow in their input order.
+///
+/// Resolution (`###`) directives are dropped, as record groups are no longer contiguous after
+/// sorting.
+///
+/// If the input has a `FASTA` directive, it is written after the records, followed by the
+/// embedded sequences added with [`Self::add_fasta_record`], in input order. Note that a file with
+/// embedded sequences cannot be indexed with tabix.
+///
+/// All records are held in memory until the sorter is finished.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_gff as gff;
+///
+/// let data = b"##gff-version 3
+/// sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+/// sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1
+/// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene2
+/// ";
+/// let mut reader = gff::Reader::new(&data[..]);
+///
+/// let mut sorter = gff::Sorter::new();
+///
+/// for result in reader.lines() {
+///     let line = result?;
+///     sorter.add_line(line);
+/// }
+///
+/// let mut writer = gff::Writer::new(Vec::new());
+/// sorter.write(&mut writer)?;
+///
+/// let expected = b"##gff-version 3
+/// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene2
+/// sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1
+/// sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+/// ";
+///
+/// assert_eq!(&writer.get_ref()[..], &expected[..]);
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Sorter {
+    gff_version: Option<Directive>,
+    sequence_regions: Vec<SequenceRegion>,
+    header_lines: Vec<Line>,
+    records: Vec<Record>,
+    is_fasta_started: bool,
+    fasta_records: Vec<fasta::Record>,
+}
+
+impl Sorter {
+    /// Creates a GFF sorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let sorter = gff::Sorter::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a line to the sorter.
+    ///
+    /// If there are multiple GFF version directives, only the first is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    ///
+    /// let mut sorter = gff::Sorter::new();
+    /// sorter.add_line(gff::Line::Record(gff::Record::default()));
+    ///
+    /// assert_eq!(sorter.into_lines(), [gff::Line::Record(gff::Record::default())]);
+    /// ```
+    pub fn add_line(&mut self, line: Line) {
+        match line {
+            Line::Directive(directive) => self.add_directive(directive),
+            Line::Comment(_) => self.header_lines.push(line),
+            Line::Record(record) => self.records.push(record),
+        }
+    }
+
+    /// Adds an embedded FASTA record to the sorter.
+    ///
+    /// This is typically a record read from [`crate::Reader::fasta_reader`] after the `FASTA`
+    /// directive. FASTA records are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut sorter = gff::Sorter::new();
+    /// sorter.add_fasta_record(fasta::Record::new(
+    ///     fasta::record::Definition::new(String::from("sq0"), None),
+    ///     fasta::record::Sequence::from(b"ACGT".to_vec()),
+    /// ));
+    ///
+    /// let mut writer = gff::Writer::new(Vec::new());
+    /// sorter.write(&mut writer)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"##FASTA\n>sq0\nACGT\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn add_fasta_record(&mut self, record: fasta::Record) {
+        self.is_fasta_started = true;
+        self.fasta_records.push(record);
+    }
+
+    /// Returns the sorted lines.
+    ///
+    /// If the input has a `FASTA` directive, it is the last line. Embedded FASTA records are not
+    /// lines and are only written by [`Self::write`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    ///
+    /// let mut sorter = gff::Sorter::new();
+    /// sorter.add_line(gff::Line::Comment(String::from("format: gff3")));
+    /// sorter.add_line(gff::Line::Directive(gff::Directive::GffVersion(Default::default())));
+    ///
+    /// assert_eq!(
+    ///     sorter.into_lines(),
+    ///     [
+    ///         gff::Line::Directive(gff::Directive::GffVersion(Default::default())),
+    ///         gff::Line::Comment(String::from("format: gff3")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn into_lines(self) -> Vec<Line> {
+        self.into_lines_and_fasta_records().0
+    }
+
+    /// Writes the sorted lines to a GFF writer.
+    ///
+    /// If the input has a `FASTA` directive, it is written after the records, followed by the
+    /// embedded FASTA records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut sorter = gff::Sorter::new();
+    /// sorter.add_line(gff::Line::Directive(gff::Directive::GffVersion(Default::default())));
+    ///
+    /// let mut writer = gff::Writer::new(Vec::new());
+    /// sorter.write(&mut writer)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"##gff-version 3\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write<W>(self, writer: &mut Writer<W>) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let (lines, fasta_records) = self.into_lines_and_fasta_records();
+
+        for line in lines {
+            writer.write_line(&line)?;
+        }
+
+        let mut fasta_writer = fasta::Writer::new(writer.get_mut());
+
+        for record in &fasta_records {
+            fasta_writer.write_record(record)?;
+        }
+
+        Ok(())
+    }
+
+    fn into_lines_and_fasta_records(mut self) -> (Vec<Line>, Vec<fasta::Record>) {
+        self.sort();
+
+        let start_of_fasta = if self.is_fasta_started {
+            Some(Line::Directive(Directive::StartOfFasta))
+        } else {
+            None
+        };
+
+        let lines = self
+            .gff_version
+            .into_iter()
+            .chain(
+                self.sequence_regions
+                    .into_iter()
+                    .map(Directive::SequenceRegion),
+            )
+            .map(Line::Directive)
+            .chain(self.header_lines)
+            .chain(self.records.into_iter().map(Line::Record))
+            .chain(start_of_fasta)
+            .collect();
+
+        (lines, self.fasta_records)
+    }
+
+    fn add_directive(&mut self, directive: Directive) {
+        match directive {
+            Directive::GffVersion(_) => {
+                if self.gff_version.is_none() {
+                    self.gff_version = Some(directive);
+                }
+            }
+            Directive::SequenceRegion(sequence_region) => {
+                self.sequence_regions.push(sequence_region);
+            }
+            Directive::ForwardReferencesAreResolved => {}
+            Directive::StartOfFasta => self.is_fasta_started = true,
+            _ => self.header_lines.push(Line::Directive(directive)),
+        }
+    }
+
+    fn sort(&mut self) {
+        self.sequence_regions
+            .sort_by(|a, b| a.reference_sequence_name().cmp(b.reference_sequence_name()));
+
+        self.records.sort_by(|a, b| {
+            a.reference_sequence_name()
+                .cmp(b.reference_sequence_name())
+                .then_with(|| a.start().cmp(&b.start()))
+                .then_with(|| a.end().cmp(&b.end()))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_lines() -> Result<(), Box<dyn std::error::Error>> {
+        let raw_lines = [
+            "#comment 0",
+            "##sequence-region sq1 1 34",
+            "##gff-version 3",
+            "sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0",
+            "###",
+            "##sequence-region sq0 1 21",
+            "sq0\tNOODLES\tgene\t8\t21\t.\t+\t.\tID=gene1",
+            "sq0\tNOODLES\tgene\t5\t21\t.\t+\t.\tID=gene2",
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene3",
+            "##species https://www.ncbi.nlm.nih.gov/Taxonomy/Browser/wwwtax.cgi?id=9606",
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene4",
+            "##gff-version 3.1.26",
+            "##FASTA",
+        ];
+
+        let mut sorter = Sorter::new();
+
+        for raw_line in &raw_lines {
+            sorter.add_line(raw_line.parse()?);
+        }
+
+        let actual: Vec<_> = sorter
+            .into_lines()
+            .into_iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        let expected = [
+            "##gff-version 3",
+            "##sequence-region sq0 1 21",
+            "##sequence-region sq1 1 34",
+            "#comment 0",
+            "##species https://www.ncbi.nlm.nih.gov/Taxonomy/Browser/wwwtax.cgi?id=9606",
+            "sq0\tNOODLES\tgene\t5\t21\t.\t+\t.\tID=gene2",
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene3",
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene4",
+            "sq0\tNOODLES\tgene\t8\t21\t.\t+\t.\tID=gene1",
+            "sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0",
+            "##FASTA",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_fasta() -> io::Result<()> {
+        let data = b"##gff-version 3
+sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene1
+##FASTA
+>sq1
+ACGT
+>sq0
+NNNN
+";
+
+        let mut reader = crate::Reader::new(&data[..]);
+        let mut sorter = Sorter::new();
+
+        for result in reader.lines() {
+            sorter.add_line(result?);
+        }
+
+        for result in reader.fasta_reader().records() {
+            sorter.add_fasta_record(result?);
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        sorter.write(&mut writer)?;
+
+        let expected = b"##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene1
+sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0
+##FASTA
+>sq1
+ACGT
+>sq0
+NNNN
+";
+
+        assert_eq!(&writer.get_ref()[..], &expected[..]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+//! CDS translation.
+//!
+//! Coding sequence (CDS) records describe the segments of a reference sequence that are spliced
+//! together and translated to a protein. [`translate`] extracts these segments from a FASTA
+//! [`Repository`], splices them, reverse complements them if on the reverse strand, and
+//! translates them using a [`CodonTable`].
+
+pub mod codon_table;
+
+pub use self::codon_table::CodonTable;
+
+use std::{convert::TryFrom, io};
+
+use noodles_fasta::{record::Sequence, Repository};
+
+use super::{
+    record::{Phase, Strand},
+    Record,
+};
+
+const CODON_LEN: usize = 3;
+
+/// Translates a set of CDS records to a protein sequence.
+///
+/// The records are expected to be the CDS records of a single transcript, i.e., on the same
+/// reference sequence and strand. They are spliced in order of their start positions, and on the
+/// reverse strand, the spliced sequence is reverse complemented. The phase of the 5'-most record,
+/// i.e., the first record on the forward strand and the last record on the reverse strand,
+/// determines the number of bases skipped before the first codon. An incomplete trailing codon is
+/// ignored.
+///
+/// Each record's bases are read with [`Repository::get_subsequence`], so the whole reference
+/// sequence is never loaded by repositories that read on demand.
+///
+/// If the phase of the 5'-most record is 0, the first codon is translated as a start codon (see
+/// [`CodonTable::translate_start`]), e.g., an alternative start codon like `GTG` in the bacterial
+/// codon table is translated to `M`. Otherwise, the coding sequence is 5' partial, and the first
+/// codon is translated as is. Stop codons are translated to `*` and codons with ambiguous bases, to
+/// `X`.
+///
+/// This returns an invalid input error if the records are empty, are on different reference
+/// sequences or strands, are not stranded, are missing the phase of the 5'-most record, or are
+/// out of bounds of the reference sequence. It returns an invalid data error if the reference
+/// sequence is missing from the repository.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{
+///     self as fasta,
+///     record::{Definition, Sequence},
+///     repository::InMemoryRepository,
+/// };
+/// use noodles_gff::{self as gff, translation::{self, CodonTable}};
+///
+/// let mut repository = InMemoryRepository::new(vec![fasta::Record::new(
+///     Definition::new(String::from("sq0"), None),
+///     Sequence::from(b"CATGGCCNNNNTTTTAAG".to_vec()),
+/// )]);
+///
+/// let records: Vec<gff::Record> = [
+///     "sq0\tNOODLES\tCDS\t2\t7\t.\t+\t0\tParent=mRNA0",
+///     "sq0\tNOODLES\tCDS\t12\t17\t.\t+\t0\tParent=mRNA0",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<_, _>>()
+/// .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+///
+/// let protein = translation::translate(&mut repository, &records, &CodonTable::standard())?;
+/// assert_eq!(protein, "MAF*");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn translate<R>(
+    repository: &mut R,
+    records: &[Record],
+    codon_table: &CodonTable,
+) -> io::Result<String>
+where
+    R: Repository,
+{
+    let first_record = records
+        .first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no CDS records"))?;
+
+    let reference_sequence_name = first_record.reference_sequence_name();
+    let strand = first_record.strand();
+
+    if !matches!(strand, Strand::Forward | Strand::Reverse) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid strand: {:?}", strand),
+        ));
+    }
+
+    for record in records {
+        if record.reference_sequence_name() != reference_sequence_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "reference sequence name mismatch: expected {}, got {}",
+                    reference_sequence_name,
+                    record.reference_sequence_name()
+                ),
+            ));
+        }
+
+        if record.strand() != strand {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "strand mismatch: expected {:?}, got {:?}",
+                    strand,
+                    record.strand()
+                ),
+            ));
+        }
+    }
+
+    let mut sorted_records: Vec<_> = records.iter().collect();
+    sorted_records.sort_by_key(|record| record.start());
+
+    let five_prime_record = if strand == Strand::Reverse {
+        sorted_records.last()
+    } else {
+        sorted_records.first()
+    };
+
+    let phase = five_prime_record
+        .and_then(|record| record.phase())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing phase"))?;
+
+    let mut bases = Vec::new();
+
+    for record in sorted_records {
+        let start = usize::try_from(record.start())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let end = usize::try_from(record.end())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let segment = repository
+            .get_subsequence(reference_sequence_name, start, end)
+            .transpose()?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("missing reference sequence: {}", reference_sequence_name),
+                )
+            })?;
+
+        bases.extend_from_slice(&segment);
+    }
+
+    let mut sequence = Sequence::from(bases);
+
+    if strand == Strand::Reverse {
+        sequence = sequence.reverse_complement();
+    }
+
+    let offset = match phase {
+        Phase::Zero => 0,
+        Phase::One => 1,
+        Phase::Two => 2,
+    };
+
+    let protein = sequence
+        .get(offset..)
+        .unwrap_or_default()
+        .chunks_exact(CODON_LEN)
+        .enumerate()
+        .map(|(i, codon)| {
+            if i == 0 && offset == 0 {
+                char::from(codon_table.translate_start(codon))
+            } else {
+                char::from(codon_table.translate(codon))
+            }
+        })
+        .collect();
+
+    Ok(protein)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_fasta::{self as fasta, record::Definition, repository::InMemoryRepository};
+
+    use super::*;
+
+    fn build_repository() -> InMemoryRepository {
+        InMemoryRepository::new(vec![fasta::Record::new(
+            Definition::new(String::from("sq0"), None),
+            Sequence::from(b"CATGGCCNNNNTTTTAAG".to_vec()),
+        )])
+    }
+
+    fn parse_records(lines: &[&str]) -> Vec<Record> {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_translate() -> io::Result<()> {
+        let mut repository = build_repository();
+        let codon_table = CodonTable::standard();
+
+        let records = parse_records(&[
+            "sq0\tNOODLES\tCDS\t12\t17\t.\t+\t0\tParent=mRNA0",
+            "sq0\tNOODLES\tCDS\t2\t7\t.\t+\t0\tParent=mRNA0",
+        ]);
+        assert_eq!(translate(&mut repository, &records, &codon_table)?, "MAF*");
+
+        // NTTTTAAG -> reverse complement: CTTAAAAN, skipping 1 base: TTAAAAN
+        let records = parse_records(&["sq0\tNOODLES\tCDS\t11\t18\t.\t-\t1\tParent=mRNA0"]);
+        assert_eq!(translate(&mut repository, &records, &codon_table)?, "LK");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_with_alternative_start_codon() -> io::Result<()> {
+        let mut repository = InMemoryRepository::new(vec![fasta::Record::new(
+            Definition::new(String::from("sq0"), None),
+            Sequence::from(b"NGTGAAATAG".to_vec()),
+        )]);
+
+        let records = parse_records(&["sq0\tNOODLES\tCDS\t2\t10\t.\t+\t0\tParent=mRNA0"]);
+
+        let codon_table = CodonTable::standard();
+        assert_eq!(translate(&mut repository, &records, &codon_table)?, "VK*");
+
+        let codon_table = CodonTable::from_ncbi_id(11).unwrap();
+        assert_eq!(translate(&mut repository, &records, &codon_table)?, "MK*");
+
+        let records = parse_records(&["sq0\tNOODLES\tCDS\t1\t7\t.\t+\t1\tParent=mRNA0"]);
+        assert_eq!(translate(&mut repository, &records, &codon_table)?, "VK");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_with_invalid_records() {
+        fn t<R>(repository: &mut R, records: &[Record], kind: io::ErrorKind)
+        where
+            R: Repository,
+        {
+            let codon_table = CodonTable::standard();
+            assert!(matches!(
+                translate(repository, records, &codon_table),
+                Err(ref e) if e.kind() == kind
+            ));
+        }
+
+        let mut repository = build_repository();
+
+        t(&mut repository, &[], io::ErrorKind::InvalidInput);
+
+        let records = parse_records(&["sq0\tNOODLES\texon\t2\t7\t.\t.\t.\tParent=mRNA0"]);
+        t(&mut repository, &records, io::ErrorKind::InvalidInput);
+
+        let records = parse_records(&[
+            "sq0\tNOODLES\tCDS\t2\t7\t.\t+\t0\tParent=mRNA0",
+            "sq0\tNOODLES\tCDS\t12\t17\t.\t-\t0\tParent=mRNA0",
+        ]);
+        t(&mut repository, &records, io::ErrorKind::InvalidInput);
+
+        let records = parse_records(&["sq0\tNOODLES\texon\t2\t7\t.\t+\t.\tParent=mRNA0"]);
+        t(&mut repository, &records, io::ErrorKind::InvalidInput);
+
+        let records = parse_records(&["sq0\tNOODLES\tCDS\t13\t21\t.\t+\t0\tParent=mRNA0"]);
+        t(&mut repository, &records, io::ErrorKind::InvalidInput);
+
+        let records = parse_records(&["sq1\tNOODLES\tCDS\t2\t7\t.\t+\t0\tParent=mRNA0"]);
+        t(&mut repository, &records, io::ErrorKind::InvalidData);
+    }
+}
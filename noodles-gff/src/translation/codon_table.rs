@@ -0,0 +1,301 @@
+//! Genetic code codon table.
+
+const STANDARD: &[u8; 64] = b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const VERTEBRATE_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG";
+const YEAST_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const MOLD_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const INVERTEBRATE_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG";
+const CILIATE_NUCLEAR: &[u8; 64] =
+    b"FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const ECHINODERM_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG";
+const EUPLOTID_NUCLEAR: &[u8; 64] =
+    b"FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const ALTERNATIVE_YEAST_NUCLEAR: &[u8; 64] =
+    b"FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const ASCIDIAN_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG";
+const ALTERNATIVE_FLATWORM_MITOCHONDRIAL: &[u8; 64] =
+    b"FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG";
+
+// Start codons, in the same codon order as the amino acids, where `M` marks a start codon. These
+// are the `Starts` of the NCBI genetic code tables.
+const STANDARD_STARTS: &[u8; 64] =
+    b"---M------**--*----M---------------M----------------------------";
+const VERTEBRATE_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"----------**--------------------MMMM----------**---M------------";
+const YEAST_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"----------**----------------------MM---------------M------------";
+const MOLD_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"--MM------**-------M------------MMMM---------------M------------";
+const INVERTEBRATE_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"---M------**--------------------MMMM---------------M------------";
+const CILIATE_NUCLEAR_STARTS: &[u8; 64] =
+    b"--------------*--------------------M----------------------------";
+const ECHINODERM_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"-----------*-----------------------M---------------M------------";
+const EUPLOTID_NUCLEAR_STARTS: &[u8; 64] =
+    b"-----------*-----------------------M----------------------------";
+const BACTERIAL_STARTS: &[u8; 64] =
+    b"---M------**--*----M------------MMMM---------------M------------";
+const ALTERNATIVE_YEAST_NUCLEAR_STARTS: &[u8; 64] =
+    b"-------------------M---------------M----------------------------";
+const ASCIDIAN_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"---M------**----------------------MM---------------M------------";
+const ALTERNATIVE_FLATWORM_MITOCHONDRIAL_STARTS: &[u8; 64] =
+    b"-----------------------------------M----------------------------";
+
+const UNKNOWN_AMINO_ACID: u8 = b'X';
+const START_AMINO_ACID: u8 = b'M';
+
+/// A codon table, i.e., a genetic code.
+///
+/// A codon table maps each of the 64 codons to an amino acid, using the one-letter IUPAC codes
+/// and `*` for stop codons. It also marks which codons can be used as start codons, which are
+/// translated to `M` at the start of a coding sequence (see [`Self::translate_start`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CodonTable {
+    amino_acids: [u8; 64],
+    start_codons: [bool; 64],
+}
+
+impl CodonTable {
+    /// Creates a codon table.
+    ///
+    /// The amino acids are ordered by codon, where bases are ordered `T`, `C`, `A`, `G`, from the
+    /// first to the third base, e.g., `TTT`, `TTC`, `TTA`, `TTG`, `TCT`, ..., `GGG`. This is the
+    /// same order as the NCBI genetic code tables.
+    ///
+    /// Only codons that translate to `M` are start codons. Use [`Self::with_start_codons`] to
+    /// set alternative start codons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::translation::CodonTable;
+    ///
+    /// let codon_table = CodonTable::new(
+    ///     *b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    /// );
+    ///
+    /// assert_eq!(codon_table.translate(b"ATG"), b'M');
+    /// assert_eq!(codon_table.translate_start(b"CTG"), b'L');
+    /// ```
+    pub fn new(amino_acids: [u8; 64]) -> Self {
+        Self::with_start_codons(amino_acids, amino_acids)
+    }
+
+    /// Creates a codon table with start codons.
+    ///
+    /// The amino acids and start codons use the same codon order as [`Self::new`]. A codon is a
+    /// start codon if it is marked with `M` in `starts`, e.g., as in the `Starts` line of an NCBI
+    /// genetic code table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::translation::CodonTable;
+    ///
+    /// let codon_table = CodonTable::with_start_codons(
+    ///     *b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    ///     *b"---M------**--*----M---------------M----------------------------",
+    /// );
+    ///
+    /// assert_eq!(codon_table, CodonTable::standard());
+    /// ```
+    pub fn with_start_codons(amino_acids: [u8; 64], starts: [u8; 64]) -> Self {
+        let mut start_codons = [false; 64];
+
+        for (is_start_codon, start) in start_codons.iter_mut().zip(starts.iter()) {
+            *is_start_codon = *start == START_AMINO_ACID;
+        }
+
+        Self {
+            amino_acids,
+            start_codons,
+        }
+    }
+
+    /// Returns the standard codon table (NCBI translation table 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::translation::CodonTable;
+    /// let codon_table = CodonTable::standard();
+    /// assert_eq!(codon_table.translate(b"ATG"), b'M');
+    /// ```
+    pub fn standard() -> Self {
+        Self::with_start_codons(*STANDARD, *STANDARD_STARTS)
+    }
+
+    /// Returns the codon table with the given NCBI translation table ID.
+    ///
+    /// This returns `None` if the table ID is unknown. Supported tables are 1–6 and 9–14.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::translation::CodonTable;
+    ///
+    /// let codon_table = CodonTable::from_ncbi_id(2).expect("missing codon table");
+    /// assert_eq!(codon_table.translate(b"TGA"), b'W');
+    ///
+    /// assert!(CodonTable::from_ncbi_id(7).is_none());
+    /// ```
+    pub fn from_ncbi_id(id: u8) -> Option<Self> {
+        let (amino_acids, starts) = match id {
+            1 => (STANDARD, STANDARD_STARTS),
+            2 => (VERTEBRATE_MITOCHONDRIAL, VERTEBRATE_MITOCHONDRIAL_STARTS),
+            3 => (YEAST_MITOCHONDRIAL, YEAST_MITOCHONDRIAL_STARTS),
+            4 => (MOLD_MITOCHONDRIAL, MOLD_MITOCHONDRIAL_STARTS),
+            5 => (
+                INVERTEBRATE_MITOCHONDRIAL,
+                INVERTEBRATE_MITOCHONDRIAL_STARTS,
+            ),
+            6 => (CILIATE_NUCLEAR, CILIATE_NUCLEAR_STARTS),
+            9 => (ECHINODERM_MITOCHONDRIAL, ECHINODERM_MITOCHONDRIAL_STARTS),
+            10 => (EUPLOTID_NUCLEAR, EUPLOTID_NUCLEAR_STARTS),
+            11 => (STANDARD, BACTERIAL_STARTS),
+            12 => (ALTERNATIVE_YEAST_NUCLEAR, ALTERNATIVE_YEAST_NUCLEAR_STARTS),
+            13 => (ASCIDIAN_MITOCHONDRIAL, ASCIDIAN_MITOCHONDRIAL_STARTS),
+            14 => (
+                ALTERNATIVE_FLATWORM_MITOCHONDRIAL,
+                ALTERNATIVE_FLATWORM_MITOCHONDRIAL_STARTS,
+            ),
+            _ => return None,
+        };
+
+        Some(Self::with_start_codons(*amino_acids, *starts))
+    }
+
+    /// Translates a codon to an amino acid.
+    ///
+    /// Bases are case-insensitive, and `U` is treated as `T`. If the codon is not 3 bases or
+    /// has an ambiguous base, e.g., `N`, this returns `X`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::translation::CodonTable;
+    ///
+    /// let codon_table = CodonTable::standard();
+    ///
+    /// assert_eq!(codon_table.translate(b"atg"), b'M');
+    /// assert_eq!(codon_table.translate(b"UAA"), b'*');
+    /// assert_eq!(codon_table.translate(b"ANG"), b'X');
+    /// ```
+    pub fn translate(&self, codon: &[u8]) -> u8 {
+        codon_index(codon)
+            .map(|i| self.amino_acids[i])
+            .unwrap_or(UNKNOWN_AMINO_ACID)
+    }
+
+    /// Translates the first codon of a coding sequence to an amino acid.
+    ///
+    /// Start codons are translated to `M`, e.g., `GTG` in the bacterial codon table (NCBI
+    /// translation table 11). All other codons are translated as in [`Self::translate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::translation::CodonTable;
+    ///
+    /// let codon_table = CodonTable::from_ncbi_id(11).expect("missing codon table");
+    ///
+    /// assert_eq!(codon_table.translate_start(b"GTG"), b'M');
+    /// assert_eq!(codon_table.translate(b"GTG"), b'V');
+    /// assert_eq!(codon_table.translate_start(b"GCG"), b'A');
+    /// ```
+    pub fn translate_start(&self, codon: &[u8]) -> u8 {
+        match codon_index(codon) {
+            Some(i) if self.start_codons[i] => START_AMINO_ACID,
+            Some(i) => self.amino_acids[i],
+            None => UNKNOWN_AMINO_ACID,
+        }
+    }
+}
+
+impl Default for CodonTable {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+fn codon_index(codon: &[u8]) -> Option<usize> {
+    match codon {
+        [a, b, c] => match (base_index(*a), base_index(*b), base_index(*c)) {
+            (Some(i), Some(j), Some(k)) => Some(16 * i + 4 * j + k),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn base_index(base: u8) -> Option<usize> {
+    match base.to_ascii_uppercase() {
+        b'T' | b'U' => Some(0),
+        b'C' => Some(1),
+        b'A' => Some(2),
+        b'G' => Some(3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate() {
+        let codon_table = CodonTable::standard();
+
+        assert_eq!(codon_table.translate(b"TTT"), b'F');
+        assert_eq!(codon_table.translate(b"TGG"), b'W');
+        assert_eq!(codon_table.translate(b"TGA"), b'*');
+        assert_eq!(codon_table.translate(b"GGG"), b'G');
+        assert_eq!(codon_table.translate(b"AtG"), b'M');
+        assert_eq!(codon_table.translate(b"AT"), b'X');
+        assert_eq!(codon_table.translate(b"ATGA"), b'X');
+    }
+
+    #[test]
+    fn test_translate_start() {
+        let codon_table = CodonTable::standard();
+
+        assert_eq!(codon_table.translate_start(b"ATG"), b'M');
+        assert_eq!(codon_table.translate_start(b"TTG"), b'M');
+        assert_eq!(codon_table.translate_start(b"GTG"), b'V');
+        assert_eq!(codon_table.translate_start(b"ANG"), b'X');
+
+        let codon_table = CodonTable::new(*STANDARD);
+        assert_eq!(codon_table.translate_start(b"ATG"), b'M');
+        assert_eq!(codon_table.translate_start(b"TTG"), b'L');
+    }
+
+    #[test]
+    fn test_from_ncbi_id() {
+        assert_eq!(CodonTable::from_ncbi_id(1), Some(CodonTable::standard()));
+
+        let codon_table = CodonTable::from_ncbi_id(11).unwrap();
+        assert_eq!(codon_table.translate(b"CTG"), b'L');
+        assert_eq!(codon_table.translate_start(b"CTG"), b'M');
+        assert_eq!(codon_table.translate_start(b"ATT"), b'M');
+        assert_ne!(codon_table, CodonTable::standard());
+
+        let codon_table = CodonTable::from_ncbi_id(2).unwrap();
+        assert_eq!(codon_table.translate(b"AGA"), b'*');
+        assert_eq!(codon_table.translate(b"ATA"), b'M');
+        assert_eq!(codon_table.translate(b"TGA"), b'W');
+
+        let codon_table = CodonTable::from_ncbi_id(6).unwrap();
+        assert_eq!(codon_table.translate(b"TAA"), b'Q');
+
+        assert!(CodonTable::from_ncbi_id(0).is_none());
+        assert!(CodonTable::from_ncbi_id(15).is_none());
+    }
+}
@@ -1,6 +1,11 @@
+mod record;
+
 use std::io::{self, Write};
 
-use super::{record, Directive, Record};
+use self::record::write_record;
+use super::{Directive, Line, Record};
+
+const LINE_FEED: &[u8] = b"\n";
 
 /// A GFF writer.
 pub struct Writer<W> {
@@ -36,6 +41,21 @@ where
         &self.inner
     }
 
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// This can be used to write an embedded FASTA section after the `FASTA` directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let mut writer = gff::Writer::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
     /// Writes a GFF directive.
     ///
     /// # Examples
@@ -58,6 +78,10 @@ where
 
     /// Writes a GFF record.
     ///
+    /// Fields are escaped and written directly to the underlying writer, which avoids the
+    /// overhead of formatting the record using its `Display` implementation. The output is the
+    /// same.
+    ///
     /// # Examples
     ///
     /// ```
@@ -80,34 +104,37 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        write!(
-            self.inner,
-            "{seqid}\t{source}\t{ty}\t{start}\t{end}",
-            seqid = record.reference_sequence_name(),
-            source = record.source(),
-            ty = record.ty(),
-            start = record.start(),
-            end = record.end(),
-        )?;
-
-        if let Some(score) = record.score() {
-            write!(self.inner, "\t{}", score)?;
-        } else {
-            write!(self.inner, "\t{}", record::NULL_FIELD)?;
-        }
-
-        write!(self.inner, "\t{}", record.strand())?;
-
-        if let Some(phase) = record.phase() {
-            write!(self.inner, "\t{}", phase)?;
-        } else {
-            write!(self.inner, "\t{}", record::NULL_FIELD)?;
-        }
+        write_record(&mut self.inner, record)?;
+        self.inner.write_all(LINE_FEED)
+    }
 
-        if record.attributes().is_empty() {
-            writeln!(self.inner, "\t{}", record::NULL_FIELD)
-        } else {
-            writeln!(self.inner, "\t{}", record.attributes())
+    /// Writes a GFF line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut writer = gff::Writer::new(Vec::new());
+    ///
+    /// let version = gff::Directive::GffVersion(Default::default());
+    /// writer.write_line(&gff::Line::Directive(version))?;
+    /// writer.write_line(&gff::Line::Comment(String::from("format: gff3")))?;
+    /// writer.write_line(&gff::Line::Record(gff::Record::default()))?;
+    ///
+    /// let expected = b"##gff-version 3
+    /// #format: gff3
+    /// .\t.\t.\t1\t1\t.\t.\t.\t.
+    /// ";
+    ///
+    /// assert_eq!(&writer.get_ref()[..], &expected[..]);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_line(&mut self, line: &Line) -> io::Result<()> {
+        match line {
+            Line::Record(record) => self.write_record(record),
+            _ => writeln!(self.inner, "{}", line),
         }
     }
 }
@@ -0,0 +1,159 @@
+use std::io::{self, Write};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet};
+
+use crate::{
+    record::{
+        attributes::{entry, Entry},
+        Attributes, PERCENT_ENCODE_SET, REFERENCE_SEQUENCE_NAME_PERCENT_ENCODE_SET,
+    },
+    Record,
+};
+
+const MISSING: &[u8] = b".";
+const FIELD_DELIMITER: &[u8] = b"\t";
+const ATTRIBUTES_DELIMITER: &[u8] = b";";
+const ATTRIBUTE_SEPARATOR: &[u8] = b"=";
+const ATTRIBUTE_VALUE_DELIMITER: &[u8] = b",";
+
+// Serializes a record field by field. This is equivalent to its `Display` implementation but
+// escapes and writes strings directly to the writer rather than through the formatting machinery.
+pub(super) fn write_record<W>(writer: &mut W, record: &Record) -> io::Result<()>
+where
+    W: Write,
+{
+    write_escaped(
+        writer,
+        record.reference_sequence_name(),
+        REFERENCE_SEQUENCE_NAME_PERCENT_ENCODE_SET,
+    )?;
+
+    writer.write_all(FIELD_DELIMITER)?;
+    write_escaped(writer, record.source(), PERCENT_ENCODE_SET)?;
+
+    writer.write_all(FIELD_DELIMITER)?;
+    write_escaped(writer, record.ty(), PERCENT_ENCODE_SET)?;
+
+    writer.write_all(FIELD_DELIMITER)?;
+    write!(writer, "{}", record.start())?;
+
+    writer.write_all(FIELD_DELIMITER)?;
+    write!(writer, "{}", record.end())?;
+
+    writer.write_all(FIELD_DELIMITER)?;
+    if let Some(score) = record.score() {
+        write!(writer, "{}", score)?;
+    } else {
+        writer.write_all(MISSING)?;
+    }
+
+    writer.write_all(FIELD_DELIMITER)?;
+    writer.write_all(record.strand().as_ref().as_bytes())?;
+
+    writer.write_all(FIELD_DELIMITER)?;
+    if let Some(phase) = record.phase() {
+        writer.write_all(phase.as_ref().as_bytes())?;
+    } else {
+        writer.write_all(MISSING)?;
+    }
+
+    writer.write_all(FIELD_DELIMITER)?;
+    write_attributes(writer, record.attributes())?;
+
+    Ok(())
+}
+
+fn write_attributes<W>(writer: &mut W, attributes: &Attributes) -> io::Result<()>
+where
+    W: Write,
+{
+    if attributes.is_empty() {
+        return writer.write_all(MISSING);
+    }
+
+    for (i, entry) in attributes.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(ATTRIBUTES_DELIMITER)?;
+        }
+
+        write_attributes_entry(writer, entry)?;
+    }
+
+    Ok(())
+}
+
+fn write_attributes_entry<W>(writer: &mut W, entry: &Entry) -> io::Result<()>
+where
+    W: Write,
+{
+    write_escaped(writer, entry.key(), entry::PERCENT_ENCODE_SET)?;
+    writer.write_all(ATTRIBUTE_SEPARATOR)?;
+
+    for (i, value) in entry.values().iter().enumerate() {
+        if i > 0 {
+            writer.write_all(ATTRIBUTE_VALUE_DELIMITER)?;
+        }
+
+        write_escaped(writer, value, entry::PERCENT_ENCODE_SET)?;
+    }
+
+    Ok(())
+}
+
+fn write_escaped<W>(writer: &mut W, s: &str, ascii_set: &'static AsciiSet) -> io::Result<()>
+where
+    W: Write,
+{
+    for chunk in utf8_percent_encode(s, ascii_set) {
+        writer.write_all(chunk.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(record: &Record) -> io::Result<()> {
+            let mut buf = Vec::new();
+            write_record(&mut buf, record)?;
+            assert_eq!(buf, record.to_string().into_bytes());
+            Ok(())
+        }
+
+        t(&Record::default())?;
+
+        let record = Record::builder()
+            .set_reference_sequence_name(String::from("sq 0"))
+            .set_source(String::from("NOODLES%"))
+            .set_type(String::from("CDS"))
+            .set_start(8)
+            .set_end(13)
+            .set_score(0.5)
+            .set_strand(crate::record::Strand::Reverse)
+            .set_phase(crate::record::Phase::Two)
+            .set_attributes(Attributes::from(vec![
+                Entry::new(String::from("ID"), String::from("cds;0")),
+                Entry::with_values(
+                    String::from("Parent"),
+                    vec![String::from("mRNA,0"), String::from("mRNA1")],
+                ),
+                Entry::new(String::from("Note"), String::from("a=b")),
+            ]))
+            .build()?;
+
+        t(&record)?;
+
+        let mut buf = Vec::new();
+        write_record(&mut buf, &record)?;
+        assert_eq!(
+            buf,
+            b"sq%200\tNOODLES%25\tCDS\t8\t13\t0.5\t-\t2\tID=cds%3B0;Parent=mRNA%2C0,mRNA1;Note=a%3Db"
+        );
+
+        Ok(())
+    }
+}
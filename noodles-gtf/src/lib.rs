@@ -0,0 +1,40 @@
+#![warn(missing_docs)]
+
+//! **noodles-gtf** handles the reading and writing of the [Gene Transfer Format (GTF)][gtf-spec].
+//!
+//! GTF is a text-based format used to represent gene annotations. It is similar to GFF2 but
+//! requires each record to have the `gene_id` and `transcript_id` attributes.
+//!
+//! [gtf-spec]: https://mblab.wustl.edu/GTF22.html
+//!
+//! # Examples
+//!
+//! ## Read all records in a GTF file
+//!
+//! ```no_run
+//! # use std::{fs::File, io::{self, BufReader}};
+//! use noodles_gtf as gtf;
+//!
+//! let mut reader = File::open("annotations.gtf")
+//!     .map(BufReader::new)
+//!     .map(gtf::Reader::new)?;
+//!
+//! for result in reader.records() {
+//!     let record = result?;
+//!
+//!     println!(
+//!         "{}\t{}\t{}",
+//!         record.reference_sequence_name(),
+//!         record.start(),
+//!         record.end(),
+//!     );
+//! }
+//! # Ok::<(), io::Error>(())
+//! ```
+
+pub mod line;
+pub mod reader;
+pub mod record;
+mod writer;
+
+pub use self::{line::Line, reader::Reader, record::Record, writer::Writer};
@@ -0,0 +1,83 @@
+//! GTF lines.
+
+use std::{error, fmt, str::FromStr};
+
+use super::{record, Record};
+
+const COMMENT_PREFIX: char = '#';
+
+/// A GTF line.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Line {
+    /// A comment (`#`).
+    Comment(String),
+    /// A record.
+    Record(Record),
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Comment(comment) => write!(f, "{}{}", COMMENT_PREFIX, comment),
+            Self::Record(record) => write!(f, "{}", record),
+        }
+    }
+}
+
+/// An error returned when a raw GTF line fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record is invalid.
+    InvalidRecord(record::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecord(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(t) = s.strip_prefix(COMMENT_PREFIX) {
+            Ok(Self::Comment(t.into()))
+        } else {
+            s.parse()
+                .map(Self::Record)
+                .map_err(ParseError::InvalidRecord)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let line = Line::Comment(String::from("format: gtf"));
+        assert_eq!(line.to_string(), "#format: gtf");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "#format: gtf".parse(),
+            Ok(Line::Comment(String::from("format: gtf")))
+        );
+
+        let s = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";";
+        assert!(matches!(s.parse(), Ok(Line::Record(_))));
+
+        assert!(matches!(
+            "".parse::<Line>(),
+            Err(ParseError::InvalidRecord(_))
+        ));
+    }
+}
@@ -0,0 +1,232 @@
+//! GTF reader and iterators.
+
+mod lines;
+mod records;
+
+pub use self::{lines::Lines, records::Records};
+
+use std::io::{self, BufRead};
+
+const LINE_FEED: char = '\n';
+const CARRIAGE_RETURN: char = '\r';
+
+/// A GTF reader.
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    /// Creates a GTF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let data = b"#format: gtf\n";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"#format: gtf\n";
+    /// let reader = gtf::Reader::new(&data[..]);
+    ///
+    /// let _ = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps and returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"#format: gtf
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+    /// ";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    /// reader.read_line(&mut String::new())?;
+    ///
+    /// assert_eq!(
+    ///     reader.into_inner(),
+    ///     b"sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";\n"
+    /// );
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads a raw GTF line.
+    ///
+    /// This reads from the underlying stream until a newline is reached and appends it to the
+    /// given buffer, sans the final newline character (`\n` or `\r\n`). The buffer can
+    /// subsequently be parsed as a [`crate::Line`].
+    ///
+    /// It is more ergonomic to read records using an iterator (see [`Self::lines`] and
+    /// [`Self::records`]), but using this method allows control of the line buffer and whether
+    /// the raw line should be parsed.
+    ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
+    /// stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"#format: gtf
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+    /// ";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    ///
+    /// let mut buf = String::new();
+    /// reader.read_line(&mut buf)?;
+    /// assert_eq!(buf, "#format: gtf");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        read_line(&mut self.inner, buf)
+    }
+
+    /// Returns an iterator over lines starting from the current stream position.
+    ///
+    /// Unlike [`Self::read_line`], each line is parsed as a [`crate::Line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"#format: gtf
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+    /// ";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    /// let mut lines = reader.lines();
+    ///
+    /// let line = lines.next().transpose()?;
+    /// assert_eq!(line, Some(gtf::Line::Comment(String::from("format: gtf"))));
+    ///
+    /// let line = lines.next().transpose()?;
+    /// assert!(matches!(line, Some(gtf::Line::Record(_))));
+    ///
+    /// assert!(lines.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn lines(&mut self) -> Lines<'_, R> {
+        Lines::new(self)
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// This filters lines for only records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"#format: gtf
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+    /// ";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    /// let mut records = reader.records();
+    ///
+    /// let record = records.next().transpose()?;
+    /// assert_eq!(record.map(|r| r.start()), Some(8));
+    ///
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records(&mut self) -> Records<'_, R> {
+        Records::new(self.lines())
+    }
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let result = reader.read_line(buf);
+
+    if buf.ends_with(LINE_FEED) {
+        buf.pop();
+
+        if buf.ends_with(CARRIAGE_RETURN) {
+            buf.pop();
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_line() -> io::Result<()> {
+        fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
+            buf.clear();
+            read_line(&mut reader, buf)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = String::new();
+
+        t(&mut buf, b"#format: gtf\n", "#format: gtf")?;
+        t(&mut buf, b"#format: gtf\r\n", "#format: gtf")?;
+        t(&mut buf, b"#format: gtf", "#format: gtf")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records() -> io::Result<()> {
+        let data = b"#format: gtf
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+#comment
+sq0\tNOODLES\ttranscript\t8\t13\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].ty(), "gene");
+        assert_eq!(records[1].attributes().transcript_id(), Some("t0"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_invalid_record() {
+        let data = b"sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\";\n";
+        let mut reader = Reader::new(&data[..]);
+        let mut records = reader.records();
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(ref e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+use std::io::{self, BufRead};
+
+use crate::{Line, Record};
+
+use super::Lines;
+
+/// An iterator over records of a GTF reader.
+///
+/// This filters lines for only records.
+///
+/// This is created by calling [`crate::Reader::records`].
+pub struct Records<'a, R> {
+    lines: Lines<'a, R>,
+}
+
+impl<'a, R> Records<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(lines: Lines<'a, R>) -> Self {
+        Self { lines }
+    }
+}
+
+impl<'a, R> Iterator for Records<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.lines.next()? {
+                Ok(Line::Record(r)) => return Some(Ok(r)),
+                Ok(Line::Comment(_)) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
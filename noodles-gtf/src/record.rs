@@ -0,0 +1,410 @@
+//! GTF record and fields.
+
+pub mod attributes;
+mod builder;
+mod field;
+pub mod frame;
+pub mod strand;
+
+pub use self::{
+    attributes::Attributes,
+    builder::{BuildError, Builder},
+    field::Field,
+    frame::Frame,
+    strand::Strand,
+};
+
+use std::{error, fmt, num, str::FromStr};
+
+const NULL_FIELD: &str = ".";
+const FIELD_DELIMITER: char = '\t';
+const MAX_FIELDS: usize = 9;
+
+/// A GTF record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    reference_sequence_name: String,
+    source: String,
+    ty: String,
+    start: i32,
+    end: i32,
+    score: Option<f32>,
+    strand: Option<Strand>,
+    frame: Option<Frame>,
+    attributes: Attributes,
+}
+
+impl Record {
+    /// Returns a builder to create a record from each of its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_reference_sequence_name(String::from("sq0"))
+    ///     .set_type(String::from("gene"))
+    ///     .set_attributes(r#"gene_id "g0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// Returns the reference sequence name of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the source of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.source(), "NOODLES");
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the feature type of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.ty(), "gene");
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /// Returns the start position of the record.
+    ///
+    /// This position is 1-based, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.start(), 8);
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    /// Returns the end position of the record.
+    ///
+    /// This position is 1-based, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.end(), 13);
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    /// Returns the score of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert!(record.score().is_none());
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn score(&self) -> Option<f32> {
+        self.score
+    }
+
+    /// Returns the strand of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::Strand};
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.strand(), Some(Strand::Forward));
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+
+    /// Returns the frame of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::Frame};
+    ///
+    /// let record: gtf::Record =
+    ///     "sq0\tNOODLES\tCDS\t8\t13\t.\t+\t0\tgene_id \"g0\"; transcript_id \"t0\";".parse()?;
+    ///
+    /// assert_eq!(record.frame(), Some(Frame::Zero));
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn frame(&self) -> Option<Frame> {
+        self.frame
+    }
+
+    /// Returns the attributes of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let record: gtf::Record = "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse()?;
+    /// assert_eq!(record.attributes().gene_id(), Some("g0"));
+    /// # Ok::<_, gtf::record::ParseError>(())
+    /// ```
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{seqname}{delimiter}{source}{delimiter}{ty}{delimiter}{start}{delimiter}{end}",
+            delimiter = FIELD_DELIMITER,
+            seqname = self.reference_sequence_name(),
+            source = self.source(),
+            ty = self.ty(),
+            start = self.start(),
+            end = self.end(),
+        )?;
+
+        if let Some(score) = self.score() {
+            write!(f, "{}{}", FIELD_DELIMITER, score)?;
+        } else {
+            write!(f, "{}{}", FIELD_DELIMITER, NULL_FIELD)?;
+        }
+
+        if let Some(strand) = self.strand() {
+            write!(f, "{}{}", FIELD_DELIMITER, strand)?;
+        } else {
+            write!(f, "{}{}", FIELD_DELIMITER, NULL_FIELD)?;
+        }
+
+        if let Some(frame) = self.frame() {
+            write!(f, "{}{}", FIELD_DELIMITER, frame)?;
+        } else {
+            write!(f, "{}{}", FIELD_DELIMITER, NULL_FIELD)?;
+        }
+
+        write!(f, "{}{}", FIELD_DELIMITER, self.attributes())
+    }
+}
+
+/// An error returned when a raw GTF record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// A field is missing.
+    MissingField(Field),
+    /// The start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// The end is invalid.
+    InvalidEnd(num::ParseIntError),
+    /// The score is invalid.
+    InvalidScore(num::ParseFloatError),
+    /// The strand is invalid.
+    InvalidStrand(strand::ParseError),
+    /// The frame is invalid.
+    InvalidFrame(frame::ParseError),
+    /// The attributes are invalid.
+    InvalidAttributes(attributes::ParseError),
+    /// The gene ID (`gene_id`) attribute is missing.
+    MissingGeneId,
+    /// The transcript ID (`transcript_id`) attribute is missing.
+    ///
+    /// This is required for all features except genes.
+    MissingTranscriptId,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::MissingField(field) => write!(f, "missing field: {:?}", field),
+            Self::InvalidStart(e) => write!(f, "invalid start: {}", e),
+            Self::InvalidEnd(e) => write!(f, "invalid end: {}", e),
+            Self::InvalidScore(e) => write!(f, "invalid score: {}", e),
+            Self::InvalidStrand(e) => write!(f, "invalid strand: {}", e),
+            Self::InvalidFrame(e) => write!(f, "invalid frame: {}", e),
+            Self::InvalidAttributes(e) => write!(f, "invalid attributes: {}", e),
+            Self::MissingGeneId => write!(f, "missing gene ID"),
+            Self::MissingTranscriptId => write!(f, "missing transcript ID"),
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
+
+        let reference_sequence_name =
+            parse_string(&mut fields, Field::ReferenceSequenceName).map(|s| s.into())?;
+        let source = parse_string(&mut fields, Field::Source).map(|s| s.into())?;
+        let ty: String = parse_string(&mut fields, Field::Type).map(|s| s.into())?;
+
+        let start = parse_string(&mut fields, Field::Start)
+            .and_then(|s| s.parse().map_err(ParseError::InvalidStart))?;
+
+        let end = parse_string(&mut fields, Field::End)
+            .and_then(|s| s.parse().map_err(ParseError::InvalidEnd))?;
+
+        let score = parse_string(&mut fields, Field::Score).and_then(|s| {
+            if s == NULL_FIELD {
+                Ok(None)
+            } else {
+                s.parse().map(Some).map_err(ParseError::InvalidScore)
+            }
+        })?;
+
+        let strand = parse_string(&mut fields, Field::Strand).and_then(|s| {
+            if s == NULL_FIELD {
+                Ok(None)
+            } else {
+                s.parse().map(Some).map_err(ParseError::InvalidStrand)
+            }
+        })?;
+
+        let frame = parse_string(&mut fields, Field::Frame).and_then(|s| {
+            if s == NULL_FIELD {
+                Ok(None)
+            } else {
+                s.parse().map(Some).map_err(ParseError::InvalidFrame)
+            }
+        })?;
+
+        let attributes: Attributes = parse_string(&mut fields, Field::Attributes)
+            .and_then(|s| s.parse().map_err(ParseError::InvalidAttributes))?;
+
+        builder::validate_attributes(&ty, &attributes).map_err(|e| match e {
+            BuildError::MissingGeneId => ParseError::MissingGeneId,
+            BuildError::MissingTranscriptId => ParseError::MissingTranscriptId,
+        })?;
+
+        Ok(Self {
+            reference_sequence_name,
+            source,
+            ty,
+            start,
+            end,
+            score,
+            strand,
+            frame,
+            attributes,
+        })
+    }
+}
+
+fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    fields.next().ok_or(ParseError::MissingField(field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let record = Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_source(String::from("NOODLES"))
+            .set_type(String::from("exon"))
+            .set_start(8)
+            .set_end(13)
+            .set_strand(Strand::Reverse)
+            .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+            .build()?;
+
+        assert_eq!(
+            record.to_string(),
+            "sq0\tNOODLES\texon\t8\t13\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        let s = "sq0\tNOODLES\tCDS\t8\t13\t5.5\t+\t2\tgene_id \"g0\"; transcript_id \"t0\"; exon_number 1;";
+        let record = s.parse::<Record>()?;
+
+        assert_eq!(record.reference_sequence_name(), "sq0");
+        assert_eq!(record.source(), "NOODLES");
+        assert_eq!(record.ty(), "CDS");
+        assert_eq!(record.start(), 8);
+        assert_eq!(record.end(), 13);
+        assert_eq!(record.score(), Some(5.5));
+        assert_eq!(record.strand(), Some(Strand::Forward));
+        assert_eq!(record.frame(), Some(Frame::Two));
+        assert_eq!(record.attributes().gene_id(), Some("g0"));
+        assert_eq!(record.attributes().transcript_id(), Some("t0"));
+        assert_eq!(record.attributes().get("exon_number"), Some("1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_with_invalid_record() {
+        assert_eq!("".parse::<Record>(), Err(ParseError::Empty));
+
+        assert_eq!(
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.".parse::<Record>(),
+            Err(ParseError::MissingField(Field::Attributes))
+        );
+
+        assert_eq!(
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\ttranscript_id \"t0\";".parse::<Record>(),
+            Err(ParseError::MissingGeneId)
+        );
+
+        assert_eq!(
+            "sq0\tNOODLES\texon\t8\t13\t.\t+\t.\tgene_id \"g0\";".parse::<Record>(),
+            Err(ParseError::MissingTranscriptId)
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! GTF record attributes and entry.
+
+pub mod entry;
+
+pub use self::entry::Entry;
+
+use std::{error, fmt, ops::Deref, str::FromStr};
+
+const DELIMITER: char = ';';
+const QUOTATION_MARK: char = '"';
+const ESCAPE: char = '\\';
+
+const GENE_ID: &str = "gene_id";
+const TRANSCRIPT_ID: &str = "transcript_id";
+
+/// GTF record attributes.
+///
+/// Entries are kept in insertion order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Attributes(Vec<Entry>);
+
+impl Attributes {
+    /// Returns the value of the first entry with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::{attributes::Entry, Attributes};
+    ///
+    /// let attributes = Attributes::from(vec![
+    ///     Entry::new(String::from("gene_id"), String::from("ndls0")),
+    /// ]);
+    ///
+    /// assert_eq!(attributes.get("gene_id"), Some("ndls0"));
+    /// assert!(attributes.get("gene_name").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter()
+            .find(|entry| entry.key() == key)
+            .map(|entry| entry.value())
+    }
+
+    /// Returns the gene ID (`gene_id`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::Attributes;
+    /// let attributes: Attributes = r#"gene_id "ndls0"; transcript_id "ndls0.1";"#.parse()?;
+    /// assert_eq!(attributes.gene_id(), Some("ndls0"));
+    /// # Ok::<(), noodles_gtf::record::attributes::ParseError>(())
+    /// ```
+    pub fn gene_id(&self) -> Option<&str> {
+        self.get(GENE_ID)
+    }
+
+    /// Returns the transcript ID (`transcript_id`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::Attributes;
+    /// let attributes: Attributes = r#"gene_id "ndls0"; transcript_id "ndls0.1";"#.parse()?;
+    /// assert_eq!(attributes.transcript_id(), Some("ndls0.1"));
+    /// # Ok::<(), noodles_gtf::record::attributes::ParseError>(())
+    /// ```
+    pub fn transcript_id(&self) -> Option<&str> {
+        self.get(TRANSCRIPT_ID)
+    }
+}
+
+impl Deref for Attributes {
+    type Target = [Entry];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, entry) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{}{}", entry, DELIMITER)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when raw attributes fail to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input attributes has an invalid entry.
+    InvalidEntry(entry::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEntry(e) => write!(f, "invalid entry: {}", e),
+        }
+    }
+}
+
+impl From<Vec<Entry>> for Attributes {
+    fn from(entries: Vec<Entry>) -> Self {
+        Self(entries)
+    }
+}
+
+impl FromStr for Attributes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        split_entries(s)
+            .into_iter()
+            .filter(|t| !t.trim().is_empty())
+            .map(|t| t.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+            .map_err(ParseError::InvalidEntry)
+    }
+}
+
+// Splits raw attributes on entry delimiters (`;`) that are not in a quoted value. A quotation mark
+// escaped with a backslash does not end a quoted value.
+fn split_entries(s: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut start = 0;
+    let mut is_quoted = false;
+    let mut is_escaped = false;
+
+    for (i, c) in s.char_indices() {
+        if is_escaped {
+            is_escaped = false;
+            continue;
+        }
+
+        match c {
+            ESCAPE if is_quoted => is_escaped = true,
+            QUOTATION_MARK => is_quoted = !is_quoted,
+            DELIMITER if !is_quoted => {
+                entries.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    entries.push(&s[start..]);
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let attributes = Attributes::default();
+        assert!(attributes.to_string().is_empty());
+
+        let attributes = Attributes::from(vec![
+            Entry::new(String::from("gene_id"), String::from("ndls0")),
+            Entry::new(String::from("transcript_id"), String::from("ndls0.1")),
+        ]);
+
+        assert_eq!(
+            attributes.to_string(),
+            r#"gene_id "ndls0"; transcript_id "ndls0.1";"#
+        );
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        let s = r#"gene_id "ndls0"; transcript_id "ndls0.1"; note "a;b"; exon_number 1;"#;
+        let actual = s.parse::<Attributes>()?;
+        let expected = Attributes::from(vec![
+            Entry::new(String::from("gene_id"), String::from("ndls0")),
+            Entry::new(String::from("transcript_id"), String::from("ndls0.1")),
+            Entry::new(String::from("note"), String::from("a;b")),
+            Entry::new_unquoted(String::from("exon_number"), String::from("1")),
+        ]);
+        assert_eq!(actual, expected);
+        assert_eq!(actual.to_string(), s);
+
+        let s = r#"note "a \"b;\""; gene_id "ndls0";"#;
+        let actual = s.parse::<Attributes>()?;
+        let expected = Attributes::from(vec![
+            Entry::new(String::from("note"), String::from(r#"a "b;""#)),
+            Entry::new(String::from("gene_id"), String::from("ndls0")),
+        ]);
+        assert_eq!(actual, expected);
+
+        let s = r#"gene_id "ndls0""#;
+        let actual = s.parse::<Attributes>()?;
+        let expected = Attributes::from(vec![Entry::new(
+            String::from("gene_id"),
+            String::from("ndls0"),
+        )]);
+        assert_eq!(actual, expected);
+
+        assert_eq!("".parse::<Attributes>()?, Attributes::default());
+
+        assert!(matches!(
+            "gene_id;".parse::<Attributes>(),
+            Err(ParseError::InvalidEntry(_))
+        ));
+
+        Ok(())
+    }
+}
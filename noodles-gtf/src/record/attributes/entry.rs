@@ -0,0 +1,280 @@
+//! GTF record attribute entry.
+
+use std::{error, fmt, str::FromStr};
+
+const SEPARATOR: char = ' ';
+const QUOTATION_MARK: char = '"';
+const ESCAPE: char = '\\';
+const DELIMITER: char = ';';
+
+/// A GTF record attribute entry.
+///
+/// An entry is a key-value pair, e.g., `gene_id "ndls0"`. Values are typically quoted, but
+/// unquoted values, e.g., `exon_number 1`, are kept unquoted when the entry is written.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    key: String,
+    value: String,
+    is_quoted: bool,
+}
+
+impl Entry {
+    /// Creates a GTF record attribute entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::attributes::Entry;
+    /// let entry = Entry::new(String::from("gene_id"), String::from("ndls0"));
+    /// ```
+    pub fn new(key: String, value: String) -> Self {
+        Self {
+            key,
+            value,
+            is_quoted: true,
+        }
+    }
+
+    /// Creates a GTF record attribute entry with an unquoted value.
+    ///
+    /// The value is still quoted when written if it would otherwise be ambiguous, i.e., if it is
+    /// empty or has whitespace, a quotation mark, or an entry delimiter (`;`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::attributes::Entry;
+    /// let entry = Entry::new_unquoted(String::from("exon_number"), String::from("1"));
+    /// assert_eq!(entry.to_string(), "exon_number 1");
+    /// ```
+    pub fn new_unquoted(key: String, value: String) -> Self {
+        Self {
+            key,
+            value,
+            is_quoted: false,
+        }
+    }
+
+    /// Returns the key of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::attributes::Entry;
+    /// let entry = Entry::new(String::from("gene_id"), String::from("ndls0"));
+    /// assert_eq!(entry.key(), "gene_id");
+    /// ```
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::attributes::Entry;
+    /// let entry = Entry::new(String::from("gene_id"), String::from("ndls0"));
+    /// assert_eq!(entry.value(), "ndls0");
+    /// ```
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns whether the value is quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::record::attributes::Entry;
+    ///
+    /// let entry = Entry::new(String::from("gene_id"), String::from("ndls0"));
+    /// assert!(entry.is_quoted());
+    ///
+    /// let entry = Entry::new_unquoted(String::from("exon_number"), String::from("1"));
+    /// assert!(!entry.is_quoted());
+    /// ```
+    pub fn is_quoted(&self) -> bool {
+        self.is_quoted
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.key, SEPARATOR)?;
+
+        if self.is_quoted || needs_quotes(&self.value) {
+            write!(f, "{}", QUOTATION_MARK)?;
+
+            for c in self.value.chars() {
+                if c == QUOTATION_MARK || c == ESCAPE {
+                    write!(f, "{}", ESCAPE)?;
+                }
+
+                write!(f, "{}", c)?;
+            }
+
+            write!(f, "{}", QUOTATION_MARK)
+        } else {
+            f.write_str(&self.value)
+        }
+    }
+}
+
+fn needs_quotes(s: &str) -> bool {
+    s.is_empty()
+        || s.chars()
+            .any(|c| c.is_whitespace() || c == QUOTATION_MARK || c == DELIMITER)
+}
+
+/// An error returned when a raw GTF record attribute entry fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The entry key is missing.
+    MissingKey,
+    /// The entry value is missing.
+    MissingValue,
+    /// The entry value is invalid.
+    ///
+    /// A quoted value must end with a closing quotation mark. Embedded quotation marks and
+    /// backslashes must be escaped with a backslash.
+    InvalidValue(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MissingKey => f.write_str("missing key"),
+            Self::MissingValue => f.write_str("missing value"),
+            Self::InvalidValue(s) => write!(f, "invalid value: {}", s),
+        }
+    }
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (key, raw_value) = match s.find(char::is_whitespace) {
+            Some(i) => (&s[..i], s[i..].trim_start()),
+            None => return Err(ParseError::MissingValue),
+        };
+
+        if key.is_empty() {
+            return Err(ParseError::MissingKey);
+        } else if raw_value.is_empty() {
+            return Err(ParseError::MissingValue);
+        }
+
+        if let Some(t) = raw_value.strip_prefix(QUOTATION_MARK) {
+            let value = unescape(t).ok_or_else(|| ParseError::InvalidValue(raw_value.into()))?;
+            Ok(Self::new(key.into(), value))
+        } else {
+            Ok(Self::new_unquoted(key.into(), raw_value.into()))
+        }
+    }
+}
+
+// Unescapes the rest of a quoted value, i.e., the value after the opening quotation mark.
+//
+// This returns `None` if the closing quotation mark is missing or is not at the end.
+fn unescape(s: &str) -> Option<String> {
+    let mut value = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ESCAPE => value.push(chars.next()?),
+            QUOTATION_MARK => {
+                return if chars.as_str().is_empty() {
+                    Some(value)
+                } else {
+                    None
+                };
+            }
+            _ => value.push(c),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let entry = Entry::new(String::from("gene_id"), String::from("ndls0"));
+        assert_eq!(entry.to_string(), r#"gene_id "ndls0""#);
+
+        let entry = Entry::new(String::from("note"), String::from(r#"a "b" \c"#));
+        assert_eq!(entry.to_string(), r#"note "a \"b\" \\c""#);
+
+        let entry = Entry::new_unquoted(String::from("exon_number"), String::from("1"));
+        assert_eq!(entry.to_string(), "exon_number 1");
+
+        let entry = Entry::new_unquoted(String::from("note"), String::from("a b"));
+        assert_eq!(entry.to_string(), r#"note "a b""#);
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        assert_eq!(
+            r#"gene_id "ndls0""#.parse::<Entry>()?,
+            Entry::new(String::from("gene_id"), String::from("ndls0"))
+        );
+
+        assert_eq!(
+            "exon_number 1".parse::<Entry>()?,
+            Entry::new_unquoted(String::from("exon_number"), String::from("1"))
+        );
+
+        assert_eq!(
+            r#"note "noodles gtf""#.parse::<Entry>()?,
+            Entry::new(String::from("note"), String::from("noodles gtf"))
+        );
+
+        assert_eq!(
+            r#"note "a \"b\"""#.parse::<Entry>()?,
+            Entry::new(String::from("note"), String::from(r#"a "b""#))
+        );
+
+        assert_eq!("".parse::<Entry>(), Err(ParseError::Empty));
+        assert_eq!("gene_id".parse::<Entry>(), Err(ParseError::MissingValue));
+        assert_eq!(
+            r#"gene_id "ndls0"#.parse::<Entry>(),
+            Err(ParseError::InvalidValue(String::from(r#""ndls0"#)))
+        );
+        assert_eq!(
+            r#"gene_id "nd"ls0""#.parse::<Entry>(),
+            Err(ParseError::InvalidValue(String::from(r#""nd"ls0""#)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), ParseError> {
+        for s in &[
+            r#"gene_id "ndls0""#,
+            "exon_number 1",
+            r#"note "a \"b\" \\c""#,
+        ] {
+            assert_eq!(s.parse::<Entry>()?.to_string(), *s);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+use std::{error, fmt};
+
+use super::{Attributes, Frame, Record, Strand, NULL_FIELD};
+
+const GENE_TYPE: &str = "gene";
+
+/// A GTF record builder.
+#[derive(Debug)]
+pub struct Builder {
+    reference_sequence_name: String,
+    source: String,
+    ty: String,
+    start: i32,
+    end: i32,
+    score: Option<f32>,
+    strand: Option<Strand>,
+    frame: Option<Frame>,
+    attributes: Attributes,
+}
+
+/// An error returned when a GTF record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The gene ID (`gene_id`) attribute is missing.
+    MissingGeneId,
+    /// The transcript ID (`transcript_id`) attribute is missing.
+    ///
+    /// This is required for all features except genes.
+    MissingTranscriptId,
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingGeneId => f.write_str("missing gene ID"),
+            Self::MissingTranscriptId => f.write_str("missing transcript ID"),
+        }
+    }
+}
+
+impl Builder {
+    /// Creates a GTF record builder.
+    ///
+    /// Typically, [`Record::builder`] is used instead of calling this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let builder = gtf::Record::builder();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a GTF record reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_reference_sequence_name(String::from("sq0"))
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_sequence_name(mut self, reference_sequence_name: String) -> Self {
+        self.reference_sequence_name = reference_sequence_name;
+        self
+    }
+
+    /// Sets a GTF record source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_source(String::from("NOODLES"))
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.source(), "NOODLES");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_source(mut self, source: String) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets a GTF record feature type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_type(String::from("gene"))
+    ///     .set_attributes(r#"gene_id "g0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.ty(), "gene");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_type(mut self, ty: String) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    /// Sets a GTF record start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_start(8)
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.start(), 8);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_start(mut self, start: i32) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets a GTF record end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_end(13)
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.end(), 13);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_end(mut self, end: i32) -> Self {
+        self.end = end;
+        self
+    }
+
+    /// Sets a GTF record score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_score(21.0)
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.score(), Some(21.0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_score(mut self, score: f32) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Sets a GTF record strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::Strand};
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_strand(Strand::Forward)
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.strand(), Some(Strand::Forward));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_strand(mut self, strand: Strand) -> Self {
+        self.strand = Some(strand);
+        self
+    }
+
+    /// Sets a GTF record frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::Frame};
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_frame(Frame::Zero)
+    ///     .set_attributes(r#"gene_id "g0"; transcript_id "t0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.frame(), Some(Frame::Zero));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Sets GTF record attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{
+    ///     self as gtf,
+    ///     record::{attributes::Entry, Attributes},
+    /// };
+    ///
+    /// let attributes = Attributes::from(vec![
+    ///     Entry::new(String::from("gene_id"), String::from("g0")),
+    ///     Entry::new(String::from("transcript_id"), String::from("t0")),
+    /// ]);
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_attributes(attributes.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.attributes(), &attributes);
+    /// # Ok::<_, gtf::record::BuildError>(())
+    /// ```
+    pub fn set_attributes(mut self, attributes: Attributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Builds a GTF record.
+    ///
+    /// The gene ID (`gene_id`) attribute is required. The transcript ID (`transcript_id`)
+    /// attribute is also required, except for gene features.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, record::BuildError};
+    /// assert_eq!(gtf::Record::builder().build(), Err(BuildError::MissingGeneId));
+    /// ```
+    pub fn build(self) -> Result<Record, BuildError> {
+        validate_attributes(&self.ty, &self.attributes)?;
+
+        Ok(Record {
+            reference_sequence_name: self.reference_sequence_name,
+            source: self.source,
+            ty: self.ty,
+            start: self.start,
+            end: self.end,
+            score: self.score,
+            strand: self.strand,
+            frame: self.frame,
+            attributes: self.attributes,
+        })
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_name: NULL_FIELD.into(),
+            source: NULL_FIELD.into(),
+            ty: NULL_FIELD.into(),
+            start: 1,
+            end: 1,
+            score: None,
+            strand: None,
+            frame: None,
+            attributes: Attributes::default(),
+        }
+    }
+}
+
+pub(super) fn validate_attributes(ty: &str, attributes: &Attributes) -> Result<(), BuildError> {
+    if attributes.gene_id().is_none() {
+        Err(BuildError::MissingGeneId)
+    } else if ty != GENE_TYPE && attributes.transcript_id().is_none() {
+        Err(BuildError::MissingTranscriptId)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::record::attributes::Entry;
+
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), BuildError> {
+        let attributes = Attributes::from(vec![
+            Entry::new(String::from("gene_id"), String::from("g0")),
+            Entry::new(String::from("transcript_id"), String::from("t0")),
+        ]);
+
+        let record = Builder::new()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_source(String::from("NOODLES"))
+            .set_type(String::from("CDS"))
+            .set_start(8)
+            .set_end(13)
+            .set_score(21.0)
+            .set_strand(Strand::Forward)
+            .set_frame(Frame::Zero)
+            .set_attributes(attributes.clone())
+            .build()?;
+
+        assert_eq!(record.reference_sequence_name(), "sq0");
+        assert_eq!(record.source(), "NOODLES");
+        assert_eq!(record.ty(), "CDS");
+        assert_eq!(record.start(), 8);
+        assert_eq!(record.end(), 13);
+        assert_eq!(record.score(), Some(21.0));
+        assert_eq!(record.strand(), Some(Strand::Forward));
+        assert_eq!(record.frame(), Some(Frame::Zero));
+        assert_eq!(record.attributes(), &attributes);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_missing_ids() {
+        assert_eq!(Builder::new().build(), Err(BuildError::MissingGeneId));
+
+        let attributes = Attributes::from(vec![Entry::new(
+            String::from("gene_id"),
+            String::from("g0"),
+        )]);
+
+        assert_eq!(
+            Builder::new().set_attributes(attributes.clone()).build(),
+            Err(BuildError::MissingTranscriptId)
+        );
+
+        assert!(Builder::new()
+            .set_type(String::from("gene"))
+            .set_attributes(attributes)
+            .build()
+            .is_ok());
+    }
+}
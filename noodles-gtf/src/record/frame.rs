@@ -0,0 +1,95 @@
+//! GTF record frame.
+
+use std::{error, fmt, str::FromStr};
+
+/// A GTF record frame.
+///
+/// The frame is used for coding features (e.g., `CDS`, `start_codon`, and `stop_codon`) to
+/// describe the number of bases to remove from the 5' end of the feature to reach the first base
+/// of the next codon.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Frame {
+    /// The codon begins at the first nucleotide (`0`).
+    Zero,
+    /// The codon begins at the second nucleotide (`1`).
+    One,
+    /// The codon begins at the third nucleotide (`2`).
+    Two,
+}
+
+impl AsRef<str> for Frame {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Zero => "0",
+            Self::One => "1",
+            Self::Two => "2",
+        }
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw GTF record frame fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The frame is invalid.
+    Invalid(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid(s) => write!(f, "expected {{0, 1, 2}}, got {}", s),
+        }
+    }
+}
+
+impl FromStr for Frame {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "0" => Ok(Self::Zero),
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(ParseError::Invalid(s.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Frame::Zero.to_string(), "0");
+        assert_eq!(Frame::One.to_string(), "1");
+        assert_eq!(Frame::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        assert_eq!("0".parse::<Frame>()?, Frame::Zero);
+        assert_eq!("1".parse::<Frame>()?, Frame::One);
+        assert_eq!("2".parse::<Frame>()?, Frame::Two);
+
+        assert_eq!("".parse::<Frame>(), Err(ParseError::Empty));
+        assert_eq!(
+            "3".parse::<Frame>(),
+            Err(ParseError::Invalid(String::from("3")))
+        );
+
+        Ok(())
+    }
+}
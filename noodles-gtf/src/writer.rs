@@ -0,0 +1,80 @@
+use std::io::{self, Write};
+
+use super::{Line, Record};
+
+/// A GTF writer.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a GTF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let writer = gtf::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    /// let writer = gtf::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Writes a GTF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let mut writer = gtf::Writer::new(Vec::new());
+    ///
+    /// let record = gtf::Record::builder()
+    ///     .set_reference_sequence_name(String::from("sq0"))
+    ///     .set_type(String::from("gene"))
+    ///     .set_attributes(r#"gene_id "g0";"#.parse()?)
+    ///     .build()?;
+    ///
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"sq0\t.\tgene\t1\t1\t.\t.\t.\tgene_id \"g0\";\n");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        writeln!(self.inner, "{}", record)
+    }
+
+    /// Writes a GTF line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let mut writer = gtf::Writer::new(Vec::new());
+    /// writer.write_line(&gtf::Line::Comment(String::from("format: gtf")))?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"#format: gtf\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_line(&mut self, line: &Line) -> io::Result<()> {
+        writeln!(self.inner, "{}", line)
+    }
+}
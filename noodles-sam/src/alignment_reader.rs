@@ -0,0 +1,42 @@
+use std::io;
+
+use super::{AlignmentRecord, Header};
+
+/// An alignment reader.
+///
+/// This is a common interface for readers of alignment formats, e.g., SAM and BAM. It allows
+/// reading the header and records of an alignment file without knowing the format at compile
+/// time.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufReader}};
+/// use noodles_sam::{self as sam, AlignmentReader};
+///
+/// let mut reader: Box<dyn AlignmentReader> = File::open("sample.sam")
+///     .map(BufReader::new)
+///     .map(sam::Reader::new)
+///     .map(Box::new)?;
+///
+/// reader.read_alignment_header()?;
+///
+/// for result in reader.alignment_records() {
+///     let record = result?;
+///     println!("{:?}", record.alignment_start());
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub trait AlignmentReader {
+    /// Reads and parses the alignment header.
+    ///
+    /// The position of the stream is expected to be at the start.
+    fn read_alignment_header(&mut self) -> io::Result<Header>;
+
+    /// Returns an iterator over alignment records starting from the current stream position.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    fn alignment_records(
+        &mut self,
+    ) -> Box<dyn Iterator<Item = io::Result<Box<dyn AlignmentRecord>>> + '_>;
+}
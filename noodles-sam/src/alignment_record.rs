@@ -0,0 +1,49 @@
+use std::{convert::TryFrom, io};
+
+use super::{
+    header::{ReferenceSequence, ReferenceSequences},
+    record::{Flags, MappingQuality, Position},
+};
+
+/// An alignment record.
+///
+/// This is a common interface for records of alignment formats, e.g., SAM and BAM. It exposes the
+/// fields describing where a read maps, which is typically all that is needed to filter or
+/// summarize alignments independent of the underlying format.
+pub trait AlignmentRecord {
+    /// Returns the associated reference sequence of this record.
+    ///
+    /// The reference sequence is resolved from the given reference sequence dictionary. If the
+    /// record is not mapped to a reference sequence, this returns `None`.
+    fn reference_sequence<'rs>(
+        &self,
+        reference_sequences: &'rs ReferenceSequences,
+    ) -> Option<io::Result<&'rs ReferenceSequence>>;
+
+    /// Returns the flags of this record.
+    fn flags(&self) -> Flags;
+
+    /// Returns the 1-based start position of this record.
+    fn alignment_start(&self) -> Option<Position>;
+
+    /// Returns the number of bases this record spans on its reference sequence.
+    fn alignment_span(&self) -> io::Result<u32>;
+
+    /// Returns the 1-based end position of this record, inclusive.
+    ///
+    /// This is calculated from the alignment start and span. If the record has no alignment
+    /// start, this returns `None`.
+    fn alignment_end(&self) -> Option<io::Result<Position>> {
+        self.alignment_start().map(|start| {
+            let span = self.alignment_span().and_then(|len| {
+                i32::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })?;
+
+            let end = i32::from(start) + span - 1;
+            Position::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+    }
+
+    /// Returns the mapping quality of this record.
+    fn mapping_quality(&self) -> MappingQuality;
+}
@@ -8,8 +8,11 @@ use std::io::{self, BufRead, Read, Seek};
 
 use noodles_bgzf as bgzf;
 
+use super::{AlignmentReader, AlignmentRecord, Header};
+
 const HEADER_PREFIX: u8 = b'@';
-const LINE_FEED: u8 = b'\n';
+const LINE_FEED: char = '\n';
+const CARRIAGE_RETURN: char = '\r';
 
 /// A SAM reader.
 ///
@@ -100,7 +103,7 @@ where
                 break;
             }
 
-            let (read_eol, len) = if let Some(i) = buf.iter().position(|&b| b == LINE_FEED) {
+            let (read_eol, len) = if let Some(i) = buf.iter().position(|&b| b == LINE_FEED as u8) {
                 header_buf.extend(&buf[..=i]);
                 (true, i + 1)
             } else {
@@ -119,7 +122,8 @@ where
     /// Reads a single raw SAM record.
     ///
     /// This reads from the underlying stream until a newline is reached and appends it to the
-    /// given buffer, sans the final newline character.
+    /// given buffer, sans the final newline character (`\n` or `\r\n`). The buffer can
+    /// subsequently be parsed as a [`crate::Record`].
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
     ///
@@ -150,9 +154,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
-        let result = self.inner.read_line(buf);
-        buf.pop();
-        result
+        read_line(&mut self.inner, buf)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -214,6 +216,48 @@ where
     }
 }
 
+impl<R> AlignmentReader for Reader<R>
+where
+    R: BufRead,
+{
+    fn read_alignment_header(&mut self) -> io::Result<Header> {
+        self.read_header().and_then(|s| {
+            s.parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+    }
+
+    fn alignment_records(
+        &mut self,
+    ) -> Box<dyn Iterator<Item = io::Result<Box<dyn AlignmentRecord>>> + '_> {
+        Box::new(
+            self.records()
+                .map(|result| result.map(|record| Box::new(record) as Box<dyn AlignmentRecord>)),
+        )
+    }
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    match reader.read_line(buf) {
+        Ok(0) => Ok(0),
+        Ok(n) => {
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+
+                if buf.ends_with(CARRIAGE_RETURN) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+        Err(e) => Err(e),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::BufReader;
@@ -248,4 +292,51 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_record() -> io::Result<()> {
+        let data =
+            b"@HD\tVN:1.6\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\r\n*\t4\t*\t0\t255\t*\t*\t0\t0\tA\t*";
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let mut buf = String::new();
+        let bytes_read = reader.read_record(&mut buf)?;
+        assert_eq!(bytes_read, 25);
+        assert_eq!(buf, "*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*");
+
+        buf.clear();
+        let bytes_read = reader.read_record(&mut buf)?;
+        assert_eq!(bytes_read, 23);
+        assert_eq!(buf, "*\t4\t*\t0\t255\t*\t*\t0\t0\tA\t*");
+
+        buf.clear();
+        let bytes_read = reader.read_record(&mut buf)?;
+        assert_eq!(bytes_read, 0);
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_alignment_records() -> io::Result<()> {
+        let data = b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\nr0\t0\tsq0\t2\t255\t4M\t*\t0\t0\t*\t*\n";
+        let mut reader: Box<dyn AlignmentReader> = Box::new(Reader::new(&data[..]));
+
+        let header = reader.read_alignment_header()?;
+        assert_eq!(header.reference_sequences().len(), 1);
+
+        let records: Vec<_> = reader.alignment_records().collect::<Result<_, _>>()?;
+        assert_eq!(records.len(), 1);
+
+        let record = &records[0];
+        let reference_sequence = record
+            .reference_sequence(header.reference_sequences())
+            .transpose()?;
+        assert_eq!(reference_sequence.map(|rs| rs.name()), Some("sq0"));
+        assert_eq!(record.alignment_start().map(i32::from), Some(2));
+        assert_eq!(record.alignment_end().transpose()?.map(i32::from), Some(5));
+
+        Ok(())
+    }
 }
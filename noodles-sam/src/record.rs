@@ -18,7 +18,12 @@ pub use self::{
     read_name::ReadName, reference_sequence_name::ReferenceSequenceName, sequence::Sequence,
 };
 
-use std::{error, fmt, num, str::FromStr};
+use std::{error, fmt, io, num, str::FromStr};
+
+use super::{
+    header::{ReferenceSequence, ReferenceSequences},
+    AlignmentRecord,
+};
 
 pub(crate) const NULL_FIELD: &str = "*";
 const ZERO_FIELD: &str = "0";
@@ -358,7 +363,7 @@ impl Record {
     /// );
     ///
     /// let data = record.data_mut();
-    /// data.insert(field.tag().clone(), field.clone());
+    /// data.insert(field.clone());
     ///
     /// let data = record.data();
     /// assert_eq!(data.len(), 1);
@@ -369,6 +374,38 @@ impl Record {
     }
 }
 
+impl AlignmentRecord for Record {
+    fn reference_sequence<'rs>(
+        &self,
+        reference_sequences: &'rs ReferenceSequences,
+    ) -> Option<io::Result<&'rs ReferenceSequence>> {
+        self.reference_sequence_name().map(|name| {
+            reference_sequences.get(name.as_str()).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid reference sequence name: {}", name),
+                )
+            })
+        })
+    }
+
+    fn flags(&self) -> Flags {
+        self.flags()
+    }
+
+    fn alignment_start(&self) -> Option<Position> {
+        self.position()
+    }
+
+    fn alignment_span(&self) -> io::Result<u32> {
+        Ok(self.cigar().reference_len())
+    }
+
+    fn mapping_quality(&self) -> MappingQuality {
+        self.mapping_quality()
+    }
+}
+
 impl Default for Record {
     fn default() -> Self {
         Builder::new().build()
@@ -609,4 +646,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences: ReferenceSequences =
+            vec![(String::from("sq0"), ReferenceSequence::new("sq0", 13))]
+                .into_iter()
+                .collect();
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".parse()?)
+            .set_position(Position::try_from(3)?)
+            .set_cigar("2S4M1D2M".parse()?)
+            .build();
+
+        let reference_sequence =
+            AlignmentRecord::reference_sequence(&record, &reference_sequences).transpose()?;
+        assert_eq!(reference_sequence.map(|rs| rs.name()), Some("sq0"));
+        assert_eq!(
+            AlignmentRecord::alignment_start(&record),
+            Position::try_from(3).ok()
+        );
+        assert_eq!(AlignmentRecord::alignment_span(&record)?, 7);
+        assert_eq!(
+            AlignmentRecord::alignment_end(&record).transpose()?,
+            Position::try_from(9).ok()
+        );
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq1".parse()?)
+            .build();
+        assert!(matches!(
+            AlignmentRecord::reference_sequence(&record, &reference_sequences),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = Record::default();
+        assert!(AlignmentRecord::reference_sequence(&record, &reference_sequences).is_none());
+        assert!(AlignmentRecord::alignment_end(&record).is_none());
+
+        Ok(())
+    }
 }
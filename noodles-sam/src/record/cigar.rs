@@ -1,10 +1,11 @@
 //! SAM CIGAR and operations.
 
 pub mod op;
+mod ops;
 
 use std::{error, fmt, ops::Deref, str::FromStr};
 
-pub use self::op::Op;
+pub use self::{op::Op, ops::Ops};
 
 use super::NULL_FIELD;
 
@@ -107,22 +108,14 @@ impl FromStr for Cigar {
             return Ok(Self::default());
         }
 
-        let mut ops = Vec::new();
+        let mut ops = Vec::with_capacity(ops::count_ops(s));
 
-        let matches = s.match_indices(|c: char| !c.is_digit(10));
-        let mut start = 0;
-
-        for (end, raw_kind) in matches {
-            let op = s[start..=end].parse().map_err(ParseError::InvalidOp)?;
+        for result in Ops::new(s) {
+            let op = result?;
             ops.push(op);
-            start = end + raw_kind.len();
         }
 
-        if start == s.len() {
-            Ok(Self::from(ops))
-        } else {
-            Err(ParseError::Invalid)
-        }
+        Ok(Self::from(ops))
     }
 }
 
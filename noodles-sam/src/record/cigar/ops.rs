@@ -0,0 +1,110 @@
+use super::{Op, ParseError};
+
+/// An iterator over the operations of a raw CIGAR string.
+///
+/// Each operation is parsed incrementally as the iterator is advanced, and no intermediate
+/// buffers are allocated. This is useful when only a few operations need to be inspected or when
+/// the operations are immediately transformed, e.g., to calculate an alignment span.
+///
+/// The input is expected to be a non-empty list of operations, i.e., not the null field (`*`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::record::cigar::{op::Kind, Op, Ops};
+///
+/// let mut ops = Ops::new("36M4D8S");
+///
+/// assert_eq!(ops.next(), Some(Ok(Op::new(Kind::Match, 36))));
+/// assert_eq!(ops.next(), Some(Ok(Op::new(Kind::Deletion, 4))));
+/// assert_eq!(ops.next(), Some(Ok(Op::new(Kind::SoftClip, 8))));
+/// assert_eq!(ops.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ops<'a> {
+    s: &'a str,
+}
+
+impl<'a> Ops<'a> {
+    /// Creates an iterator over the operations of a raw CIGAR string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::cigar::Ops;
+    /// let ops = Ops::new("36M4D8S");
+    /// ```
+    pub fn new(s: &'a str) -> Self {
+        Self { s }
+    }
+}
+
+impl<'a> Iterator for Ops<'a> {
+    type Item = Result<Op, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.s.is_empty() {
+            return None;
+        }
+
+        let i = match self.s.find(|c: char| !c.is_ascii_digit()) {
+            Some(i) => i,
+            None => {
+                self.s = "";
+                return Some(Err(ParseError::Invalid));
+            }
+        };
+
+        let kind_len = self.s[i..].chars().next().map(char::len_utf8).unwrap_or(1);
+        let (raw_op, rest) = self.s.split_at(i + kind_len);
+        self.s = rest;
+
+        Some(raw_op.parse().map_err(ParseError::InvalidOp))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.s.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.s.len() / 2 + 1))
+        }
+    }
+}
+
+pub(super) fn count_ops(s: &str) -> usize {
+    s.bytes().filter(|b| !b.is_ascii_digit()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::op::Kind, *};
+
+    #[test]
+    fn test_next() {
+        let mut ops = Ops::new("1M13N144S");
+        assert_eq!(ops.next(), Some(Ok(Op::new(Kind::Match, 1))));
+        assert_eq!(ops.next(), Some(Ok(Op::new(Kind::Skip, 13))));
+        assert_eq!(ops.next(), Some(Ok(Op::new(Kind::SoftClip, 144))));
+        assert_eq!(ops.next(), None);
+
+        let mut ops = Ops::new("8M13");
+        assert_eq!(ops.next(), Some(Ok(Op::new(Kind::Match, 8))));
+        assert_eq!(ops.next(), Some(Err(ParseError::Invalid)));
+        assert_eq!(ops.next(), None);
+
+        let mut ops = Ops::new("8Q");
+        assert!(matches!(ops.next(), Some(Err(ParseError::InvalidOp(_)))));
+        assert_eq!(ops.next(), None);
+
+        let mut ops = Ops::new("M");
+        assert!(matches!(ops.next(), Some(Err(ParseError::InvalidOp(_)))));
+        assert_eq!(ops.next(), None);
+    }
+
+    #[test]
+    fn test_count_ops() {
+        assert_eq!(count_ops(""), 0);
+        assert_eq!(count_ops("8M"), 1);
+        assert_eq!(count_ops("1M13N144S"), 3);
+    }
+}
@@ -21,6 +21,62 @@ const DELIMITER: char = '\t';
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Data(IndexMap<field::Tag, Field>);
 
+impl Data {
+    /// Inserts a field into the data map.
+    ///
+    /// The field tag is used as the key. If a field with the same tag already exists, it is
+    /// replaced in place, i.e., the order of the fields is preserved, and the old field is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_sam::record::{data::{field::{Tag, Value}, Field}, Data};
+    ///
+    /// let mut data = Data::try_from(vec![
+    ///     Field::new(Tag::AlignmentHitCount, Value::Int32(1)),
+    ///     Field::new(Tag::ReadGroup, Value::String(String::from("rg0"))),
+    /// ])?;
+    ///
+    /// let old_field = data.insert(Field::new(Tag::AlignmentHitCount, Value::Int32(2)));
+    /// assert_eq!(old_field, Some(Field::new(Tag::AlignmentHitCount, Value::Int32(1))));
+    /// assert_eq!(data.to_string(), "NH:i:2\tRG:Z:rg0");
+    /// # Ok::<(), noodles_sam::record::data::TryFromFieldVectorError>(())
+    /// ```
+    pub fn insert(&mut self, field: Field) -> Option<Field> {
+        self.0.insert(field.tag().clone(), field)
+    }
+
+    /// Removes the field with the given tag.
+    ///
+    /// The relative order of the remaining fields is preserved. The removed field is returned, if
+    /// it existed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_sam::record::{data::{field::{Tag, Value}, Field}, Data};
+    ///
+    /// let mut data = Data::try_from(vec![
+    ///     Field::new(Tag::AlignmentHitCount, Value::Int32(1)),
+    ///     Field::new(Tag::ReadGroup, Value::String(String::from("rg0"))),
+    ///     Field::new(Tag::Comment, Value::String(String::from("noodles"))),
+    /// ])?;
+    ///
+    /// let field = data.remove(&Tag::AlignmentHitCount);
+    /// assert_eq!(field, Some(Field::new(Tag::AlignmentHitCount, Value::Int32(1))));
+    /// assert_eq!(data.to_string(), "RG:Z:rg0\tCO:Z:noodles");
+    ///
+    /// assert!(data.remove(&Tag::AlignmentHitCount).is_none());
+    /// # Ok::<(), noodles_sam::record::data::TryFromFieldVectorError>(())
+    /// ```
+    pub fn remove(&mut self, tag: &field::Tag) -> Option<Field> {
+        self.0.shift_remove(tag)
+    }
+}
+
 impl Deref for Data {
     type Target = IndexMap<field::Tag, Field>;
 
@@ -3,12 +3,18 @@
 pub mod builder;
 pub mod header;
 mod indexer;
+mod merge;
 pub mod reference_sequence;
 
 pub use self::{
     builder::Builder, header::Header, indexer::Indexer, reference_sequence::ReferenceSequence,
 };
 
+use std::{convert::TryFrom, io};
+
+use noodles_bgzf::index::{optimize_chunks, Chunk};
+use noodles_core::{region::Interval, Region};
+
 /// A tabix index.
 #[derive(Debug)]
 pub struct Index {
@@ -102,6 +108,183 @@ impl Index {
     pub fn unmapped_read_count(&self) -> Option<u64> {
         self.unmapped_read_count
     }
+
+    /// Merges the indexes of bgzip-compressed files that are concatenated in the given order.
+    ///
+    /// `compressed_sizes` are the sizes, in bytes, of each compressed file. Virtual positions in
+    /// each index are offset by the total size of the files before it, so the merged index is
+    /// valid for the concatenated file without rescanning it.
+    ///
+    /// Reference sequences that appear in more than one index are combined.
+    ///
+    /// This returns an invalid input error if there are no indexes, the number of indexes and
+    /// compressed sizes differ, or the index headers do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{self as bgzf, index::Chunk};
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.add_record("sq0", 8, 13, Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// ))?;
+    /// let index0 = indexer.build()?;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.add_record("sq1", 8, 13, Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// ))?;
+    /// let index1 = indexer.build()?;
+    ///
+    /// let index = tabix::Index::merge(&[index0, index1], &[55, 55])?;
+    ///
+    /// assert_eq!(index.reference_sequence_names(), ["sq0", "sq1"]);
+    /// assert_eq!(
+    ///     index.reference_sequences()[1].bins()[0].chunks()[0].start(),
+    ///     bgzf::VirtualPosition::from(55 << 16),
+    /// );
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn merge(indexes: &[Self], compressed_sizes: &[u64]) -> io::Result<Self> {
+        merge::merge(indexes, compressed_sizes)
+    }
+
+    /// Returns the chunks that may contain records that intersect the given interval.
+    ///
+    /// The reference sequence is resolved by name. Chunks from all bins that overlap the interval
+    /// are collected, those that end before the linear index minimum offset are removed, and the
+    /// rest are merged.
+    ///
+    /// This returns an invalid input error if the reference sequence name is not in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{self as bgzf, index::Chunk};
+    /// use noodles_core::{region::Interval, Position};
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.add_record("sq0", 8, 13, Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// ))?;
+    /// let index = indexer.build()?;
+    ///
+    /// let interval = Interval::new(Position::new(5).unwrap(), Position::new(10).unwrap()).unwrap();
+    /// let chunks = index.query("sq0", interval)?;
+    ///
+    /// assert_eq!(chunks, [Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// )]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn query(
+        &self,
+        reference_sequence_name: &str,
+        interval: Interval,
+    ) -> io::Result<Vec<Chunk>> {
+        let i = self
+            .reference_sequence_names
+            .iter()
+            .position(|name| name == reference_sequence_name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "reference sequence name does not exist in index: {}",
+                        reference_sequence_name
+                    ),
+                )
+            })?;
+
+        let reference_sequence = self.reference_sequences.get(i).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "could not find reference in index: {} >= {}",
+                    i,
+                    self.reference_sequences.len()
+                ),
+            )
+        })?;
+
+        let start = position_to_i32(interval.start())?;
+        let end = position_to_i32(interval.end())?;
+
+        let chunks: Vec<_> = reference_sequence
+            .query(start, end)
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .cloned()
+            .collect();
+
+        let min_offset = reference_sequence.min_offset(start);
+
+        Ok(optimize_chunks(&chunks, min_offset))
+    }
+
+    /// Returns the chunks that may contain records that intersect the given region.
+    ///
+    /// This is the same as calling [`Self::query`] with the name and interval of the region.
+    ///
+    /// This returns an invalid input error if the region is not mapped or if its interval is
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{self as bgzf, index::Chunk};
+    /// use noodles_core::Region;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.add_record("sq0", 8, 13, Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// ))?;
+    /// let index = indexer.build()?;
+    ///
+    /// let chunks = index.query_region(&Region::mapped("sq0", 5, 10))?;
+    ///
+    /// assert_eq!(chunks, [Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// )]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn query_region(&self, region: &Region) -> io::Result<Vec<Chunk>> {
+        let name = match region {
+            Region::Mapped { name, .. } => name,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "region is not mapped",
+                ))
+            }
+        };
+
+        let interval = region.interval().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid region interval: {:?}", region),
+            )
+        })?;
+
+        self.query(name, interval)
+    }
+}
+
+fn position_to_i32(position: noodles_core::Position) -> io::Result<i32> {
+    i32::try_from(usize::from(position)).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
 }
 
 impl Default for Index {
@@ -109,3 +292,116 @@ impl Default for Index {
         Builder::default().build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_core::Position;
+
+    use super::*;
+
+    fn build_interval(start: usize, end: usize) -> Interval {
+        Interval::new(Position::new(start).unwrap(), Position::new(end).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_query() -> io::Result<()> {
+        let mut indexer = Index::indexer();
+
+        indexer.add_record(
+            "sq0",
+            8,
+            13,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(21),
+            ),
+        )?;
+
+        indexer.add_record(
+            "sq0",
+            34,
+            55,
+            Chunk::new(
+                bgzf::VirtualPosition::from(21),
+                bgzf::VirtualPosition::from(42),
+            ),
+        )?;
+
+        indexer.add_record(
+            "sq1",
+            8,
+            13,
+            Chunk::new(
+                bgzf::VirtualPosition::from(42),
+                bgzf::VirtualPosition::from(63),
+            ),
+        )?;
+
+        let index = indexer.build()?;
+
+        assert_eq!(
+            index.query("sq0", build_interval(5, 40))?,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(42),
+            )]
+        );
+
+        assert_eq!(
+            index.query("sq1", build_interval(1, 8))?,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(42),
+                bgzf::VirtualPosition::from(63),
+            )]
+        );
+
+        assert!(matches!(
+            index.query("sq2", build_interval(1, 8)),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+    #[test]
+    fn test_query_region() -> io::Result<()> {
+        let mut indexer = Index::indexer();
+
+        indexer.add_record(
+            "sq0",
+            8,
+            13,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(21),
+            ),
+        )?;
+
+        let index = indexer.build()?;
+
+        assert_eq!(
+            index.query_region(&Region::mapped("sq0", 5, 10))?,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(21),
+            )]
+        );
+
+        assert!(matches!(
+            index.query_region(&Region::Unmapped),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            index.query_region(&Region::All),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            index.query_region(&Region::mapped("sq0", 0, 10)),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
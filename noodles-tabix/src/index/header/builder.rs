@@ -15,6 +15,10 @@ pub struct Builder {
 impl Builder {
     /// Creates a builder that targets the BED format.
     ///
+    /// This uses the BED coordinate system with 1-based column indices for the reference
+    /// sequence name (1), start position (2), and end position (3). Lines prefixed with `#` are
+    /// skipped.
+    ///
     /// # Examples
     ///
     /// ```
@@ -34,6 +38,10 @@ impl Builder {
 
     /// Creates a builder that targets the GFF format.
     ///
+    /// This uses the GFF coordinate system with 1-based column indices for the reference
+    /// sequence name (1), start position (4), and end position (5). Lines prefixed with `#` are
+    /// skipped.
+    ///
     /// # Examples
     ///
     /// ```
@@ -53,6 +61,9 @@ impl Builder {
 
     /// Creates a builder that targets the SAM format.
     ///
+    /// The reference sequence name and position are read from columns 3 and 4, respectively. The
+    /// end position is calculated from the record. Lines prefixed with `@` are skipped.
+    ///
     /// # Examples
     ///
     /// ```
@@ -72,6 +83,9 @@ impl Builder {
 
     /// Creates a builder that targets the VCF format.
     ///
+    /// The reference sequence name and position are read from columns 1 and 2, respectively. The
+    /// end position is calculated from the record. Lines prefixed with `#` are skipped.
+    ///
     /// # Examples
     ///
     /// ```
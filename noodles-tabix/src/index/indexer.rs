@@ -1,24 +1,149 @@
+use std::io;
+
 use noodles_bgzf::index::Chunk;
+use noodles_csi as csi;
 
 use super::{reference_sequence, Header, Index, ReferenceSequence};
 
+// The tabix binning scheme has a min shift of 14 and a depth of 5, i.e., a maximum position of
+// 2^29.
+const TABIX_MAX_POSITION: i32 = 1 << 29;
+
+// CSI bin IDs are stored as 32-bit integers, which limits the depth to 10 levels.
+const CSI_MAX_DEPTH: i32 = 10;
+
 /// A tabix indexer.
+///
+/// An indexer builds a tabix index from the records of a bgzip-compressed, tab-delimited file.
+/// Each record is added with its reference sequence name, start and end positions, and the chunk
+/// of the compressed stream it spans, i.e., the virtual positions before and after it is written
+/// or read.
+///
+/// Records must be added in file order. As required by tabix, records must be grouped by
+/// reference sequence name and sorted by start position within each group.
+///
+/// By default, the indexer builds a tabix index ([`Self::build`]), which cannot represent
+/// positions greater than 2^29. Data on longer reference sequences can instead be indexed as a
+/// coordinate-sorted index (CSI) by creating the indexer with [`Self::csi`] and building it with
+/// [`Self::build_csi`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf::{self as bgzf, index::Chunk};
+/// use noodles_tabix as tabix;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+///
+/// let mut indexer = tabix::index::Indexer::new();
+/// indexer.set_header(tabix::index::header::Builder::bed().build());
+///
+/// for (reference_sequence_name, start, end) in &[("sq0", 8, 13), ("sq0", 21, 34), ("sq1", 5, 8)] {
+///     let start_position = writer.virtual_position()?;
+///     writeln!(writer, "{}\t{}\t{}", reference_sequence_name, start, end)?;
+///     let end_position = writer.virtual_position()?;
+///
+///     let chunk = Chunk::new(start_position, end_position);
+///     indexer.add_record(reference_sequence_name, *start, *end, chunk)?;
+/// }
+///
+/// writer.finish()?;
+/// let index = indexer.build()?;
+///
+/// assert_eq!(index.reference_sequence_names(), ["sq0", "sq1"]);
+/// # Ok::<_, io::Error>(())
+/// ```
 #[derive(Debug, Default)]
 pub struct Indexer {
     header: Header,
     current_reference_sequence_name: String,
+    current_start: i32,
     reference_sequence_names: Vec<String>,
-    reference_sequence_builders: Vec<reference_sequence::Builder>,
+    target: Target,
+}
+
+#[derive(Debug)]
+enum Target {
+    Tabix(Vec<reference_sequence::Builder>),
+    Csi {
+        indexer: csi::index::Indexer,
+        max_position: i64,
+    },
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Self::Tabix(Vec::new())
+    }
 }
 
 impl Indexer {
+    /// Creates a tabix indexer.
+    ///
+    /// This is the same as calling [`Index::indexer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// let indexer = tabix::index::Indexer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an indexer that builds a coordinate-sorted index (CSI) with the given binning
+    /// parameters.
+    ///
+    /// The largest position that can be indexed is 2^(min_shift + 3 * depth), e.g., a min shift
+    /// of 14 and a depth of 6 allows positions up to 2^32.
+    ///
+    /// This returns an invalid input error if the min shift or depth is < 1, the depth is > 10,
+    /// or the maximum position cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_tabix as tabix;
+    /// let indexer = tabix::index::Indexer::csi(14, 6)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn csi(min_shift: i32, depth: i32) -> io::Result<Self> {
+        if !(1..=62).contains(&min_shift)
+            || !(1..=CSI_MAX_DEPTH).contains(&depth)
+            || min_shift + 3 * depth > 62
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid CSI binning parameters: min_shift = {}, depth = {}",
+                    min_shift, depth
+                ),
+            ));
+        }
+
+        Ok(Self {
+            target: Target::Csi {
+                indexer: csi::index::Indexer::new(min_shift, depth),
+                max_position: 1 << (min_shift + 3 * depth),
+            },
+            ..Default::default()
+        })
+    }
+
     /// Sets an index header.
     ///
+    /// The header describes the columns of the indexed file, e.g., which columns have the
+    /// reference sequence name and positions. See [`super::header::Builder`] for presets.
+    ///
     /// # Examples
     ///
     /// ```
     /// use noodles_tabix as tabix;
-    /// let builder = tabix::Index::indexer().set_header(tabix::index::Header::default());
+    /// let mut indexer = tabix::Index::indexer();
+    /// indexer.set_header(tabix::index::header::Builder::gff().build());
     /// ```
     pub fn set_header(&mut self, header: Header) {
         self.header = header;
@@ -26,9 +151,16 @@ impl Indexer {
 
     /// Adds a record.
     ///
+    /// This returns an invalid input error if the record is out of order, i.e., its reference
+    /// sequence name was already added before a different one, or its start position is less
+    /// than the start position of the previous record on the same reference sequence. It also
+    /// returns an invalid input error if the end position is greater than the maximum position
+    /// of the target index format.
+    ///
     /// # Examples
     ///
     /// ```
+    /// # use std::io;
     /// use noodles_bgzf::{self as bgzf, index::Chunk};
     /// use noodles_tabix as tabix;
     ///
@@ -37,7 +169,8 @@ impl Indexer {
     /// indexer.add_record("sq0", 8, 13, Chunk::new(
     ///     bgzf::VirtualPosition::from(144),
     ///     bgzf::VirtualPosition::from(233),
-    /// ));
+    /// ))?;
+    /// # Ok::<_, io::Error>(())
     /// ```
     pub fn add_record(
         &mut self,
@@ -45,45 +178,317 @@ impl Indexer {
         start: i32,
         end: i32,
         chunk: Chunk,
-    ) {
-        if reference_sequence_name != self.current_reference_sequence_name {
-            self.reference_sequence_builders
-                .push(ReferenceSequence::builder());
+    ) -> io::Result<()> {
+        let max_position = match &self.target {
+            Target::Tabix(_) => i64::from(TABIX_MAX_POSITION),
+            Target::Csi { max_position, .. } => *max_position,
+        };
+
+        if i64::from(end) > max_position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "record end position exceeds the maximum position of the index: {}:{} > {}",
+                    reference_sequence_name, end, max_position
+                ),
+            ));
+        }
+
+        if reference_sequence_name != self.current_reference_sequence_name
+            || self.reference_sequence_names.is_empty()
+        {
+            if self
+                .reference_sequence_names
+                .iter()
+                .any(|name| name == reference_sequence_name)
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "records are not grouped by reference sequence name: {}",
+                        reference_sequence_name
+                    ),
+                ));
+            }
+
+            if let Target::Tabix(reference_sequence_builders) = &mut self.target {
+                reference_sequence_builders.push(ReferenceSequence::builder());
+            }
 
             self.current_reference_sequence_name = reference_sequence_name.into();
+            self.current_start = start;
 
             self.reference_sequence_names
                 .push(reference_sequence_name.into());
+        } else if start < self.current_start {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "records are not sorted by start position: {}:{} < {}",
+                    reference_sequence_name, start, self.current_start
+                ),
+            ));
         }
 
-        let reference_sequence_builder = self
-            .reference_sequence_builders
-            .last_mut()
-            .expect("reference_sequence_builders cannot be empty");
+        self.current_start = start;
+
+        match &mut self.target {
+            Target::Tabix(reference_sequence_builders) => {
+                let reference_sequence_builder = reference_sequence_builders
+                    .last_mut()
+                    .expect("reference_sequence_builders cannot be empty");
+
+                reference_sequence_builder.add_record(start, end, chunk);
+            }
+            Target::Csi { indexer, .. } => {
+                let reference_sequence_id = self.reference_sequence_names.len() - 1;
+                indexer.add_record(
+                    reference_sequence_id,
+                    i64::from(start),
+                    i64::from(end),
+                    chunk,
+                );
+            }
+        }
 
-        reference_sequence_builder.add_record(start, end, chunk);
+        Ok(())
     }
 
     /// Builds a tabix index.
     ///
+    /// This returns an invalid input error if the indexer was created to build a CSI (see
+    /// [`Self::csi`]).
+    ///
     /// # Examples
     ///
     /// ```
+    /// # use std::io;
     /// use noodles_tabix as tabix;
     /// let indexer = tabix::Index::indexer();
-    /// let index = indexer.build();
+    /// let index = indexer.build()?;
+    /// # Ok::<_, io::Error>(())
     /// ```
-    pub fn build(self) -> Index {
-        let reference_sequences = self
-            .reference_sequence_builders
+    pub fn build(self) -> io::Result<Index> {
+        let reference_sequence_builders = match self.target {
+            Target::Tabix(reference_sequence_builders) => reference_sequence_builders,
+            Target::Csi { .. } => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "indexer targets a CSI",
+                ))
+            }
+        };
+
+        let reference_sequences = reference_sequence_builders
             .into_iter()
             .map(|b| b.build())
             .collect();
 
-        Index::builder()
+        Ok(Index::builder()
             .set_header(self.header)
             .set_reference_sequence_names(self.reference_sequence_names)
             .set_reference_sequences(reference_sequences)
-            .build()
+            .build())
+    }
+
+    /// Builds a coordinate-sorted index (CSI).
+    ///
+    /// The tabix header and reference sequence names are stored in the auxiliary data, as done by
+    /// `tabix --csi`.
+    ///
+    /// This returns an invalid input error if the indexer was not created with [`Self::csi`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{self as bgzf, index::Chunk};
+    /// use noodles_tabix as tabix;
+    ///
+    /// let mut indexer = tabix::index::Indexer::csi(14, 6)?;
+    ///
+    /// indexer.add_record("sq0", 1 << 30, (1 << 30) + 5, Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(21),
+    /// ))?;
+    ///
+    /// let index = indexer.build_csi()?;
+    /// assert_eq!(index.depth(), 6);
+    /// assert_eq!(index.reference_sequences().len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_csi(self) -> io::Result<csi::Index> {
+        let indexer = match self.target {
+            Target::Csi { indexer, .. } => indexer,
+            Target::Tabix(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "indexer targets a tabix index",
+                ))
+            }
+        };
+
+        let mut aux = Vec::new();
+        crate::writer::write_aux(&mut aux, &self.header, &self.reference_sequence_names)?;
+
+        let index = indexer.build();
+
+        let mut builder = csi::Index::builder()
+            .set_min_shift(index.min_shift())
+            .set_depth(index.depth())
+            .set_aux(aux)
+            .set_reference_sequences(index.reference_sequences().to_vec());
+
+        if let Some(n) = index.unmapped_read_count() {
+            builder = builder.set_n_no_coor(n);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    #[test]
+    fn test_add_record() -> io::Result<()> {
+        let mut indexer = Indexer::new();
+
+        indexer.add_record("sq0", 8, 13, build_chunk(0, 21))?;
+        indexer.add_record("sq0", 8, 21, build_chunk(21, 34))?;
+        indexer.add_record("sq1", 5, 8, build_chunk(34, 55))?;
+
+        let index = indexer.build()?;
+        assert_eq!(index.reference_sequence_names(), ["sq0", "sq1"]);
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_empty_reference_sequence_name() -> io::Result<()> {
+        let mut indexer = Indexer::new();
+        indexer.add_record("", 8, 13, build_chunk(0, 21))?;
+
+        let index = indexer.build()?;
+        assert_eq!(index.reference_sequence_names(), [""]);
+        assert_eq!(index.reference_sequences().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_unsorted_records() -> io::Result<()> {
+        let mut indexer = Indexer::new();
+        indexer.add_record("sq0", 8, 13, build_chunk(0, 21))?;
+        indexer.add_record("sq1", 5, 8, build_chunk(21, 34))?;
+
+        assert!(matches!(
+            indexer.add_record("sq0", 21, 34, build_chunk(34, 55)),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut indexer = Indexer::new();
+        indexer.add_record("sq0", 8, 13, build_chunk(0, 21))?;
+
+        assert!(matches!(
+            indexer.add_record("sq0", 5, 8, build_chunk(21, 34)),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_position_exceeding_max_position() -> io::Result<()> {
+        let mut indexer = Indexer::new();
+
+        assert!(matches!(
+            indexer.add_record("sq0", 8, (1 << 29) + 1, build_chunk(0, 21)),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut indexer = Indexer::csi(14, 6)?;
+        indexer.add_record("sq0", 8, (1 << 29) + 1, build_chunk(0, 21))?;
+
+        assert!(matches!(
+            indexer.add_record("sq0", 8, i32::MAX, build_chunk(21, 34)),
+            Ok(())
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_start_0() -> io::Result<()> {
+        let mut indexer = Indexer::new();
+        indexer.add_record("sq0", 0, 0, build_chunk(0, 21))?;
+        indexer.build()?;
+
+        let mut indexer = Indexer::csi(14, 5)?;
+        indexer.add_record("sq0", 0, 0, build_chunk(0, 21))?;
+        indexer.build_csi()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csi_with_invalid_binning() {
+        assert!(Indexer::csi(0, 5).is_err());
+        assert!(Indexer::csi(14, 0).is_err());
+        assert!(Indexer::csi(14, 11).is_err());
+        assert!(Indexer::csi(60, 1).is_err());
+        assert!(Indexer::csi(i32::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn test_build_with_mismatched_target() -> io::Result<()> {
+        assert!(Indexer::new().build_csi().is_err());
+        assert!(Indexer::csi(14, 5)?.build().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_csi() -> io::Result<()> {
+        let mut indexer = Indexer::csi(14, 6)?;
+        indexer.set_header(crate::index::header::Builder::vcf().build());
+
+        indexer.add_record("sq0", 8, 13, build_chunk(0, 21))?;
+        indexer.add_record("sq1", 1 << 30, (1 << 30) + 1, build_chunk(21, 34))?;
+
+        let index = indexer.build_csi()?;
+
+        assert_eq!(index.min_shift(), 14);
+        assert_eq!(index.depth(), 6);
+        assert_eq!(index.reference_sequences().len(), 2);
+        assert_eq!(
+            index.reference_sequences()[1]
+                .query(14, 6, 1 << 30, (1 << 30) + 1)
+                .len(),
+            1
+        );
+
+        let mut expected_aux = Vec::new();
+        expected_aux.extend_from_slice(&2i32.to_le_bytes()); // format
+        expected_aux.extend_from_slice(&1i32.to_le_bytes()); // col_seq
+        expected_aux.extend_from_slice(&2i32.to_le_bytes()); // col_beg
+        expected_aux.extend_from_slice(&0i32.to_le_bytes()); // col_end
+        expected_aux.extend_from_slice(&i32::from(b'#').to_le_bytes()); // meta
+        expected_aux.extend_from_slice(&0i32.to_le_bytes()); // skip
+        expected_aux.extend_from_slice(&8i32.to_le_bytes()); // l_nm
+        expected_aux.extend_from_slice(b"sq0\x00sq1\x00"); // names
+        assert_eq!(index.aux(), expected_aux);
+
+        Ok(())
     }
 }
@@ -0,0 +1,249 @@
+use std::{cmp, convert::TryFrom, io};
+
+use noodles_bgzf::{self as bgzf, index::Chunk};
+
+use super::{
+    reference_sequence::{Bin, Metadata},
+    Index, ReferenceSequence,
+};
+
+#[derive(Default)]
+struct ReferenceSequenceBuilder {
+    bins: Vec<Bin>,
+    intervals: Vec<Option<bgzf::VirtualPosition>>,
+    metadata: Option<Metadata>,
+}
+
+impl ReferenceSequenceBuilder {
+    fn add_reference_sequence(
+        &mut self,
+        reference_sequence: &ReferenceSequence,
+        offset: u64,
+    ) -> io::Result<()> {
+        for bin in reference_sequence.bins() {
+            let chunks = bin
+                .chunks()
+                .iter()
+                .map(|chunk| shift_chunk(*chunk, offset))
+                .collect::<io::Result<Vec<_>>>()?;
+
+            match self.bins.iter_mut().find(|b| b.id() == bin.id()) {
+                Some(b) => {
+                    let mut merged_chunks = b.chunks().to_vec();
+                    merged_chunks.extend(chunks);
+                    *b = Bin::new(bin.id(), merged_chunks);
+                }
+                None => self.bins.push(Bin::new(bin.id(), chunks)),
+            }
+        }
+
+        let intervals = reference_sequence.intervals();
+
+        if intervals.len() > self.intervals.len() {
+            self.intervals.resize(intervals.len(), None);
+        }
+
+        for (merged_interval, interval) in self.intervals.iter_mut().zip(intervals) {
+            // Shards are in file order, so the first shard that covers the window has the
+            // smallest position. A window without records is stored as 0, which shifts to the
+            // start of its shard and remains a valid lower bound.
+            if merged_interval.is_none() {
+                *merged_interval = Some(shift_virtual_position(*interval, offset)?);
+            }
+        }
+
+        if let Some(metadata) = reference_sequence.metadata() {
+            let start_position = shift_virtual_position(metadata.start_position(), offset)?;
+            let end_position = shift_virtual_position(metadata.end_position(), offset)?;
+
+            let merged_metadata = match self.metadata.take() {
+                Some(m) => Metadata::new(
+                    cmp::min(m.start_position(), start_position),
+                    cmp::max(m.end_position(), end_position),
+                    m.mapped_record_count() + metadata.mapped_record_count(),
+                    m.unmapped_record_count() + metadata.unmapped_record_count(),
+                ),
+                None => Metadata::new(
+                    start_position,
+                    end_position,
+                    metadata.mapped_record_count(),
+                    metadata.unmapped_record_count(),
+                ),
+            };
+
+            self.metadata = Some(merged_metadata);
+        }
+
+        Ok(())
+    }
+
+    fn build(self) -> ReferenceSequence {
+        let intervals = self
+            .intervals
+            .into_iter()
+            .map(|p| p.unwrap_or_default())
+            .collect();
+
+        ReferenceSequence::new(self.bins, intervals, self.metadata)
+    }
+}
+
+pub(crate) fn merge(indexes: &[Index], compressed_sizes: &[u64]) -> io::Result<Index> {
+    if indexes.len() != compressed_sizes.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "index count mismatch: expected {} compressed sizes, got {}",
+                indexes.len(),
+                compressed_sizes.len()
+            ),
+        ));
+    }
+
+    let header = indexes
+        .first()
+        .map(|index| index.header().clone())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no indexes to merge"))?;
+
+    let mut reference_sequence_names: Vec<String> = Vec::new();
+    let mut builders: Vec<ReferenceSequenceBuilder> = Vec::new();
+    let mut unmapped_read_count = None;
+
+    let mut offset = 0;
+
+    for (index, compressed_size) in indexes.iter().zip(compressed_sizes) {
+        if index.header() != &header {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "index headers do not match",
+            ));
+        }
+
+        for (name, reference_sequence) in index
+            .reference_sequence_names()
+            .iter()
+            .zip(index.reference_sequences())
+        {
+            let i = match reference_sequence_names.iter().position(|n| n == name) {
+                Some(i) => i,
+                None => {
+                    reference_sequence_names.push(name.clone());
+                    builders.push(ReferenceSequenceBuilder::default());
+                    builders.len() - 1
+                }
+            };
+
+            builders[i].add_reference_sequence(reference_sequence, offset)?;
+        }
+
+        if let Some(n) = index.unmapped_read_count() {
+            unmapped_read_count = Some(unmapped_read_count.unwrap_or(0) + n);
+        }
+
+        offset = offset.checked_add(*compressed_size).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "compressed size overflow")
+        })?;
+    }
+
+    let reference_sequences = builders.into_iter().map(|b| b.build()).collect();
+
+    let mut builder = Index::builder()
+        .set_header(header)
+        .set_reference_sequence_names(reference_sequence_names)
+        .set_reference_sequences(reference_sequences);
+
+    if let Some(n) = unmapped_read_count {
+        builder = builder.set_unmapped_read_count(n);
+    }
+
+    Ok(builder.build())
+}
+
+fn shift_virtual_position(
+    virtual_position: bgzf::VirtualPosition,
+    offset: u64,
+) -> io::Result<bgzf::VirtualPosition> {
+    let compressed_position = virtual_position
+        .compressed()
+        .checked_add(offset)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "compressed size overflow"))?;
+
+    bgzf::VirtualPosition::try_from((compressed_position, virtual_position.uncompressed()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+fn shift_chunk(chunk: Chunk, offset: u64) -> io::Result<Chunk> {
+    let start = shift_virtual_position(chunk.start(), offset)?;
+    let end = shift_virtual_position(chunk.end(), offset)?;
+    Ok(Chunk::new(start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_chunk(start: (u64, u16), end: (u64, u16)) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::try_from(start).unwrap(),
+            bgzf::VirtualPosition::try_from(end).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        let mut indexer = Index::indexer();
+        indexer.add_record("sq0", 8, 13, build_chunk((0, 0), (0, 21)))?;
+        indexer.add_record("sq1", 8, 13, build_chunk((0, 21), (0, 42)))?;
+        let index0 = indexer.build()?;
+
+        let mut indexer = Index::indexer();
+        indexer.add_record("sq1", 34, 55, build_chunk((0, 0), (0, 21)))?;
+        indexer.add_record("sq2", 8, 13, build_chunk((0, 21), (0, 42)))?;
+        let index1 = indexer.build()?;
+
+        let index = merge(&[index0, index1], &[89, 144])?;
+
+        assert_eq!(index.reference_sequence_names(), ["sq0", "sq1", "sq2"]);
+
+        let reference_sequence = &index.reference_sequences()[1];
+        let bin = &reference_sequence.bins()[0];
+        assert_eq!(
+            bin.chunks(),
+            [
+                build_chunk((0, 21), (0, 42)),
+                build_chunk((89, 0), (89, 21)),
+            ]
+        );
+        assert_eq!(
+            reference_sequence.intervals(),
+            [bgzf::VirtualPosition::try_from((0, 21)).unwrap()]
+        );
+
+        let metadata = reference_sequence.metadata().expect("missing metadata");
+        assert_eq!(metadata.mapped_record_count(), 2);
+        assert_eq!(
+            metadata.end_position(),
+            bgzf::VirtualPosition::try_from((89, 21)).unwrap()
+        );
+
+        let reference_sequence = &index.reference_sequences()[2];
+        assert_eq!(
+            reference_sequence.intervals(),
+            [bgzf::VirtualPosition::try_from((89, 21)).unwrap()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_invalid_input() {
+        assert!(merge(&[], &[]).is_err());
+        assert!(merge(&[Index::default()], &[]).is_err());
+
+        let index0 = Index::default();
+        let index1 = Index::builder()
+            .set_header(crate::index::header::Builder::vcf().build())
+            .build();
+        assert!(merge(&[index0, index1], &[0, 0]).is_err());
+    }
+}
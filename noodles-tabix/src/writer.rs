@@ -85,20 +85,11 @@ where
         let n_ref = index.reference_sequences().len() as i32;
         self.inner.write_i32::<LittleEndian>(n_ref)?;
 
-        write_header(&mut self.inner, index.header())?;
-
-        // Add 1 for each trailing nul.
-        let l_nm = index
-            .reference_sequence_names()
-            .iter()
-            .map(|n| n.len() + 1)
-            .sum::<usize>() as i32;
-        self.inner.write_i32::<LittleEndian>(l_nm)?;
-
-        for reference_sequence_name in index.reference_sequence_names() {
-            self.inner.write_all(reference_sequence_name.as_bytes())?;
-            self.inner.write_u8(NUL)?;
-        }
+        write_aux(
+            &mut self.inner,
+            index.header(),
+            index.reference_sequence_names(),
+        )?;
 
         for reference_sequence in index.reference_sequences() {
             write_reference_sequence(&mut self.inner, reference_sequence)?;
@@ -119,6 +110,34 @@ where
     writer.write_all(MAGIC_NUMBER)
 }
 
+// Writes the header and reference sequence names.
+//
+// This is also used as the auxiliary data of a CSI built from a tabix indexer.
+pub(crate) fn write_aux<W>(
+    writer: &mut W,
+    header: &index::Header,
+    reference_sequence_names: &[String],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    write_header(writer, header)?;
+
+    // Add 1 for each trailing nul.
+    let l_nm = reference_sequence_names
+        .iter()
+        .map(|n| n.len() + 1)
+        .sum::<usize>() as i32;
+    writer.write_i32::<LittleEndian>(l_nm)?;
+
+    for reference_sequence_name in reference_sequence_names {
+        writer.write_all(reference_sequence_name.as_bytes())?;
+        writer.write_u8(NUL)?;
+    }
+
+    Ok(())
+}
+
 fn write_header<W>(writer: &mut W, header: &index::Header) -> io::Result<()>
 where
     W: Write,
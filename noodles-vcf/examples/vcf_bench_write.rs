@@ -0,0 +1,63 @@
+//! Benchmarks writing VCF records.
+//!
+//! This writes a number of synthetic multi-sample records (default: 1000000) to a sink, once with
+//! the VCF writer and once by formatting each record using its `Display` implementation, and
+//! prints the elapsed time and throughput of each.
+
+use std::{
+    env,
+    io::{self, BufWriter, Write},
+    time::{Duration, Instant},
+};
+
+use noodles_vcf as vcf;
+
+const SAMPLE_COUNT: usize = 16;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let n: u64 = match env::args().nth(1) {
+        Some(s) => s.parse()?,
+        None => 1_000_000,
+    };
+
+    let mut s =
+        String::from("sq0\t8\trs13\tACGT\tA,ACGTT\t42.5\tPASS\tNS=16;AF=0.25,0.5\tGT:GQ:DP");
+
+    for _ in 0..SAMPLE_COUNT {
+        s.push_str("\t0|1:13:21");
+    }
+
+    let record: vcf::Record = s.parse()?;
+
+    let mut writer = vcf::Writer::new(BufWriter::new(io::sink()));
+
+    let start = Instant::now();
+
+    for _ in 0..n {
+        writer.write_record(&record)?;
+    }
+
+    print_throughput("vcf::Writer", n, start.elapsed());
+
+    let mut writer = BufWriter::new(io::sink());
+
+    let start = Instant::now();
+
+    for _ in 0..n {
+        writeln!(writer, "{}", record)?;
+    }
+
+    print_throughput("Display", n, start.elapsed());
+
+    Ok(())
+}
+
+fn print_throughput(name: &str, n: u64, elapsed: Duration) {
+    println!(
+        "{}: wrote {} records in {:?} ({:.0} records/s)",
+        name,
+        n,
+        elapsed,
+        n as f64 / elapsed.as_secs_f64()
+    );
+}
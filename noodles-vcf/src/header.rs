@@ -21,8 +21,9 @@ pub use self::{
 };
 
 use std::{
+    collections::{HashMap, HashSet},
     convert::TryFrom,
-    error,
+    error, mem,
     str::{FromStr, Lines},
 };
 
@@ -59,7 +60,7 @@ pub type Pedigrees = IndexMap<String, Pedigree>;
 pub type SampleNames = IndexSet<String>;
 
 /// A VCF header.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq)]
 pub struct Header {
     file_format: FileFormat,
     infos: Infos,
@@ -74,6 +75,29 @@ pub struct Header {
     pedigree_db: Option<String>,
     sample_names: SampleNames,
     map: IndexMap<String, Vec<Record>>,
+    raw_lines: Vec<String>,
+    entries: Vec<Entry>,
+}
+
+// A reference to a header record, used to preserve the order in which records were added.
+//
+// Entries are only a formatting hint. A record that is referenced by an entry but no longer
+// exists is skipped, and records without an entry (e.g., added through a mutable accessor) are
+// written after the referenced records.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Entry {
+    Info(crate::record::info::field::Key),
+    Filter(String),
+    Format(crate::record::genotype::field::Key),
+    AlternativeAllele(crate::record::alternate_bases::allele::Symbol),
+    Assembly,
+    Contig(String),
+    Meta(String),
+    Sample(String),
+    Pedigree(String),
+    PedigreeDb,
+    Other(String, usize),
+    Raw(usize),
 }
 
 impl Header {
@@ -105,7 +129,22 @@ impl Header {
     /// assert_eq!(header.file_format(), FileFormat::default());
     /// ```
     pub fn file_format(&self) -> FileFormat {
-        self.file_format.clone()
+        self.file_format
+    }
+
+    /// Returns a mutable reference to the file format (`fileformat`) of the VCF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::FileFormat};
+    ///
+    /// let mut header = vcf::Header::default();
+    /// *header.file_format_mut() = FileFormat::new(4, 2);
+    /// assert_eq!(header.file_format(), FileFormat::new(4, 2));
+    /// ```
+    pub fn file_format_mut(&mut self) -> &mut FileFormat {
+        &mut self.file_format
     }
 
     /// Returns a map of information records (`INFO`).
@@ -127,6 +166,27 @@ impl Header {
         &self.infos
     }
 
+    /// Returns the information record (`INFO`) with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Info, record::info::field::Key};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::from(Key::AlleleFrequencies))
+    ///     .build();
+    ///
+    /// assert_eq!(header.get_info("AF").map(|info| info.id()), Some(&Key::AlleleFrequencies));
+    /// assert!(header.get_info("NS").is_none());
+    /// ```
+    pub fn get_info(&self, id: &str) -> Option<&Info> {
+        id.parse::<crate::record::info::field::Key>()
+            .ok()
+            .and_then(|key| self.infos.get(&key))
+            .or_else(|| self.infos.values().find(|info| info.id().as_ref() == id))
+    }
+
     /// Returns a map of filter records (`FILTER`).
     ///
     /// # Examples
@@ -149,6 +209,27 @@ impl Header {
         &self.filters
     }
 
+    /// Returns the filter record (`FILTER`) with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Filter};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_filter(Filter::new(
+    ///         String::from("q10"),
+    ///         String::from("Quality below 10"),
+    ///     ))
+    ///     .build();
+    ///
+    /// assert_eq!(header.get_filter("q10").map(|filter| filter.id()), Some("q10"));
+    /// assert!(header.get_filter("s50").is_none());
+    /// ```
+    pub fn get_filter(&self, id: &str) -> Option<&Filter> {
+        self.filters.get(id)
+    }
+
     /// Returns a list of genotype format records (`FORMAT`).
     ///
     /// # Examples
@@ -168,6 +249,76 @@ impl Header {
         &self.formats
     }
 
+    /// Returns the genotype format record (`FORMAT`) with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Format, record::genotype::field::Key};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(Format::from(Key::Genotype))
+    ///     .build();
+    ///
+    /// assert_eq!(header.get_format("GT").map(|format| format.id()), Some(&Key::Genotype));
+    /// assert!(header.get_format("GQ").is_none());
+    /// ```
+    pub fn get_format(&self, id: &str) -> Option<&Format> {
+        id.parse::<crate::record::genotype::field::Key>()
+            .ok()
+            .and_then(|key| self.formats.get(&key))
+            .or_else(|| {
+                self.formats
+                    .values()
+                    .find(|format| format.id().as_ref() == id)
+            })
+    }
+
+    /// Returns the IDs and indices (`IDX`) of the filter (`FILTER`), information (`INFO`), and
+    /// genotype format (`FORMAT`) records, in the order they are written.
+    ///
+    /// These are the strings of a BCF dictionary of strings. A reader of the written header
+    /// assigns positions to strings without an index in this order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{Filter, Format, Info},
+    ///     record::{genotype, info},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(Info::from(info::field::Key::SamplesWithDataCount))
+    ///     .add_format(Format::from(genotype::field::Key::Genotype))
+    ///     .add_filter(Filter::new(String::from("q10"), String::from("Quality below 10")))
+    ///     .build();
+    ///
+    /// assert_eq!(header.string_ids(), [("NS", None), ("GT", None), ("q10", None)]);
+    /// ```
+    pub fn string_ids(&self) -> Vec<(&str, Option<usize>)> {
+        let mut visited_entries = HashSet::new();
+        let mut ids = Vec::new();
+
+        for entry in self.entries.iter().cloned().chain(self.default_entries()) {
+            let id = match &entry {
+                Entry::Filter(id) => self.filters.get(id).map(|f| (f.id(), f.idx())),
+                Entry::Info(id) => self.infos.get(id).map(|i| (i.id().as_ref(), i.idx())),
+                Entry::Format(id) => self.formats.get(id).map(|f| (f.id().as_ref(), f.idx())),
+                _ => None,
+            };
+
+            if let Some(id) = id {
+                if visited_entries.insert(entry) {
+                    ids.push(id);
+                }
+            }
+        }
+
+        ids
+    }
+
     /// Returns a map of symbolic alternate alleles (`ALT`).
     ///
     /// # Examples
@@ -236,6 +387,45 @@ impl Header {
         &self.contigs
     }
 
+    /// Returns a mutable reference to a map of contig records (`contig`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Contig};
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// header
+    ///     .contigs_mut()
+    ///     .insert(String::from("sq0"), Contig::new(String::from("sq0")));
+    ///
+    /// let contigs = header.contigs();
+    /// assert_eq!(contigs.len(), 1);
+    /// assert_eq!(contigs[0], Contig::new(String::from("sq0")));
+    /// ```
+    pub fn contigs_mut(&mut self) -> &mut Contigs {
+        &mut self.contigs
+    }
+
+    /// Returns the contig record (`contig`) with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Contig};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig(Contig::new(String::from("sq0")))
+    ///     .build();
+    ///
+    /// assert_eq!(header.get_contig("sq0"), Some(&Contig::new(String::from("sq0"))));
+    /// assert!(header.get_contig("sq1").is_none());
+    /// ```
+    pub fn get_contig(&self, id: &str) -> Option<&Contig> {
+        self.contigs.get(id)
+    }
+
     /// Returns a map of meta records (`META`).
     ///
     /// # Examples
@@ -348,10 +538,42 @@ impl Header {
         &self.sample_names
     }
 
+    /// Returns the index of the sample with the given name.
+    ///
+    /// The index is the position of the sample's genotype in a record's genotypes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// assert_eq!(header.sample_index("sample1"), Some(1));
+    /// assert!(header.sample_index("sample2").is_none());
+    ///
+    /// let record: vcf::Record = "sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0\t0|1".parse()?;
+    ///
+    /// let genotype = header
+    ///     .sample_index("sample1")
+    ///     .and_then(|i| record.genotypes().get(i))
+    ///     .map(|genotype| genotype.to_string());
+    ///
+    /// assert_eq!(genotype, Some(String::from("0|1")));
+    /// # Ok::<(), vcf::record::ParseError>(())
+    /// ```
+    pub fn sample_index(&self, name: &str) -> Option<usize> {
+        self.sample_names.get_index_of(name)
+    }
+
     /// Returns a header record with the given key.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
-    /// `assembly`, `contig`, `META`, `SAMPLE`, and `pedigreeDB`.
+    /// `assembly`, `contig`, `META`, `SAMPLE`, and `pedigreeDB`. When parsed leniently (see
+    /// [`Self::from_str_lenient`]), this also includes invalid records of any key.
     ///
     /// # Examples
     ///
@@ -372,6 +594,45 @@ impl Header {
         self.map.get(key).map(|r| &**r)
     }
 
+    /// Returns the lines that are not header records.
+    ///
+    /// When parsed leniently (see [`Self::from_str_lenient`]), lines between the file format and
+    /// the header (`#CHROM`...) that do not have the form `##key=value` are kept here verbatim and
+    /// written back unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let s = "##fileformat=VCFv4.3\n##noodles\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+    /// let (header, _) = vcf::Header::from_str_lenient(s)?;
+    ///
+    /// assert_eq!(header.raw_lines(), [String::from("##noodles")]);
+    /// # Ok::<(), vcf::header::ParseError>(())
+    /// ```
+    pub fn raw_lines(&self) -> &[String] {
+        &self.raw_lines
+    }
+
+    // Replaces the contigs, renaming contig entries with the given names, so that the renamed
+    // contigs keep their position when formatting.
+    pub(crate) fn replace_contigs(&mut self, contigs: Contigs, names: &HashMap<String, String>) {
+        for entry in &mut self.entries {
+            if let Entry::Contig(id) = entry {
+                if let Some(name) = names.get(id) {
+                    *id = name.clone();
+                }
+            }
+        }
+
+        self.contigs = contigs;
+    }
+
+    pub(crate) fn other_records(&self) -> impl Iterator<Item = &Record> {
+        self.map.values().flatten()
+    }
+
     /// Inserts a key-value pair representing an unstructured record into the header.
     ///
     /// # Examples
@@ -394,8 +655,9 @@ impl Header {
     /// ```
     pub fn insert(&mut self, record: Record) {
         let key = record.key().to_string();
-        let records = self.map.entry(key).or_default();
+        let records = self.map.entry(key.clone()).or_default();
         records.push(record);
+        self.entries.push(Entry::Other(key, records.len() - 1));
     }
 }
 
@@ -405,6 +667,131 @@ impl Default for Header {
     }
 }
 
+impl PartialEq for Header {
+    // The record order is not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.file_format == other.file_format
+            && self.infos == other.infos
+            && self.filters == other.filters
+            && self.formats == other.formats
+            && self.alternative_alleles == other.alternative_alleles
+            && self.assembly == other.assembly
+            && self.contigs == other.contigs
+            && self.meta == other.meta
+            && self.samples == other.samples
+            && self.pedigrees == other.pedigrees
+            && self.pedigree_db == other.pedigree_db
+            && self.sample_names == other.sample_names
+            && self.map == other.map
+            && self.raw_lines == other.raw_lines
+    }
+}
+
+impl Header {
+    // Returns references to all records, grouped by kind.
+    fn default_entries(&self) -> impl Iterator<Item = Entry> + '_ {
+        self.infos
+            .keys()
+            .cloned()
+            .map(Entry::Info)
+            .chain(self.filters.keys().cloned().map(Entry::Filter))
+            .chain(self.formats.keys().cloned().map(Entry::Format))
+            .chain(
+                self.alternative_alleles
+                    .keys()
+                    .cloned()
+                    .map(Entry::AlternativeAllele),
+            )
+            .chain(self.assembly.iter().map(|_| Entry::Assembly))
+            .chain(self.contigs.keys().cloned().map(Entry::Contig))
+            .chain(self.meta.keys().cloned().map(Entry::Meta))
+            .chain(self.samples.keys().cloned().map(Entry::Sample))
+            .chain(self.pedigrees.keys().cloned().map(Entry::Pedigree))
+            .chain(self.pedigree_db.iter().map(|_| Entry::PedigreeDb))
+            .chain(self.map.iter().flat_map(|(key, records)| {
+                (0..records.len()).map(move |i| Entry::Other(key.clone(), i))
+            }))
+            .chain((0..self.raw_lines.len()).map(Entry::Raw))
+    }
+
+    // Writes the record referenced by the entry, if it exists. This returns whether a record was
+    // written.
+    fn fmt_entry(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        entry: &Entry,
+    ) -> Result<bool, std::fmt::Error> {
+        match entry {
+            Entry::Info(id) => write_record(f, self.infos.get(id)),
+            Entry::Filter(id) => write_record(f, self.filters.get(id)),
+            Entry::Format(id) => write_record(f, self.formats.get(id)),
+            Entry::AlternativeAllele(id) => write_record(f, self.alternative_alleles.get(id)),
+            Entry::Assembly => match self.assembly() {
+                Some(assembly) => {
+                    writeln!(
+                        f,
+                        "{}{}={}",
+                        record::PREFIX,
+                        record::Key::Assembly,
+                        assembly
+                    )?;
+
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+            Entry::Contig(id) => write_record(f, self.contigs.get(id)),
+            Entry::Meta(id) => write_record(f, self.meta.get(id)),
+            Entry::Sample(id) => write_record(f, self.samples.get(id)),
+            Entry::Pedigree(id) => write_record(f, self.pedigrees.get(id)),
+            Entry::PedigreeDb => match self.pedigree_db() {
+                Some(pedigree_db) => {
+                    writeln!(
+                        f,
+                        "{}{}={}",
+                        record::PREFIX,
+                        record::Key::PedigreeDb,
+                        pedigree_db
+                    )?;
+
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+            Entry::Other(key, i) => match self.map.get(key).and_then(|records| records.get(*i)) {
+                Some(record) => {
+                    writeln!(f, "{}{}={}", record::PREFIX, record.key(), record.value())?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+            Entry::Raw(i) => match self.raw_lines.get(*i) {
+                Some(line) => {
+                    writeln!(f, "{}", line)?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+        }
+    }
+}
+
+fn write_record<T>(
+    f: &mut std::fmt::Formatter<'_>,
+    record: Option<&T>,
+) -> Result<bool, std::fmt::Error>
+where
+    T: std::fmt::Display,
+{
+    match record {
+        Some(record) => {
+            writeln!(f, "{}", record)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
 impl std::fmt::Display for Header {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(
@@ -415,61 +802,17 @@ impl std::fmt::Display for Header {
             self.file_format()
         )?;
 
-        for info in self.infos().values() {
-            writeln!(f, "{}", info)?;
-        }
-
-        for filter in self.filters().values() {
-            writeln!(f, "{}", filter)?;
-        }
-
-        for format in self.formats().values() {
-            writeln!(f, "{}", format)?;
-        }
+        let mut written_entries = HashSet::new();
 
-        for alternative_allele in self.alternative_alleles().values() {
-            writeln!(f, "{}", alternative_allele)?;
-        }
-
-        if let Some(assembly) = self.assembly() {
-            writeln!(
-                f,
-                "{}{}={}",
-                record::PREFIX,
-                record::Key::Assembly,
-                assembly
-            )?;
-        }
-
-        for contig in self.contigs().values() {
-            writeln!(f, "{}", contig)?;
-        }
-
-        for meta in self.meta().values() {
-            writeln!(f, "{}", meta)?;
-        }
-
-        for sample in self.samples().values() {
-            writeln!(f, "{}", sample)?;
-        }
-
-        for pedigree in self.pedigrees().values() {
-            writeln!(f, "{}", pedigree)?;
-        }
-
-        if let Some(pedigree_db) = self.pedigree_db() {
-            writeln!(
-                f,
-                "{}{}={}",
-                record::PREFIX,
-                record::Key::PedigreeDb,
-                pedigree_db
-            )?;
+        for entry in &self.entries {
+            if !written_entries.contains(entry) && self.fmt_entry(f, entry)? {
+                written_entries.insert(entry.clone());
+            }
         }
 
-        for records in self.map.values() {
-            for record in records {
-                writeln!(f, "{}{}={}", record::PREFIX, record.key(), record.value())?;
+        for entry in self.default_entries() {
+            if !written_entries.contains(&entry) {
+                self.fmt_entry(f, &entry)?;
             }
         }
 
@@ -566,34 +909,85 @@ impl FromStr for Header {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut builder = Self::builder();
-        let mut lines = s.lines();
+        parse(s, false).map(|(header, _)| header)
+    }
+}
 
-        let file_format = parse_file_format(&mut lines)?;
-        builder = builder.set_file_format(file_format);
+impl Header {
+    /// Parses a raw VCF header, preserving records that fail to parse.
+    ///
+    /// Unlike [`FromStr`], which is strict, this preserves invalid records verbatim as unstructured
+    /// records (see [`Self::get`]) and returns the errors that would have otherwise failed parsing
+    /// as warnings. Lines that are not header records, i.e., do not have the form `##key=value`,
+    /// are preserved verbatim as raw lines (see [`Self::raw_lines`]).
+    ///
+    /// The file format, header (`#CHROM`...), and sample names are still strictly validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let s = concat!(
+    ///     "##fileformat=VCFv4.3\n",
+    ///     "##INFO=<ID=NS,Number=Z,Type=Integer,Description=\"Number of samples with data\">\n",
+    ///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+    /// );
+    ///
+    /// assert!(s.parse::<vcf::Header>().is_err());
+    ///
+    /// let (header, warnings) = vcf::Header::from_str_lenient(s)?;
+    /// assert!(header.infos().is_empty());
+    /// assert_eq!(header.get("INFO").map(|records| records.len()), Some(1));
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(header.to_string(), s);
+    /// # Ok::<(), vcf::header::ParseError>(())
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<(Self, Vec<ParseError>), ParseError> {
+        parse(s, true)
+    }
+}
 
-        let mut has_header = false;
+fn parse(s: &str, is_lenient: bool) -> Result<(Header, Vec<ParseError>), ParseError> {
+    let mut builder = Header::builder();
+    let mut lines = s.lines();
+    let mut warnings = Vec::new();
 
-        while let Some(line) = lines.next() {
-            if line.starts_with("#CHROM") {
-                builder = parse_header(builder, line)?;
-                has_header = true;
-                break;
-            }
+    let file_format = parse_file_format(&mut lines)?;
+    builder = builder.set_file_format(file_format);
 
-            builder = parse_record(builder, line)?;
-        }
+    let mut has_header = false;
 
-        if !has_header {
-            return Err(ParseError::MissingHeader);
+    while let Some(line) = lines.next() {
+        if line.starts_with("#CHROM") {
+            builder = parse_header(builder, line)?;
+            has_header = true;
+            break;
         }
 
-        if lines.next().is_some() {
-            return Err(ParseError::ExpectedEof);
+        if let Err(e) = parse_record(&mut builder, line) {
+            if !is_lenient {
+                return Err(e);
+            }
+
+            builder = match parse_raw_record(line) {
+                Some(record) => builder.insert(record),
+                None => builder.add_raw_line(line),
+            };
+
+            warnings.push(e);
         }
+    }
 
-        Ok(builder.build())
+    if !has_header {
+        return Err(ParseError::MissingHeader);
     }
+
+    if lines.next().is_some() {
+        return Err(ParseError::ExpectedEof);
+    }
+
+    Ok((builder.build(), warnings))
 }
 
 fn parse_file_format(lines: &mut Lines<'_>) -> Result<FileFormat, ParseError> {
@@ -612,58 +1006,71 @@ fn parse_file_format(lines: &mut Lines<'_>) -> Result<FileFormat, ParseError> {
     }
 }
 
-fn parse_record(mut builder: Builder, line: &str) -> Result<Builder, ParseError> {
+// The builder is only modified if the record is valid.
+fn parse_record(builder: &mut Builder, line: &str) -> Result<(), ParseError> {
     let record: Record = line.parse().map_err(ParseError::InvalidRecord)?;
 
-    builder = match record.key() {
+    match record.key() {
         record::Key::FileFormat => {
             return Err(ParseError::UnexpectedFileFormat);
         }
         record::Key::Info => {
             let info = Info::try_from(record).map_err(ParseError::InvalidInfo)?;
-            builder.add_info(info)
+            *builder = mem::take(builder).add_info(info);
         }
         record::Key::Filter => {
             let filter = Filter::try_from(record).map_err(ParseError::InvalidFilter)?;
-            builder.add_filter(filter)
+            *builder = mem::take(builder).add_filter(filter);
         }
         record::Key::Format => {
             let format = Format::try_from(record).map_err(ParseError::InvalidFormat)?;
-            builder.add_format(format)
+            *builder = mem::take(builder).add_format(format);
         }
         record::Key::AlternativeAllele => {
             let alternative_allele = AlternativeAllele::try_from(record)
                 .map_err(ParseError::InvalidAlternativeAllele)?;
-            builder.add_alternative_allele(alternative_allele)
+            *builder = mem::take(builder).add_alternative_allele(alternative_allele);
         }
         record::Key::Assembly => match record.value() {
-            record::Value::String(value) => builder.set_assembly(value),
+            record::Value::String(value) => *builder = mem::take(builder).set_assembly(value),
             _ => return Err(ParseError::InvalidRecordValue),
         },
         record::Key::Contig => {
             let contig = Contig::try_from(record).map_err(ParseError::InvalidContig)?;
-            builder.add_contig(contig)
+            *builder = mem::take(builder).add_contig(contig);
         }
         record::Key::Meta => {
             let meta = Meta::try_from(record).map_err(ParseError::InvalidMeta)?;
-            builder.add_meta(meta)
+            *builder = mem::take(builder).add_meta(meta);
         }
         record::Key::Sample => {
             let sample = Sample::try_from(record).map_err(ParseError::InvalidSample)?;
-            builder.add_sample(sample)
+            *builder = mem::take(builder).add_sample(sample);
         }
         record::Key::Pedigree => {
             let pedigree = Pedigree::try_from(record).map_err(ParseError::InvalidPedigree)?;
-            builder.add_pedigree(pedigree)
+            *builder = mem::take(builder).add_pedigree(pedigree);
         }
         record::Key::PedigreeDb => match record.value() {
-            record::Value::String(value) => builder.set_pedigree_db(value),
+            record::Value::String(value) => *builder = mem::take(builder).set_pedigree_db(value),
             _ => return Err(ParseError::InvalidRecordValue),
         },
-        record::Key::Other(_) => builder.insert(record),
-    };
+        record::Key::Other(_) => *builder = mem::take(builder).insert(record),
+    }
 
-    Ok(builder)
+    Ok(())
+}
+
+// Splits a raw header record into its key and unparsed value, e.g., `##INFO=<ID=NS,...>` into
+// (`INFO`, `<ID=NS,...>`). This keeps the original text when the record is written.
+fn parse_raw_record(line: &str) -> Option<Record> {
+    let s = line.strip_prefix(record::PREFIX)?;
+    let mut components = s.splitn(2, '=');
+
+    let key = components.next().and_then(|t| t.parse().ok())?;
+    let value = components.next()?;
+
+    Some(Record::new(key, record::Value::String(value.into())))
 }
 
 fn parse_header(mut builder: Builder, line: &str) -> Result<Builder, ParseError> {
@@ -755,6 +1162,72 @@ mod tests {
         assert_eq!(header.to_string(), expected);
     }
 
+    #[test]
+    fn test_fmt_preserves_record_order() -> Result<(), ParseError> {
+        let s = r#"##fileformat=VCFv4.3
+##fileDate=20200506
+##contig=<ID=sq0,length=8>
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##contig=<ID=sq1,length=13>
+##source=noodles-vcf
+##FILTER=<ID=q10,Description="Quality below 10">
+##assembly=file:///assemblies.fasta
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##fileDate=20200507
+##ALT=<ID=DEL,Description="Deletion">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        let header: Header = s.parse()?;
+        assert_eq!(header.to_string(), s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_with_mutated_contigs() -> Result<(), ParseError> {
+        let s = r#"##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##fileDate=20200506
+##contig=<ID=sq1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let mut header: Header = s.parse()?;
+
+        let contigs = header.contigs_mut();
+        contigs.remove("sq0");
+        contigs.insert(String::from("chr0"), Contig::new(String::from("chr0")));
+        contigs.insert(String::from("sq2"), Contig::new(String::from("sq2")));
+
+        let expected = r#"##fileformat=VCFv4.3
+##fileDate=20200506
+##contig=<ID=sq1>
+##contig=<ID=chr0>
+##contig=<ID=sq2>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(header.to_string(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_ignores_record_order() {
+        let header_a = Header::builder()
+            .add_contig(Contig::new(String::from("sq0")))
+            .add_filter(Filter::pass())
+            .build();
+
+        let header_b = Header::builder()
+            .add_filter(Filter::pass())
+            .add_contig(Contig::new(String::from("sq0")))
+            .build();
+
+        assert_eq!(header_a, header_b);
+    }
+
     #[test]
     fn test_fmt_with_genotypes() {
         let header = Header::builder().add_sample_name("sample0").build();
@@ -777,6 +1250,47 @@ mod tests {
         assert_eq!(header.to_string(), expected);
     }
 
+    #[test]
+    fn test_get_info() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::info::field::Key;
+
+        let header: Header = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">\n",
+            "##INFO=<ID=NDLS,Number=A,Type=Float,Description=\"noodles\">\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        )
+        .parse()?;
+
+        assert_eq!(
+            header.get_info("NS").map(|info| info.id()),
+            Some(&Key::SamplesWithDataCount)
+        );
+        assert_eq!(
+            header.get_info("NDLS").map(|info| info.number()),
+            Some(Number::A)
+        );
+        assert!(header.get_info("AF").is_none());
+
+        let header = Header::builder()
+            .add_info(Info::new(
+                Key::Other(
+                    String::from("NDLS"),
+                    Number::A,
+                    info::Type::Float,
+                    String::from("noodles"),
+                ),
+                Number::A,
+                info::Type::Float,
+                String::from("noodles"),
+            ))
+            .build();
+
+        assert!(header.get_info("NDLS").is_some());
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), ParseError> {
         let s = r#"##fileformat=VCFv4.3
@@ -929,6 +1443,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_str_lenient() -> Result<(), ParseError> {
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=AF,Description="Allele frequency">
+##FORMAT=<ID=GT,Number=1,Type=Float,Description="Genotype">
+##fileformat=VCFv4.2
+##source=noodles
+an unstructured line
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert!(s.parse::<Header>().is_err());
+
+        let (header, warnings) = Header::from_str_lenient(s)?;
+
+        assert_eq!(header.infos().len(), 1);
+        assert!(header.formats().is_empty());
+
+        assert_eq!(
+            header.get("INFO"),
+            Some(
+                &[Record::new(
+                    record::Key::Info,
+                    record::Value::String(String::from(
+                        r#"<ID=AF,Description="Allele frequency">"#
+                    )),
+                )][..]
+            )
+        );
+
+        assert_eq!(header.get("FORMAT").map(|records| records.len()), Some(1));
+        assert_eq!(
+            header.get("fileformat").map(|records| records.len()),
+            Some(1)
+        );
+        assert_eq!(header.get("source").map(|records| records.len()), Some(1));
+
+        assert_eq!(warnings.len(), 4);
+        assert!(matches!(warnings[0], ParseError::InvalidRecord(_)));
+        assert!(matches!(warnings[1], ParseError::InvalidFormat(_)));
+        assert_eq!(warnings[2], ParseError::UnexpectedFileFormat);
+        assert!(matches!(warnings[3], ParseError::InvalidRecord(_)));
+
+        assert_eq!(header.raw_lines(), [String::from("an unstructured line")]);
+        assert_eq!(header.to_string(), s);
+
+        let s = "##fileformat=VCFv4.3\n##unstructured line\n##source=noodles\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+        let (header, warnings) = Header::from_str_lenient(s)?;
+        assert_eq!(header.raw_lines(), [String::from("##unstructured line")]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(header.to_string(), s);
+
+        assert_eq!(
+            Header::from_str_lenient("##fileformat=VCFv4.3\n"),
+            Err(ParseError::MissingHeader)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_insert_with_duplicate_keys() {
         let records = [
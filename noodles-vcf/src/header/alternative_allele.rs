@@ -4,6 +4,8 @@ mod key;
 
 use std::{convert::TryFrom, error, fmt};
 
+use indexmap::IndexMap;
+
 use crate::record::alternate_bases::allele::{symbol, Symbol};
 
 use super::{record, Record};
@@ -15,6 +17,7 @@ use self::key::Key;
 pub struct AlternativeAllele {
     id: Symbol,
     description: String,
+    fields: IndexMap<String, String>,
 }
 
 impl AlternativeAllele {
@@ -37,7 +40,11 @@ impl AlternativeAllele {
     /// );
     /// ```
     pub fn new(id: Symbol, description: String) -> Self {
-        Self { id, description }
+        Self {
+            id,
+            description,
+            fields: IndexMap::new(),
+        }
     }
 
     /// Returns the alternate allele symbol.
@@ -90,6 +97,32 @@ impl AlternativeAllele {
     pub fn description(&self) -> &str {
         &self.description
     }
+
+    /// Returns the extra fields in the record.
+    ///
+    /// This includes fields other than `ID` and `Description`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::AlternativeAllele,
+    ///     record::alternate_bases::allele::{
+    ///         symbol::{structural_variant::Type, StructuralVariant},
+    ///         Symbol,
+    ///     },
+    /// };
+    ///
+    /// let alternative_allele = AlternativeAllele::new(
+    ///     Symbol::StructuralVariant(StructuralVariant::from(Type::Deletion)),
+    ///     String::from("Deletion"),
+    /// );
+    ///
+    /// assert!(alternative_allele.fields().is_empty());
+    /// ```
+    pub fn fields(&self) -> &IndexMap<String, String> {
+        &self.fields
+    }
 }
 
 impl fmt::Display for AlternativeAllele {
@@ -103,6 +136,11 @@ impl fmt::Display for AlternativeAllele {
         write!(f, ",{}=", Key::Description)?;
         super::fmt::write_escaped_string(f, self.description())?;
 
+        for (key, value) in &self.fields {
+            write!(f, ",{}=", key)?;
+            super::fmt::write_escaped_string(f, value)?;
+        }
+
         f.write_str(">")?;
 
         Ok(())
@@ -163,7 +201,13 @@ fn parse_struct(fields: Vec<(String, String)>) -> Result<AlternativeAllele, TryF
             _ => Err(TryFromRecordError::MissingField(Key::Description)),
         })?;
 
-    Ok(AlternativeAllele { id, description })
+    let fields = it.collect();
+
+    Ok(AlternativeAllele {
+        id,
+        description,
+        fields,
+    })
 }
 
 #[cfg(test)]
@@ -203,6 +247,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_record_for_alternative_allele_with_extra_fields() {
+        let record = Record::new(
+            record::Key::AlternativeAllele,
+            record::Value::Struct(vec![
+                (String::from("ID"), del().to_string()),
+                (String::from("Description"), String::from("Deletion")),
+                (String::from("Source"), String::from("noodles")),
+            ]),
+        );
+
+        let alternative_allele = AlternativeAllele::try_from(record);
+
+        assert_eq!(
+            alternative_allele,
+            Ok(AlternativeAllele {
+                id: del(),
+                description: String::from("Deletion"),
+                fields: vec![(String::from("Source"), String::from("noodles"))]
+                    .into_iter()
+                    .collect(),
+            })
+        );
+
+        assert_eq!(
+            alternative_allele.map(|a| a.to_string()),
+            Ok(String::from(
+                r#"##ALT=<ID=DEL,Description="Deletion",Source="noodles">"#
+            ))
+        );
+    }
+
     #[test]
     fn test_try_from_record_for_filter_with_an_invalid_record_key() {
         let record = Record::new(
@@ -1,6 +1,7 @@
 use super::{
-    AlternativeAllele, AlternativeAlleles, Contig, Contigs, FileFormat, Filter, Filters, Format,
-    Formats, Header, Info, Infos, Meta, Pedigree, Pedigrees, Record, Sample, SampleNames, Samples,
+    AlternativeAllele, AlternativeAlleles, Contig, Contigs, Entry, FileFormat, Filter, Filters,
+    Format, Formats, Header, Info, Infos, Meta, Pedigree, Pedigrees, Record, Sample, SampleNames,
+    Samples,
 };
 
 use indexmap::IndexMap;
@@ -21,6 +22,8 @@ pub struct Builder {
     pedigree_db: Option<String>,
     sample_names: SampleNames,
     map: IndexMap<String, Vec<Record>>,
+    raw_lines: Vec<String>,
+    entries: Vec<Entry>,
 }
 
 impl Builder {
@@ -44,21 +47,40 @@ impl Builder {
 
     /// Adds an information record (`INFO`).
     ///
+    /// This also accepts a key, where the number, type, and description are filled in from its
+    /// reserved definition.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use noodles_vcf::{self as vcf, header::Info, record::info::field::Key};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Type, Info, Number},
+    ///     record::info::field::Key,
+    /// };
     ///
     /// let header = vcf::Header::builder()
     ///     .add_info(Info::from(Key::SamplesWithDataCount))
+    ///     .add_info(Key::AlleleFrequencies)
     ///     .build();
     ///
     /// let infos = header.infos();
-    /// assert_eq!(infos.len(), 1);
+    /// assert_eq!(infos.len(), 2);
     /// assert_eq!(infos[0].id(), &Key::SamplesWithDataCount);
+    /// assert_eq!(infos[1].number(), Number::A);
+    /// assert_eq!(infos[1].ty(), Type::Float);
     /// ```
-    pub fn add_info(mut self, info: Info) -> Self {
-        self.infos.insert(info.id().clone(), info);
+    pub fn add_info<I>(mut self, info: I) -> Self
+    where
+        I: Into<Info>,
+    {
+        let info = info.into();
+        let id = info.id().clone();
+
+        if self.infos.insert(id.clone(), info).is_none() {
+            self.entries.push(Entry::Info(id));
+        }
+
         self
     }
 
@@ -81,27 +103,77 @@ impl Builder {
     /// assert_eq!(filters[0].id(), "q10");
     /// ```
     pub fn add_filter(mut self, filter: Filter) -> Self {
-        self.filters.insert(filter.id().into(), filter);
+        let id = String::from(filter.id());
+
+        if self.filters.insert(id.clone(), filter).is_none() {
+            self.entries.push(Entry::Filter(id));
+        }
+
         self
     }
 
+    /// Adds a filter record (`FILTER`) with the given ID and description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::Filter};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_filter_with_description("q10", "Quality below 10")
+    ///     .build();
+    ///
+    /// let filters = header.filters();
+    /// assert_eq!(filters.len(), 1);
+    /// assert_eq!(
+    ///     filters[0],
+    ///     Filter::new(String::from("q10"), String::from("Quality below 10")),
+    /// );
+    /// ```
+    pub fn add_filter_with_description<I, D>(self, id: I, description: D) -> Self
+    where
+        I: Into<String>,
+        D: Into<String>,
+    {
+        self.add_filter(Filter::new(id.into(), description.into()))
+    }
+
     /// Adds a genotype format record (`FORMAT`).
     ///
+    /// This also accepts a key, where the number, type, and description are filled in from its
+    /// reserved definition.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use noodles_vcf::{self as vcf, header::Format, record::genotype::field::Key};
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Type, Format, Number},
+    ///     record::genotype::field::Key,
+    /// };
     ///
     /// let header = vcf::Header::builder()
     ///     .add_format(Format::from(Key::Genotype))
+    ///     .add_format(Key::ConditionalGenotypeQuality)
     ///     .build();
     ///
     /// let formats = header.formats();
-    /// assert_eq!(formats.len(), 1);
+    /// assert_eq!(formats.len(), 2);
     /// assert_eq!(formats[0].id(), &Key::Genotype);
+    /// assert_eq!(formats[1].number(), Number::Count(1));
+    /// assert_eq!(formats[1].ty(), Type::Integer);
     /// ```
-    pub fn add_format(mut self, format: Format) -> Self {
-        self.formats.insert(format.id().clone(), format);
+    pub fn add_format<F>(mut self, format: F) -> Self
+    where
+        F: Into<Format>,
+    {
+        let format = format.into();
+        let id = format.id().clone();
+
+        if self.formats.insert(id.clone(), format).is_none() {
+            self.entries.push(Entry::Format(id));
+        }
+
         self
     }
 
@@ -134,8 +206,16 @@ impl Builder {
     /// );
     /// ```
     pub fn add_alternative_allele(mut self, alternative_allele: AlternativeAllele) -> Self {
-        self.alternative_alleles
-            .insert(alternative_allele.id().clone(), alternative_allele);
+        let id = alternative_allele.id().clone();
+
+        if self
+            .alternative_alleles
+            .insert(id.clone(), alternative_allele)
+            .is_none()
+        {
+            self.entries.push(Entry::AlternativeAllele(id));
+        }
+
         self
     }
 
@@ -156,7 +236,10 @@ impl Builder {
     where
         I: Into<String>,
     {
-        self.assembly = Some(assembly.into());
+        if self.assembly.replace(assembly.into()).is_none() {
+            self.entries.push(Entry::Assembly);
+        }
+
         self
     }
 
@@ -176,10 +259,40 @@ impl Builder {
     /// assert_eq!(contigs[0], Contig::new(String::from("sq0")));
     /// ```
     pub fn add_contig(mut self, contig: Contig) -> Self {
-        self.contigs.insert(contig.id().into(), contig);
+        let id = String::from(contig.id());
+
+        if self.contigs.insert(id.clone(), contig).is_none() {
+            self.entries.push(Entry::Contig(id));
+        }
+
         self
     }
 
+    /// Adds a contig record (`contig`) with the given name and length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig_with_len("sq0", 13)
+    ///     .build();
+    ///
+    /// let contigs = header.contigs();
+    /// assert_eq!(contigs.len(), 1);
+    /// assert_eq!(contigs[0].id(), "sq0");
+    /// assert_eq!(contigs[0].len(), Some(13));
+    /// ```
+    pub fn add_contig_with_len<N>(self, name: N, len: i32) -> Self
+    where
+        N: Into<String>,
+    {
+        let mut contig = Contig::new(name.into());
+        *contig.len_mut() = Some(len);
+        self.add_contig(contig)
+    }
+
     /// Adds a meta record (`META`).
     ///
     /// # Examples
@@ -201,7 +314,12 @@ impl Builder {
     /// assert_eq!(records[0], meta);
     /// ```
     pub fn add_meta(mut self, meta: Meta) -> Self {
-        self.meta.insert(meta.id().into(), meta);
+        let id = String::from(meta.id());
+
+        if self.meta.insert(id.clone(), meta).is_none() {
+            self.entries.push(Entry::Meta(id));
+        }
+
         self
     }
 
@@ -223,7 +341,12 @@ impl Builder {
     /// assert_eq!(records[0], sample);
     /// ```
     pub fn add_sample(mut self, sample: Sample) -> Self {
-        self.samples.insert(sample.id().into(), sample);
+        let id = String::from(sample.id());
+
+        if self.samples.insert(id.clone(), sample).is_none() {
+            self.entries.push(Entry::Sample(id));
+        }
+
         self
     }
 
@@ -253,7 +376,12 @@ impl Builder {
     /// assert_eq!(records[0], pedigree);
     /// ```
     pub fn add_pedigree(mut self, pedigree: Pedigree) -> Self {
-        self.pedigrees.insert(pedigree.id().into(), pedigree);
+        let id = String::from(pedigree.id());
+
+        if self.pedigrees.insert(id.clone(), pedigree).is_none() {
+            self.entries.push(Entry::Pedigree(id));
+        }
+
         self
     }
 
@@ -274,7 +402,10 @@ impl Builder {
     where
         I: Into<String>,
     {
-        self.pedigree_db = Some(pedigree_db.into());
+        if self.pedigree_db.replace(pedigree_db.into()).is_none() {
+            self.entries.push(Entry::PedigreeDb);
+        }
+
         self
     }
 
@@ -348,8 +479,19 @@ impl Builder {
     /// ```
     pub fn insert(mut self, record: Record) -> Self {
         let key = record.key().to_string();
-        let records = self.map.entry(key).or_default();
+        let records = self.map.entry(key.clone()).or_default();
         records.push(record);
+        self.entries.push(Entry::Other(key, records.len() - 1));
+        self
+    }
+
+    // Adds a line that is not a header record. It is written as is.
+    pub(crate) fn add_raw_line<L>(mut self, line: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.raw_lines.push(line.into());
+        self.entries.push(Entry::Raw(self.raw_lines.len() - 1));
         self
     }
 
@@ -376,6 +518,8 @@ impl Builder {
             pedigree_db: self.pedigree_db,
             sample_names: self.sample_names,
             map: self.map,
+            raw_lines: self.raw_lines,
+            entries: self.entries,
         }
     }
 }
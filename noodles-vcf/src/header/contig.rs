@@ -13,11 +13,17 @@ use super::{record, Record};
 use self::key::Key;
 
 /// A VCF header contig record (`contig`).
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq)]
 pub struct Contig {
     id: String,
     len: Option<i32>,
+    md5: Option<String>,
+    assembly: Option<String>,
+    url: Option<String>,
+    idx: Option<usize>,
     fields: IndexMap<String, String>,
+    // The order of the fields when the record was parsed. This is only used as a formatting hint.
+    keys: Vec<Key>,
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -34,7 +40,12 @@ impl Contig {
         Self {
             id,
             len: None,
+            md5: None,
+            assembly: None,
+            url: None,
+            idx: None,
             fields: IndexMap::new(),
+            keys: Vec::new(),
         }
     }
 
@@ -51,6 +62,12 @@ impl Contig {
         &self.id
     }
 
+    // The ID is also the key of the contig in the header, so it is only changed through the
+    // header.
+    pub(crate) fn id_mut(&mut self) -> &mut String {
+        &mut self.id
+    }
+
     /// Returns the length of the contig, if it is set.
     ///
     /// # Examples
@@ -64,6 +81,145 @@ impl Contig {
         self.len
     }
 
+    /// Returns a mutable reference to the length of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let mut contig = Contig::new(String::from("sq0"));
+    /// *contig.len_mut() = Some(13);
+    /// assert_eq!(contig.len(), Some(13));
+    /// ```
+    pub fn len_mut(&mut self) -> &mut Option<i32> {
+        &mut self.len
+    }
+
+    /// Returns the MD5 checksum of the contig, if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new(String::from("sq0"));
+    /// assert!(contig.md5().is_none());
+    /// ```
+    pub fn md5(&self) -> Option<&str> {
+        self.md5.as_deref()
+    }
+
+    /// Returns a mutable reference to the MD5 checksum of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let mut contig = Contig::new(String::from("sq0"));
+    /// *contig.md5_mut() = Some(String::from("d7eba311421bbc9d3ada44709dd61534"));
+    /// assert_eq!(contig.md5(), Some("d7eba311421bbc9d3ada44709dd61534"));
+    /// ```
+    pub fn md5_mut(&mut self) -> &mut Option<String> {
+        &mut self.md5
+    }
+
+    /// Returns the assembly of the contig, if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new(String::from("sq0"));
+    /// assert!(contig.assembly().is_none());
+    /// ```
+    pub fn assembly(&self) -> Option<&str> {
+        self.assembly.as_deref()
+    }
+
+    /// Returns a mutable reference to the assembly of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let mut contig = Contig::new(String::from("sq0"));
+    /// *contig.assembly_mut() = Some(String::from("sq0.fa"));
+    /// assert_eq!(contig.assembly(), Some("sq0.fa"));
+    /// ```
+    pub fn assembly_mut(&mut self) -> &mut Option<String> {
+        &mut self.assembly
+    }
+
+    /// Returns the URL of the contig, if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new(String::from("sq0"));
+    /// assert!(contig.url().is_none());
+    /// ```
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns a mutable reference to the URL of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let mut contig = Contig::new(String::from("sq0"));
+    /// *contig.url_mut() = Some(String::from("https://example.com/sq0.fa"));
+    /// assert_eq!(contig.url(), Some("https://example.com/sq0.fa"));
+    /// ```
+    pub fn url_mut(&mut self) -> &mut Option<String> {
+        &mut self.url
+    }
+
+    /// Returns the index of the ID in the dictionary of contigs.
+    ///
+    /// This is typically used in BCF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new(String::from("sq0"));
+    /// assert!(contig.idx().is_none());
+    /// ```
+    pub fn idx(&self) -> Option<usize> {
+        self.idx
+    }
+
+    /// Returns a mutable reference to the index of the ID in the dictionary of contigs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let mut contig = Contig::new(String::from("sq0"));
+    /// *contig.idx_mut() = Some(0);
+    /// assert_eq!(contig.idx(), Some(0));
+    /// ```
+    pub fn idx_mut(&mut self) -> &mut Option<usize> {
+        &mut self.idx
+    }
+
+    /// Returns the extra fields in the record.
+    ///
+    /// This includes fields other than `ID`, `length`, `md5`, `assembly`, `URL`, and `IDX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Contig;
+    /// let contig = Contig::new(String::from("sq0"));
+    /// assert!(contig.fields().is_empty());
+    /// ```
+    pub fn fields(&self) -> &IndexMap<String, String> {
+        &self.fields
+    }
+
     /// Returns the value of the field with the given key.
     ///
     /// # Examples
@@ -76,13 +232,13 @@ impl Contig {
     ///     record::Key::Contig,
     ///     record::Value::Struct(vec![
     ///         (String::from("ID"), String::from("sq0")),
-    ///         (String::from("md5"), String::from("d7eba311421bbc9d3ada44709dd61534")),
+    ///         (String::from("species"), String::from("Homo sapiens")),
     ///     ]),
     /// );
     /// let contig = Contig::try_from(record)?;
     ///
-    /// assert_eq!(contig.get("md5"), Some("d7eba311421bbc9d3ada44709dd61534"));
-    /// assert!(contig.get("species").is_none());
+    /// assert_eq!(contig.get("species"), Some("Homo sapiens"));
+    /// assert!(contig.get("taxonomy").is_none());
     ///
     /// # Ok::<(), contig::TryFromRecordError>(())
     /// ```
@@ -91,21 +247,90 @@ impl Contig {
     }
 }
 
+impl PartialEq for Contig {
+    // The field order is not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.len == other.len
+            && self.md5 == other.md5
+            && self.assembly == other.assembly
+            && self.url == other.url
+            && self.idx == other.idx
+            && self.fields == other.fields
+    }
+}
+
+impl Contig {
+    // Returns the keys of all fields in the default order.
+    fn default_keys(&self) -> impl Iterator<Item = Key> + '_ {
+        [Key::Id, Key::Length, Key::Md5, Key::Assembly, Key::Url]
+            .iter()
+            .cloned()
+            .chain(self.fields.keys().cloned().map(Key::Other))
+            .chain(std::iter::once(Key::Idx))
+    }
+
+    // Writes the field with the given key, returning whether the field was set.
+    fn fmt_field(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        key: &Key,
+        prefix: &str,
+    ) -> Result<bool, fmt::Error> {
+        match key {
+            Key::Id => write!(f, "{}{}={}", prefix, key, self.id)?,
+            Key::Length => match self.len {
+                Some(len) => write!(f, "{}{}={}", prefix, key, len)?,
+                None => return Ok(false),
+            },
+            Key::Idx => match self.idx {
+                Some(idx) => write!(f, "{}{}={}", prefix, key, idx)?,
+                None => return Ok(false),
+            },
+            _ => {
+                let value = match key {
+                    Key::Md5 => self.md5(),
+                    Key::Assembly => self.assembly(),
+                    Key::Url => self.url(),
+                    Key::Other(k) => self.get(k),
+                    _ => unreachable!(),
+                };
+
+                match value {
+                    Some(value) => {
+                        write!(f, "{}{}=", prefix, key)?;
+                        super::fmt::write_string(f, value)?;
+                    }
+                    None => return Ok(false),
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
 impl fmt::Display for Contig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(record::PREFIX)?;
         f.write_str(record::Key::Contig.as_ref())?;
         f.write_str("=<")?;
 
-        write!(f, "{}={}", Key::Id, self.id)?;
+        // Fields are written in the order they were parsed, followed by any remaining fields in
+        // the default order.
+        let mut written_keys: Vec<&Key> = Vec::new();
+        let default_keys: Vec<_> = self.default_keys().collect();
 
-        if let Some(len) = self.len {
-            write!(f, ",{}={}", Key::Length, len)?;
-        }
+        for key in self.keys.iter().chain(&default_keys) {
+            if written_keys.contains(&key) {
+                continue;
+            }
 
-        for (key, value) in &self.fields {
-            write!(f, ",{}=", key)?;
-            super::fmt::write_escaped_string(f, value)?;
+            let prefix = if written_keys.is_empty() { "" } else { "," };
+
+            if self.fmt_field(f, key, prefix)? {
+                written_keys.push(key);
+            }
         }
 
         f.write_str(">")?;
@@ -125,6 +350,8 @@ pub enum TryFromRecordError {
     InvalidId,
     /// The length is invalid.
     InvalidLength(num::ParseIntError),
+    /// The index (`IDX`) is invalid.
+    InvalidIdx(num::ParseIntError),
     /// A required field is missing.
     MissingField(Key),
 }
@@ -139,6 +366,7 @@ impl fmt::Display for TryFromRecordError {
             Self::InvalidId => f.write_str("invalid ID"),
             Self::InvalidKey(e) => write!(f, "invalid key: {}", e),
             Self::InvalidLength(e) => write!(f, "invalid length: {}", e),
+            Self::InvalidIdx(e) => write!(f, "invalid index (`{}`): {}", Key::Idx, e),
         }
     }
 }
@@ -157,10 +385,16 @@ impl TryFrom<Record> for Contig {
 fn parse_struct(fields: Vec<(String, String)>) -> Result<Contig, TryFromRecordError> {
     let mut id = None;
     let mut len = None;
+    let mut md5 = None;
+    let mut assembly = None;
+    let mut url = None;
+    let mut idx = None;
     let mut other_fields = IndexMap::new();
+    let mut keys = Vec::with_capacity(fields.len());
 
     for (raw_key, value) in fields {
-        let key = raw_key.parse().map_err(TryFromRecordError::InvalidKey)?;
+        let key: Key = raw_key.parse().map_err(TryFromRecordError::InvalidKey)?;
+        keys.push(key.clone());
 
         match key {
             Key::Id => {
@@ -176,6 +410,15 @@ fn parse_struct(fields: Vec<(String, String)>) -> Result<Contig, TryFromRecordEr
                     .map(Some)
                     .map_err(TryFromRecordError::InvalidLength)?;
             }
+            Key::Md5 => md5 = Some(value),
+            Key::Assembly => assembly = Some(value),
+            Key::Url => url = Some(value),
+            Key::Idx => {
+                idx = value
+                    .parse()
+                    .map(Some)
+                    .map_err(TryFromRecordError::InvalidIdx)?;
+            }
             Key::Other(k) => {
                 other_fields.insert(k, value);
             }
@@ -185,7 +428,12 @@ fn parse_struct(fields: Vec<(String, String)>) -> Result<Contig, TryFromRecordEr
     Ok(Contig {
         id: id.ok_or(TryFromRecordError::MissingField(Key::Id))?,
         len,
+        md5,
+        assembly,
+        url,
+        idx,
         fields: other_fields,
+        keys,
     })
 }
 
@@ -203,6 +451,8 @@ mod tests {
                     String::from("md5"),
                     String::from("d7eba311421bbc9d3ada44709dd61534"),
                 ),
+                (String::from("species"), String::from("Homo sapiens")),
+                (String::from("IDX"), String::from("0")),
             ]),
         )
     }
@@ -212,9 +462,27 @@ mod tests {
         let record = build_record();
         let contig = Contig::try_from(record)?;
 
-        let expected = r#"##contig=<ID=sq0,length=13,md5="d7eba311421bbc9d3ada44709dd61534">"#;
+        let expected = r#"##contig=<ID=sq0,length=13,md5=d7eba311421bbc9d3ada44709dd61534,species="Homo sapiens",IDX=0>"#;
         assert_eq!(contig.to_string(), expected);
 
+        let mut contig = Contig::new(String::from("sq0"));
+        *contig.idx_mut() = Some(0);
+        *contig.len_mut() = Some(13);
+        assert_eq!(contig.to_string(), "##contig=<ID=sq0,length=13,IDX=0>");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_with_parsed_field_order() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "##contig=<ID=sq0,md5=d7eba311421bbc9d3ada44709dd61534,length=8,species=human>";
+        let contig = Contig::try_from(s.parse::<Record>()?)?;
+        assert_eq!(contig.to_string(), s);
+
+        let s = r#"##contig=<species="Homo sapiens",IDX=1,ID=sq0,URL=https://example.com/sq0.fa>"#;
+        let contig = Contig::try_from(s.parse::<Record>()?)?;
+        assert_eq!(contig.to_string(), s);
+
         Ok(())
     }
 
@@ -227,12 +495,14 @@ mod tests {
             Ok(Contig {
                 id: String::from("sq0"),
                 len: Some(13),
-                fields: vec![(
-                    String::from("md5"),
-                    String::from("d7eba311421bbc9d3ada44709dd61534")
-                )]
-                .into_iter()
-                .collect(),
+                md5: Some(String::from("d7eba311421bbc9d3ada44709dd61534")),
+                assembly: None,
+                url: None,
+                idx: Some(0),
+                fields: vec![(String::from("species"), String::from("Homo sapiens"))]
+                    .into_iter()
+                    .collect(),
+                keys: Vec::new(),
             })
         );
     }
@@ -301,4 +571,20 @@ mod tests {
             Err(TryFromRecordError::InvalidLength(_))
         ));
     }
+
+    #[test]
+    fn test_try_from_record_for_contig_with_an_invalid_idx() {
+        let record = Record::new(
+            record::Key::Contig,
+            record::Value::Struct(vec![
+                (String::from("ID"), String::from("sq0")),
+                (String::from("IDX"), String::from("ndls")),
+            ]),
+        );
+
+        assert!(matches!(
+            Contig::try_from(record),
+            Err(TryFromRecordError::InvalidIdx(_))
+        ));
+    }
 }
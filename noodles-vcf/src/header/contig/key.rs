@@ -9,6 +9,14 @@ pub enum Key {
     Id,
     /// (`length`).
     Length,
+    /// (`md5`).
+    Md5,
+    /// (`assembly`).
+    Assembly,
+    /// (`URL`).
+    Url,
+    /// (`IDX`).
+    Idx,
     /// Any other key.
     Other(String),
 }
@@ -18,6 +26,10 @@ impl AsRef<str> for Key {
         match self {
             Self::Id => "ID",
             Self::Length => "length",
+            Self::Md5 => "md5",
+            Self::Assembly => "assembly",
+            Self::Url => "URL",
+            Self::Idx => "IDX",
             Self::Other(s) => s,
         }
     }
@@ -54,6 +66,10 @@ impl FromStr for Key {
             "" => Err(ParseError::Empty),
             "ID" => Ok(Self::Id),
             "length" => Ok(Self::Length),
+            "md5" => Ok(Self::Md5),
+            "assembly" => Ok(Self::Assembly),
+            "URL" => Ok(Self::Url),
+            "IDX" => Ok(Self::Idx),
             _ => Ok(Self::Other(s.into())),
         }
     }
@@ -67,18 +83,23 @@ mod tests {
     fn test_fmt() {
         assert_eq!(Key::Id.to_string(), "ID");
         assert_eq!(Key::Length.to_string(), "length");
-        assert_eq!(Key::Other(String::from("md5")).to_string(), "md5");
+        assert_eq!(Key::Md5.to_string(), "md5");
+        assert_eq!(Key::Assembly.to_string(), "assembly");
+        assert_eq!(Key::Url.to_string(), "URL");
+        assert_eq!(Key::Idx.to_string(), "IDX");
+        assert_eq!(Key::Other(String::from("species")).to_string(), "species");
     }
 
     #[test]
     fn test_from_str() {
         assert_eq!("ID".parse(), Ok(Key::Id));
         assert_eq!("length".parse(), Ok(Key::Length));
-        assert_eq!("assembly".parse(), Ok(Key::Other(String::from("assembly"))));
-        assert_eq!("md5".parse(), Ok(Key::Other(String::from("md5"))));
+        assert_eq!("md5".parse(), Ok(Key::Md5));
+        assert_eq!("assembly".parse(), Ok(Key::Assembly));
+        assert_eq!("URL".parse(), Ok(Key::Url));
+        assert_eq!("IDX".parse(), Ok(Key::Idx));
         assert_eq!("species".parse(), Ok(Key::Other(String::from("species"))));
         assert_eq!("taxonomy".parse(), Ok(Key::Other(String::from("taxonomy"))));
-        assert_eq!("URL".parse(), Ok(Key::Other(String::from("URL"))));
 
         assert_eq!("".parse::<Key>(), Err(ParseError::Empty));
     }
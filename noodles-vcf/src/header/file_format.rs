@@ -3,7 +3,19 @@
 use std::{error, fmt, num, str::FromStr};
 
 /// A VCF header file format.
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// File formats are ordered by version.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::header::FileFormat;
+///
+/// let file_format: FileFormat = "VCFv4.10".parse()?;
+/// assert!(file_format > FileFormat::new(4, 3));
+/// # Ok::<(), noodles_vcf::header::file_format::ParseError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct FileFormat {
     major: u32,
     minor: u32,
@@ -148,6 +160,14 @@ mod tests {
         assert_eq!(file_format.minor(), MINOR_VERSION);
     }
 
+    #[test]
+    fn test_ord() {
+        assert!(FileFormat::new(4, 2) < FileFormat::new(4, 3));
+        assert!(FileFormat::new(4, 10) > FileFormat::new(4, 3));
+        assert!(FileFormat::new(5, 0) > FileFormat::new(4, 3));
+        assert_eq!(FileFormat::new(4, 3), FileFormat::default());
+    }
+
     #[test]
     fn test_fmt() {
         let file_format = FileFormat::new(4, 3);
@@ -96,6 +96,20 @@ impl Filter {
         self.idx
     }
 
+    /// Returns a mutable reference to the index of the ID in the dictionary of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Filter;
+    /// let mut filter = Filter::new(String::from("q10"), String::from("Quality below 10"));
+    /// *filter.idx_mut() = Some(8);
+    /// assert_eq!(filter.idx(), Some(8));
+    /// ```
+    pub fn idx_mut(&mut self) -> &mut Option<usize> {
+        &mut self.idx
+    }
+
     /// Returns the extra fields in the record.
     ///
     /// This includes fields other than `ID` and `Description`.
@@ -272,8 +286,10 @@ mod tests {
             ]),
         );
 
+        let filter = Filter::try_from(record);
+
         assert_eq!(
-            Filter::try_from(record),
+            filter,
             Ok(Filter {
                 id: String::from("q10"),
                 description: String::from("Quality below 10"),
@@ -283,6 +299,13 @@ mod tests {
                     .collect()
             })
         );
+
+        assert_eq!(
+            filter.map(|f| f.to_string()),
+            Ok(String::from(
+                r#"##FILTER=<ID=q10,Description="Quality below 10",Source="noodles",IDX=1>"#
+            ))
+        );
     }
 
     #[test]
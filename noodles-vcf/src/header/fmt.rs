@@ -20,6 +20,23 @@ pub(crate) fn write_escaped_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::
     Ok(())
 }
 
+/// Formats a string, escaping it only if necessary.
+///
+/// Strings that are empty or contain whitespace or characters used as delimiters in structured
+/// header records are written as escaped strings (see [`write_escaped_string`]). Otherwise, the
+/// string is written as is.
+pub(crate) fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let needs_escaping = s.is_empty()
+        || s.chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\\' | ',' | '=' | '<' | '>'));
+
+    if needs_escaping {
+        write_escaped_string(f, s)
+    } else {
+        f.write_str(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,6 +49,27 @@ mod tests {
         }
     }
 
+    struct StringFormat(&'static str);
+
+    impl fmt::Display for StringFormat {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write_string(f, self.0)
+        }
+    }
+
+    #[test]
+    fn test_write_string() {
+        assert_eq!(StringFormat("noodles").to_string(), "noodles");
+        assert_eq!(StringFormat("").to_string(), r#""""#);
+        assert_eq!(StringFormat("noodles vcf").to_string(), r#""noodles vcf""#);
+        assert_eq!(StringFormat("noodles,vcf").to_string(), r#""noodles,vcf""#);
+        assert_eq!(StringFormat("noodles=vcf").to_string(), r#""noodles=vcf""#);
+        assert_eq!(
+            StringFormat(r#"noodles"vcf"#).to_string(),
+            r#""noodles\"vcf""#
+        );
+    }
+
     #[test]
     fn test_write_escaped_string() {
         assert_eq!(
@@ -120,6 +120,20 @@ impl Format {
         self.idx
     }
 
+    /// Returns a mutable reference to the index of the ID in the dictionary of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::Format, record::genotype::field::Key};
+    /// let mut format = Format::from(Key::Genotype);
+    /// *format.idx_mut() = Some(8);
+    /// assert_eq!(format.idx(), Some(8));
+    /// ```
+    pub fn idx_mut(&mut self) -> &mut Option<usize> {
+        &mut self.idx
+    }
+
     /// Returns the extra fields in the record.
     ///
     /// This includes fields other than `ID`, `Number`, `Type`, `Description`, and `IDX`.
@@ -329,6 +343,190 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_from_genotype_field_key_for_format_with_reserved_keys(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use genotype::field::Key;
+
+        let expected = [
+            (
+                Key::ReadDepths,
+                "AD",
+                Number::R,
+                Type::Integer,
+                "Read depth for each allele",
+            ),
+            (
+                Key::ForwardStrandReadDepths,
+                "ADF",
+                Number::R,
+                Type::Integer,
+                "Read depth for each allele on the forward strand",
+            ),
+            (
+                Key::ReverseStrandReadDepths,
+                "ADR",
+                Number::R,
+                Type::Integer,
+                "Read depth for each allele on the reverse strand",
+            ),
+            (
+                Key::ReadDepth,
+                "DP",
+                Number::Count(1),
+                Type::Integer,
+                "Read depth",
+            ),
+            (
+                Key::ExpectedAlternateAlleleCounts,
+                "EC",
+                Number::A,
+                Type::Integer,
+                "Expected alternate allele counts",
+            ),
+            (
+                Key::Filter,
+                "FT",
+                Number::Count(1),
+                Type::String,
+                r#"Filter indicating if this genotype was "called""#,
+            ),
+            (
+                Key::GenotypeLikelihoods,
+                "GL",
+                Number::G,
+                Type::Float,
+                "Genotype likelihoods",
+            ),
+            (
+                Key::GenotypePosteriorProbabilities,
+                "GP",
+                Number::G,
+                Type::Float,
+                "Genotype posterior probabilities",
+            ),
+            (
+                Key::ConditionalGenotypeQuality,
+                "GQ",
+                Number::Count(1),
+                Type::Integer,
+                "Conditional genotype quality",
+            ),
+            (
+                Key::Genotype,
+                "GT",
+                Number::Count(1),
+                Type::String,
+                "Genotype",
+            ),
+            (
+                Key::HaplotypeQuality,
+                "HQ",
+                Number::Count(2),
+                Type::Integer,
+                "Haplotype quality",
+            ),
+            (
+                Key::MappingQuality,
+                "MQ",
+                Number::Count(1),
+                Type::Integer,
+                "RMS mapping quality",
+            ),
+            (
+                Key::RoundedGenotypeLikelihoods,
+                "PL",
+                Number::G,
+                Type::Integer,
+                "Phred-scaled genotype likelihoods rounded to the closest integer",
+            ),
+            (
+                Key::RoundedGenotypePosteriorProbabilities,
+                "PP",
+                Number::G,
+                Type::Integer,
+                "Phred-scaled genotype posterior probabilities rounded to the closest integer",
+            ),
+            (
+                Key::PhasingQuality,
+                "PQ",
+                Number::Count(1),
+                Type::Integer,
+                "Phasing quality",
+            ),
+            (
+                Key::PhaseSet,
+                "PS",
+                Number::Count(1),
+                Type::Integer,
+                "Phase set",
+            ),
+            (
+                Key::GenotypeCopyNumber,
+                "CN",
+                Number::Count(1),
+                Type::Integer,
+                "Copy number genotype for imprecise events",
+            ),
+            (
+                Key::GenotypeCopyNumberQuality,
+                "CNQ",
+                Number::Count(1),
+                Type::Float,
+                "Copy number genotype quality for imprecise events",
+            ),
+            (
+                Key::GenotypeCopyNumberLikelihoods,
+                "CNL",
+                Number::G,
+                Type::Float,
+                "Copy number genotype likelihood for imprecise events",
+            ),
+            (
+                Key::GenotypeCopyNumberPosteriorProbabilities,
+                "CNP",
+                Number::G,
+                Type::Float,
+                "Copy number posterior probabilities",
+            ),
+            (
+                Key::NovelVariantQualityScore,
+                "NQ",
+                Number::Count(1),
+                Type::Integer,
+                "Phred style probability score that the variant is novel",
+            ),
+            (
+                Key::HaplotypeId,
+                "HAP",
+                Number::Count(1),
+                Type::Integer,
+                "Unique haplotype identifier",
+            ),
+            (
+                Key::AncestralHaplotypeId,
+                "AHAP",
+                Number::Count(1),
+                Type::Integer,
+                "Unique identifier of ancestral haplotype",
+            ),
+        ];
+
+        for (key, id, number, ty, description) in expected.iter().cloned() {
+            let format = Format::from(key);
+
+            assert_eq!(format.id().as_ref(), id);
+            assert_eq!(format.number(), number);
+            assert_eq!(format.ty(), ty);
+            assert_eq!(format.description(), description);
+
+            let record: Record = format.to_string().parse()?;
+            assert_eq!(Format::try_from(record)?, format);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), TryFromRecordError> {
         let record = build_record();
@@ -120,6 +120,20 @@ impl Info {
         self.idx
     }
 
+    /// Returns a mutable reference to the index of the ID in the dictionary of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::Info, record::info::field::Key};
+    /// let mut info = Info::from(Key::SamplesWithDataCount);
+    /// *info.idx_mut() = Some(8);
+    /// assert_eq!(info.idx(), Some(8));
+    /// ```
+    pub fn idx_mut(&mut self) -> &mut Option<usize> {
+        &mut self.idx
+    }
+
     /// Returns the extra fields in the record.
     ///
     /// This includes fields other than `ID`, `Number`, `Type`, `Description`, and `IDX`.
@@ -15,6 +15,40 @@ pub enum Number {
     Unknown,
 }
 
+impl Number {
+    /// Returns the expected number of values for a field with this cardinality.
+    ///
+    /// `A` and `R` are relative to the number of alternate alleles. `G` is the number of
+    /// unordered genotypes for the given ploidy, i.e., `(n + ploidy - 1) choose ploidy`, where `n`
+    /// is the number of alleles (reference and alternates). This returns `None` if the size is
+    /// unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Number;
+    ///
+    /// assert_eq!(Number::Count(1).expected_count(2, 2), Some(1));
+    /// assert_eq!(Number::A.expected_count(2, 2), Some(2));
+    /// assert_eq!(Number::R.expected_count(2, 2), Some(3));
+    /// assert_eq!(Number::G.expected_count(2, 2), Some(6));
+    /// assert_eq!(Number::G.expected_count(2, 1), Some(3));
+    /// assert_eq!(Number::Unknown.expected_count(2, 2), None);
+    /// ```
+    pub fn expected_count(self, alternate_allele_count: usize, ploidy: usize) -> Option<usize> {
+        match self {
+            Self::Count(n) => Some(n),
+            Self::A => Some(alternate_allele_count),
+            Self::R => Some(alternate_allele_count + 1),
+            Self::G => {
+                let allele_count = alternate_allele_count + 1;
+                Some(binomial_coefficient(allele_count + ploidy - 1, ploidy))
+            }
+            Self::Unknown => None,
+        }
+    }
+}
+
 impl Default for Number {
     fn default() -> Self {
         Self::Unknown
@@ -71,6 +105,11 @@ impl FromStr for Number {
     }
 }
 
+fn binomial_coefficient(n: usize, k: usize) -> usize {
+    let k = k.min(n - k);
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,6 +119,20 @@ mod tests {
         assert_eq!(Number::default(), Number::Unknown);
     }
 
+    #[test]
+    fn test_expected_count() {
+        assert_eq!(Number::Count(3).expected_count(2, 2), Some(3));
+        assert_eq!(Number::A.expected_count(2, 2), Some(2));
+        assert_eq!(Number::R.expected_count(2, 2), Some(3));
+        assert_eq!(Number::G.expected_count(0, 2), Some(1));
+        assert_eq!(Number::G.expected_count(1, 1), Some(2));
+        assert_eq!(Number::G.expected_count(1, 2), Some(3));
+        assert_eq!(Number::G.expected_count(2, 2), Some(6));
+        assert_eq!(Number::G.expected_count(1, 3), Some(4));
+        assert_eq!(Number::G.expected_count(3, 4), Some(35));
+        assert_eq!(Number::Unknown.expected_count(2, 2), None);
+    }
+
     #[test]
     fn test_fmt() {
         assert_eq!(Number::Count(1).to_string(), "1");
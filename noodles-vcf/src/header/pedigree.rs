@@ -57,6 +57,71 @@ impl Pedigree {
     pub fn fields(&self) -> &IndexMap<String, String> {
         &self.fields
     }
+
+    /// Returns the value of the field with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Pedigree;
+    ///
+    /// let fields = vec![(String::from("Father"), String::from("fid"))].into_iter().collect();
+    /// let pedigree = Pedigree::new(String::from("cid"), fields);
+    ///
+    /// assert_eq!(pedigree.get("Father"), Some("fid"));
+    /// assert!(pedigree.get("Noodles").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(|s| &**s)
+    }
+
+    /// Returns the ID of the father (`Father`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Pedigree;
+    ///
+    /// let fields = vec![(String::from("Father"), String::from("fid"))].into_iter().collect();
+    /// let pedigree = Pedigree::new(String::from("cid"), fields);
+    ///
+    /// assert_eq!(pedigree.father(), Some("fid"));
+    /// ```
+    pub fn father(&self) -> Option<&str> {
+        self.get(Key::Father.as_ref())
+    }
+
+    /// Returns the ID of the mother (`Mother`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Pedigree;
+    ///
+    /// let fields = vec![(String::from("Mother"), String::from("mid"))].into_iter().collect();
+    /// let pedigree = Pedigree::new(String::from("cid"), fields);
+    ///
+    /// assert_eq!(pedigree.mother(), Some("mid"));
+    /// ```
+    pub fn mother(&self) -> Option<&str> {
+        self.get(Key::Mother.as_ref())
+    }
+
+    /// Returns the ID of the original genome (`Original`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Pedigree;
+    ///
+    /// let fields = vec![(String::from("Original"), String::from("gid"))].into_iter().collect();
+    /// let pedigree = Pedigree::new(String::from("cid"), fields);
+    ///
+    /// assert_eq!(pedigree.original(), Some("gid"));
+    /// ```
+    pub fn original(&self) -> Option<&str> {
+        self.get(Key::Original.as_ref())
+    }
 }
 
 impl fmt::Display for Pedigree {
@@ -65,10 +130,11 @@ impl fmt::Display for Pedigree {
         f.write_str(record::Key::Pedigree.as_ref())?;
         f.write_str("=<")?;
 
-        write!(f, "ID={}", self.id())?;
+        write!(f, "{}={}", Key::Id, self.id())?;
 
         for (key, value) in &self.fields {
-            write!(f, r#",{}={}"#, key, value)?;
+            write!(f, ",{}=", key)?;
+            super::fmt::write_string(f, value)?;
         }
 
         f.write_str(">")?;
@@ -166,4 +232,29 @@ mod tests {
 
         assert_eq!(actual, Ok(expected));
     }
+
+    #[test]
+    fn test_relationships() -> Result<(), TryFromRecordError> {
+        let record = build_record();
+        let pedigree = Pedigree::try_from(record)?;
+
+        assert_eq!(pedigree.father(), Some("fid"));
+        assert_eq!(pedigree.mother(), Some("mid"));
+        assert!(pedigree.original().is_none());
+
+        let record = Record::new(
+            record::Key::Pedigree,
+            record::Value::Struct(vec![
+                (String::from("ID"), String::from("tumor")),
+                (String::from("Original"), String::from("germline")),
+            ]),
+        );
+        let pedigree = Pedigree::try_from(record)?;
+
+        assert!(pedigree.father().is_none());
+        assert!(pedigree.mother().is_none());
+        assert_eq!(pedigree.original(), Some("germline"));
+
+        Ok(())
+    }
 }
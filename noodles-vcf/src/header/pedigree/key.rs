@@ -7,12 +7,21 @@ use std::{error, fmt, str::FromStr};
 pub enum Key {
     /// (`ID`).
     Id,
+    /// (`Father`).
+    Father,
+    /// (`Mother`).
+    Mother,
+    /// (`Original`).
+    Original,
 }
 
 impl AsRef<str> for Key {
     fn as_ref(&self) -> &str {
         match self {
             Self::Id => "ID",
+            Self::Father => "Father",
+            Self::Mother => "Mother",
+            Self::Original => "Original",
         }
     }
 }
@@ -50,6 +59,9 @@ impl FromStr for Key {
         match s {
             "" => Err(ParseError::Empty),
             "ID" => Ok(Self::Id),
+            "Father" => Ok(Self::Father),
+            "Mother" => Ok(Self::Mother),
+            "Original" => Ok(Self::Original),
             _ => Err(ParseError::Invalid),
         }
     }
@@ -62,11 +74,17 @@ mod tests {
     #[test]
     fn test_fmt() {
         assert_eq!(Key::Id.to_string(), "ID");
+        assert_eq!(Key::Father.to_string(), "Father");
+        assert_eq!(Key::Mother.to_string(), "Mother");
+        assert_eq!(Key::Original.to_string(), "Original");
     }
 
     #[test]
     fn test_from_str() {
         assert_eq!("ID".parse(), Ok(Key::Id));
+        assert_eq!("Father".parse(), Ok(Key::Father));
+        assert_eq!("Mother".parse(), Ok(Key::Mother));
+        assert_eq!("Original".parse(), Ok(Key::Original));
 
         assert_eq!("".parse::<Key>(), Err(ParseError::Empty));
         assert_eq!("Noodles".parse::<Key>(), Err(ParseError::Invalid));
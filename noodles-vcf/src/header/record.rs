@@ -11,7 +11,7 @@ use std::{error, fmt, str::FromStr};
 pub(crate) const PREFIX: &str = "##";
 
 /// A generic VCF header record.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Record {
     key: Key,
     value: Value,
@@ -1,7 +1,7 @@
 use std::fmt;
 
 /// A VCF header record value.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Value {
     /// A string.
     String(String),
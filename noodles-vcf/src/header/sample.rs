@@ -57,6 +57,103 @@ impl Sample {
     pub fn fields(&self) -> &IndexMap<String, String> {
         &self.fields
     }
+
+    /// Returns the value of the field with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Sample;
+    ///
+    /// let fields = vec![(String::from("Assay"), String::from("WholeGenome"))].into_iter().collect();
+    /// let sample = Sample::new(String::from("sample0"), fields);
+    ///
+    /// assert_eq!(sample.get("Assay"), Some("WholeGenome"));
+    /// assert!(sample.get("Noodles").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(|s| &**s)
+    }
+
+    /// Returns the assay (`Assay`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Sample;
+    ///
+    /// let fields = vec![(String::from("Assay"), String::from("WholeGenome"))].into_iter().collect();
+    /// let sample = Sample::new(String::from("sample0"), fields);
+    ///
+    /// assert_eq!(sample.assay(), Some("WholeGenome"));
+    /// ```
+    pub fn assay(&self) -> Option<&str> {
+        self.get(Key::Assay.as_ref())
+    }
+
+    /// Returns the ethnicity (`Ethnicity`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Sample;
+    ///
+    /// let fields = vec![(String::from("Ethnicity"), String::from("AFR"))].into_iter().collect();
+    /// let sample = Sample::new(String::from("sample0"), fields);
+    ///
+    /// assert_eq!(sample.ethnicity(), Some("AFR"));
+    /// ```
+    pub fn ethnicity(&self) -> Option<&str> {
+        self.get(Key::Ethnicity.as_ref())
+    }
+
+    /// Returns the disease (`Disease`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Sample;
+    ///
+    /// let fields = vec![(String::from("Disease"), String::from("None"))].into_iter().collect();
+    /// let sample = Sample::new(String::from("sample0"), fields);
+    ///
+    /// assert_eq!(sample.disease(), Some("None"));
+    /// ```
+    pub fn disease(&self) -> Option<&str> {
+        self.get(Key::Disease.as_ref())
+    }
+
+    /// Returns the description (`Description`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Sample;
+    ///
+    /// let fields = vec![(String::from("Description"), String::from("Patient germline genome"))].into_iter().collect();
+    /// let sample = Sample::new(String::from("sample0"), fields);
+    ///
+    /// assert_eq!(sample.description(), Some("Patient germline genome"));
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.get(Key::Description.as_ref())
+    }
+
+    /// Returns the digital object identifier (`DOI`), if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::Sample;
+    ///
+    /// let fields = vec![(String::from("DOI"), String::from("https://doi.org/10.1000/182"))].into_iter().collect();
+    /// let sample = Sample::new(String::from("sample0"), fields);
+    ///
+    /// assert_eq!(sample.doi(), Some("https://doi.org/10.1000/182"));
+    /// ```
+    pub fn doi(&self) -> Option<&str> {
+        self.get(Key::Doi.as_ref())
+    }
 }
 
 impl fmt::Display for Sample {
@@ -68,7 +165,8 @@ impl fmt::Display for Sample {
         write!(f, "{}={}", Key::Id, self.id())?;
 
         for (key, value) in &self.fields {
-            write!(f, r#",{}={}"#, key, value)?;
+            write!(f, ",{}=", key)?;
+            super::fmt::write_string(f, value)?;
         }
 
         f.write_str(">")?;
@@ -150,6 +248,18 @@ mod tests {
             sample.to_string(),
             "##SAMPLE=<ID=sample0,Assay=WholeGenome>"
         );
+
+        let mut fields = IndexMap::new();
+        fields.insert(String::from("Assay"), String::from("WholeGenome"));
+        fields.insert(
+            String::from("Description"),
+            String::from("Patient germline genome"),
+        );
+        let sample = Sample::new(String::from("sample0"), fields);
+        assert_eq!(
+            sample.to_string(),
+            r#"##SAMPLE=<ID=sample0,Assay=WholeGenome,Description="Patient germline genome">"#
+        );
     }
 
     #[test]
@@ -163,4 +273,35 @@ mod tests {
 
         assert_eq!(actual, Ok(expected));
     }
+
+    #[test]
+    fn test_try_from_record_for_sample_with_typed_fields() -> Result<(), TryFromRecordError> {
+        let record = Record::new(
+            record::Key::Sample,
+            record::Value::Struct(vec![
+                (String::from("ID"), String::from("sample0")),
+                (String::from("Assay"), String::from("WholeGenome")),
+                (String::from("Ethnicity"), String::from("AFR")),
+                (String::from("Disease"), String::from("None")),
+                (
+                    String::from("Description"),
+                    String::from("Patient germline genome"),
+                ),
+                (
+                    String::from("DOI"),
+                    String::from("https://doi.org/10.1000/182"),
+                ),
+            ]),
+        );
+
+        let sample = Sample::try_from(record)?;
+
+        assert_eq!(sample.assay(), Some("WholeGenome"));
+        assert_eq!(sample.ethnicity(), Some("AFR"));
+        assert_eq!(sample.disease(), Some("None"));
+        assert_eq!(sample.description(), Some("Patient germline genome"));
+        assert_eq!(sample.doi(), Some("https://doi.org/10.1000/182"));
+
+        Ok(())
+    }
 }
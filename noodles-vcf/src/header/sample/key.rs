@@ -7,12 +7,27 @@ use std::{error, fmt, str::FromStr};
 pub enum Key {
     /// (`ID`).
     Id,
+    /// (`Assay`).
+    Assay,
+    /// (`Ethnicity`).
+    Ethnicity,
+    /// (`Disease`).
+    Disease,
+    /// (`Description`).
+    Description,
+    /// (`DOI`).
+    Doi,
 }
 
 impl AsRef<str> for Key {
     fn as_ref(&self) -> &str {
         match self {
             Self::Id => "ID",
+            Self::Assay => "Assay",
+            Self::Ethnicity => "Ethnicity",
+            Self::Disease => "Disease",
+            Self::Description => "Description",
+            Self::Doi => "DOI",
         }
     }
 }
@@ -50,6 +65,11 @@ impl FromStr for Key {
         match s {
             "" => Err(ParseError::Empty),
             "ID" => Ok(Self::Id),
+            "Assay" => Ok(Self::Assay),
+            "Ethnicity" => Ok(Self::Ethnicity),
+            "Disease" => Ok(Self::Disease),
+            "Description" => Ok(Self::Description),
+            "DOI" => Ok(Self::Doi),
             _ => Err(ParseError::Invalid),
         }
     }
@@ -62,11 +82,21 @@ mod tests {
     #[test]
     fn test_fmt() {
         assert_eq!(Key::Id.to_string(), "ID");
+        assert_eq!(Key::Assay.to_string(), "Assay");
+        assert_eq!(Key::Ethnicity.to_string(), "Ethnicity");
+        assert_eq!(Key::Disease.to_string(), "Disease");
+        assert_eq!(Key::Description.to_string(), "Description");
+        assert_eq!(Key::Doi.to_string(), "DOI");
     }
 
     #[test]
     fn test_from_str() {
         assert_eq!("ID".parse(), Ok(Key::Id));
+        assert_eq!("Assay".parse(), Ok(Key::Assay));
+        assert_eq!("Ethnicity".parse(), Ok(Key::Ethnicity));
+        assert_eq!("Disease".parse(), Ok(Key::Disease));
+        assert_eq!("Description".parse(), Ok(Key::Description));
+        assert_eq!("DOI".parse(), Ok(Key::Doi));
 
         assert_eq!("".parse::<Key>(), Err(ParseError::Empty));
         assert_eq!("Noodles".parse::<Key>(), Err(ParseError::Invalid));
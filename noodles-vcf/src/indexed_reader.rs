@@ -0,0 +1,499 @@
+//! Indexed VCF reader.
+
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{self, Read, Seek},
+    path::Path,
+};
+
+use noodles_bgzf::{self as bgzf, index::optimize_chunks};
+use noodles_core::{path::push_extension, Region};
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+
+use super::{
+    reader::{Query, Records},
+    Header, Reader,
+};
+
+const TABIX_EXTENSION: &str = "tbi";
+const CSI_EXTENSION: &str = "csi";
+
+// The tabix header fields stored in CSI auxiliary data: format, col_seq, col_beg, col_end, meta,
+// skip, and l_nm (all `int32_t`).
+const CSI_AUX_HEADER_LEN: usize = 28;
+
+/// An index associated with an indexed VCF reader.
+#[derive(Debug)]
+pub enum Index {
+    /// A tabix index (`.tbi`).
+    Tabix(tabix::Index),
+    /// A coordinate-sorted index (`.csi`).
+    Csi(csi::Index),
+}
+
+impl From<tabix::Index> for Index {
+    fn from(index: tabix::Index) -> Self {
+        Self::Tabix(index)
+    }
+}
+
+impl From<csi::Index> for Index {
+    fn from(index: csi::Index) -> Self {
+        Self::Csi(index)
+    }
+}
+
+/// A bgzip-compressed VCF reader with an associated index.
+///
+/// This wraps a VCF reader over a BGZF stream, its parsed header, and its tabix or CSI index.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_core::Region;
+/// use noodles_vcf as vcf;
+///
+/// let mut reader = vcf::IndexedReader::from_path("sample.vcf.gz")?;
+///
+/// let region = Region::mapped("sq0", 8, 13);
+///
+/// for result in reader.query(&region)? {
+///     let record = result?;
+///     println!("{}", record);
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<bgzf::Reader<R>>,
+    header: Header,
+    index: Index,
+}
+
+impl IndexedReader<File> {
+    /// Opens a bgzip-compressed VCF file and its index.
+    ///
+    /// The index is discovered by appending `.tbi` to the given path and, if that does not exist,
+    /// `.csi`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let reader = vcf::IndexedReader::from_path("sample.vcf.gz")?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn from_path<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let tabix_src = push_extension(src, TABIX_EXTENSION);
+
+        let index = if tabix_src.exists() {
+            tabix::read(tabix_src).map(Index::from)?
+        } else {
+            let csi_src = push_extension(src, CSI_EXTENSION);
+
+            if csi_src.exists() {
+                csi::read(csi_src).map(Index::from)?
+            } else {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("could not find index for {}", src.display()),
+                ));
+            }
+        };
+
+        let file = File::open(src)?;
+
+        Self::new(file, index)
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read,
+{
+    /// Creates an indexed VCF reader.
+    ///
+    /// The input is expected to be bgzip-compressed. This immediately reads and parses the VCF
+    /// header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let file = File::open("sample.vcf.gz")?;
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    ///
+    /// let reader = vcf::IndexedReader::new(file, index)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn new<I>(inner: R, index: I) -> io::Result<Self>
+    where
+        I: Into<Index>,
+    {
+        let mut inner = Reader::new(bgzf::Reader::new(inner));
+
+        let header = inner
+            .read_header()?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(Self {
+            inner,
+            header,
+            index: index.into(),
+        })
+    }
+
+    /// Returns the VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let reader = vcf::IndexedReader::from_path("sample.vcf.gz")?;
+    /// let header = reader.header();
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_vcf::{self as vcf, indexed_reader::Index};
+    ///
+    /// let reader = vcf::IndexedReader::from_path("sample.vcf.gz")?;
+    ///
+    /// match reader.index() {
+    ///     Index::Tabix(_) => println!("tabix"),
+    ///     Index::Csi(_) => println!("CSI"),
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// Directly after creating the reader, this is the first record after the header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = vcf::IndexedReader::from_path("sample.vcf.gz")?;
+    ///
+    /// for result in reader.records() {
+    ///     let record = result?;
+    ///     println!("{}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records(&mut self) -> Records<'_, bgzf::Reader<R>> {
+        self.inner.records()
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// With a CSI index, the reference sequence names are read from the index auxiliary data, if
+    /// present, and otherwise taken from the order of the header contigs.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_core::Region;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = vcf::IndexedReader::from_path("sample.vcf.gz")?;
+    ///
+    /// let region = Region::mapped("sq0", 8, 13);
+    ///
+    /// for result in reader.query(&region)? {
+    ///     let record = result?;
+    ///     println!("{}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Query<'_, R>> {
+        match &self.index {
+            Index::Tabix(index) => self.inner.query(index, region),
+            Index::Csi(index) => {
+                let (name, start, end) = match region {
+                    Region::Mapped { name, start, end } => (name, *start, *end),
+                    _ => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "region is not mapped",
+                        ))
+                    }
+                };
+
+                let i = resolve_csi_reference_sequence_index(index, &self.header, name)?;
+
+                let chunks: Vec<_> = index
+                    .reference_sequences()
+                    .get(i)
+                    .map(|reference_sequence| {
+                        reference_sequence
+                            .query(
+                                index.min_shift(),
+                                index.depth(),
+                                i64::from(start),
+                                i64::from(end),
+                            )
+                            .iter()
+                            .flat_map(|bin| bin.chunks())
+                            .cloned()
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let merged_chunks = optimize_chunks(&chunks, bgzf::VirtualPosition::default());
+
+                Ok(Query::new(
+                    &mut self.inner,
+                    merged_chunks,
+                    vec![region.clone()],
+                ))
+            }
+        }
+    }
+}
+
+// Reference sequence names are read from the tabix-style auxiliary data when present. Otherwise,
+// they are assumed to be in the order of the header contigs.
+fn resolve_csi_reference_sequence_index(
+    index: &csi::Index,
+    header: &Header,
+    name: &str,
+) -> io::Result<usize> {
+    let i = match parse_csi_reference_sequence_names(index.aux()) {
+        Some(names) => names.iter().position(|n| n == name),
+        None => header.contigs().get_index_of(name),
+    };
+
+    i.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "region reference sequence does not exist in reference sequences: {}",
+                name
+            ),
+        )
+    })
+}
+
+fn parse_csi_reference_sequence_names(aux: &[u8]) -> Option<Vec<String>> {
+    if aux.len() < CSI_AUX_HEADER_LEN {
+        return None;
+    }
+
+    let mut buf = [0; 4];
+    buf.copy_from_slice(&aux[CSI_AUX_HEADER_LEN - 4..CSI_AUX_HEADER_LEN]);
+    let l_nm = usize::try_from(i32::from_le_bytes(buf)).ok()?;
+
+    let names = aux.get(CSI_AUX_HEADER_LEN..CSI_AUX_HEADER_LEN + l_nm)?;
+
+    names
+        .split(|&b| b == 0)
+        .filter(|name| !name.is_empty())
+        .map(|name| String::from_utf8(name.to_vec()).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, io::Cursor};
+
+    use crate::{header::Contig, record::Position, IndexedWriter, Record, Writer};
+
+    use super::*;
+
+    fn build_csi_data(
+        header: &Header,
+        aux: Vec<u8>,
+    ) -> Result<(Vec<u8>, csi::Index), Box<dyn std::error::Error>> {
+        let mut writer = Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(header)?;
+
+        let mut indexer = csi::Index::indexer();
+
+        for (id, chromosome, position) in &[(0, "sq0", 8), (0, "sq0", 13), (1, "sq1", 5)] {
+            let record = Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::try_from(*position)?)
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            let start = writer.get_ref().virtual_position()?;
+            writer.write_record(&record)?;
+            let end = writer.get_ref().virtual_position()?;
+
+            let position = i64::from(*position);
+            indexer.add_record(*id, position, position, bgzf::index::Chunk::new(start, end));
+        }
+
+        writer.get_mut().try_finish()?;
+        let data = writer.get_ref().get_ref().clone();
+
+        let index = indexer.build();
+        let index = csi::Index::builder()
+            .set_min_shift(index.min_shift())
+            .set_depth(index.depth())
+            .set_aux(aux)
+            .set_reference_sequences(index.reference_sequences().to_vec())
+            .build();
+
+        Ok((data, index))
+    }
+
+    fn query_positions<R>(reader: &mut IndexedReader<R>, region: &Region) -> io::Result<Vec<i32>>
+    where
+        R: Read + Seek,
+    {
+        reader
+            .query(region)?
+            .map(|result| result.map(|r| i32::from(r.position())))
+            .collect()
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&Header::default())?;
+
+        for (chromosome, position) in &[("sq0", 8), ("sq0", 13), ("sq1", 5)] {
+            let record = Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::try_from(*position)?)
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_record(&record)?;
+        }
+
+        let (data, index) = writer.finish()?;
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index)?;
+        assert_eq!(reader.header(), &Header::default());
+
+        let region = Region::mapped("sq0", 10, 21);
+        let positions = reader
+            .query(&region)?
+            .map(|result| result.map(|r| i32::from(r.position())))
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(positions, [13]);
+
+        let region = Region::mapped("sq2", 1, 21);
+        assert!(reader.query(&region).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_csi_index() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig(Contig::new(String::from("sq0")))
+            .add_contig(Contig::new(String::from("sq1")))
+            .build();
+
+        let (data, index) = build_csi_data(&header, Vec::new())?;
+        let mut reader = IndexedReader::new(Cursor::new(data), index)?;
+
+        assert_eq!(
+            query_positions(&mut reader, &Region::mapped("sq0", 10, 21))?,
+            [13]
+        );
+        assert_eq!(
+            query_positions(&mut reader, &Region::mapped("sq1", 1, 21))?,
+            [5]
+        );
+        assert!(reader.query(&Region::mapped("sq2", 1, 21)).is_err());
+        assert!(reader.query(&Region::Unmapped).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_csi_index_and_aux_names() -> Result<(), Box<dyn std::error::Error>> {
+        let mut aux = vec![0; CSI_AUX_HEADER_LEN - 4];
+        aux.extend_from_slice(&8i32.to_le_bytes());
+        aux.extend_from_slice(b"sq0\x00sq1\x00");
+
+        // The header has no contigs, so reference sequence names must come from the index.
+        let (data, index) = build_csi_data(&Header::default(), aux)?;
+        let mut reader = IndexedReader::new(Cursor::new(data), index)?;
+
+        assert_eq!(
+            query_positions(&mut reader, &Region::mapped("sq0", 1, 8))?,
+            [8]
+        );
+        assert_eq!(
+            query_positions(&mut reader, &Region::mapped("sq1", 1, 21))?,
+            [5]
+        );
+        assert!(reader.query(&Region::mapped("sq2", 1, 21)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&Header::default())?;
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        writer.write_record(&record)?;
+
+        let (data, index) = writer.finish()?;
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index)?;
+        assert_eq!(reader.records().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_csi_reference_sequence_names() {
+        let mut aux = vec![0; CSI_AUX_HEADER_LEN - 4];
+        aux.extend_from_slice(&8i32.to_le_bytes());
+        aux.extend_from_slice(b"sq0\x00sq1\x00");
+
+        assert_eq!(
+            parse_csi_reference_sequence_names(&aux),
+            Some(vec![String::from("sq0"), String::from("sq1")])
+        );
+
+        assert!(parse_csi_reference_sequence_names(&[]).is_none());
+    }
+}
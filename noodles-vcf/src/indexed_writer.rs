@@ -0,0 +1,317 @@
+use std::io::{self, Write};
+
+use noodles_bgzf::{self as bgzf, index::Chunk};
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+
+use super::{Header, Record};
+
+/// A bgzip-compressed VCF writer that builds a tabix index or coordinate-sorted index (CSI).
+///
+/// Records are written to a BGZF stream, and the chunk of each record is added to the index
+/// using its chromosome, position, and end position. Records must be sorted by coordinate.
+///
+/// # Examples
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// use noodles_vcf::{self as vcf, header::Contig, record::Position};
+///
+/// let mut writer = vcf::IndexedWriter::new(Vec::new());
+///
+/// let header = vcf::Header::builder()
+///     .add_contig(Contig::new(String::from("sq0")))
+///     .build();
+///
+/// writer.write_header(&header)?;
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::try_from(1)?)
+///     .set_reference_bases("A".parse()?)
+///     .build()?;
+///
+/// writer.write_record(&record)?;
+///
+/// let (data, index) = writer.finish()?;
+/// assert_eq!(index.reference_sequence_names(), [String::from("sq0")]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: bgzf::Writer<W>,
+    indexer: tabix::index::Indexer,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::vcf().build());
+
+        Self {
+            inner: bgzf::Writer::new(inner),
+            indexer,
+        }
+    }
+
+    /// Creates an indexed VCF writer that builds a coordinate-sorted index (CSI) with the given
+    /// binning parameters.
+    ///
+    /// A CSI can index positions larger than the 2^29 limit of tabix. Use [`Self::finish_csi`]
+    /// to finish the stream and get the index.
+    ///
+    /// This returns an invalid input error if the binning parameters are invalid (see
+    /// [`tabix::index::Indexer::csi`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::csi(Vec::new(), 14, 6)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn csi(inner: W, min_shift: i32, depth: i32) -> io::Result<Self> {
+        let mut indexer = tabix::index::Indexer::csi(min_shift, depth)?;
+        indexer.set_header(tabix::index::header::Builder::vcf().build());
+
+        Ok(Self {
+            inner: bgzf::Writer::new(inner),
+            indexer,
+        })
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        write!(self.inner, "{}", header)
+    }
+
+    /// Writes a VCF record and adds it to the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    /// writer.write_record(&record)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let start = i32::from(record.position());
+        let end = record
+            .end()
+            .map(i32::from)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let start_position = self.inner.virtual_position()?;
+        writeln!(self.inner, "{}", record)?;
+        let end_position = self.inner.virtual_position()?;
+
+        let reference_sequence_name = record.chromosome().to_string();
+        let chunk = Chunk::new(start_position, end_position);
+        self.indexer
+            .add_record(&reference_sequence_name, start, end, chunk)?;
+
+        Ok(())
+    }
+
+    /// Finishes the output stream and returns the underlying writer and the built tabix index.
+    ///
+    /// This returns an invalid input error if the writer was created to build a CSI (see
+    /// [`Self::csi`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::new(Vec::new());
+    /// writer.write_header(&vcf::Header::default())?;
+    ///
+    /// let (data, index) = writer.finish()?;
+    /// assert!(index.reference_sequence_names().is_empty());
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(self) -> io::Result<(W, tabix::Index)> {
+        let inner = self.inner.finish()?;
+        let index = self.indexer.build()?;
+        Ok((inner, index))
+    }
+
+    /// Finishes the output stream and returns the underlying writer and the built
+    /// coordinate-sorted index (CSI).
+    ///
+    /// This returns an invalid input error if the writer was not created with [`Self::csi`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::IndexedWriter::csi(Vec::new(), 14, 6)?;
+    /// writer.write_header(&vcf::Header::default())?;
+    ///
+    /// let (data, index) = writer.finish_csi()?;
+    /// assert_eq!(index.depth(), 6);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish_csi(self) -> io::Result<(W, csi::Index)> {
+        let inner = self.inner.finish()?;
+        let index = self.indexer.build_csi()?;
+        Ok((inner, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::{record::Position, Reader};
+
+    use super::*;
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = IndexedWriter::new(Vec::new());
+
+        let header = Header::default();
+        writer.write_header(&header)?;
+
+        for (chromosome, position) in &[("sq0", 8), ("sq0", 13), ("sq1", 21)] {
+            let record = Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::try_from(*position)?)
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_record(&record)?;
+        }
+
+        let (data, index) = writer.finish()?;
+
+        assert_eq!(
+            index.reference_sequence_names(),
+            [String::from("sq0"), String::from("sq1")]
+        );
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        let mut reader = Reader::new(bgzf::Reader::new(&data[..]));
+        reader.read_header()?;
+
+        let mut buf = String::new();
+        reader.read_record(&mut buf)?;
+        assert_eq!(buf, "sq0\t8\t.\tA\t.\t.\t.\t.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_at_position_0() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&Header::default())?;
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(0)?)
+            .set_reference_bases("N".parse()?)
+            .build()?;
+
+        writer.write_record(&record)?;
+
+        let (_, index) = writer.finish()?;
+        assert_eq!(index.reference_sequence_names(), [String::from("sq0")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_csi() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = IndexedWriter::csi(Vec::new(), 14, 6)?;
+        writer.write_header(&Header::default())?;
+
+        for (chromosome, position) in &[("sq0", 8), ("sq1", 1 << 30)] {
+            let record = Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::try_from(*position)?)
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_record(&record)?;
+        }
+
+        let (_, index) = writer.finish_csi()?;
+
+        assert_eq!(index.min_shift(), 14);
+        assert_eq!(index.depth(), 6);
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_mismatched_index_format() -> Result<(), Box<dyn std::error::Error>> {
+        let writer = IndexedWriter::new(Vec::new());
+        assert!(matches!(
+            writer.finish_csi(),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let writer = IndexedWriter::csi(Vec::new(), 14, 6)?;
+        assert!(matches!(
+            writer.finish(),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
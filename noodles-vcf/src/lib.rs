@@ -21,8 +21,16 @@
 //! ```
 
 pub mod header;
+pub mod indexed_reader;
+mod indexed_writer;
+pub mod merge;
 mod reader;
 pub mod record;
+pub mod rename;
+pub mod sort;
 mod writer;
 
-pub use self::{header::Header, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    header::Header, indexed_reader::IndexedReader, indexed_writer::IndexedWriter, reader::Reader,
+    record::Record, writer::Writer,
+};
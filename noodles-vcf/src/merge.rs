@@ -0,0 +1,733 @@
+//! VCF merging.
+//!
+//! This combines multiple VCFs that share the same reference into a single VCF. Headers are
+//! unioned, and records are aligned by their site (`CHROM`, `POS`, `REF`, and `ALT`). Each merged
+//! record has a sample column for every input sample, which is set to missing when an input has
+//! no record at that site.
+
+use std::{cmp::Ordering, collections::HashSet, convert::TryFrom, error, fmt, io, iter::Peekable};
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    header::{Contigs, Filters, Formats, Infos, SampleNames},
+    record::{
+        self,
+        genotype::{self, field::Value, Field},
+        Genotype,
+    },
+    Header, Record,
+};
+
+const PASS_STATUS: &str = "PASS";
+const MISSING_ALLELE: &str = ".";
+const UNPHASED_SEPARATOR: char = '/';
+const PHASED_SEPARATOR: char = '|';
+
+/// An error returned when VCF headers fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeHeadersError {
+    /// A sample name is duplicated across headers.
+    DuplicateSampleName(String),
+    /// The number of inputs does not match the number of headers.
+    InputCountMismatch {
+        /// The number of headers.
+        expected: usize,
+        /// The number of inputs.
+        actual: usize,
+    },
+}
+
+impl error::Error for MergeHeadersError {}
+
+impl fmt::Display for MergeHeadersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateSampleName(name) => write!(f, "duplicate sample name: {}", name),
+            Self::InputCountMismatch { expected, actual } => write!(
+                f,
+                "input count mismatch: expected {}, got {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+/// Merges a list of VCF headers into a single header.
+///
+/// The file format is the latest of all inputs. Structured records (`INFO`, `FILTER`, `FORMAT`,
+/// `ALT`, `contig`, `META`, `SAMPLE`, and `PEDIGREE`) are unioned by ID, keeping the first
+/// definition. Sample names are concatenated in input order and must be unique.
+///
+/// If any input defines an `IDX` field, all indices are recomputed for the merged header: the
+/// string dictionary starts with `PASS`, followed by the filter, info, and format IDs, and contig
+/// indices are their positions in the contig list.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, header::Contig};
+///
+/// let header_a = vcf::Header::builder()
+///     .add_contig(Contig::new(String::from("sq0")))
+///     .add_sample_name("sample0")
+///     .build();
+///
+/// let header_b = vcf::Header::builder()
+///     .add_contig(Contig::new(String::from("sq1")))
+///     .add_sample_name("sample1")
+///     .build();
+///
+/// let header = vcf::merge::merge_headers(&[header_a, header_b])?;
+///
+/// assert_eq!(header.contigs().len(), 2);
+/// assert_eq!(header.sample_names().len(), 2);
+/// # Ok::<(), vcf::merge::MergeHeadersError>(())
+/// ```
+pub fn merge_headers(headers: &[Header]) -> Result<Header, MergeHeadersError> {
+    let mut builder = Header::builder();
+
+    if let Some(file_format) = headers.iter().map(|h| h.file_format()).max() {
+        builder = builder.set_file_format(file_format);
+    }
+
+    let mut infos = Infos::new();
+    let mut filters = Filters::new();
+    let mut formats = Formats::new();
+    let mut contigs = Contigs::new();
+    let mut sample_names = SampleNames::new();
+
+    let mut has_idx = false;
+
+    for header in headers {
+        for (key, info) in header.infos() {
+            has_idx |= info.idx().is_some();
+            infos.entry(key.clone()).or_insert_with(|| info.clone());
+        }
+
+        for (id, filter) in header.filters() {
+            has_idx |= filter.idx().is_some();
+            filters.entry(id.clone()).or_insert_with(|| filter.clone());
+        }
+
+        for (key, format) in header.formats() {
+            has_idx |= format.idx().is_some();
+            formats.entry(key.clone()).or_insert_with(|| format.clone());
+        }
+
+        for (id, contig) in header.contigs() {
+            has_idx |= contig.idx().is_some();
+            contigs.entry(id.clone()).or_insert_with(|| contig.clone());
+        }
+
+        for sample_name in header.sample_names() {
+            if !sample_names.insert(sample_name.clone()) {
+                return Err(MergeHeadersError::DuplicateSampleName(sample_name.clone()));
+            }
+        }
+    }
+
+    if has_idx {
+        recompute_indices(&mut infos, &mut filters, &mut formats, &mut contigs);
+    }
+
+    for filter in filters.into_iter().map(|(_, f)| f) {
+        builder = builder.add_filter(filter);
+    }
+
+    for info in infos.into_iter().map(|(_, i)| i) {
+        builder = builder.add_info(info);
+    }
+
+    for format in formats.into_iter().map(|(_, f)| f) {
+        builder = builder.add_format(format);
+    }
+
+    let mut alternative_alleles = IndexSet::new();
+
+    for (id, alternative_allele) in headers.iter().flat_map(|h| h.alternative_alleles()) {
+        if alternative_alleles.insert(id) {
+            builder = builder.add_alternative_allele(alternative_allele.clone());
+        }
+    }
+
+    if let Some(assembly) = headers.iter().find_map(|h| h.assembly()) {
+        builder = builder.set_assembly(assembly);
+    }
+
+    for contig in contigs.into_iter().map(|(_, c)| c) {
+        builder = builder.add_contig(contig);
+    }
+
+    let mut meta = IndexSet::new();
+
+    for (id, m) in headers.iter().flat_map(|h| h.meta()) {
+        if meta.insert(id) {
+            builder = builder.add_meta(m.clone());
+        }
+    }
+
+    let mut samples = IndexSet::new();
+
+    for (id, sample) in headers.iter().flat_map(|h| h.samples()) {
+        if samples.insert(id) {
+            builder = builder.add_sample(sample.clone());
+        }
+    }
+
+    let mut pedigrees = IndexSet::new();
+
+    for (id, pedigree) in headers.iter().flat_map(|h| h.pedigrees()) {
+        if pedigrees.insert(id) {
+            builder = builder.add_pedigree(pedigree.clone());
+        }
+    }
+
+    if let Some(pedigree_db) = headers.iter().find_map(|h| h.pedigree_db()) {
+        builder = builder.set_pedigree_db(pedigree_db);
+    }
+
+    let mut records = HashSet::new();
+
+    for record in headers.iter().flat_map(|h| h.other_records()) {
+        if records.insert(record) {
+            builder = builder.insert(record.clone());
+        }
+    }
+
+    Ok(builder.set_sample_names(sample_names).build())
+}
+
+fn recompute_indices(
+    infos: &mut Infos,
+    filters: &mut Filters,
+    formats: &mut Formats,
+    contigs: &mut Contigs,
+) {
+    let mut string_map = IndexSet::new();
+    string_map.insert(String::from(PASS_STATUS));
+
+    for filter in filters.values_mut() {
+        let (i, _) = string_map.insert_full(filter.id().into());
+        *filter.idx_mut() = Some(i);
+    }
+
+    for info in infos.values_mut() {
+        let (i, _) = string_map.insert_full(info.id().to_string());
+        *info.idx_mut() = Some(i);
+    }
+
+    for format in formats.values_mut() {
+        let (i, _) = string_map.insert_full(format.id().to_string());
+        *format.idx_mut() = Some(i);
+    }
+
+    for (i, contig) in contigs.values_mut().enumerate() {
+        *contig.idx_mut() = Some(i);
+    }
+}
+
+/// A merger of multiple VCF record streams.
+///
+/// Each input must be sorted by coordinate in the same contig order. Records from different
+/// inputs that share the same site are combined into a single record.
+///
+/// Samples of inputs without a record at the site, or without a genotype (`GT`), are given a
+/// missing genotype with the ploidy of the first genotype at the site, e.g., `./.` for a diploid
+/// site.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf as vcf;
+///
+/// let data_a = concat!(
+///     "##fileformat=VCFv4.3\n",
+///     "##contig=<ID=sq0>\n",
+///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\n",
+///     "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0/1\n",
+///     "sq0\t8\t.\tC\tT\t.\tPASS\t.\tGT\t1/1\n",
+/// );
+///
+/// let data_b = concat!(
+///     "##fileformat=VCFv4.3\n",
+///     "##contig=<ID=sq0>\n",
+///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample1\n",
+///     "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t1/1\n",
+/// );
+///
+/// let mut reader_a = vcf::Reader::new(data_a.as_bytes());
+/// let header_a = reader_a.read_header()?.parse()?;
+///
+/// let mut reader_b = vcf::Reader::new(data_b.as_bytes());
+/// let header_b = reader_b.read_header()?.parse()?;
+///
+/// let merger = vcf::merge::Merger::new(
+///     &[header_a, header_b],
+///     vec![reader_a.records(), reader_b.records()],
+/// )?;
+///
+/// let records: Vec<_> = merger.collect::<Result<_, _>>()?;
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0/1\t1/1");
+/// assert_eq!(records[1].to_string(), "sq0\t8\t.\tC\tT\t.\tPASS\t.\tGT\t1/1\t./.");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Merger<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    header: Header,
+    inputs: Vec<Peekable<I>>,
+    sample_counts: Vec<usize>,
+}
+
+impl<I> Merger<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    /// Creates a merger from a list of input headers and their record streams.
+    ///
+    /// The headers are merged using [`merge_headers`]. There must be one input per header, in the
+    /// same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf::{self as vcf, merge::Merger};
+    ///
+    /// let inputs: Vec<std::vec::IntoIter<io::Result<vcf::Record>>> = Vec::new();
+    /// let merger = Merger::new(&[], inputs)?;
+    /// # Ok::<(), vcf::merge::MergeHeadersError>(())
+    /// ```
+    pub fn new(headers: &[Header], inputs: Vec<I>) -> Result<Self, MergeHeadersError> {
+        if inputs.len() != headers.len() {
+            return Err(MergeHeadersError::InputCountMismatch {
+                expected: headers.len(),
+                actual: inputs.len(),
+            });
+        }
+
+        let header = merge_headers(headers)?;
+        let sample_counts = headers.iter().map(|h| h.sample_names().len()).collect();
+        let inputs = inputs.into_iter().map(|i| i.peekable()).collect();
+
+        Ok(Self {
+            header,
+            inputs,
+            sample_counts,
+        })
+    }
+
+    /// Returns the merged header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf::{self as vcf, merge::Merger};
+    ///
+    /// let header = vcf::Header::builder().add_sample_name("sample0").build();
+    /// let inputs: Vec<std::vec::IntoIter<io::Result<vcf::Record>>> = vec![Vec::new().into_iter()];
+    ///
+    /// let merger = Merger::new(&[header], inputs)?;
+    /// assert_eq!(merger.header().sample_names().len(), 1);
+    /// # Ok::<(), vcf::merge::MergeHeadersError>(())
+    /// ```
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    fn merge_records(&self, records: Vec<Option<Record>>) -> io::Result<Record> {
+        let first = records.iter().flatten().next().expect("missing record");
+
+        let mut builder = Record::builder()
+            .set_chromosome(first.chromosome().clone())
+            .set_position(first.position())
+            .set_reference_bases(first.reference_bases().clone())
+            .set_alternate_bases(first.alternate_bases().clone());
+
+        let ids: IndexSet<_> = records
+            .iter()
+            .flatten()
+            .flat_map(|r| r.ids().iter())
+            .collect();
+
+        if !ids.is_empty() {
+            let s = ids.into_iter().cloned().collect::<Vec<_>>().join(";");
+            let ids = s
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            builder = builder.set_ids(ids);
+        }
+
+        if let Some(quality_score) = records
+            .iter()
+            .flatten()
+            .map(|r| r.quality_score())
+            .find(|q| q.is_some())
+        {
+            builder = builder.set_quality_score(quality_score);
+        }
+
+        builder = builder.set_filters(merge_filters(&records));
+
+        let mut info = first.info().clone();
+
+        for field in records.iter().flatten().flat_map(|r| r.info().fields()) {
+            if !info.contains_key(field.key()) {
+                info.insert(field.clone());
+            }
+        }
+
+        builder = builder.set_info(info);
+
+        let sample_count: usize = self.sample_counts.iter().sum();
+
+        if sample_count > 0 {
+            let keys = merge_format_keys(&records);
+            let missing_genotype = build_missing_genotype(&records);
+
+            for (record, &count) in records.iter().zip(&self.sample_counts) {
+                let genotypes = record.as_ref().map(|r| r.genotypes()).unwrap_or_default();
+
+                for i in 0..count {
+                    let fields: Vec<_> = keys
+                        .iter()
+                        .map(|key| {
+                            genotypes
+                                .get(i)
+                                .and_then(|g| g.get(key))
+                                .cloned()
+                                .unwrap_or_else(|| {
+                                    if key == &genotype::field::Key::Genotype {
+                                        Field::new(key.clone(), missing_genotype.clone())
+                                    } else {
+                                        Field::new(key.clone(), None)
+                                    }
+                                })
+                        })
+                        .collect();
+
+                    let genotype = Genotype::try_from(fields)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                    builder = builder.add_genotype(genotype);
+                }
+            }
+
+            let format = record::Format::try_from(keys)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            builder = builder.set_format(format);
+        }
+
+        builder
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl<I> Iterator for Merger<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for input in &mut self.inputs {
+            if let Some(Err(_)) = input.peek() {
+                return input.next();
+            }
+        }
+
+        let mut min: Option<((usize, String, i32), usize)> = None;
+
+        for (i, input) in self.inputs.iter_mut().enumerate() {
+            if let Some(Ok(record)) = input.peek() {
+                let key = sort_key(&self.header, record);
+
+                let is_less = match &min {
+                    Some((min_key, _)) => key.cmp(min_key) == Ordering::Less,
+                    None => true,
+                };
+
+                if is_less {
+                    min = Some((key, i));
+                }
+            }
+        }
+
+        let (_, i) = min?;
+
+        let site = match self.inputs[i].peek() {
+            Some(Ok(record)) => record.clone(),
+            _ => unreachable!(),
+        };
+
+        let records = self
+            .inputs
+            .iter_mut()
+            .map(|input| match input.peek() {
+                Some(Ok(record)) if is_same_site(record, &site) => {
+                    input.next().and_then(|r| r.ok())
+                }
+                _ => None,
+            })
+            .collect();
+
+        Some(self.merge_records(records))
+    }
+}
+
+fn sort_key(header: &Header, record: &Record) -> (usize, String, i32) {
+    let name = record.chromosome().to_string();
+    let rank = header.contigs().get_index_of(&name).unwrap_or(usize::MAX);
+    (rank, name, i32::from(record.position()))
+}
+
+fn is_same_site(a: &Record, b: &Record) -> bool {
+    a.chromosome() == b.chromosome()
+        && a.position() == b.position()
+        && a.reference_bases() == b.reference_bases()
+        && a.alternate_bases() == b.alternate_bases()
+}
+
+fn merge_filters(records: &[Option<Record>]) -> record::Filters {
+    let mut failed_filters = IndexSet::new();
+    let mut is_pass = false;
+
+    for filters in records.iter().flatten().map(|r| r.filters()) {
+        match filters {
+            record::Filters::Missing => {}
+            record::Filters::Pass => is_pass = true,
+            record::Filters::Fail(ids) => failed_filters.extend(ids.iter().cloned()),
+        }
+    }
+
+    if !failed_filters.is_empty() {
+        record::Filters::Fail(failed_filters)
+    } else if is_pass {
+        record::Filters::Pass
+    } else {
+        record::Filters::Missing
+    }
+}
+
+// Builds a missing genotype with the ploidy of the first genotype at the site.
+fn build_missing_genotype(records: &[Option<Record>]) -> Option<Value> {
+    let ploidy = records
+        .iter()
+        .flatten()
+        .flat_map(|r| r.genotypes().iter())
+        .find_map(
+            |genotype| match genotype.get(&genotype::field::Key::Genotype) {
+                Some(field) => match field.value() {
+                    Some(Value::String(s)) => Some(
+                        s.matches(&[UNPHASED_SEPARATOR, PHASED_SEPARATOR][..])
+                            .count()
+                            + 1,
+                    ),
+                    _ => None,
+                },
+                None => None,
+            },
+        )?;
+
+    let alleles = vec![MISSING_ALLELE; ploidy];
+    let mut separator = [0; 4];
+    let separator = UNPHASED_SEPARATOR.encode_utf8(&mut separator);
+
+    Some(Value::String(alleles.join(separator)))
+}
+
+fn merge_format_keys(records: &[Option<Record>]) -> Vec<genotype::field::Key> {
+    let mut keys: IndexMap<genotype::field::Key, ()> = IndexMap::new();
+    keys.insert(genotype::field::Key::Genotype, ());
+
+    for format in records.iter().flatten().filter_map(|r| r.format()) {
+        for key in format.iter() {
+            keys.insert(key.clone(), ());
+        }
+    }
+
+    keys.into_iter().map(|(k, _)| k).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::header::{Contig, Filter, Info};
+
+    use super::*;
+
+    #[test]
+    fn test_merge_headers() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = Header::builder()
+            .add_filter(Filter::new(
+                String::from("q10"),
+                String::from("Quality below 10"),
+            ))
+            .add_contig(Contig::new(String::from("sq0")))
+            .add_sample_name("sample0")
+            .build();
+
+        let header_b = Header::builder()
+            .add_filter(Filter::new(
+                String::from("q10"),
+                String::from("Quality below 10"),
+            ))
+            .add_filter(Filter::new(
+                String::from("s50"),
+                String::from("Less than 50% of samples"),
+            ))
+            .add_contig(Contig::new(String::from("sq0")))
+            .add_contig(Contig::new(String::from("sq1")))
+            .add_sample_name("sample1")
+            .build();
+
+        let header = merge_headers(&[header_a.clone(), header_b])?;
+
+        assert_eq!(header.filters().len(), 2);
+        assert_eq!(header.contigs().len(), 2);
+        assert_eq!(
+            header.sample_names().iter().collect::<Vec<_>>(),
+            ["sample0", "sample1"]
+        );
+        assert!(header.filters()[0].idx().is_none());
+
+        assert_eq!(
+            merge_headers(&[header_a.clone(), header_a]),
+            Err(MergeHeadersError::DuplicateSampleName(String::from(
+                "sample0"
+            )))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_headers_with_idx() -> Result<(), Box<dyn std::error::Error>> {
+        let mut info = Info::from(record::info::field::Key::SamplesWithDataCount);
+        *info.idx_mut() = Some(8);
+
+        let header_a = Header::builder()
+            .add_info(info)
+            .add_contig(Contig::new(String::from("sq1")))
+            .build();
+
+        let header_b = Header::builder()
+            .add_filter(Filter::new(
+                String::from("q10"),
+                String::from("Quality below 10"),
+            ))
+            .add_contig(Contig::new(String::from("sq0")))
+            .build();
+
+        let header = merge_headers(&[header_a, header_b])?;
+
+        assert_eq!(header.filters()[0].idx(), Some(1));
+        assert_eq!(header.infos()[0].idx(), Some(2));
+        assert_eq!(header.contigs()["sq1"].idx(), Some(0));
+        assert_eq!(header.contigs()["sq0"].idx(), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = Header::builder()
+            .add_contig(Contig::new(String::from("sq0")))
+            .add_contig(Contig::new(String::from("sq1")))
+            .add_sample_name("sample0")
+            .build();
+
+        let header_b = Header::builder()
+            .add_contig(Contig::new(String::from("sq0")))
+            .add_contig(Contig::new(String::from("sq1")))
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let records_a = vec![
+            "sq0\t1\tr0\tA\tG\t13\tq10\tNS=1\tGT:GQ\t0/1:8",
+            "sq1\t5\t.\tC\tT\t.\t.\t.\tGT\t1/1",
+        ];
+
+        let records_b = vec![
+            "sq0\t1\tr1\tA\tG\t.\tPASS\tDP=5\tGT\t0/0\t1/1",
+            "sq0\t3\t.\tT\tA\t.\tPASS\t.\tGT\t0/0\t0/1",
+            "sq1\t5\t.\tC\tA\t.\tPASS\t.\tGT\t0/1\t0/1",
+        ];
+
+        let inputs = vec![
+            records_a
+                .into_iter()
+                .map(|s| {
+                    s.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+                .collect::<Vec<_>>()
+                .into_iter(),
+            records_b
+                .into_iter()
+                .map(|s| {
+                    s.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ];
+
+        let merger = Merger::new(&[header_a, header_b], inputs)?;
+        let records: Vec<_> = merger
+            .map(|result| result.map(|r| r.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            records,
+            [
+                "sq0\t1\tr0;r1\tA\tG\t13\tq10\tNS=1;DP=5\tGT:GQ\t0/1:8\t0/0:.\t1/1:.",
+                "sq0\t3\t.\tT\tA\t.\tPASS\t.\tGT\t./.\t0/0\t0/1",
+                "sq1\t5\t.\tC\tT\t.\t.\t.\tGT\t1/1\t./.\t./.",
+                "sq1\t5\t.\tC\tA\t.\tPASS\t.\tGT\t./.\t0/1\t0/1",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_input_count_mismatch() {
+        let headers = [Header::default(), Header::default()];
+        let inputs: Vec<std::vec::IntoIter<io::Result<Record>>> = vec![Vec::new().into_iter()];
+
+        assert!(matches!(
+            Merger::new(&headers, inputs),
+            Err(MergeHeadersError::InputCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_next_with_haploid_genotypes() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = Header::builder().add_sample_name("sample0").build();
+        let header_b = Header::builder().add_sample_name("sample1").build();
+
+        let inputs = vec![
+            vec![Ok("sq0\t1\t.\tA\tG\t.\t.\t.\tGT\t1".parse()?)].into_iter(),
+            Vec::new().into_iter(),
+        ];
+
+        let merger = Merger::new(&[header_a, header_b], inputs)?;
+        let records: Vec<_> = merger
+            .map(|result| result.map(|r| r.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records, ["sq0\t1\t.\tA\tG\t.\t.\t.\tGT\t1\t."]);
+
+        Ok(())
+    }
+}
@@ -2,8 +2,9 @@
 
 mod query;
 mod records;
+mod records_in_region;
 
-pub use self::{query::Query, records::Records};
+pub use self::{query::Query, records::Records, records_in_region::RecordsInRegion};
 
 use std::io::{self, BufRead, Read, Seek};
 
@@ -11,6 +12,8 @@ use noodles_bgzf::{self as bgzf, index::optimize_chunks};
 use noodles_core::Region;
 use noodles_tabix as tabix;
 
+use crate::{Header, Record};
+
 const LINE_FEED: char = '\n';
 const CARRIAGE_RETURN: char = '\r';
 
@@ -46,6 +49,7 @@ const HEADER_PREFIX: u8 = b'#';
 #[derive(Debug)]
 pub struct Reader<R> {
     inner: R,
+    buf: String,
 }
 
 impl<R> Reader<R>
@@ -67,7 +71,10 @@ where
     /// let reader = vcf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            buf: String::new(),
+        }
     }
 
     /// Reads the raw VCF header.
@@ -162,6 +169,59 @@ where
         read_line(&mut self.inner, buf)
     }
 
+    /// Reads and parses a single VCF record using the header definitions.
+    ///
+    /// This reads a line from the underlying stream into an internal buffer and parses it into the
+    /// given record using the header (see [`crate::Record::try_from_str`]). Both the line buffer
+    /// and the buffers of the record fields, e.g., the info and genotype maps, are reused across
+    /// calls. If the record fails to parse, an invalid data error is returned, and the contents of
+    /// the record are unspecified.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// If successful, the number of bytes is returned. If the number of bytes read is 0, the
+    /// stream reached EOF, and the record is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t8\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// reader.read_record_with(&header, &mut record)?;
+    /// assert_eq!(i32::from(record.position()), 8);
+    ///
+    /// assert_eq!(reader.read_record_with(&header, &mut record)?, 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_record_with(&mut self, header: &Header, record: &mut Record) -> io::Result<usize> {
+        self.buf.clear();
+
+        match read_line(&mut self.inner, &mut self.buf)? {
+            0 => Ok(0),
+            n => {
+                crate::record::parse_into(&self.buf, header, record)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                Ok(n)
+            }
+        }
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
@@ -190,6 +250,90 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Returns an iterator over records that intersect the given region, scanning from the
+    /// current stream position.
+    ///
+    /// This does not require an index and can be used with uncompressed VCFs. Records are
+    /// filtered by their chromosome (`CHROM`), start position (`POS`), and end position (`END`
+    /// or the length of the reference bases).
+    ///
+    /// The input is not required to be sorted, so every record to the end of the stream is read.
+    /// Use [`Self::sorted_records_in_region`] to stop early when the input is sorted by
+    /// coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Region;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// sq0\t8\t.\tA\t.\t.\tPASS\t.
+    /// sq1\t13\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let region = Region::mapped("sq0", 5, 10);
+    /// let records: Vec<_> = reader
+    ///     .records_in_region(&header, &region)
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(i32::from(records[0].position()), 8);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records_in_region<'a>(
+        &'a mut self,
+        header: &'a Header,
+        region: &'a Region,
+    ) -> RecordsInRegion<'a, R> {
+        RecordsInRegion::new(self, header, region, false)
+    }
+
+    /// Returns an iterator over records that intersect the given region, scanning from the
+    /// current stream position of a coordinate-sorted VCF.
+    ///
+    /// This is the same as [`Self::records_in_region`], except the scan stops once a record
+    /// starts after the end of the region or is on a different reference sequence after records
+    /// in the region's reference sequence were seen. Records in unsorted input may be missed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Region;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// sq0\t8\t.\tA\t.\t.\tPASS\t.
+    /// sq1\t13\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let region = Region::mapped("sq0", 5, 10);
+    /// let records: Vec<_> = reader
+    ///     .sorted_records_in_region(&header, &region)
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(i32::from(records[0].position()), 8);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sorted_records_in_region<'a>(
+        &'a mut self,
+        header: &'a Header,
+        region: &'a Region,
+    ) -> RecordsInRegion<'a, R> {
+        RecordsInRegion::new(self, header, region, true)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -271,37 +415,58 @@ where
     /// Ok::<(), io::Error>(())
     /// ```
     pub fn query(&mut self, index: &tabix::Index, region: &Region) -> io::Result<Query<'_, R>> {
-        let (i, reference_sequence_name, start, end) = resolve_region(index, region)?;
-
-        let index_reference_sequence = index.reference_sequences().get(i).ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "could not find reference in index: {} >= {}",
-                    i,
-                    index.reference_sequences().len()
-                ),
-            )
-        })?;
-
-        let query_bins = index_reference_sequence.query(start, end);
-
-        let chunks: Vec<_> = query_bins
-            .iter()
-            .flat_map(|bin| bin.chunks())
-            .cloned()
-            .collect();
-
-        let min_offset = index_reference_sequence.min_offset(start);
-        let merged_chunks = optimize_chunks(&chunks, min_offset);
-
-        Ok(Query::new(
-            self,
-            merged_chunks,
-            reference_sequence_name,
-            start,
-            end,
-        ))
+        let chunks = index.query_region(region)?;
+        Ok(Query::new(self, chunks, vec![region.clone()]))
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged before reading, so each block of the file is read at
+    /// most once. Records are returned in file order, and a record that intersects more than one
+    /// region is only returned once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_core::Region;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(vcf::Reader::new)?;
+    ///
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    ///
+    /// let regions = [
+    ///     Region::mapped("sq0", 8, 13),
+    ///     Region::mapped("sq1", 21, 34),
+    /// ];
+    ///
+    /// let query = reader.query_regions(&index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn query_regions(
+        &mut self,
+        index: &tabix::Index,
+        regions: &[Region],
+    ) -> io::Result<Query<'_, R>> {
+        let mut chunks = Vec::new();
+
+        for region in regions {
+            chunks.extend(index.query_region(region)?);
+        }
+
+        let merged_chunks = optimize_chunks(&chunks, bgzf::VirtualPosition::default());
+
+        Ok(Query::new(self, merged_chunks, regions.to_vec()))
     }
 }
 
@@ -329,32 +494,6 @@ where
     }
 }
 
-fn resolve_region(index: &tabix::Index, region: &Region) -> io::Result<(usize, String, i32, i32)> {
-    match region {
-        Region::Mapped { name, start, end } => {
-            let i = index
-                .reference_sequence_names()
-                .iter()
-                .position(|n| name == n)
-                .ok_or_else(|| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidInput,
-                        format!(
-                            "region reference sequence does not exist in reference sequences: {:?}",
-                            region
-                        ),
-                    )
-                })?;
-
-            Ok((i, name.into(), *start, *end))
-        }
-        _ => Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "region is not mapped",
-        )),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::io::BufReader;
@@ -444,6 +583,185 @@ sq0\t13
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with() -> Result<(), Box<dyn std::error::Error>> {
+        use std::convert::TryFrom;
+
+        use crate::record::{
+            info::field::{Key, Value},
+            Position,
+        };
+
+        let data = b"\
+##fileformat=VCFv4.3
+##INFO=<ID=NDLS,Number=1,Type=Integer,Description=\"noodles\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t8\t.\tA\t.\t.\tPASS\tNDLS=5
+sq0\t13\t.\tC\t.\t.\tPASS\tNDLS=8
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header: Header = reader.read_header()?.parse()?;
+
+        let mut record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let key: Key = "NDLS".parse()?;
+
+        reader.read_record_with(&header, &mut record)?;
+        assert_eq!(i32::from(record.position()), 8);
+        assert_eq!(
+            record.info().get(&key).map(|field| field.value()),
+            Some(&Value::Integer(5))
+        );
+
+        reader.read_record_with(&header, &mut record)?;
+        assert_eq!(i32::from(record.position()), 13);
+        assert_eq!(
+            record.info().get(&key).map(|field| field.value()),
+            Some(&Value::Integer(8))
+        );
+
+        assert_eq!(reader.read_record_with(&header, &mut record)?, 0);
+        assert_eq!(i32::from(record.position()), 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{convert::TryFrom, io::Cursor};
+
+        use crate::{record::Position, IndexedWriter};
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&Header::default())?;
+
+        for (chromosome, position) in &[("sq0", 8), ("sq0", 13), ("sq0", 21), ("sq1", 5)] {
+            let record = Record::builder()
+                .set_chromosome(chromosome.parse()?)
+                .set_position(Position::try_from(*position)?)
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            writer.write_record(&record)?;
+        }
+
+        let (data, index) = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        reader.read_header()?;
+
+        let regions = [
+            Region::mapped("sq0", 8, 13),
+            Region::mapped("sq0", 10, 21),
+            Region::mapped("sq1", 1, 10),
+        ];
+
+        let positions = reader
+            .query_regions(&index, &regions)?
+            .map(|result| result.map(|r| (r.chromosome().to_string(), i32::from(r.position()))))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(
+            positions,
+            [
+                (String::from("sq0"), 8),
+                (String::from("sq0"), 13),
+                (String::from("sq0"), 21),
+                (String::from("sq1"), 5),
+            ]
+        );
+
+        assert!(matches!(
+            reader.query(&index, &Region::Unmapped),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_in_region() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tAC\t.\t.\tPASS\t.
+sq0\t3\t.\tA\t.\t.\tPASS\tEND=8
+sq1\t5\t.\tA\t.\t.\tPASS\t.
+sq1\t13\t.\tA\t.\t.\tPASS\t.
+sq2\t8\t.\tA\t.\t.\tPASS\t.
+";
+
+        let collect_positions =
+            |region: Region, is_sorted: bool| -> Result<Vec<_>, Box<dyn std::error::Error>> {
+                let mut reader = Reader::new(&data[..]);
+                let header = reader.read_header()?.parse()?;
+
+                let positions = RecordsInRegion::new(&mut reader, &header, &region, is_sorted)
+                    .map(|result| result.map(|r| i32::from(r.position())))
+                    .collect::<io::Result<_>>()?;
+
+                Ok(positions)
+            };
+
+        for &is_sorted in &[false, true] {
+            assert_eq!(
+                collect_positions(Region::mapped("sq0", 2, 5), is_sorted)?,
+                [1, 3]
+            );
+            assert_eq!(
+                collect_positions(Region::mapped("sq0", 5, 10), is_sorted)?,
+                [3]
+            );
+            assert_eq!(
+                collect_positions(Region::mapped("sq1", 1, 10), is_sorted)?,
+                [5]
+            );
+            assert!(collect_positions(Region::mapped("sq3", 1, 10), is_sorted)?.is_empty());
+            assert_eq!(collect_positions(Region::All, is_sorted)?, [1, 3, 5, 13, 8]);
+            assert!(collect_positions(Region::Unmapped, is_sorted)?.is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_in_region_with_unsorted_input() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t13\t.\tA\t.\t.\tPASS\t.
+sq0\t5\t.\tA\t.\t.\tPASS\t.
+sq1\t8\t.\tA\t.\t.\tPASS\t.
+sq0\t8\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?.parse()?;
+        let region = Region::mapped("sq0", 1, 10);
+
+        let positions: Vec<_> = reader
+            .records_in_region(&header, &region)
+            .map(|result| result.map(|r| i32::from(r.position())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(positions, [5, 8]);
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let positions: Vec<_> = reader
+            .sorted_records_in_region(&header, &region)
+            .map(|result| result.map(|r| i32::from(r.position())))
+            .collect::<io::Result<_>>()?;
+
+        assert!(positions.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -1,31 +1,27 @@
-use std::io::{self, Read, Seek};
+use std::{
+    collections::HashMap,
+    io::{self, Read, Seek},
+};
 
-use noodles_bgzf::{self as bgzf, index::Chunk};
+use noodles_bgzf::{
+    self as bgzf,
+    index::{Chunk, ChunkReader},
+};
+use noodles_core::Region;
 
 use crate::{record::Chromosome, Record};
 
 use super::Reader;
 
-enum State {
-    Seek,
-    Read(bgzf::VirtualPosition),
-    End,
-}
-
-/// An iterator over records of a VCF reader that intersects a given region.
+/// An iterator over records of a VCF reader that intersects a given list of regions.
 ///
-/// This is created by calling [`Reader::query`].
+/// This is created by calling [`Reader::query`] or [`Reader::query_regions`].
 pub struct Query<'a, R>
 where
     R: Read + Seek,
 {
-    reader: &'a mut Reader<bgzf::Reader<R>>,
-    chunks: Vec<Chunk>,
-    reference_sequence_name: String,
-    start: i32,
-    end: i32,
-    i: usize,
-    state: State,
+    reader: Reader<ChunkReader<'a, R>>,
+    intervals: HashMap<String, Intervals>,
     line_buf: String,
 }
 
@@ -36,35 +32,15 @@ where
     pub(crate) fn new(
         reader: &'a mut Reader<bgzf::Reader<R>>,
         chunks: Vec<Chunk>,
-        reference_sequence_name: String,
-        start: i32,
-        end: i32,
+        regions: Vec<Region>,
     ) -> Self {
         Self {
-            reader,
-            chunks,
-            reference_sequence_name,
-            start,
-            end,
-            i: 0,
-            state: State::Seek,
+            reader: Reader::new(ChunkReader::new(&mut reader.inner, chunks)),
+            intervals: build_intervals(regions),
             line_buf: String::new(),
         }
     }
 
-    fn next_chunk(&mut self) -> io::Result<Option<bgzf::VirtualPosition>> {
-        if self.i >= self.chunks.len() {
-            return Ok(None);
-        }
-
-        let chunk = self.chunks[self.i];
-        self.reader.seek(chunk.start())?;
-
-        self.i += 1;
-
-        Ok(Some(chunk.end()))
-    }
-
     fn read_and_parse_record(&mut self) -> Option<io::Result<Record>> {
         self.line_buf.clear();
 
@@ -88,58 +64,138 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            match self.state {
-                State::Seek => {
-                    self.state = match self.next_chunk() {
-                        Ok(Some(chunk_end)) => State::Read(chunk_end),
-                        Ok(None) => State::End,
-                        Err(e) => return Some(Err(e)),
-                    }
-                }
-                State::Read(chunk_end) => match self.read_and_parse_record() {
-                    Some(result) => {
-                        if self.reader.virtual_position() >= chunk_end {
-                            self.state = State::Seek;
-                        }
-
-                        match result {
-                            Ok(record) => {
-                                let reference_sequence_name = match record.chromosome() {
-                                    Chromosome::Name(n) => n.into(),
-                                    Chromosome::Symbol(n) => n.to_string(),
-                                };
-
-                                let start = i32::from(record.position());
-
-                                let end = match record.end() {
-                                    Ok(pos) => i32::from(pos),
-                                    Err(e) => {
-                                        return Some(Err(io::Error::new(
-                                            io::ErrorKind::InvalidData,
-                                            e,
-                                        )))
-                                    }
-                                };
-
-                                if reference_sequence_name == self.reference_sequence_name
-                                    && in_interval(start, end, self.start, self.end)
-                                {
-                                    return Some(Ok(record));
-                                }
-                            }
-                            Err(e) => return Some(Err(e)),
-                        }
-                    }
-                    None => {
-                        self.state = State::Seek;
-                    }
-                },
-                State::End => return None,
+            let record = match self.read_and_parse_record()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let reference_sequence_name = match record.chromosome() {
+                Chromosome::Name(n) => n.into(),
+                Chromosome::Symbol(n) => n.to_string(),
+            };
+
+            let intervals = match self.intervals.get_mut(&reference_sequence_name) {
+                Some(intervals) => intervals,
+                None => continue,
+            };
+
+            let start = i32::from(record.position());
+
+            let end = match record.end() {
+                Ok(pos) => i32::from(pos),
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+
+            if intervals.intersects(start, end) {
+                return Some(Ok(record));
             }
         }
     }
 }
 
-fn in_interval(a_start: i32, a_end: i32, b_start: i32, b_end: i32) -> bool {
-    a_start <= b_end && b_start <= a_end
+// The merged intervals of the regions on a reference sequence, sorted by start position.
+//
+// Records are read in coordinate-sorted order, so the intervals that end before a record starts
+// cannot intersect any later record on the same reference sequence and are skipped for good.
+struct Intervals {
+    intervals: Vec<(i32, i32)>,
+    i: usize,
+    prev_start: i32,
+}
+
+impl Intervals {
+    fn intersects(&mut self, start: i32, end: i32) -> bool {
+        // Restart the sweep if the records are not sorted.
+        if start < self.prev_start {
+            self.i = 0;
+        }
+
+        self.prev_start = start;
+
+        while let Some(&(_, interval_end)) = self.intervals.get(self.i) {
+            if interval_end < start {
+                self.i += 1;
+            } else {
+                break;
+            }
+        }
+
+        self.intervals
+            .get(self.i)
+            .map(|&(interval_start, _)| interval_start <= end)
+            .unwrap_or(false)
+    }
+}
+
+fn build_intervals(regions: Vec<Region>) -> HashMap<String, Intervals> {
+    let mut ranges: HashMap<String, Vec<(i32, i32)>> = HashMap::new();
+
+    for region in regions {
+        if let Region::Mapped { name, start, end } = region {
+            ranges.entry(name).or_default().push((start, end));
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(name, mut ranges)| {
+            ranges.sort_unstable();
+
+            let mut intervals: Vec<(i32, i32)> = Vec::with_capacity(ranges.len());
+
+            for (start, end) in ranges {
+                match intervals.last_mut() {
+                    Some((_, prev_end)) if start <= *prev_end => *prev_end = (*prev_end).max(end),
+                    _ => intervals.push((start, end)),
+                }
+            }
+
+            let intervals = Intervals {
+                intervals,
+                i: 0,
+                prev_start: i32::MIN,
+            };
+
+            (name, intervals)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_intervals() {
+        let intervals = build_intervals(vec![
+            Region::mapped("sq0", 21, 34),
+            Region::mapped("sq0", 5, 8),
+            Region::mapped("sq0", 8, 13),
+            Region::mapped("sq1", 1, 2),
+            Region::Unmapped,
+        ]);
+
+        assert_eq!(intervals.len(), 2);
+        assert_eq!(intervals["sq0"].intervals, [(5, 13), (21, 34)]);
+        assert_eq!(intervals["sq1"].intervals, [(1, 2)]);
+    }
+
+    #[test]
+    fn test_intervals_intersects() {
+        let mut intervals = Intervals {
+            intervals: vec![(5, 13), (21, 34)],
+            i: 0,
+            prev_start: i32::MIN,
+        };
+
+        assert!(!intervals.intersects(1, 4));
+        assert!(intervals.intersects(3, 5));
+        assert!(intervals.intersects(13, 13));
+        assert!(!intervals.intersects(14, 20));
+        assert!(intervals.intersects(20, 40));
+        assert!(!intervals.intersects(35, 40));
+
+        // An unsorted record restarts the sweep.
+        assert!(intervals.intersects(8, 8));
+    }
 }
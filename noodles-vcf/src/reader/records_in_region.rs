@@ -0,0 +1,110 @@
+use std::io::{self, BufRead};
+
+use noodles_core::Region;
+
+use crate::{Header, Record};
+
+use super::Reader;
+
+/// An iterator over records of a VCF reader that intersect a given region.
+///
+/// This is created by calling [`Reader::records_in_region`] or
+/// [`Reader::sorted_records_in_region`].
+pub struct RecordsInRegion<'a, R> {
+    inner: &'a mut Reader<R>,
+    header: &'a Header,
+    region: &'a Region,
+    line_buf: String,
+    is_sorted: bool,
+    is_in_reference_sequence: bool,
+    is_done: bool,
+}
+
+impl<'a, R> RecordsInRegion<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(
+        inner: &'a mut Reader<R>,
+        header: &'a Header,
+        region: &'a Region,
+        is_sorted: bool,
+    ) -> Self {
+        Self {
+            inner,
+            header,
+            region,
+            line_buf: String::new(),
+            is_sorted,
+            is_in_reference_sequence: false,
+            is_done: matches!(region, Region::Unmapped),
+        }
+    }
+
+    fn read_record(&mut self) -> Option<io::Result<Record>> {
+        self.line_buf.clear();
+
+        match self.inner.read_record(&mut self.line_buf) {
+            Ok(0) => None,
+            Ok(_) => Some(
+                Record::try_from_str(&self.line_buf, self.header)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<'a, R> Iterator for RecordsInRegion<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.is_done {
+                return None;
+            }
+
+            let record = match self.read_record()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let (name, start, end) = match self.region {
+                Region::Mapped { name, start, end } => (name, *start, *end),
+                _ => return Some(Ok(record)),
+            };
+
+            if record.chromosome().to_string() != *name {
+                if self.is_sorted && self.is_in_reference_sequence {
+                    self.is_done = true;
+                }
+
+                continue;
+            }
+
+            self.is_in_reference_sequence = true;
+
+            let record_start = i32::from(record.position());
+
+            if record_start > end {
+                if self.is_sorted {
+                    self.is_done = true;
+                }
+
+                continue;
+            }
+
+            let record_end = match record.end() {
+                Ok(pos) => i32::from(pos),
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+
+            if record_end >= start {
+                return Some(Ok(record));
+            }
+        }
+    }
+}
@@ -9,19 +9,25 @@ pub mod format;
 pub mod genotype;
 pub mod ids;
 pub mod info;
+pub mod lazy;
+pub mod lenient;
 pub mod position;
 pub mod quality_score;
 pub mod reference_bases;
+pub mod split;
 pub(crate) mod value;
+pub mod variant_key;
 
 pub use self::{
     alternate_bases::AlternateBases, builder::Builder, chromosome::Chromosome, field::Field,
     filters::Filters, format::Format, genotype::Genotype, ids::Ids, info::Info, position::Position,
-    quality_score::QualityScore, reference_bases::ReferenceBases,
+    quality_score::QualityScore, reference_bases::ReferenceBases, variant_key::VariantKey,
 };
 
 use std::{convert::TryFrom, error, fmt, num, str::FromStr};
 
+use super::Header;
+
 pub(crate) const MISSING_FIELD: &str = ".";
 pub(crate) const FIELD_DELIMITER: char = '\t';
 
@@ -92,6 +98,28 @@ impl Record {
         &self.chromosome
     }
 
+    /// Returns a mutable reference to the chromosome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::{Chromosome, Position}};
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .build()?;
+    ///
+    /// *record.chromosome_mut() = "sq1".parse()?;
+    /// assert_eq!(record.chromosome(), &Chromosome::Name(String::from("sq1")));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn chromosome_mut(&mut self) -> &mut Chromosome {
+        &mut self.chromosome
+    }
+
     /// Returns the start position of the reference bases or indicates a telomeric breakend.
     ///
     /// This field is overloaded. If the record represents a telomere, the telomeric breakends are
@@ -198,6 +226,29 @@ impl Record {
         &self.alternate_bases
     }
 
+    /// Returns a mutable reference to the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::Position};
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C".parse()?)
+    ///     .build()?;
+    ///
+    /// *record.alternate_bases_mut() = "G".parse()?;
+    /// assert_eq!(record.alternate_bases().to_string(), "G");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn alternate_bases_mut(&mut self) -> &mut AlternateBases {
+        &mut self.alternate_bases
+    }
+
     /// Returns the quality score of the record.
     ///
     /// The quality score is a [Phred quality score].
@@ -270,7 +321,7 @@ impl Record {
     ///
     /// let expected = Info::try_from(vec![
     ///     Field::new(Key::SamplesWithDataCount, Value::Integer(3)),
-    ///     Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![0.5])),
+    ///     Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![Some(0.5)])),
     /// ])?;
     ///
     /// assert_eq!(record.info(), &expected);
@@ -280,6 +331,37 @@ impl Record {
         &self.info
     }
 
+    /// Returns a mutable reference to the additional information of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     record::{info::{field::{Key, Value}, Field}, Position},
+    /// };
+    ///
+    /// let mut record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C".parse()?)
+    ///     .set_info("NS=3;AF=0.5".parse()?)
+    ///     .build()?;
+    ///
+    /// record.info_mut().insert(Field::new(
+    ///     Key::AlleleFrequencies,
+    ///     Value::FloatArray(vec![Some(0.25)]),
+    /// ));
+    ///
+    /// assert_eq!(record.info().to_string(), "NS=3;AF=0.25");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn info_mut(&mut self) -> &mut Info {
+        &mut self.info
+    }
+
     /// Returns the format of the genotypes of the record.
     ///
     /// # Examples
@@ -521,60 +603,197 @@ impl FromStr for Record {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut fields = s.split(FIELD_DELIMITER);
+        parse(s, None)
+    }
+}
 
-        let chrom = parse_string(&mut fields, Field::Chromosome)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidChromosome))?;
+impl Record {
+    /// Parses a raw VCF record using header definitions.
+    ///
+    /// INFO and genotype field values are parsed using the number and type defined by the
+    /// matching INFO and FORMAT header records, respectively, if present. Missing values in lists
+    /// are parsed as `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::info::field::{Key, Value}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(vcf::header::Info::from(Key::AlleleFrequencies))
+    ///     .add_info(vcf::header::Info::from(Key::IsInDbSnp))
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.5,.;DB", &header)?;
+    ///
+    /// let info = record.info();
+    ///
+    /// assert_eq!(
+    ///     info.get(&Key::AlleleFrequencies).map(|field| field.value()),
+    ///     Some(&Value::FloatArray(vec![Some(0.5), None])),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     info.get(&Key::IsInDbSnp).map(|field| field.value()),
+    ///     Some(&Value::Flag),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_str(s: &str, header: &Header) -> Result<Self, ParseError> {
+        parse(s, Some(header))
+    }
+}
 
-        let pos = parse_string(&mut fields, Field::Position)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidPosition))?;
+fn parse(s: &str, header: Option<&Header>) -> Result<Record, ParseError> {
+    let mut fields = s.split(FIELD_DELIMITER);
 
-        let ids = parse_string(&mut fields, Field::Ids)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidIds))?;
+    let chrom = parse_string(&mut fields, Field::Chromosome)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidChromosome))?;
 
-        let r#ref = parse_string(&mut fields, Field::ReferenceBases)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidReferenceBases))?;
+    let pos = parse_string(&mut fields, Field::Position)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidPosition))?;
 
-        let alt = parse_string(&mut fields, Field::AlternateBases)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidAlternateBases))?;
+    let ids = parse_string(&mut fields, Field::Ids)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidIds))?;
 
-        let qual = parse_string(&mut fields, Field::QualityScore)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidQualityScore))?;
+    let r#ref = parse_string(&mut fields, Field::ReferenceBases)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidReferenceBases))?;
 
-        let filter = parse_string(&mut fields, Field::Filters)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidFilters))?;
+    let alt: AlternateBases = parse_string(&mut fields, Field::AlternateBases)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidAlternateBases))?;
 
-        let info = parse_string(&mut fields, Field::Info)
-            .and_then(|s| s.parse().map_err(ParseError::InvalidInfo))?;
+    let qual = parse_string(&mut fields, Field::QualityScore)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidQualityScore))?;
 
-        let format = match fields.next() {
-            Some(s) => s.parse().map(Some).map_err(ParseError::InvalidFormat)?,
-            None => None,
-        };
+    let filter = parse_string(&mut fields, Field::Filters)
+        .and_then(|s| s.parse().map_err(ParseError::InvalidFilters))?;
 
-        let genotypes = format
-            .as_ref()
-            .map(|f| {
-                fields
-                    .map(|s| Genotype::from_str_format(s, f))
-                    .collect::<Result<_, _>>()
-                    .map_err(ParseError::InvalidGenotype)
-            })
-            .unwrap_or_else(|| Ok(Vec::new()))?;
-
-        Ok(Self {
-            chromosome: chrom,
-            position: pos,
-            ids,
-            reference_bases: r#ref,
-            alternate_bases: alt,
-            quality_score: qual,
-            filters: filter,
-            info,
-            format,
-            genotypes,
+    let info = parse_string(&mut fields, Field::Info).and_then(|s| {
+        match header {
+            Some(h) => Info::try_from_str_with_file_format(s, h.infos(), h.file_format()),
+            None => s.parse(),
+        }
+        .map_err(ParseError::InvalidInfo)
+    })?;
+
+    let format = match fields.next() {
+        Some(s) => s.parse().map(Some).map_err(ParseError::InvalidFormat)?,
+        None => None,
+    };
+
+    let genotypes = format
+        .as_ref()
+        .map(|f| {
+            fields
+                .map(|s| match header {
+                    Some(h) => Genotype::try_from_str_with_file_format(
+                        s,
+                        f,
+                        h.formats(),
+                        h.file_format(),
+                        Some(alt.len()),
+                    ),
+                    None => Genotype::from_str_format(s, f),
+                })
+                .collect::<Result<_, _>>()
+                .map_err(ParseError::InvalidGenotype)
         })
+        .unwrap_or_else(|| Ok(Vec::new()))?;
+
+    Ok(Record {
+        chromosome: chrom,
+        position: pos,
+        ids,
+        reference_bases: r#ref,
+        alternate_bases: alt,
+        quality_score: qual,
+        filters: filter,
+        info,
+        format,
+        genotypes,
+    })
+}
+
+// Parses a raw VCF record into an existing record using the header definitions, reusing the
+// buffers of its fields.
+//
+// If parsing fails, the record is left in an unspecified state.
+pub(crate) fn parse_into(s: &str, header: &Header, record: &mut Record) -> Result<(), ParseError> {
+    let mut fields = s.split(FIELD_DELIMITER);
+
+    parse_string(&mut fields, Field::Chromosome).and_then(|t| {
+        chromosome::parse_into(t, &mut record.chromosome).map_err(ParseError::InvalidChromosome)
+    })?;
+
+    record.position = parse_string(&mut fields, Field::Position)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidPosition))?;
+
+    record.ids = parse_string(&mut fields, Field::Ids)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidIds))?;
+
+    parse_string(&mut fields, Field::ReferenceBases).and_then(|t| {
+        reference_bases::parse_into(t, &mut record.reference_bases)
+            .map_err(ParseError::InvalidReferenceBases)
+    })?;
+
+    parse_string(&mut fields, Field::AlternateBases).and_then(|t| {
+        alternate_bases::parse_into(t, &mut record.alternate_bases)
+            .map_err(ParseError::InvalidAlternateBases)
+    })?;
+
+    record.quality_score = parse_string(&mut fields, Field::QualityScore)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidQualityScore))?;
+
+    record.filters = parse_string(&mut fields, Field::Filters)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidFilters))?;
+
+    parse_string(&mut fields, Field::Info).and_then(|t| {
+        info::parse_into(t, header.infos(), header.file_format(), &mut record.info)
+            .map_err(ParseError::InvalidInfo)
+    })?;
+
+    record.format = match fields.next() {
+        Some(t) => t.parse().map(Some).map_err(ParseError::InvalidFormat)?,
+        None => None,
+    };
+
+    let alternate_allele_count = Some(record.alternate_bases.len());
+    let genotypes = &mut record.genotypes;
+
+    match &record.format {
+        Some(format) => {
+            let mut n = 0;
+
+            for t in fields {
+                match genotypes.get_mut(n) {
+                    Some(genotype) => genotype::parse_into(
+                        t,
+                        format,
+                        header.formats(),
+                        header.file_format(),
+                        alternate_allele_count,
+                        genotype,
+                    ),
+                    None => Genotype::try_from_str_with_file_format(
+                        t,
+                        format,
+                        header.formats(),
+                        header.file_format(),
+                        alternate_allele_count,
+                    )
+                    .map(|genotype| genotypes.push(genotype)),
+                }
+                .map_err(ParseError::InvalidGenotype)?;
+
+                n += 1;
+            }
+
+            genotypes.truncate(n);
+        }
+        None => genotypes.clear(),
     }
+
+    Ok(())
 }
 
 fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
@@ -714,4 +933,93 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_str_with_missing_array_values() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "sq0\t1\t.\tA\tC\t.\tPASS\tAD=.,10\tGT:AD\t0/1:5,.";
+        let record: Record = s.parse()?;
+
+        assert_eq!(
+            record
+                .info()
+                .get(&info::field::Key::TotalReadDepths)
+                .map(|field| field.value()),
+            Some(&info::field::Value::IntegerArray(vec![None, Some(10)]))
+        );
+
+        assert_eq!(
+            record.genotypes()[0]
+                .get(&genotype::field::Key::ReadDepths)
+                .and_then(|field| field.value()),
+            Some(&genotype::field::Value::IntegerArray(vec![Some(5), None]))
+        );
+
+        assert_eq!(record.to_string(), s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_str_with_file_format() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::FileFormat;
+
+        let s = "sq0\t1\t.\tA\t.\t.\tPASS\tNDLS=a%3Bb\tGT:NDLS\t0|0:c%3Dd";
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
+
+        let key: info::field::Key = "NDLS".parse()?;
+
+        let record = Record::try_from_str(s, &header)?;
+        assert_eq!(
+            record.info().get(&key).map(|field| field.value()),
+            Some(&info::field::Value::String(String::from("a;b")))
+        );
+        assert_eq!(
+            record.to_string(),
+            "sq0\t1\t.\tA\t.\t.\tPASS\tNDLS=a;b\tGT:NDLS\t0|0:c=d"
+        );
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 2))
+            .build();
+
+        let record = Record::try_from_str(s, &header)?;
+        assert_eq!(
+            record.info().get(&key).map(|field| field.value()),
+            Some(&info::field::Value::String(String::from("a%3Bb")))
+        );
+        assert_eq!(record.to_string(), s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_into() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let mut record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        for s in &[
+            "sq0\t1\t.\tA\t.\t.\tPASS\t.",
+            "sq1\t8\tr0;r1\tAC\tG,T\t13\tq10\tNS=2;DP=13\tGT:GQ\t0|1:8\t1/1:13",
+            "<sq2>\t13\t.\tg\t<DEL>\t.\t.\tDP=8\tGT\t0/0",
+            "sq0\t21\t.\tT\t.\t.\tPASS\tNS=1",
+        ] {
+            parse_into(s, &header, &mut record)?;
+            assert_eq!(record, Record::try_from_str(s, &header)?);
+        }
+
+        assert!(parse_into("sq0\t1\t.\tN\t.", &header, &mut record).is_err());
+
+        Ok(())
+    }
 }
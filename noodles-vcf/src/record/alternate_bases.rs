@@ -70,14 +70,27 @@ impl FromStr for AlternateBases {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "" => Err(ParseError::Empty),
-            MISSING_FIELD => Ok(Self::default()),
-            _ => s
-                .split(DELIMITER)
-                .map(|s| s.parse().map_err(ParseError::InvalidAllele))
-                .collect::<Result<_, _>>()
-                .map(Self),
+        let mut alternate_bases = Self::default();
+        parse_into(s, &mut alternate_bases)?;
+        Ok(alternate_bases)
+    }
+}
+
+// Parses raw alternate bases into existing alternate bases, reusing its buffer.
+pub(crate) fn parse_into(s: &str, alternate_bases: &mut AlternateBases) -> Result<(), ParseError> {
+    let alleles = &mut alternate_bases.0;
+    alleles.clear();
+
+    match s {
+        "" => Err(ParseError::Empty),
+        MISSING_FIELD => Ok(()),
+        _ => {
+            for t in s.split(DELIMITER) {
+                let allele = t.parse().map_err(ParseError::InvalidAllele)?;
+                alleles.push(allele);
+            }
+
+            Ok(())
         }
     }
 }
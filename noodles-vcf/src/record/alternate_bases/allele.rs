@@ -1,8 +1,9 @@
-//! VCF record alternate bases allele and symbol.
+//! VCF record alternate bases allele, breakend, and symbol.
 
+pub mod breakend;
 pub mod symbol;
 
-pub use self::symbol::Symbol;
+pub use self::{breakend::Breakend, symbol::Symbol};
 
 use std::{convert::TryFrom, error, fmt, str::FromStr};
 
@@ -16,7 +17,7 @@ pub enum Allele {
     /// A symbolic allele (e.g., `<DEL>`, `<CN:0>`, etc.).
     Symbol(Symbol),
     /// A breakend (e.g., `]sq0:5]A`, `G.`, etc.).
-    Breakend(String),
+    Breakend(Breakend),
     /// An overlapping deletion, i.e., a missing allele (`*`).
     OverlappingDeletion,
 }
@@ -32,7 +33,7 @@ impl fmt::Display for Allele {
                 Ok(())
             }
             Self::Symbol(symbol) => write!(f, "<{}>", symbol),
-            Self::Breakend(breakend) => f.write_str(breakend),
+            Self::Breakend(breakend) => write!(f, "{}", breakend),
             Self::OverlappingDeletion => f.write_str("*"),
         }
     }
@@ -45,6 +46,8 @@ pub enum ParseError {
     Empty,
     /// The symbol is invalid.
     InvalidSymbol(symbol::ParseError),
+    /// The breakend is invalid.
+    InvalidBreakend(breakend::ParseError),
     /// A base is invalid.
     InvalidBase(base::TryFromCharError),
 }
@@ -56,6 +59,7 @@ impl fmt::Display for ParseError {
         match self {
             Self::Empty => f.write_str("empty input"),
             Self::InvalidSymbol(e) => write!(f, "invalid symbol: {}", e),
+            Self::InvalidBreakend(e) => write!(f, "invalid breakend: {}", e),
             Self::InvalidBase(e) => write!(f, "invalid base: {}", e),
         }
     }
@@ -74,9 +78,10 @@ impl FromStr for Allele {
                         .parse()
                         .map(Self::Symbol)
                         .map_err(ParseError::InvalidSymbol)
-                } else if s.contains(|c| c == '[' || c == ']') || (s.len() == 2 && s.contains('.'))
-                {
-                    Ok(Self::Breakend(s.into()))
+                } else if s.contains(|c| c == '[' || c == ']') || s.contains('.') {
+                    s.parse()
+                        .map(Self::Breakend)
+                        .map_err(ParseError::InvalidBreakend)
                 } else {
                     s.chars()
                         .map(|c| c.to_ascii_uppercase())
@@ -95,7 +100,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_fmt() {
+    fn test_fmt() -> Result<(), breakend::ParseError> {
         let allele = Allele::Bases(vec![Base::G]);
         assert_eq!(allele.to_string(), "G");
 
@@ -113,21 +118,29 @@ mod tests {
         let allele = Allele::Symbol(Symbol::NonstructuralVariant(String::from("CN:0")));
         assert_eq!(allele.to_string(), "<CN:0>");
 
-        let allele = Allele::Breakend(String::from("]sq0:5]A"));
+        let allele = Allele::Symbol(Symbol::NonReference);
+        assert_eq!(allele.to_string(), "<NON_REF>");
+
+        let allele = Allele::Symbol(Symbol::Unspecified);
+        assert_eq!(allele.to_string(), "<*>");
+
+        let allele = Allele::Breakend("]sq0:5]A".parse()?);
         assert_eq!(allele.to_string(), "]sq0:5]A");
 
-        let allele = Allele::Breakend(String::from("C[sq1:13["));
+        let allele = Allele::Breakend("C[sq1:13[".parse()?);
         assert_eq!(allele.to_string(), "C[sq1:13[");
 
-        let allele = Allele::Breakend(String::from("G."));
+        let allele = Allele::Breakend("G.".parse()?);
         assert_eq!(allele.to_string(), "G.");
 
-        let allele = Allele::Breakend(String::from(".A"));
+        let allele = Allele::Breakend(".A".parse()?);
         assert_eq!(allele.to_string(), ".A");
+
+        Ok(())
     }
 
     #[test]
-    fn test_from_str() {
+    fn test_from_str() -> Result<(), breakend::ParseError> {
         assert_eq!("G".parse::<Allele>(), Ok(Allele::Bases(vec![Base::G])));
 
         assert_eq!(
@@ -157,33 +170,43 @@ mod tests {
         );
 
         assert_eq!(
-            "]sq0:5]A".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("]sq0:5]A")))
+            "<NON_REF>".parse::<Allele>(),
+            Ok(Allele::Symbol(Symbol::NonReference))
         );
 
         assert_eq!(
-            "C[sq1:13[".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("C[sq1:13[")))
+            "<*>".parse::<Allele>(),
+            Ok(Allele::Symbol(Symbol::Unspecified))
         );
 
         assert_eq!(
-            "G.".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from("G.")))
+            "]sq0:5]A".parse::<Allele>(),
+            Ok(Allele::Breakend("]sq0:5]A".parse()?))
         );
 
         assert_eq!(
-            ".A".parse::<Allele>(),
-            Ok(Allele::Breakend(String::from(".A")))
+            "C[sq1:13[".parse::<Allele>(),
+            Ok(Allele::Breakend("C[sq1:13[".parse()?))
         );
 
+        assert_eq!("G.".parse::<Allele>(), Ok(Allele::Breakend("G.".parse()?)));
+
+        assert_eq!(".A".parse::<Allele>(), Ok(Allele::Breakend(".A".parse()?)));
+
         assert_eq!("".parse::<Allele>(), Err(ParseError::Empty));
         assert!(matches!(
             "<>".parse::<Allele>(),
             Err(ParseError::InvalidSymbol(_))
         ));
+        assert!(matches!(
+            "G[sq0:5]".parse::<Allele>(),
+            Err(ParseError::InvalidBreakend(_))
+        ));
         assert!(matches!(
             "Z".parse::<Allele>(),
             Err(ParseError::InvalidBase(_))
         ));
+
+        Ok(())
     }
 }
@@ -0,0 +1,285 @@
+//! VCF record alternate bases allele breakend and mate.
+
+pub mod mate;
+
+pub use self::mate::Mate;
+
+use std::{error, fmt, str::FromStr};
+
+const MISSING_MATE: char = '.';
+
+/// The side of the sequence the breakend join is on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    /// The join is to the left of the sequence (e.g., `.G`, `]sq0:5]G`, `[sq0:5[G`).
+    Left,
+    /// The join is to the right of the sequence (e.g., `G.`, `G]sq0:5]`, `G[sq0:5[`).
+    Right,
+}
+
+/// A VCF record alternate bases allele breakend.
+///
+/// A breakend is a sequence (i.e., the reference base and any inserted bases) and a join to
+/// either a mate position or, for single breakends, an unknown position (`.`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breakend {
+    sequence: String,
+    orientation: Orientation,
+    mate: Option<Mate>,
+}
+
+impl Breakend {
+    /// Creates a breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::{breakend::Orientation, Breakend};
+    /// let breakend = Breakend::new(String::from("G"), Orientation::Right, None);
+    /// ```
+    pub fn new(sequence: String, orientation: Orientation, mate: Option<Mate>) -> Self {
+        Self {
+            sequence,
+            orientation,
+            mate,
+        }
+    }
+
+    /// Returns the sequence of the breakend.
+    ///
+    /// This includes the reference base and any inserted bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::Breakend;
+    /// let breakend: Breakend = "GAC]sq0:5]".parse()?;
+    /// assert_eq!(breakend.sequence(), "GAC");
+    /// # Ok::<(), noodles_vcf::record::alternate_bases::allele::breakend::ParseError>(())
+    /// ```
+    pub fn sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    /// Returns the side of the sequence the join is on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::{breakend::Orientation, Breakend};
+    ///
+    /// let breakend: Breakend = "G]sq0:5]".parse()?;
+    /// assert_eq!(breakend.orientation(), Orientation::Right);
+    ///
+    /// let breakend: Breakend = ".G".parse()?;
+    /// assert_eq!(breakend.orientation(), Orientation::Left);
+    /// # Ok::<(), noodles_vcf::record::alternate_bases::allele::breakend::ParseError>(())
+    /// ```
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the mate of the breakend.
+    ///
+    /// This is `None` for single breakends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::{breakend::{mate::Direction, Mate}, Breakend},
+    ///     Position,
+    /// };
+    ///
+    /// let breakend: Breakend = "G]sq0:5]".parse()?;
+    /// assert_eq!(
+    ///     breakend.mate(),
+    ///     Some(&Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left)),
+    /// );
+    ///
+    /// let breakend: Breakend = "G.".parse()?;
+    /// assert!(breakend.mate().is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn mate(&self) -> Option<&Mate> {
+        self.mate.as_ref()
+    }
+}
+
+impl fmt::Display for Breakend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.orientation() == Orientation::Right {
+            f.write_str(self.sequence())?;
+        }
+
+        if let Some(mate) = self.mate() {
+            write!(f, "{}", mate)?;
+        } else {
+            write!(f, "{}", MISSING_MATE)?;
+        }
+
+        if self.orientation() == Orientation::Left {
+            f.write_str(self.sequence())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw VCF record alternate bases allele breakend fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The sequence is missing.
+    MissingSequence,
+    /// The input is invalid.
+    ///
+    /// The sequence must be on exactly one side of the join.
+    Invalid,
+    /// The mate is invalid.
+    InvalidMate(mate::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MissingSequence => f.write_str("missing sequence"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidMate(e) => write!(f, "invalid mate: {}", e),
+        }
+    }
+}
+
+impl FromStr for Breakend {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (prefix, raw_mate, suffix) = if let Some(i) = s.find(is_bracket) {
+            let j = s.rfind(is_bracket).map(|j| j + 1).unwrap_or(s.len());
+            (&s[..i], Some(&s[i..j]), &s[j..])
+        } else if let Some(t) = s.strip_suffix(MISSING_MATE) {
+            (t, None, "")
+        } else if let Some(t) = s.strip_prefix(MISSING_MATE) {
+            ("", None, t)
+        } else {
+            return Err(ParseError::Invalid);
+        };
+
+        let (sequence, orientation) = match (prefix.is_empty(), suffix.is_empty()) {
+            (false, true) => (prefix, Orientation::Right),
+            (true, false) => (suffix, Orientation::Left),
+            (true, true) => return Err(ParseError::MissingSequence),
+            (false, false) => return Err(ParseError::Invalid),
+        };
+
+        if sequence.contains(MISSING_MATE) {
+            return Err(ParseError::Invalid);
+        }
+
+        let mate = raw_mate
+            .map(|t| t.parse().map_err(ParseError::InvalidMate))
+            .transpose()?;
+
+        Ok(Self::new(sequence.into(), orientation, mate))
+    }
+}
+
+fn is_bracket(c: char) -> bool {
+    matches!(c, '[' | ']')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::record::Position;
+
+    use super::{mate::Direction, *};
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let breakend = Breakend::new(String::from("G"), Orientation::Right, None);
+        assert_eq!(breakend.to_string(), "G.");
+
+        let breakend = Breakend::new(String::from("A"), Orientation::Left, None);
+        assert_eq!(breakend.to_string(), ".A");
+
+        let mate = Mate::new("17".parse()?, Position::try_from(198982)?, Direction::Left);
+        let breakend = Breakend::new(String::from("G"), Orientation::Right, Some(mate));
+        assert_eq!(breakend.to_string(), "G]17:198982]");
+
+        let mate = Mate::new("sq1".parse()?, Position::try_from(13)?, Direction::Right);
+        let breakend = Breakend::new(String::from("T"), Orientation::Left, Some(mate));
+        assert_eq!(breakend.to_string(), "[sq1:13[T");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            "G.".parse(),
+            Ok(Breakend::new(String::from("G"), Orientation::Right, None))
+        );
+
+        assert_eq!(
+            ".GTA".parse(),
+            Ok(Breakend::new(String::from("GTA"), Orientation::Left, None))
+        );
+
+        let mate = Mate::new("17".parse()?, Position::try_from(198982)?, Direction::Left);
+        assert_eq!(
+            "G]17:198982]".parse(),
+            Ok(Breakend::new(
+                String::from("G"),
+                Orientation::Right,
+                Some(mate)
+            ))
+        );
+
+        let mate = Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left);
+        assert_eq!(
+            "]sq0:5]AGT".parse(),
+            Ok(Breakend::new(
+                String::from("AGT"),
+                Orientation::Left,
+                Some(mate)
+            ))
+        );
+
+        let mate = Mate::new("sq1".parse()?, Position::try_from(13)?, Direction::Right);
+        assert_eq!(
+            "C[sq1:13[".parse(),
+            Ok(Breakend::new(
+                String::from("C"),
+                Orientation::Right,
+                Some(mate)
+            ))
+        );
+
+        assert_eq!("".parse::<Breakend>(), Err(ParseError::Empty));
+        assert_eq!(".".parse::<Breakend>(), Err(ParseError::MissingSequence));
+        assert_eq!(
+            "[sq0:5[".parse::<Breakend>(),
+            Err(ParseError::MissingSequence)
+        );
+        assert_eq!("G".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G.A".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G[sq0:5[A".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert!(matches!(
+            "G[sq0:5]".parse::<Breakend>(),
+            Err(ParseError::InvalidMate(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+//! VCF record alternate bases allele breakend mate.
+
+use std::{convert::TryFrom, error, fmt, str::FromStr};
+
+use noodles_core::Region;
+
+use crate::record::{chromosome, position, Chromosome, Position};
+
+const SEPARATOR: char = ':';
+
+/// The direction of a joined sequence relative to the mate position.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// The joined sequence extends to the left of the mate position (`]`).
+    Left,
+    /// The joined sequence extends to the right of the mate position (`[`).
+    Right,
+}
+
+impl From<Direction> for char {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Left => ']',
+            Direction::Right => '[',
+        }
+    }
+}
+
+/// A VCF record alternate bases allele breakend mate.
+///
+/// This is the bracketed position of the mate breakend, e.g., `]sq0:5]` in `G]sq0:5]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mate {
+    chromosome: Chromosome,
+    position: Position,
+    direction: Direction,
+}
+
+impl Mate {
+    /// Creates a breakend mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::breakend::{mate::Direction, Mate},
+    ///     Position,
+    /// };
+    ///
+    /// let mate = Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(chromosome: Chromosome, position: Position, direction: Direction) -> Self {
+        Self {
+            chromosome,
+            position,
+            direction,
+        }
+    }
+
+    /// Returns the chromosome of the mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::breakend::{mate::Direction, Mate},
+    ///     Chromosome, Position,
+    /// };
+    ///
+    /// let mate = Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left);
+    /// assert_eq!(mate.chromosome(), &Chromosome::Name(String::from("sq0")));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+
+    /// Returns the position of the mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::breakend::{mate::Direction, Mate},
+    ///     Position,
+    /// };
+    ///
+    /// let mate = Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left);
+    /// assert_eq!(i32::from(mate.position()), 5);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the direction of the joined sequence relative to the mate position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::breakend::{mate::Direction, Mate},
+    ///     Position,
+    /// };
+    ///
+    /// let mate = Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left);
+    /// assert_eq!(mate.direction(), Direction::Left);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Creates a breakend mate from the start of a genomic region.
+    ///
+    /// Both the region and mate positions are 1-based. The region must be mapped, and its end
+    /// position is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Region;
+    /// use noodles_vcf::record::alternate_bases::allele::breakend::{mate::Direction, Mate};
+    ///
+    /// let region = Region::mapped("sq0", 5, 5);
+    /// let mate = Mate::try_from_region(&region, Direction::Left)?;
+    /// assert_eq!(mate.to_string(), "]sq0:5]");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_region(
+        region: &Region,
+        direction: Direction,
+    ) -> Result<Self, TryFromRegionError> {
+        match region {
+            Region::Mapped { name, start, .. } => {
+                let chromosome = name
+                    .parse()
+                    .map_err(TryFromRegionError::InvalidChromosome)?;
+                let position =
+                    Position::try_from(*start).map_err(TryFromRegionError::InvalidPosition)?;
+                Ok(Self::new(chromosome, position, direction))
+            }
+            _ => Err(TryFromRegionError::Unmapped),
+        }
+    }
+}
+
+/// An error returned when a genomic region fails to convert to a breakend mate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryFromRegionError {
+    /// The region is not mapped.
+    Unmapped,
+    /// The region reference sequence name is not a valid chromosome.
+    InvalidChromosome(chromosome::ParseError),
+    /// The region start position is invalid.
+    InvalidPosition(position::TryFromIntError),
+}
+
+impl error::Error for TryFromRegionError {}
+
+impl fmt::Display for TryFromRegionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unmapped => f.write_str("unmapped region"),
+            Self::InvalidChromosome(e) => write!(f, "invalid chromosome: {}", e),
+            Self::InvalidPosition(e) => write!(f, "invalid position: {}", e),
+        }
+    }
+}
+
+/// An error returned when a breakend mate fails to convert to a genomic region.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryIntoRegionError {
+    /// The chromosome is a symbol (e.g., `<ctg0>`), which is not a reference sequence name.
+    SymbolicChromosome,
+}
+
+impl error::Error for TryIntoRegionError {}
+
+impl fmt::Display for TryIntoRegionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SymbolicChromosome => f.write_str("symbolic chromosome"),
+        }
+    }
+}
+
+/// Converts a breakend mate to a single-position genomic region.
+///
+/// Both the mate and region positions are 1-based. A telomeric mate position (0) is converted to
+/// the first position of the reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use noodles_core::Region;
+/// use noodles_vcf::record::alternate_bases::allele::breakend::Mate;
+///
+/// let mate: Mate = "]sq0:5]".parse()?;
+/// assert_eq!(Region::try_from(&mate)?, Region::mapped("sq0", 5, 5));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl TryFrom<&Mate> for Region {
+    type Error = TryIntoRegionError;
+
+    fn try_from(mate: &Mate) -> Result<Self, Self::Error> {
+        match mate.chromosome() {
+            Chromosome::Name(name) => {
+                let position = i32::from(mate.position()).max(1);
+                Ok(Region::mapped(name.as_str(), position, position))
+            }
+            Chromosome::Symbol(_) => Err(TryIntoRegionError::SymbolicChromosome),
+        }
+    }
+}
+
+impl fmt::Display for Mate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = char::from(self.direction());
+
+        write!(
+            f,
+            "{}{}{}{}{}",
+            c,
+            self.chromosome(),
+            SEPARATOR,
+            i32::from(self.position()),
+            c
+        )
+    }
+}
+
+/// An error returned when a raw VCF record alternate bases allele breakend mate fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The direction is invalid.
+    ///
+    /// The mate must be enclosed by the same type of brackets, i.e., `[` or `]`.
+    InvalidDirection,
+    /// The position is missing.
+    MissingPosition,
+    /// The chromosome is invalid.
+    InvalidChromosome(chromosome::ParseError),
+    /// The position is invalid.
+    InvalidPosition(position::ParseError),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidDirection => f.write_str("invalid direction"),
+            Self::MissingPosition => f.write_str("missing position"),
+            Self::InvalidChromosome(e) => write!(f, "invalid chromosome: {}", e),
+            Self::InvalidPosition(e) => write!(f, "invalid position: {}", e),
+        }
+    }
+}
+
+impl FromStr for Mate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let direction = match (s.chars().next(), s.chars().last()) {
+            (Some('['), Some('[')) if s.len() > 1 => Direction::Right,
+            (Some(']'), Some(']')) if s.len() > 1 => Direction::Left,
+            _ => return Err(ParseError::InvalidDirection),
+        };
+
+        let t = &s[1..s.len() - 1];
+        let mut components = t.rsplitn(2, SEPARATOR);
+
+        let raw_position = components.next().unwrap_or_default();
+        let raw_chromosome = components.next().ok_or(ParseError::MissingPosition)?;
+
+        let chromosome = raw_chromosome
+            .parse()
+            .map_err(ParseError::InvalidChromosome)?;
+
+        let position = raw_position.parse().map_err(ParseError::InvalidPosition)?;
+
+        Ok(Self::new(chromosome, position, direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_region() -> Result<(), Box<dyn std::error::Error>> {
+        let region = Region::mapped("sq0", 5, 8);
+        assert_eq!(
+            Mate::try_from_region(&region, Direction::Right)?,
+            Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Right)
+        );
+
+        assert_eq!(
+            Mate::try_from_region(&Region::Unmapped, Direction::Right),
+            Err(TryFromRegionError::Unmapped)
+        );
+
+        let region = Region::mapped("sq0", -1, 8);
+        assert!(matches!(
+            Mate::try_from_region(&region, Direction::Right),
+            Err(TryFromRegionError::InvalidPosition(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_mate_for_region() -> Result<(), ParseError> {
+        let mate: Mate = "[sq0:13[".parse()?;
+        assert_eq!(Region::try_from(&mate), Ok(Region::mapped("sq0", 13, 13)));
+
+        let mate: Mate = "]sq0:0]".parse()?;
+        assert_eq!(Region::try_from(&mate), Ok(Region::mapped("sq0", 1, 1)));
+
+        let mate: Mate = "[<ctg0>:5[".parse()?;
+        assert_eq!(
+            Region::try_from(&mate),
+            Err(TryIntoRegionError::SymbolicChromosome)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let mate = Mate::new("sq0".parse()?, Position::try_from(5)?, Direction::Left);
+        assert_eq!(mate.to_string(), "]sq0:5]");
+
+        let mate = Mate::new("<sq1>".parse()?, Position::try_from(13)?, Direction::Right);
+        assert_eq!(mate.to_string(), "[<sq1>:13[");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            "]sq0:5]".parse(),
+            Ok(Mate::new(
+                "sq0".parse()?,
+                Position::try_from(5)?,
+                Direction::Left
+            ))
+        );
+
+        assert_eq!(
+            "[<sq1>:13[".parse(),
+            Ok(Mate::new(
+                Chromosome::Symbol(String::from("sq1")),
+                Position::try_from(13)?,
+                Direction::Right
+            ))
+        );
+
+        assert_eq!("".parse::<Mate>(), Err(ParseError::Empty));
+        assert_eq!("]".parse::<Mate>(), Err(ParseError::InvalidDirection));
+        assert_eq!("]sq0:5[".parse::<Mate>(), Err(ParseError::InvalidDirection));
+        assert_eq!("sq0:5".parse::<Mate>(), Err(ParseError::InvalidDirection));
+        assert_eq!("]sq0]".parse::<Mate>(), Err(ParseError::MissingPosition));
+        assert!(matches!(
+            "]:5]".parse::<Mate>(),
+            Err(ParseError::InvalidChromosome(_))
+        ));
+        assert!(matches!(
+            "]sq0:ndls]".parse::<Mate>(),
+            Err(ParseError::InvalidPosition(_))
+        ));
+
+        Ok(())
+    }
+}
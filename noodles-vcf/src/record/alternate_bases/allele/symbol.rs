@@ -13,8 +13,33 @@ pub enum Symbol {
     StructuralVariant(StructuralVariant),
     /// A nonstructural variant.
     NonstructuralVariant(String),
-    /// An unspecific symbol.
+    /// An unspecified allele (`*`).
+    ///
+    /// This represents any possible alternate allele, e.g., in gVCF reference blocks.
     Unspecified,
+    /// A non-reference allele (`NON_REF`).
+    ///
+    /// This is semantically the same as [`Self::Unspecified`] but is kept distinct to preserve the
+    /// original symbol.
+    NonReference,
+}
+
+impl Symbol {
+    /// Returns whether the symbol represents any possible alternate allele, i.e., whether it is
+    /// `*` or `NON_REF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::Symbol;
+    ///
+    /// assert!(Symbol::Unspecified.is_unspecified());
+    /// assert!(Symbol::NonReference.is_unspecified());
+    /// assert!(!Symbol::NonstructuralVariant(String::from("CN:0")).is_unspecified());
+    /// ```
+    pub fn is_unspecified(&self) -> bool {
+        matches!(self, Self::Unspecified | Self::NonReference)
+    }
 }
 
 impl fmt::Display for Symbol {
@@ -23,6 +48,7 @@ impl fmt::Display for Symbol {
             Self::StructuralVariant(sv) => write!(f, "{}", sv),
             Self::NonstructuralVariant(nsv) => f.write_str(nsv),
             Self::Unspecified => f.write_str("*"),
+            Self::NonReference => f.write_str("NON_REF"),
         }
     }
 }
@@ -53,7 +79,8 @@ impl FromStr for Symbol {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "" => Err(ParseError::Empty),
-            "*" | "NON_REF" => Ok(Self::Unspecified),
+            "*" => Ok(Self::Unspecified),
+            "NON_REF" => Ok(Self::NonReference),
             _ => s
                 .parse::<StructuralVariant>()
                 .map(Self::StructuralVariant)
@@ -91,6 +118,9 @@ mod tests {
 
         let symbol = Symbol::Unspecified;
         assert_eq!(symbol.to_string(), "*");
+
+        let symbol = Symbol::NonReference;
+        assert_eq!(symbol.to_string(), "NON_REF");
     }
 
     #[test]
@@ -107,7 +137,23 @@ mod tests {
             Ok(Symbol::NonstructuralVariant(String::from("CN:0")))
         );
 
-        assert_eq!("NON_REF".parse(), Ok(Symbol::Unspecified));
+        assert_eq!(
+            "DUP:TANDEM".parse(),
+            Ok(Symbol::StructuralVariant(StructuralVariant::new(
+                structural_variant::Type::Duplication,
+                vec![String::from("TANDEM")]
+            )))
+        );
+
+        assert_eq!(
+            "INS:ME:ALU".parse(),
+            Ok(Symbol::StructuralVariant(StructuralVariant::new(
+                structural_variant::Type::Insertion,
+                vec![String::from("ME"), String::from("ALU")]
+            )))
+        );
+
+        assert_eq!("NON_REF".parse(), Ok(Symbol::NonReference));
         assert_eq!("*".parse(), Ok(Symbol::Unspecified));
 
         assert_eq!("".parse::<Symbol>(), Err(ParseError::Empty));
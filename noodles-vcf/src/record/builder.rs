@@ -31,6 +31,10 @@ pub enum BuildError {
     MissingPosition,
     /// The reference bases are missing.
     MissingReferenceBases,
+    /// The genotype format is missing.
+    ///
+    /// A format is required when genotypes are set.
+    MissingFormat,
 }
 
 impl error::Error for BuildError {}
@@ -41,6 +45,7 @@ impl fmt::Display for BuildError {
             Self::MissingChromosome => f.write_str("missing chromosome"),
             Self::MissingPosition => f.write_str("missing position"),
             Self::MissingReferenceBases => f.write_str("missing reference bases"),
+            Self::MissingFormat => f.write_str("missing format"),
         }
     }
 }
@@ -266,7 +271,7 @@ impl Builder {
     ///
     /// let expected = Info::try_from(vec![
     ///     Field::new(Key::SamplesWithDataCount, Value::Integer(3)),
-    ///     Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![0.5])),
+    ///     Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![Some(0.5)])),
     /// ])?;
     ///
     /// assert_eq!(record.info(), &expected);
@@ -386,6 +391,10 @@ impl Builder {
     /// let record = vcf::Record::builder().build();
     /// ```
     pub fn build(self) -> Result<Record, BuildError> {
+        if self.format.is_none() && !self.genotypes.is_empty() {
+            return Err(BuildError::MissingFormat);
+        }
+
         Ok(Record {
             chromosome: self.chromosome.ok_or(BuildError::MissingChromosome)?,
             position: self.position.ok_or(BuildError::MissingPosition)?,
@@ -451,6 +460,15 @@ mod tests {
             .build();
         assert_eq!(result, Err(BuildError::MissingReferenceBases));
 
+        let format = "GT".parse()?;
+        let result = Builder::default()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .add_genotype(Genotype::from_str_format("0|0", &format)?)
+            .build();
+        assert_eq!(result, Err(BuildError::MissingFormat));
+
         Ok(())
     }
 }
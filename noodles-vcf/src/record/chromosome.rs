@@ -51,25 +51,46 @@ impl FromStr for Chromosome {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "" => Err(ParseError::Empty),
-            MISSING_FIELD => Err(ParseError::Missing),
-            _ => {
-                parser::parse(s)
-                    .map_err(|_| ParseError::Invalid)
-                    .and_then(|(_, value)| match value {
-                        parser::Value::Name(t) => {
-                            if is_valid_name(t) {
-                                Ok(Self::Name(t.into()))
-                            } else {
-                                Err(ParseError::Invalid)
-                            }
-                        }
-                        parser::Value::Symbol(t) => Ok(Self::Symbol(t.into())),
-                    })
+        let mut chromosome = Self::Name(String::new());
+        parse_into(s, &mut chromosome)?;
+        Ok(chromosome)
+    }
+}
+
+// Parses a raw chromosome into an existing chromosome, reusing its buffer when the kind is the
+// same.
+pub(crate) fn parse_into(s: &str, chromosome: &mut Chromosome) -> Result<(), ParseError> {
+    let value = match s {
+        "" => return Err(ParseError::Empty),
+        MISSING_FIELD => return Err(ParseError::Missing),
+        _ => parser::parse(s)
+            .map(|(_, value)| value)
+            .map_err(|_| ParseError::Invalid)?,
+    };
+
+    match value {
+        parser::Value::Name(t) => {
+            if !is_valid_name(t) {
+                return Err(ParseError::Invalid);
+            }
+
+            match chromosome {
+                Chromosome::Name(name) => replace_str(name, t),
+                _ => *chromosome = Chromosome::Name(t.into()),
             }
         }
+        parser::Value::Symbol(t) => match chromosome {
+            Chromosome::Symbol(symbol) => replace_str(symbol, t),
+            _ => *chromosome = Chromosome::Symbol(t.into()),
+        },
     }
+
+    Ok(())
+}
+
+fn replace_str(dst: &mut String, src: &str) {
+    dst.clear();
+    dst.push_str(src);
 }
 
 // § 1.4.7 Contig field format
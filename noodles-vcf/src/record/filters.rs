@@ -5,6 +5,7 @@ use std::{error, fmt, str::FromStr};
 use indexmap::IndexSet;
 
 use super::MISSING_FIELD;
+use crate::Header;
 
 const PASS_STATUS: &str = "PASS";
 const DELIMITER: char = ';';
@@ -84,6 +85,61 @@ impl Filters {
             Ok(Self::Fail(filters))
         }
     }
+
+    /// Validates the filters against the filter records in the given header.
+    ///
+    /// Each filter in a failed set of filters must be defined in the header. `PASS` is always
+    /// considered to be defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::{filters::ValidateError, Filters}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_filter_with_description("q10", "Quality below 10")
+    ///     .build();
+    ///
+    /// assert!(Filters::Pass.validate(&header).is_ok());
+    ///
+    /// let filters = Filters::try_from_iter(&["q10"])?;
+    /// assert!(filters.validate(&header).is_ok());
+    ///
+    /// let filters = Filters::try_from_iter(&["q10", "s50"])?;
+    /// assert_eq!(
+    ///     filters.validate(&header),
+    ///     Err(ValidateError::UndefinedFilter(String::from("s50")))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate(&self, header: &Header) -> Result<(), ValidateError> {
+        if let Self::Fail(ids) = self {
+            for id in ids {
+                if !header.filters().contains_key(id) {
+                    return Err(ValidateError::UndefinedFilter(id.into()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when VCF filters fail validation against a header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// A filter is not defined in the header.
+    UndefinedFilter(String),
+}
+
+impl error::Error for ValidateError {}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndefinedFilter(id) => write!(f, "undefined filter: {}", id),
+        }
+    }
 }
 
 impl Default for Filters {
@@ -193,6 +249,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate() -> Result<(), TryFromIteratorError> {
+        let header = Header::builder()
+            .add_filter_with_description("q10", "Quality below 10")
+            .build();
+
+        assert_eq!(Filters::Missing.validate(&header), Ok(()));
+        assert_eq!(Filters::Pass.validate(&header), Ok(()));
+
+        let filters = Filters::try_from_iter(["q10"])?;
+        assert_eq!(filters.validate(&header), Ok(()));
+
+        let filters = Filters::try_from_iter(["s50"])?;
+        assert_eq!(
+            filters.validate(&header),
+            Err(ValidateError::UndefinedFilter(String::from("s50")))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(Filters::default(), Filters::Missing);
@@ -8,6 +8,8 @@ use std::{convert::TryFrom, error, fmt, ops::Deref};
 
 use indexmap::IndexMap;
 
+use crate::header::{self, FileFormat, Number};
+
 use super::{Format, MISSING_FIELD};
 
 const DELIMITER: char = ':';
@@ -25,6 +27,8 @@ pub enum ParseError {
     Invalid(TryFromFieldsError),
     /// A field is invalid.
     InvalidField(field::ParseError),
+    /// A field has an invalid number of values for its declared number (`Number`).
+    InvalidValueCount(field::Key, Number, usize),
 }
 
 impl error::Error for ParseError {}
@@ -35,6 +39,11 @@ impl fmt::Display for ParseError {
             Self::Empty => f.write_str("empty input"),
             Self::Invalid(e) => write!(f, "invalid input: {}", e),
             Self::InvalidField(e) => write!(f, "invalid field: {}", e),
+            Self::InvalidValueCount(key, number, len) => write!(
+                f,
+                "invalid value count for {}: expected Number={}, got {}",
+                key, number, len
+            ),
         }
     }
 }
@@ -60,19 +69,340 @@ impl Genotype {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn from_str_format(s: &str, format: &Format) -> Result<Self, ParseError> {
-        match s {
-            "" => Err(ParseError::Empty),
-            MISSING_FIELD => Ok(Self::default()),
-            _ => {
-                let fields = s
-                    .split(DELIMITER)
-                    .zip(format.iter())
-                    .map(|(t, k)| Field::from_str_key(t, k))
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(ParseError::InvalidField)?;
-
-                Self::try_from(fields).map_err(ParseError::Invalid)
+        parse(s, format, Field::from_str_key)
+    }
+
+    /// Parses a raw genotype for the given genotype format using the header format definitions.
+    ///
+    /// Each field with a definition in the header formats is parsed using the defined number and
+    /// type. Otherwise, the number and type associated with the key is used.
+    ///
+    /// The number of alternate alleles is not known here, so the number of values in each field
+    /// is not checked. Genotypes parsed as part of a record are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::Type, Number},
+    ///     record::{genotype::field::{Key, Value}, Genotype},
+    /// };
+    ///
+    /// let key: Key = "CNL".parse()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(vcf::header::Format::from(Key::Genotype))
+    ///     .add_format(vcf::header::Format::new(
+    ///         key.clone(),
+    ///         Number::G,
+    ///         Type::Float,
+    ///         String::from("Copy number genotype likelihoods"),
+    ///     ))
+    ///     .build();
+    ///
+    /// let format = "GT:CNL".parse()?;
+    /// let genotype = Genotype::try_from_str("0/1:0.5,.,0.25", &format, header.formats())?;
+    ///
+    /// assert_eq!(
+    ///     genotype.get(&key).and_then(|field| field.value()),
+    ///     Some(&Value::FloatArray(vec![Some(0.5), None, Some(0.25)])),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_str(
+        s: &str,
+        format: &Format,
+        formats: &header::Formats,
+    ) -> Result<Self, ParseError> {
+        Self::try_from_str_with_file_format(s, format, formats, FileFormat::default(), None)
+    }
+
+    // String values are only percent-decoded if the file format is VCFv4.3 or later. See
+    // `parse_into` for the value count checks.
+    pub(crate) fn try_from_str_with_file_format(
+        s: &str,
+        format: &Format,
+        formats: &header::Formats,
+        file_format: FileFormat,
+        alternate_allele_count: Option<usize>,
+    ) -> Result<Self, ParseError> {
+        let mut genotype = Self::default();
+        parse_into(
+            s,
+            format,
+            formats,
+            file_format,
+            alternate_allele_count,
+            &mut genotype,
+        )?;
+        Ok(genotype)
+    }
+
+    /// Returns the parsed value of the genotype (`GT`) field.
+    ///
+    /// This returns `None` if the genotype does not have a `GT` field. A missing value (`.`) is
+    /// parsed as a single missing allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     genotype::field::value::genotype::{allele::Phasing, Allele},
+    ///     Genotype,
+    /// };
+    ///
+    /// let format = "GT:GQ".parse()?;
+    /// let genotype = Genotype::from_str_format("0|1:13", &format)?;
+    ///
+    /// let gt = genotype.genotype().transpose()?.expect("missing GT field");
+    /// assert!(gt.is_phased());
+    /// assert_eq!(
+    ///     &gt[..],
+    ///     &[Allele::new(Some(0), None), Allele::new(Some(1), Some(Phasing::Phased))],
+    /// );
+    ///
+    /// let format = "GQ".parse()?;
+    /// let genotype = Genotype::from_str_format("13", &format)?;
+    /// assert!(genotype.genotype().is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn genotype(&self) -> Option<Result<field::value::Genotype, GenotypeError>> {
+        use field::Value;
+
+        self.get(&field::Key::Genotype)
+            .map(|field| match field.value() {
+                Some(Value::String(s)) => s.parse().map_err(GenotypeError::InvalidValue),
+                Some(_) => Err(GenotypeError::InvalidValueType),
+                None => MISSING_FIELD.parse().map_err(GenotypeError::InvalidValue),
+            })
+    }
+
+    /// Returns the phase set (`PS`) of the genotype.
+    ///
+    /// This returns `None` if the genotype does not have a `PS` field or its value is missing or
+    /// not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Genotype;
+    ///
+    /// let format = "GT:PS".parse()?;
+    ///
+    /// let genotype = Genotype::from_str_format("0|1:8", &format)?;
+    /// assert_eq!(genotype.phase_set(), Some(8));
+    ///
+    /// let genotype = Genotype::from_str_format("0|1:.", &format)?;
+    /// assert!(genotype.phase_set().is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn phase_set(&self) -> Option<i32> {
+        match self.get(&field::Key::PhaseSet).and_then(|f| f.value()) {
+            Some(field::Value::Integer(n)) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns whether this and another genotype are phased and in the same phase set.
+    ///
+    /// Both genotypes must have phased, nonmissing `GT` fields. Phased genotypes without a phase
+    /// set (`PS`) are considered to be in the same (default) phase set.
+    ///
+    /// This is typically used to compare the genotypes of the same sample in two records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Genotype;
+    ///
+    /// let format = "GT:PS".parse()?;
+    ///
+    /// let a = Genotype::from_str_format("0|1:8", &format)?;
+    /// let b = Genotype::from_str_format("1|0:8", &format)?;
+    /// assert!(a.is_in_same_phase_set(&b));
+    ///
+    /// let c = Genotype::from_str_format("1|0:13", &format)?;
+    /// assert!(!a.is_in_same_phase_set(&c));
+    ///
+    /// let d = Genotype::from_str_format("1/0:8", &format)?;
+    /// assert!(!a.is_in_same_phase_set(&d));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_in_same_phase_set(&self, other: &Self) -> bool {
+        fn is_phased(genotype: &Genotype) -> bool {
+            matches!(genotype.genotype(), Some(Ok(gt)) if gt.is_phased() && !gt.is_missing())
+        }
+
+        is_phased(self) && is_phased(other) && self.phase_set() == other.phase_set()
+    }
+
+    /// Sets the phasing of the genotype (`GT`) field.
+    ///
+    /// This rewrites the allele separators of the `GT` field, e.g., to phase (`|`) or unphase
+    /// (`/`) the genotype. Genotypes without a `GT` field are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{genotype::field::value::genotype::allele::Phasing, Genotype};
+    ///
+    /// let format = "GT:GQ".parse()?;
+    /// let mut genotype = Genotype::from_str_format("0|1:13", &format)?;
+    ///
+    /// genotype.set_phasing(Phasing::Unphased)?;
+    /// assert_eq!(genotype.to_string(), "0/1:13");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_phasing(
+        &mut self,
+        phasing: field::value::genotype::allele::Phasing,
+    ) -> Result<(), GenotypeError> {
+        if let Some(mut gt) = self.genotype().transpose()? {
+            gt.set_phasing(phasing);
+
+            self.0.insert(
+                field::Key::Genotype,
+                Field::new(
+                    field::Key::Genotype,
+                    Some(field::Value::String(gt.to_string())),
+                ),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn parse<F>(s: &str, format: &Format, parse_field: F) -> Result<Genotype, ParseError>
+where
+    F: Fn(&str, &field::Key) -> Result<Field, field::ParseError>,
+{
+    let mut genotype = Genotype::default();
+    parse_with(s, format, &mut genotype, parse_field)?;
+    Ok(genotype)
+}
+
+// Parses a raw genotype into an existing genotype using the header format definitions, reusing
+// its map.
+// If the number of alternate alleles is known, the number of values of each field is checked
+// against the expected count of its number. See `Number::expected_count`.
+pub(crate) fn parse_into(
+    s: &str,
+    format: &Format,
+    formats: &header::Formats,
+    file_format: FileFormat,
+    alternate_allele_count: Option<usize>,
+    genotype: &mut Genotype,
+) -> Result<(), ParseError> {
+    parse_with(s, format, genotype, |t, key| match formats.get(key) {
+        Some(f) => field::parse(t, key.clone(), f.number(), f.ty(), file_format),
+        None => field::parse(t, key.clone(), key.number(), key.ty(), file_format),
+    })?;
+
+    if let Some(n) = alternate_allele_count {
+        validate_value_counts(genotype, formats, n)?;
+    }
+
+    Ok(())
+}
+
+// The ploidy is the number of alleles in the genotype (`GT`) field or, if unknown, diploid.
+fn validate_value_counts(
+    genotype: &Genotype,
+    formats: &header::Formats,
+    alternate_allele_count: usize,
+) -> Result<(), ParseError> {
+    let ploidy = match genotype.genotype() {
+        Some(Ok(gt)) => gt.len(),
+        _ => 2,
+    };
+
+    for field in genotype.values() {
+        let value = match field.value() {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let key = field.key();
+
+        let number = formats
+            .get(key)
+            .map(|format| format.number())
+            .unwrap_or_else(|| key.number());
+
+        let (len, is_missing) = value_len(value);
+
+        if let Some(expected_len) = number.expected_count(alternate_allele_count, ploidy) {
+            // A single missing value is always accepted.
+            if len != expected_len && !(len == 1 && is_missing) {
+                return Err(ParseError::InvalidValueCount(key.clone(), number, len));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the number of values and whether the value is a single missing value.
+pub(crate) fn value_len(value: &field::Value) -> (usize, bool) {
+    use field::Value;
+
+    match value {
+        Value::Integer(_) | Value::Float(_) | Value::Character(_) | Value::String(_) => (1, false),
+        Value::IntegerArray(values) => (values.len(), values.iter().all(Option::is_none)),
+        Value::FloatArray(values) => (values.len(), values.iter().all(Option::is_none)),
+        Value::CharacterArray(values) => (values.len(), values.iter().all(Option::is_none)),
+        Value::StringArray(values) => (values.len(), values.iter().all(Option::is_none)),
+    }
+}
+
+// The format keys are unique, and the genotype key, if present, is first, so the fields do not
+// need to be validated again.
+fn parse_with<F>(
+    s: &str,
+    format: &Format,
+    genotype: &mut Genotype,
+    parse_field: F,
+) -> Result<(), ParseError>
+where
+    F: Fn(&str, &field::Key) -> Result<Field, field::ParseError>,
+{
+    let map = &mut genotype.0;
+    map.clear();
+
+    match s {
+        "" => Err(ParseError::Empty),
+        MISSING_FIELD => Ok(()),
+        _ => {
+            for (t, key) in s.split(DELIMITER).zip(format.iter()) {
+                let field = parse_field(t, key).map_err(ParseError::InvalidField)?;
+                map.insert(field.key().clone(), field);
             }
+
+            Ok(())
+        }
+    }
+}
+
+/// An error returned when the genotype (`GT`) field value is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GenotypeError {
+    /// The value type is invalid.
+    ///
+    /// The genotype field value must be a string.
+    InvalidValueType,
+    /// The value is invalid.
+    InvalidValue(field::value::genotype::ParseError),
+}
+
+impl error::Error for GenotypeError {}
+
+impl fmt::Display for GenotypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValueType => f.write_str("invalid value type"),
+            Self::InvalidValue(e) => write!(f, "invalid value: {}", e),
         }
     }
 }
@@ -182,6 +512,165 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::{format::Type, Number};
+
+        let key: field::Key = "CNL".parse()?;
+
+        let header = header::Header::builder()
+            .add_format(header::Format::new(
+                key.clone(),
+                Number::G,
+                Type::Float,
+                String::default(),
+            ))
+            .build();
+
+        let format = "GT:CNL".parse()?;
+        let actual = Genotype::try_from_str("0/1:0.5,.,0.25", &format, header.formats())?;
+
+        assert_eq!(
+            actual.get(&field::Key::Genotype).and_then(|f| f.value()),
+            Some(&field::Value::String(String::from("0/1")))
+        );
+        assert_eq!(
+            actual.get(&key).and_then(|f| f.value()),
+            Some(&field::Value::FloatArray(vec![Some(0.5), None, Some(0.25)]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_into_with_alternate_allele_count() -> Result<(), Box<dyn std::error::Error>> {
+        use field::Key;
+
+        let header = header::Header::builder()
+            .add_format(header::Format::from(Key::Genotype))
+            .add_format(header::Format::from(Key::ReadDepths))
+            .add_format(header::Format::from(Key::GenotypeLikelihoods))
+            .build();
+
+        let format = "GT:AD:GL".parse()?;
+
+        let parse = |s: &str, alternate_allele_count| {
+            let mut genotype = Genotype::default();
+
+            parse_into(
+                s,
+                &format,
+                header.formats(),
+                FileFormat::default(),
+                alternate_allele_count,
+                &mut genotype,
+            )
+            .map(|_| genotype)
+        };
+
+        assert!(parse("0/1:1,2:1,2,3", Some(1)).is_ok());
+        assert!(parse("0:1,2:1,2", Some(1)).is_ok());
+        assert!(parse("0/1:.:.", Some(1)).is_ok());
+        assert!(parse("0/1:1,2:1,2", None).is_ok());
+
+        assert_eq!(
+            parse("0/1:1,2,3:1,2,3", Some(1)),
+            Err(ParseError::InvalidValueCount(Key::ReadDepths, Number::R, 3))
+        );
+
+        assert_eq!(
+            parse("0/1:1,2:1,2", Some(1)),
+            Err(ParseError::InvalidValueCount(
+                Key::GenotypeLikelihoods,
+                Number::G,
+                2
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype() -> Result<(), Box<dyn std::error::Error>> {
+        use field::value::genotype::{allele::Phasing, Allele};
+
+        let format = "GT:GQ".parse()?;
+
+        let genotype = Genotype::from_str_format("0/1:13", &format)?;
+        let gt = genotype.genotype().transpose()?;
+        assert_eq!(
+            gt.as_deref(),
+            Some(
+                &[
+                    Allele::new(Some(0), None),
+                    Allele::new(Some(1), Some(Phasing::Unphased))
+                ][..]
+            )
+        );
+
+        let genotype = Genotype::from_str_format(".:13", &format)?;
+        let gt = genotype.genotype().transpose()?;
+        assert_eq!(gt.as_deref(), Some(&[Allele::new(None, None)][..]));
+
+        let genotype = Genotype::try_from(vec![Field::new(
+            field::Key::Genotype,
+            Some(field::Value::Integer(0)),
+        )])?;
+        assert_eq!(
+            genotype.genotype(),
+            Some(Err(GenotypeError::InvalidValueType))
+        );
+
+        let format = "GQ".parse()?;
+        let genotype = Genotype::from_str_format("13", &format)?;
+        assert!(genotype.genotype().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_phase_set() -> Result<(), Box<dyn std::error::Error>> {
+        let format = "GT:PS".parse()?;
+
+        let a = Genotype::from_str_format("0|1:8", &format)?;
+        assert_eq!(a.phase_set(), Some(8));
+
+        let b = Genotype::from_str_format("1|1:13", &format)?;
+        assert!(!a.is_in_same_phase_set(&b));
+
+        let c = Genotype::from_str_format("1|0:8", &format)?;
+        assert!(a.is_in_same_phase_set(&c));
+
+        let d = Genotype::from_str_format(".|.:8", &format)?;
+        assert!(!a.is_in_same_phase_set(&d));
+
+        let format = "GT".parse()?;
+        let e = Genotype::from_str_format("0|1", &format)?;
+        let f = Genotype::from_str_format("1|0", &format)?;
+        assert!(e.phase_set().is_none());
+        assert!(e.is_in_same_phase_set(&f));
+        assert!(!a.is_in_same_phase_set(&e));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_phasing() -> Result<(), Box<dyn std::error::Error>> {
+        use field::value::genotype::allele::Phasing;
+
+        let format = "GT:PS".parse()?;
+        let mut genotype = Genotype::from_str_format("0/1:8", &format)?;
+        genotype.set_phasing(Phasing::Phased)?;
+        assert_eq!(genotype.to_string(), "0|1:8");
+
+        let format = "GQ".parse()?;
+        let mut genotype = Genotype::from_str_format("13", &format)?;
+        genotype.set_phasing(Phasing::Phased)?;
+        assert_eq!(genotype.to_string(), "13");
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), TryFromFieldsError> {
         let genotype = Genotype::default();
@@ -7,6 +7,8 @@ pub use self::{key::Key, value::Value};
 
 use std::{error, fmt};
 
+use crate::header::{self, format::Type, FileFormat, Number};
+
 const MISSING_VALUE: &str = ".";
 
 /// A VCF record genotype field.
@@ -47,13 +49,42 @@ impl Field {
     /// );
     /// ```
     pub fn from_str_key(s: &str, key: &Key) -> Result<Self, ParseError> {
-        if s == MISSING_VALUE {
-            Ok(Self::new(key.clone(), None))
-        } else {
-            Value::from_str_key(s, key)
-                .map(|v| Self::new(key.clone(), Some(v)))
-                .map_err(ParseError::InvalidValue)
-        }
+        parse(
+            s,
+            key.clone(),
+            key.number(),
+            key.ty(),
+            FileFormat::default(),
+        )
+    }
+
+    /// Parses a raw genotype field using the given header definition.
+    ///
+    /// The field key is taken from the header definition ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header,
+    ///     record::genotype::{field::{Key, Value}, Field},
+    /// };
+    ///
+    /// let format = header::Format::from(Key::ConditionalGenotypeQuality);
+    ///
+    /// assert_eq!(
+    ///     Field::from_str_format("13", &format),
+    ///     Ok(Field::new(Key::ConditionalGenotypeQuality, Some(Value::Integer(13))))
+    /// );
+    /// ```
+    pub fn from_str_format(s: &str, format: &header::Format) -> Result<Self, ParseError> {
+        parse(
+            s,
+            format.id().clone(),
+            format.number(),
+            format.ty(),
+            FileFormat::default(),
+        )
     }
 
     /// Creates a VCF record genotype field.
@@ -119,6 +150,22 @@ impl fmt::Display for Field {
     }
 }
 
+pub(super) fn parse(
+    s: &str,
+    key: Key,
+    number: Number,
+    ty: Type,
+    file_format: FileFormat,
+) -> Result<Field, ParseError> {
+    if s == MISSING_VALUE {
+        Ok(Field::new(key, None))
+    } else {
+        value::parse(s, number, ty, file_format)
+            .map(|v| Field::new(key, Some(v)))
+            .map_err(ParseError::InvalidValue)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::header::{format::Type, Number};
@@ -155,6 +202,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_format() -> Result<(), ParseError> {
+        let format = header::Format::from(Key::MappingQuality);
+        let actual = Field::from_str_format(".", &format)?;
+        assert_eq!(actual.key(), &Key::MappingQuality);
+        assert_eq!(actual.value(), None);
+
+        let key = Key::Other(
+            String::from("CNL"),
+            Number::Count(1),
+            Type::String,
+            String::default(),
+        );
+        let format = header::Format::new(key.clone(), Number::G, Type::Float, String::default());
+        let actual = Field::from_str_format("8.333,.", &format)?;
+        assert_eq!(actual.key(), &key);
+        assert_eq!(
+            actual.value(),
+            Some(&Value::FloatArray(vec![Some(8.333), None]))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let field = Field::new(Key::MappingQuality, None);
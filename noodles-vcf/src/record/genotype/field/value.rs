@@ -7,8 +7,8 @@ pub use self::genotype::Genotype;
 use std::{error, fmt, num, str};
 
 use crate::{
-    header::{format::Type, Number},
-    record::value::{parse_f32_case_insensitive_extended, percent_decode},
+    header::{self, format::Type, FileFormat, Number},
+    record::value::{decode_string, parse_f32_case_insensitive_extended},
 };
 
 use super::Key;
@@ -28,12 +28,20 @@ pub enum Value {
     /// A string.
     String(String),
     /// An array of 32-bit integers.
+    ///
+    /// Missing elements (`.`) are `None`.
     IntegerArray(Vec<Option<i32>>),
     /// An array of single-precision floating-points.
+    ///
+    /// Missing elements (`.`) are `None`.
     FloatArray(Vec<Option<f32>>),
     /// An array of characters.
+    ///
+    /// Missing elements (`.`) are `None`.
     CharacterArray(Vec<Option<char>>),
     /// An array of strings.
+    ///
+    /// Missing elements (`.`) are `None`.
     StringArray(Vec<Option<String>>),
 }
 
@@ -153,28 +161,65 @@ impl Value {
     /// );
     /// ```
     pub fn from_str_key(s: &str, key: &Key) -> Result<Self, ParseError> {
-        match key.ty() {
-            Type::Integer => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_i32(s),
-                _ => parse_i32_array(s),
-            },
-            Type::Float => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_f32(s),
-                _ => parse_f32_array(s),
-            },
-            Type::Character => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_char(s),
-                _ => parse_char_array(s),
-            },
-            Type::String => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_string(s),
-                _ => parse_string_array(s),
-            },
-        }
+        parse(s, key.number(), key.ty(), FileFormat::default())
+    }
+
+    /// Parses a raw genotype field value using the number and type of the given header
+    /// definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::{self, format::Type, Number},
+    ///     record::genotype::field::Value,
+    /// };
+    ///
+    /// let format = header::Format::new(
+    ///     "CNL".parse()?,
+    ///     Number::G,
+    ///     Type::Float,
+    ///     String::from("Copy number genotype likelihoods"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::from_str_format("0.333,.", &format),
+    ///     Ok(Value::FloatArray(vec![Some(0.333), None])),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_str_format(s: &str, format: &header::Format) -> Result<Self, ParseError> {
+        parse(s, format.number(), format.ty(), FileFormat::default())
+    }
+}
+
+pub(super) fn parse(
+    s: &str,
+    number: Number,
+    ty: Type,
+    file_format: FileFormat,
+) -> Result<Value, ParseError> {
+    match ty {
+        Type::Integer => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_i32(s),
+            _ => parse_i32_array(s),
+        },
+        Type::Float => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_f32(s),
+            _ => parse_f32_array(s),
+        },
+        Type::Character => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_char(s),
+            _ => parse_char_array(s),
+        },
+        Type::String => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_string(s, file_format),
+            _ => parse_string_array(s, file_format),
+        },
     }
 }
 
@@ -247,19 +292,19 @@ fn parse_char_array(s: &str) -> Result<Value, ParseError> {
         .map(Value::CharacterArray)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    percent_decode(s)
+fn parse_string(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
+    decode_string(s, file_format)
         .map(|t| Value::String(t.into()))
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| {
             if t == MISSING_VALUE {
                 Ok(None)
             } else {
-                percent_decode(t)
+                decode_string(t, file_format)
                     .map(|u| Some(u.into()))
                     .map_err(ParseError::InvalidString)
             }
@@ -533,4 +578,30 @@ mod tests {
             ]))
         );
     }
+
+    #[test]
+    fn test_from_str_format() {
+        let format = header::Format::new(
+            Key::Other(
+                String::from("CNL"),
+                Number::Count(1),
+                Type::String,
+                String::default(),
+            ),
+            Number::G,
+            Type::Float,
+            String::default(),
+        );
+
+        assert_eq!(
+            Value::from_str_format("0.333,.", &format),
+            Ok(Value::FloatArray(vec![Some(0.333), None]))
+        );
+
+        let format = header::Format::from(Key::ConditionalGenotypeQuality);
+        assert_eq!(
+            Value::from_str_format("13", &format),
+            Ok(Value::Integer(13))
+        );
+    }
 }
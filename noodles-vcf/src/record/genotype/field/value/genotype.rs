@@ -4,12 +4,210 @@ pub mod allele;
 
 pub use self::allele::Allele;
 
-use std::{error, fmt, str::FromStr};
+use std::{error, fmt, ops::Deref, str::FromStr};
+
+use self::allele::Phasing;
 
 /// A VCF record genotype value.
+///
+/// This is the parsed value of the genotype (`GT`) field, i.e., a list of alleles, each with an
+/// optional position and phasing.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns whether all alleles in the genotype are phased.
+    ///
+    /// A haploid genotype is considered phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0|1".parse()?;
+    /// assert!(genotype.is_phased());
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(!genotype.is_phased());
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_phased(&self) -> bool {
+        self.iter()
+            .skip(1)
+            .all(|allele| allele.phasing() == Some(Phasing::Phased))
+    }
+
+    /// Returns an iterator over the positions of the called alleles.
+    ///
+    /// Missing alleles (`.`) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert_eq!(genotype.called_alleles().collect::<Vec<_>>(), [0, 1]);
+    ///
+    /// let genotype: Genotype = "./2".parse()?;
+    /// assert_eq!(genotype.called_alleles().collect::<Vec<_>>(), [2]);
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn called_alleles(&self) -> impl Iterator<Item = usize> + '_ {
+        self.iter().filter_map(|allele| allele.position())
+    }
+
+    /// Returns whether all alleles in the genotype are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// assert!("./.".parse::<Genotype>()?.is_missing());
+    /// assert!(!"0/.".parse::<Genotype>()?.is_missing());
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.iter().all(|allele| allele.position().is_none())
+    }
+
+    /// Returns whether all alleles in the genotype are called as the reference allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// assert!("0/0".parse::<Genotype>()?.is_hom_ref());
+    /// assert!(!"0/1".parse::<Genotype>()?.is_hom_ref());
+    /// assert!(!"0/.".parse::<Genotype>()?.is_hom_ref());
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_ref(&self) -> bool {
+        self.iter().all(|allele| allele.position() == Some(0))
+    }
+
+    /// Returns whether all alleles in the genotype are called as the same alternate allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// assert!("1/1".parse::<Genotype>()?.is_hom_alt());
+    /// assert!(!"1/2".parse::<Genotype>()?.is_hom_alt());
+    /// assert!(!"0/0".parse::<Genotype>()?.is_hom_alt());
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_alt(&self) -> bool {
+        match self.first().and_then(|allele| allele.position()) {
+            Some(0) | None => false,
+            Some(position) => self
+                .iter()
+                .all(|allele| allele.position() == Some(position)),
+        }
+    }
+
+    /// Returns whether the genotype is called with at least two different alleles.
+    ///
+    /// All alleles must be called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// assert!("0/1".parse::<Genotype>()?.is_het());
+    /// assert!("1|2".parse::<Genotype>()?.is_het());
+    /// assert!(!"1/1".parse::<Genotype>()?.is_het());
+    /// assert!(!"0/.".parse::<Genotype>()?.is_het());
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_het(&self) -> bool {
+        let mut positions = self.iter().map(|allele| allele.position());
+
+        match positions.next() {
+            Some(Some(first)) => {
+                let mut is_het = false;
+
+                for position in positions {
+                    match position {
+                        Some(p) => is_het |= p != first,
+                        None => return false,
+                    }
+                }
+
+                is_het
+            }
+            _ => false,
+        }
+    }
+
+    /// Sets the phasing of all alleles in the genotype.
+    ///
+    /// This rewrites the separator of each allele after the first, e.g., to phase (`|`) or
+    /// unphase (`/`) a genotype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::{genotype::allele::Phasing, Genotype};
+    ///
+    /// let mut genotype: Genotype = "0/1".parse()?;
+    /// genotype.set_phasing(Phasing::Phased);
+    /// assert_eq!(genotype.to_string(), "0|1");
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn set_phasing(&mut self, phasing: Phasing) {
+        for allele in self.0.iter_mut().skip(1) {
+            *allele.phasing_mut() = Some(phasing);
+        }
+    }
+
+    /// Returns the number of alternate alleles in the genotype.
+    ///
+    /// This is `None` if any allele is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::Genotype;
+    ///
+    /// assert_eq!("0/0".parse::<Genotype>()?.alt_allele_dosage(), Some(0));
+    /// assert_eq!("0|1".parse::<Genotype>()?.alt_allele_dosage(), Some(1));
+    /// assert_eq!("1/2".parse::<Genotype>()?.alt_allele_dosage(), Some(2));
+    /// assert_eq!("0/.".parse::<Genotype>()?.alt_allele_dosage(), None);
+    /// # Ok::<(), noodles_vcf::record::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn alt_allele_dosage(&self) -> Option<usize> {
+        self.iter().try_fold(0, |n, allele| {
+            allele
+                .position()
+                .map(|position| if position > 0 { n + 1 } else { n })
+        })
+    }
+}
+
+impl Deref for Genotype {
+    type Target = [Allele];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Genotype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for allele in self.iter() {
+            write!(f, "{}", allele)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// An error returned when a raw VCF record genotype value fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -58,9 +256,97 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_from_str() {
-        use allele::Phasing;
+    fn test_is_phased() -> Result<(), ParseError> {
+        assert!("0".parse::<Genotype>()?.is_phased());
+        assert!("0|1".parse::<Genotype>()?.is_phased());
+        assert!("0|1|2".parse::<Genotype>()?.is_phased());
+        assert!(!"0/1".parse::<Genotype>()?.is_phased());
+        assert!(!"0|1/2".parse::<Genotype>()?.is_phased());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_phasing() -> Result<(), ParseError> {
+        let mut genotype: Genotype = "0/1|2".parse()?;
+
+        genotype.set_phasing(Phasing::Phased);
+        assert_eq!(genotype.to_string(), "0|1|2");
+
+        genotype.set_phasing(Phasing::Unphased);
+        assert_eq!(genotype.to_string(), "0/1/2");
+
+        let mut genotype: Genotype = "1".parse()?;
+        genotype.set_phasing(Phasing::Phased);
+        assert_eq!(genotype.to_string(), "1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_called_alleles() -> Result<(), ParseError> {
+        let genotype: Genotype = "./.".parse()?;
+        assert_eq!(genotype.called_alleles().count(), 0);
+
+        let genotype: Genotype = "0|1/2".parse()?;
+        assert_eq!(genotype.called_alleles().collect::<Vec<_>>(), [0, 1, 2]);
+
+        Ok(())
+    }
 
+    #[test]
+    fn test_zygosity() -> Result<(), ParseError> {
+        // (genotype, is_missing, is_hom_ref, is_het, is_hom_alt)
+        let tests = [
+            (".", true, false, false, false),
+            ("./.", true, false, false, false),
+            ("0", false, true, false, false),
+            ("0/0", false, true, false, false),
+            ("0|0|0", false, true, false, false),
+            ("0/1", false, false, true, false),
+            ("1|0", false, false, true, false),
+            ("1/2", false, false, true, false),
+            ("1", false, false, false, true),
+            ("1/1", false, false, false, true),
+            ("2|2", false, false, false, true),
+            ("0/.", false, false, false, false),
+            ("./1", false, false, false, false),
+        ];
+
+        for (s, is_missing, is_hom_ref, is_het, is_hom_alt) in &tests {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.is_missing(), *is_missing, "{}", s);
+            assert_eq!(genotype.is_hom_ref(), *is_hom_ref, "{}", s);
+            assert_eq!(genotype.is_het(), *is_het, "{}", s);
+            assert_eq!(genotype.is_hom_alt(), *is_hom_alt, "{}", s);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_alt_allele_dosage() -> Result<(), ParseError> {
+        assert_eq!("0".parse::<Genotype>()?.alt_allele_dosage(), Some(0));
+        assert_eq!("1".parse::<Genotype>()?.alt_allele_dosage(), Some(1));
+        assert_eq!("0/0".parse::<Genotype>()?.alt_allele_dosage(), Some(0));
+        assert_eq!("1/0".parse::<Genotype>()?.alt_allele_dosage(), Some(1));
+        assert_eq!("1|1".parse::<Genotype>()?.alt_allele_dosage(), Some(2));
+        assert_eq!("./.".parse::<Genotype>()?.alt_allele_dosage(), None);
+        assert_eq!("1/.".parse::<Genotype>()?.alt_allele_dosage(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        for s in &[".", "0", "0/1", "0|1", "./.", "0/1|2", "1|."] {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.to_string(), *s);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() {
         assert_eq!(
             "0/1".parse(),
             Ok(Genotype(vec![
@@ -27,6 +27,75 @@ impl Allele {
     pub fn new(position: Option<usize>, phasing: Option<Phasing>) -> Self {
         Self { position, phasing }
     }
+
+    /// Returns the allele position.
+    ///
+    /// This is the index of the allele in the list of reference and alternate bases, where 0 is
+    /// the reference allele. A missing allele (`.`) has no position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::genotype::Allele;
+    ///
+    /// let allele = Allele::new(Some(1), None);
+    /// assert_eq!(allele.position(), Some(1));
+    ///
+    /// let allele = Allele::new(None, None);
+    /// assert!(allele.position().is_none());
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Returns the allele phasing.
+    ///
+    /// This is the separator preceding the allele. The first allele of a genotype does not have a
+    /// phasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::genotype::{allele::Phasing, Allele};
+    ///
+    /// let allele = Allele::new(Some(1), Some(Phasing::Phased));
+    /// assert_eq!(allele.phasing(), Some(Phasing::Phased));
+    ///
+    /// let allele = Allele::new(Some(0), None);
+    /// assert!(allele.phasing().is_none());
+    /// ```
+    pub fn phasing(&self) -> Option<Phasing> {
+        self.phasing
+    }
+
+    /// Returns a mutable reference to the allele phasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotype::field::value::genotype::{allele::Phasing, Allele};
+    ///
+    /// let mut allele = Allele::new(Some(1), Some(Phasing::Unphased));
+    /// *allele.phasing_mut() = Some(Phasing::Phased);
+    /// assert_eq!(allele.phasing(), Some(Phasing::Phased));
+    /// ```
+    pub fn phasing_mut(&mut self) -> &mut Option<Phasing> {
+        &mut self.phasing
+    }
+}
+
+impl fmt::Display for Allele {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(phasing) = self.phasing() {
+            write!(f, "{}", phasing)?;
+        }
+
+        if let Some(position) = self.position() {
+            write!(f, "{}", position)
+        } else {
+            f.write_str(MISSING_POSITION)
+        }
+    }
 }
 
 /// An error returned when a raw VCF record genotype value allele fails to parse.
@@ -88,6 +157,17 @@ fn parse_position(s: &str) -> Result<Option<usize>, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Allele::new(None, None).to_string(), ".");
+        assert_eq!(Allele::new(Some(0), None).to_string(), "0");
+        assert_eq!(Allele::new(None, Some(Phasing::Unphased)).to_string(), "/.");
+        assert_eq!(
+            Allele::new(Some(13), Some(Phasing::Phased)).to_string(),
+            "|13"
+        );
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(".".parse(), Ok(Allele::new(None, None)));
@@ -8,6 +8,8 @@ use std::{convert::TryFrom, error, fmt, ops::Deref, str::FromStr};
 
 use indexmap::IndexMap;
 
+use crate::header::{self, FileFormat};
+
 use super::MISSING_FIELD;
 
 const DELIMITER: char = ';';
@@ -16,6 +18,107 @@ const DELIMITER: char = ';';
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Info(IndexMap<field::Key, Field>);
 
+impl Info {
+    /// Parses raw VCF record info using the header info definitions.
+    ///
+    /// Each field with a definition in the header infos is parsed using the defined number and
+    /// type. See [`Field::try_from_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::info::{field::{Key, Value}, Info}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(vcf::header::Info::from(Key::AlleleFrequencies))
+    ///     .build();
+    ///
+    /// let info = Info::try_from_str("NS=2;AF=0.333,.", header.infos())?;
+    ///
+    /// assert_eq!(
+    ///     info.get(&Key::AlleleFrequencies).map(|field| field.value()),
+    ///     Some(&Value::FloatArray(vec![Some(0.333), None])),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_str(s: &str, infos: &header::Infos) -> Result<Self, ParseError> {
+        Self::try_from_str_with_file_format(s, infos, FileFormat::default())
+    }
+
+    pub(crate) fn try_from_str_with_file_format(
+        s: &str,
+        infos: &header::Infos,
+        file_format: FileFormat,
+    ) -> Result<Self, ParseError> {
+        let mut info = Self::default();
+        parse_into(s, infos, file_format, &mut info)?;
+        Ok(info)
+    }
+
+    /// Inserts a field into the info.
+    ///
+    /// This uses the field key as the key. If the key already exists, the field is replaced in
+    /// place, and the previous field is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{info::{field::{Key, Value}, Field}, Info};
+    ///
+    /// let mut info: Info = "NS=2;AF=0.333".parse()?;
+    ///
+    /// let field = Field::new(Key::AlleleFrequencies, Value::FloatArray(vec![Some(0.5)]));
+    /// let previous_field = info.insert(field);
+    ///
+    /// assert_eq!(
+    ///     previous_field.map(|field| field.value().clone()),
+    ///     Some(Value::FloatArray(vec![Some(0.333)])),
+    /// );
+    /// assert_eq!(info.to_string(), "NS=2;AF=0.5");
+    /// # Ok::<(), noodles_vcf::record::info::ParseError>(())
+    /// ```
+    pub fn insert(&mut self, field: Field) -> Option<Field> {
+        self.0.insert(field.key().clone(), field)
+    }
+
+    /// Removes a field from the info.
+    ///
+    /// This preserves the order of the remaining fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{info::field::Key, Info};
+    ///
+    /// let mut info: Info = "NS=2;AF=0.333;DP=13".parse()?;
+    ///
+    /// assert!(info.remove(&Key::AlleleFrequencies).is_some());
+    /// assert!(info.remove(&Key::AlleleFrequencies).is_none());
+    /// assert_eq!(info.to_string(), "NS=2;DP=13");
+    /// # Ok::<(), noodles_vcf::record::info::ParseError>(())
+    /// ```
+    pub fn remove(&mut self, key: &field::Key) -> Option<Field> {
+        self.0.shift_remove(key)
+    }
+
+    /// Returns an iterator over the fields in the info.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{info::field::Key, Info};
+    ///
+    /// let info: Info = "NS=2;AF=0.333".parse()?;
+    /// let keys: Vec<_> = info.fields().map(|field| field.key()).collect();
+    ///
+    /// assert_eq!(keys, [&Key::SamplesWithDataCount, &Key::AlleleFrequencies]);
+    /// # Ok::<(), noodles_vcf::record::info::ParseError>(())
+    /// ```
+    pub fn fields(&self) -> impl Iterator<Item = &Field> {
+        self.0.values()
+    }
+}
+
 impl Deref for Info {
     type Target = IndexMap<field::Key, Field>;
 
@@ -69,18 +172,53 @@ impl FromStr for Info {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "" => Err(ParseError::Empty),
-            MISSING_FIELD => Ok(Self::default()),
-            _ => {
-                let fields = s
-                    .split(DELIMITER)
-                    .map(|s| s.parse())
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(ParseError::InvalidField)?;
-
-                Self::try_from(fields).map_err(ParseError::Invalid)
+        parse(s, |t| t.parse())
+    }
+}
+
+fn parse<F>(s: &str, parse_field: F) -> Result<Info, ParseError>
+where
+    F: Fn(&str) -> Result<Field, field::ParseError>,
+{
+    let mut info = Info::default();
+    parse_with(s, &mut info, parse_field)?;
+    Ok(info)
+}
+
+// Parses raw info into existing info using the header info definitions, reusing its map.
+pub(crate) fn parse_into(
+    s: &str,
+    infos: &header::Infos,
+    file_format: FileFormat,
+    info: &mut Info,
+) -> Result<(), ParseError> {
+    parse_with(s, info, |t| {
+        Field::try_from_str_with_file_format(t, infos, file_format)
+    })
+}
+
+fn parse_with<F>(s: &str, info: &mut Info, parse_field: F) -> Result<(), ParseError>
+where
+    F: Fn(&str) -> Result<Field, field::ParseError>,
+{
+    let map = &mut info.0;
+    map.clear();
+
+    match s {
+        "" => Err(ParseError::Empty),
+        MISSING_FIELD => Ok(()),
+        _ => {
+            for t in s.split(DELIMITER) {
+                let field = parse_field(t).map_err(ParseError::InvalidField)?;
+
+                if let Some(duplicate_field) = map.insert(field.key().clone(), field) {
+                    return Err(ParseError::Invalid(TryFromFieldsError::DuplicateKey(
+                        duplicate_field.key().clone(),
+                    )));
+                }
             }
+
+            Ok(())
         }
     }
 }
@@ -145,7 +283,7 @@ mod tests {
             Field::new(field::Key::SamplesWithDataCount, field::Value::Integer(2)),
             Field::new(
                 field::Key::AlleleFrequencies,
-                field::Value::FloatArray(vec![0.333, 0.667]),
+                field::Value::FloatArray(vec![Some(0.333), Some(0.667)]),
             ),
         ])?;
         assert_eq!(info.to_string(), "NS=2;AF=0.333,0.667");
@@ -153,6 +291,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_insert() -> Result<(), ParseError> {
+        let mut info: Info = "NS=2".parse()?;
+
+        let field = Field::new(field::Key::TotalDepth, field::Value::Integer(13));
+        assert!(info.insert(field).is_none());
+        assert_eq!(info.to_string(), "NS=2;DP=13");
+
+        let field = Field::new(field::Key::SamplesWithDataCount, field::Value::Integer(3));
+        let previous_field = info.insert(field);
+        assert_eq!(
+            previous_field,
+            Some(Field::new(
+                field::Key::SamplesWithDataCount,
+                field::Value::Integer(2)
+            ))
+        );
+        assert_eq!(info.to_string(), "NS=3;DP=13");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove() -> Result<(), ParseError> {
+        let mut info: Info = "NS=2;AF=0.333;DP=13".parse()?;
+
+        assert!(info.remove(&field::Key::AlleleFrequencies).is_some());
+        assert_eq!(info.to_string(), "NS=2;DP=13");
+
+        assert!(info.remove(&field::Key::SamplesWithDataCount).is_some());
+        assert!(info.remove(&field::Key::TotalDepth).is_some());
+        assert!(info.remove(&field::Key::TotalDepth).is_none());
+        assert_eq!(info.to_string(), ".");
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() -> Result<(), ParseError> {
         let actual: Info = ".".parse()?;
@@ -173,6 +348,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_from_str() -> Result<(), ParseError> {
+        let header = header::Header::builder()
+            .add_info(header::Info::from(field::Key::AlleleFrequencies))
+            .build();
+
+        let actual = Info::try_from_str(".", header.infos())?;
+        assert!(actual.is_empty());
+
+        let actual = Info::try_from_str("NS=2;AF=0.333,.", header.infos())?;
+        assert_eq!(actual.len(), 2);
+        assert_eq!(
+            actual
+                .get(&field::Key::AlleleFrequencies)
+                .map(|field| field.value()),
+            Some(&field::Value::FloatArray(vec![Some(0.333), None]))
+        );
+
+        assert_eq!(
+            Info::try_from_str("", header.infos()),
+            Err(ParseError::Empty)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_from_fields_for_info() {
         assert_eq!(Info::try_from(Vec::new()), Ok(Info::default()));
@@ -7,7 +7,7 @@ pub use self::{key::Key, value::Value};
 
 use std::{error, fmt, str::FromStr};
 
-use crate::header::info::Type;
+use crate::header::{self, info::Type, FileFormat};
 
 const SEPARATOR: char = '=';
 const MAX_COMPONENTS: usize = 2;
@@ -57,6 +57,71 @@ impl Field {
     pub fn value(&self) -> &Value {
         &self.value
     }
+
+    /// Parses a raw VCF record info field using the header info definitions.
+    ///
+    /// If the key has a definition in the given header infos, its value is parsed using the
+    /// defined number and type. Otherwise, this behaves the same as parsing without a header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::Type, Number},
+    ///     record::info::{field::Value, Field},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(vcf::header::Info::new(
+    ///         "FREQ".parse()?,
+    ///         Number::A,
+    ///         Type::Float,
+    ///         String::from("Allele frequencies"),
+    ///     ))
+    ///     .build();
+    ///
+    /// let field = Field::try_from_str("FREQ=0.333,.", header.infos())?;
+    /// assert_eq!(field.value(), &Value::FloatArray(vec![Some(0.333), None]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_str(s: &str, infos: &header::Infos) -> Result<Self, ParseError> {
+        Self::try_from_str_with_file_format(s, infos, FileFormat::default())
+    }
+
+    // String values are only percent-decoded if the file format is VCFv4.3 or later.
+    pub(crate) fn try_from_str_with_file_format(
+        s: &str,
+        infos: &header::Infos,
+        file_format: FileFormat,
+    ) -> Result<Self, ParseError> {
+        let mut components = s.splitn(MAX_COMPONENTS, SEPARATOR);
+
+        let key: Key = components
+            .next()
+            .ok_or(ParseError::MissingKey)
+            .and_then(|s| s.parse().map_err(ParseError::InvalidKey))?;
+
+        let info = match infos.get(&key) {
+            Some(info) => info,
+            None => return parse_value(components.next(), key, file_format),
+        };
+
+        let parse = |t| {
+            value::parse(t, info.number(), info.ty(), file_format).map_err(ParseError::InvalidValue)
+        };
+
+        let value = if let Type::Flag = info.ty() {
+            parse(components.next().unwrap_or_default())?
+        } else {
+            components
+                .next()
+                .ok_or(ParseError::MissingValue)
+                .and_then(parse)?
+        };
+
+        Ok(Self::new(key, value))
+    }
 }
 
 impl fmt::Display for Field {
@@ -105,24 +170,27 @@ impl FromStr for Field {
             .ok_or(ParseError::MissingKey)
             .and_then(|s| s.parse().map_err(ParseError::InvalidKey))?;
 
-        let value = if let Type::Flag = key.ty() {
-            let t = components.next().unwrap_or_default();
-            Value::from_str_key(t, &key).map_err(ParseError::InvalidValue)?
-        } else if let Key::Other(..) = key {
-            if let Some(t) = components.next() {
-                Value::from_str_key(t, &key).map_err(ParseError::InvalidValue)?
-            } else {
-                Value::Flag
-            }
+        parse_value(components.next(), key, FileFormat::default())
+    }
+}
+
+fn parse_value(s: Option<&str>, key: Key, file_format: FileFormat) -> Result<Field, ParseError> {
+    let parse =
+        |t| value::parse(t, key.number(), key.ty(), file_format).map_err(ParseError::InvalidValue);
+
+    let value = if let Type::Flag = key.ty() {
+        parse(s.unwrap_or_default())?
+    } else if let Key::Other(..) = key {
+        if let Some(t) = s {
+            parse(t)?
         } else {
-            components
-                .next()
-                .ok_or(ParseError::MissingValue)
-                .and_then(|t| Value::from_str_key(t, &key).map_err(ParseError::InvalidValue))?
-        };
+            Value::Flag
+        }
+    } else {
+        s.ok_or(ParseError::MissingValue).and_then(parse)?
+    };
 
-        Ok(Self::new(key, value))
-    }
+    Ok(Field::new(key, value))
 }
 
 #[cfg(test)]
@@ -198,4 +266,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        let header = header::Header::builder()
+            .add_info(header::Info::from(Key::AlleleFrequencies))
+            .add_info(header::Info::new(
+                "FREQ".parse()?,
+                Number::A,
+                Type::Float,
+                String::default(),
+            ))
+            .add_info(header::Info::new(
+                "BOOL".parse()?,
+                Number::Count(0),
+                Type::Flag,
+                String::default(),
+            ))
+            .build();
+
+        let actual = Field::try_from_str("AF=0.333,.", header.infos())?;
+        assert_eq!(actual.key(), &Key::AlleleFrequencies);
+        assert_eq!(actual.value(), &Value::FloatArray(vec![Some(0.333), None]));
+
+        let actual = Field::try_from_str("FREQ=0.333,0.667", header.infos())?;
+        assert_eq!(
+            actual.value(),
+            &Value::FloatArray(vec![Some(0.333), Some(0.667)])
+        );
+
+        let actual = Field::try_from_str("BOOL", header.infos())?;
+        assert_eq!(actual.value(), &Value::Flag);
+
+        let actual = Field::try_from_str("NDLS=VCF", header.infos())?;
+        assert_eq!(actual.value(), &Value::String(String::from("VCF")));
+
+        assert_eq!(
+            Field::try_from_str("FREQ", header.infos()),
+            Err(ParseError::MissingValue)
+        );
+
+        Ok(())
+    }
 }
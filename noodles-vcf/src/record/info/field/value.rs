@@ -3,13 +3,14 @@
 use std::{error, fmt, num, str};
 
 use crate::{
-    header::{info::Type, Number},
-    record::value::{parse_f32_case_insensitive_extended, percent_decode},
+    header::{self, info::Type, FileFormat, Number},
+    record::value::{decode_string, parse_f32_case_insensitive_extended},
 };
 
 use super::Key;
 
 const DELIMITER: char = ',';
+const MISSING_VALUE: &str = ".";
 
 /// A VCF record info field value.
 #[derive(Clone, Debug, PartialEq)]
@@ -25,13 +26,21 @@ pub enum Value {
     /// A string.
     String(String),
     /// An array of 32-bit integers.
-    IntegerArray(Vec<i32>),
+    ///
+    /// Missing elements (`.`) are `None`.
+    IntegerArray(Vec<Option<i32>>),
     /// An array of single-precision floating-points.
-    FloatArray(Vec<f32>),
+    ///
+    /// Missing elements (`.`) are `None`.
+    FloatArray(Vec<Option<f32>>),
     /// An array of characters.
-    CharacterArray(Vec<char>),
+    ///
+    /// Missing elements (`.`) are `None`.
+    CharacterArray(Vec<Option<char>>),
     /// An array of strings.
-    StringArray(Vec<String>),
+    ///
+    /// Missing elements (`.`) are `None`.
+    StringArray(Vec<Option<String>>),
 }
 
 impl fmt::Display for Value {
@@ -48,7 +57,11 @@ impl fmt::Display for Value {
                         write!(f, "{}", DELIMITER)?;
                     }
 
-                    write!(f, "{}", value)?;
+                    if let Some(v) = value {
+                        write!(f, "{}", v)?;
+                    } else {
+                        f.write_str(MISSING_VALUE)?;
+                    }
                 }
 
                 Ok(())
@@ -59,7 +72,11 @@ impl fmt::Display for Value {
                         write!(f, "{}", DELIMITER)?;
                     }
 
-                    write!(f, "{}", value)?;
+                    if let Some(v) = value {
+                        write!(f, "{}", v)?;
+                    } else {
+                        f.write_str(MISSING_VALUE)?;
+                    }
                 }
 
                 Ok(())
@@ -70,7 +87,11 @@ impl fmt::Display for Value {
                         write!(f, "{}", DELIMITER)?;
                     }
 
-                    write!(f, "{}", value)?;
+                    if let Some(v) = value {
+                        write!(f, "{}", v)?;
+                    } else {
+                        f.write_str(MISSING_VALUE)?;
+                    }
                 }
 
                 Ok(())
@@ -81,7 +102,11 @@ impl fmt::Display for Value {
                         write!(f, "{}", DELIMITER)?;
                     }
 
-                    write!(f, "{}", value)?;
+                    if let Some(v) = value {
+                        write!(f, "{}", v)?;
+                    } else {
+                        f.write_str(MISSING_VALUE)?;
+                    }
                 }
 
                 Ok(())
@@ -134,32 +159,74 @@ impl Value {
     /// assert_eq!(Value::from_str_key("1", &Key::SamplesWithDataCount), Ok(Value::Integer(1)));
     /// ```
     pub fn from_str_key(s: &str, key: &Key) -> Result<Self, ParseError> {
-        match key.ty() {
-            Type::Integer => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_i32(s),
-                _ => parse_i32_array(s),
-            },
-            Type::Float => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_f32(s),
-                _ => parse_f32_array(s),
-            },
-            Type::Flag => match key.number() {
-                Number::Count(0) => parse_flag(s),
-                _ => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-            },
-            Type::Character => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_char(s),
-                _ => parse_char_array(s),
-            },
-            Type::String => match key.number() {
-                Number::Count(0) => Err(ParseError::InvalidNumberForType(key.number(), key.ty())),
-                Number::Count(1) => parse_string(s),
-                _ => parse_string_array(s),
-            },
-        }
+        parse(s, key.number(), key.ty(), FileFormat::default())
+    }
+
+    /// Parses a raw info field value using the number and type of the given header definition.
+    ///
+    /// Unlike [`Self::from_str_key`], this uses the cardinality and type declared in the header
+    /// rather than the ones associated with the key. This allows typing values of nonstandard
+    /// keys, e.g., a `Number=A,Type=Float` field is parsed as a float array.
+    ///
+    /// Missing values (`.`) in arrays are parsed as `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::{self, info::Type, Number},
+    ///     record::info::field::Value,
+    /// };
+    ///
+    /// let info = header::Info::new(
+    ///     "FREQ".parse()?,
+    ///     Number::A,
+    ///     Type::Float,
+    ///     String::from("Allele frequencies"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::from_str_info("0.333,.", &info),
+    ///     Ok(Value::FloatArray(vec![Some(0.333), None])),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_str_info(s: &str, info: &header::Info) -> Result<Self, ParseError> {
+        parse(s, info.number(), info.ty(), FileFormat::default())
+    }
+}
+
+pub(super) fn parse(
+    s: &str,
+    number: Number,
+    ty: Type,
+    file_format: FileFormat,
+) -> Result<Value, ParseError> {
+    match ty {
+        Type::Integer => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_i32(s),
+            _ => parse_i32_array(s),
+        },
+        Type::Float => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_f32(s),
+            _ => parse_f32_array(s),
+        },
+        Type::Flag => match number {
+            Number::Count(0) => parse_flag(s),
+            _ => Err(ParseError::InvalidNumberForType(number, ty)),
+        },
+        Type::Character => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_char(s),
+            _ => parse_char_array(s),
+        },
+        Type::String => match number {
+            Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
+            Number::Count(1) => parse_string(s, file_format),
+            _ => parse_string_array(s, file_format),
+        },
     }
 }
 
@@ -171,7 +238,13 @@ fn parse_i32(s: &str) -> Result<Value, ParseError> {
 
 fn parse_i32_array(s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
-        .map(|s| s.parse().map_err(ParseError::InvalidInteger))
+        .map(|t| {
+            if t == MISSING_VALUE {
+                Ok(None)
+            } else {
+                t.parse().map(Some).map_err(ParseError::InvalidInteger)
+            }
+        })
         .collect::<Result<_, _>>()
         .map(Value::IntegerArray)
 }
@@ -184,7 +257,15 @@ fn parse_f32(s: &str) -> Result<Value, ParseError> {
 
 fn parse_f32_array(s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
-        .map(|s| parse_f32_case_insensitive_extended(s).map_err(ParseError::InvalidFloat))
+        .map(|t| {
+            if t == MISSING_VALUE {
+                Ok(None)
+            } else {
+                parse_f32_case_insensitive_extended(t)
+                    .map(Some)
+                    .map_err(ParseError::InvalidFloat)
+            }
+        })
         .collect::<Result<_, _>>()
         .map(Value::FloatArray)
 }
@@ -215,23 +296,33 @@ fn parse_char(s: &str) -> Result<Value, ParseError> {
 
 fn parse_char_array(s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
-        .map(parse_raw_char)
+        .map(|t| {
+            if t == MISSING_VALUE {
+                Ok(None)
+            } else {
+                parse_raw_char(t).map(Some)
+            }
+        })
         .collect::<Result<_, _>>()
         .map(Value::CharacterArray)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    percent_decode(s)
+fn parse_string(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
+    decode_string(s, file_format)
         .map(|t| Value::String(t.into()))
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(s: &str, file_format: FileFormat) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| {
-            percent_decode(t)
-                .map(|u| u.into())
-                .map_err(ParseError::InvalidString)
+            if t == MISSING_VALUE {
+                Ok(None)
+            } else {
+                decode_string(t, file_format)
+                    .map(|u| Some(u.into()))
+                    .map_err(ParseError::InvalidString)
+            }
         })
         .collect::<Result<_, _>>()
         .map(Value::StringArray)
@@ -257,29 +348,44 @@ mod tests {
         let value = Value::String(String::from("noodles"));
         assert_eq!(value.to_string(), "noodles");
 
-        let value = Value::IntegerArray(vec![2]);
+        let value = Value::IntegerArray(vec![Some(2)]);
         assert_eq!(value.to_string(), "2");
 
-        let value = Value::IntegerArray(vec![2, 5]);
+        let value = Value::IntegerArray(vec![Some(2), Some(5)]);
         assert_eq!(value.to_string(), "2,5");
 
-        let value = Value::FloatArray(vec![0.333]);
+        let value = Value::IntegerArray(vec![Some(2), None]);
+        assert_eq!(value.to_string(), "2,.");
+
+        let value = Value::FloatArray(vec![Some(0.333)]);
         assert_eq!(value.to_string(), "0.333");
 
-        let value = Value::FloatArray(vec![0.333, 0.667]);
+        let value = Value::FloatArray(vec![Some(0.333), Some(0.667)]);
         assert_eq!(value.to_string(), "0.333,0.667");
 
-        let value = Value::CharacterArray(vec!['n']);
+        let value = Value::FloatArray(vec![Some(0.333), None]);
+        assert_eq!(value.to_string(), "0.333,.");
+
+        let value = Value::CharacterArray(vec![Some('n')]);
         assert_eq!(value.to_string(), "n");
 
-        let value = Value::CharacterArray(vec!['n', 'd', 'l', 's']);
+        let value = Value::CharacterArray(vec![Some('n'), Some('d'), Some('l'), Some('s')]);
         assert_eq!(value.to_string(), "n,d,l,s");
 
-        let value = Value::StringArray(vec![String::from("noodles")]);
+        let value = Value::CharacterArray(vec![Some('n'), Some('d'), None, Some('s')]);
+        assert_eq!(value.to_string(), "n,d,.,s");
+
+        let value = Value::StringArray(vec![Some(String::from("noodles"))]);
         assert_eq!(value.to_string(), "noodles");
 
-        let value = Value::StringArray(vec![String::from("noodles"), String::from("vcf")]);
+        let value = Value::StringArray(vec![
+            Some(String::from("noodles")),
+            Some(String::from("vcf")),
+        ]);
         assert_eq!(value.to_string(), "noodles,vcf");
+
+        let value = Value::StringArray(vec![Some(String::from("noodles")), None]);
+        assert_eq!(value.to_string(), "noodles,.");
     }
 
     #[test]
@@ -314,7 +420,11 @@ mod tests {
         );
         assert_eq!(
             Value::from_str_key("8,13", &key),
-            Ok(Value::IntegerArray(vec![8, 13])),
+            Ok(Value::IntegerArray(vec![Some(8), Some(13)])),
+        );
+        assert_eq!(
+            Value::from_str_key("8,.", &key),
+            Ok(Value::IntegerArray(vec![Some(8), None])),
         );
     }
 
@@ -350,7 +460,11 @@ mod tests {
         );
         assert_eq!(
             Value::from_str_key("0.333,0.667", &key),
-            Ok(Value::FloatArray(vec![0.333, 0.667]))
+            Ok(Value::FloatArray(vec![Some(0.333), Some(0.667)]))
+        );
+        assert_eq!(
+            Value::from_str_key("0.333,.", &key),
+            Ok(Value::FloatArray(vec![Some(0.333), None]))
         );
     }
 
@@ -422,7 +536,21 @@ mod tests {
         );
         assert_eq!(
             Value::from_str_key("n,d,l,s", &key),
-            Ok(Value::CharacterArray(vec!['n', 'd', 'l', 's']))
+            Ok(Value::CharacterArray(vec![
+                Some('n'),
+                Some('d'),
+                Some('l'),
+                Some('s')
+            ]))
+        );
+        assert_eq!(
+            Value::from_str_key("n,d,.,s", &key),
+            Ok(Value::CharacterArray(vec![
+                Some('n'),
+                Some('d'),
+                None,
+                Some('s')
+            ]))
         );
     }
 
@@ -466,16 +594,59 @@ mod tests {
         assert_eq!(
             Value::from_str_key("noodles,vcf", &key),
             Ok(Value::StringArray(vec![
-                String::from("noodles"),
-                String::from("vcf")
+                Some(String::from("noodles")),
+                Some(String::from("vcf"))
             ]))
         );
         assert_eq!(
             Value::from_str_key("8%25,13%25", &key),
             Ok(Value::StringArray(vec![
-                String::from("8%"),
-                String::from("13%")
+                Some(String::from("8%")),
+                Some(String::from("13%"))
+            ]))
+        );
+        assert_eq!(
+            Value::from_str_key("noodles,.", &key),
+            Ok(Value::StringArray(vec![
+                Some(String::from("noodles")),
+                None
             ]))
         );
     }
+
+    #[test]
+    fn test_from_str_info() {
+        use crate::header;
+
+        let info = header::Info::new(
+            Key::Other(
+                String::from("FREQ"),
+                Number::Count(1),
+                Type::String,
+                String::default(),
+            ),
+            Number::A,
+            Type::Float,
+            String::default(),
+        );
+
+        assert_eq!(
+            Value::from_str_info("0.333,.", &info),
+            Ok(Value::FloatArray(vec![Some(0.333), None]))
+        );
+
+        let info = header::Info::new(
+            Key::Other(
+                String::from("FLAG"),
+                Number::Count(1),
+                Type::String,
+                String::default(),
+            ),
+            Number::Count(0),
+            Type::Flag,
+            String::default(),
+        );
+
+        assert_eq!(Value::from_str_info("", &info), Ok(Value::Flag));
+    }
 }
@@ -0,0 +1,359 @@
+//! Lazily-evaluated VCF record.
+
+use std::convert::TryFrom;
+
+use crate::Header;
+
+use super::{
+    parse, AlternateBases, Chromosome, Field, Filters, Format, Genotype, Ids, Info, ParseError,
+    Position, QualityScore, ReferenceBases, FIELD_DELIMITER,
+};
+
+const REQUIRED_FIELD_COUNT: usize = 8;
+
+static REQUIRED_FIELDS: [Field; REQUIRED_FIELD_COUNT] = [
+    Field::Chromosome,
+    Field::Position,
+    Field::Ids,
+    Field::ReferenceBases,
+    Field::AlternateBases,
+    Field::QualityScore,
+    Field::Filters,
+    Field::Info,
+];
+
+/// A lazily-evaluated VCF record.
+///
+/// This borrows a raw VCF record line and only splits it into its fields. Each field is parsed
+/// when it is requested, which avoids the cost of parsing, e.g., the INFO field and all samples
+/// when only the chromosome and position are needed.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use noodles_vcf::record::{lazy, Chromosome, Position};
+///
+/// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\tNS=3\tGT\t0|0")?;
+///
+/// assert_eq!(record.chromosome()?, Chromosome::Name(String::from("sq0")));
+/// assert_eq!(record.position()?, Position::try_from(8)?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record<'a> {
+    line: &'a str,
+    fields: [&'a str; REQUIRED_FIELD_COUNT],
+    genotypes: Option<&'a str>,
+}
+
+impl<'a> Record<'a> {
+    /// Returns the raw record line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let s = "sq0\t8\t.\tA\t.\t.\tPASS\t.";
+    /// let record = lazy::Record::try_from(s)?;
+    /// assert_eq!(record.as_str(), s);
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.line
+    }
+
+    /// Parses the chromosome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{lazy, Chromosome};
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.chromosome()?, Chromosome::Name(String::from("sq0")));
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn chromosome(&self) -> Result<Chromosome, ParseError> {
+        self.field(Field::Chromosome)
+            .parse()
+            .map_err(ParseError::InvalidChromosome)
+    }
+
+    /// Parses the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{lazy, Position};
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.position()?, Position::try_from(8)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn position(&self) -> Result<Position, ParseError> {
+        self.field(Field::Position)
+            .parse()
+            .map_err(ParseError::InvalidPosition)
+    }
+
+    /// Parses the IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\tnd0\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.ids()?.to_string(), "nd0");
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn ids(&self) -> Result<Ids, ParseError> {
+        self.field(Field::Ids)
+            .parse()
+            .map_err(ParseError::InvalidIds)
+    }
+
+    /// Parses the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tAC\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.reference_bases()?.to_string(), "AC");
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn reference_bases(&self) -> Result<ReferenceBases, ParseError> {
+        self.field(Field::ReferenceBases)
+            .parse()
+            .map_err(ParseError::InvalidReferenceBases)
+    }
+
+    /// Parses the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\tC,G\t.\tPASS\t.")?;
+    /// assert_eq!(record.alternate_bases()?.len(), 2);
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn alternate_bases(&self) -> Result<AlternateBases, ParseError> {
+        self.field(Field::AlternateBases)
+            .parse()
+            .map_err(ParseError::InvalidAlternateBases)
+    }
+
+    /// Parses the quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t13.0\tPASS\t.")?;
+    /// assert_eq!(*record.quality_score()?, Some(13.0));
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn quality_score(&self) -> Result<QualityScore, ParseError> {
+        self.field(Field::QualityScore)
+            .parse()
+            .map_err(ParseError::InvalidQualityScore)
+    }
+
+    /// Parses the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{lazy, Filters};
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.filters()?, Filters::Pass);
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn filters(&self) -> Result<Filters, ParseError> {
+        self.field(Field::Filters)
+            .parse()
+            .map_err(ParseError::InvalidFilters)
+    }
+
+    /// Parses the information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::{info::field::Key, lazy};
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\tNS=3")?;
+    /// assert!(record.info()?.get(&Key::SamplesWithDataCount).is_some());
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn info(&self) -> Result<Info, ParseError> {
+        self.field(Field::Info)
+            .parse()
+            .map_err(ParseError::InvalidInfo)
+    }
+
+    /// Parses the format, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13")?;
+    /// assert_eq!(record.format()?.map(|format| format.len()), Some(2));
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert!(record.format()?.is_none());
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn format(&self) -> Result<Option<Format>, ParseError> {
+        match self.raw_format_and_genotypes() {
+            Some((raw_format, _)) => raw_format
+                .parse()
+                .map(Some)
+                .map_err(ParseError::InvalidFormat),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses the genotypes.
+    ///
+    /// This is empty if the record has no format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::record::lazy;
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13\t0/1:8")?;
+    /// assert_eq!(record.genotypes()?.len(), 2);
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn genotypes(&self) -> Result<Vec<Genotype>, ParseError> {
+        let format = match self.format()? {
+            Some(format) => format,
+            None => return Ok(Vec::new()),
+        };
+
+        match self.raw_format_and_genotypes() {
+            Some((_, Some(raw_genotypes))) => raw_genotypes
+                .split(FIELD_DELIMITER)
+                .map(|s| Genotype::from_str_format(s, &format))
+                .collect::<Result<_, _>>()
+                .map_err(ParseError::InvalidGenotype),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Converts this lazy record into a record, parsing fields using header definitions.
+    ///
+    /// See [`super::Record::try_from_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::lazy};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = lazy::Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.")?;
+    /// let record = record.try_into_record(&header)?;
+    /// assert_eq!(i32::from(record.position()), 8);
+    /// # Ok::<(), noodles_vcf::record::ParseError>(())
+    /// ```
+    pub fn try_into_record(self, header: &Header) -> Result<super::Record, ParseError> {
+        parse(self.line, Some(header))
+    }
+
+    fn field(&self, field: Field) -> &'a str {
+        self.fields[field as usize]
+    }
+
+    fn raw_format_and_genotypes(&self) -> Option<(&'a str, Option<&'a str>)> {
+        self.genotypes.map(|s| {
+            let mut components = s.splitn(2, FIELD_DELIMITER);
+            let raw_format = components.next().unwrap_or_default();
+            (raw_format, components.next())
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Record<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut components = s.splitn(REQUIRED_FIELD_COUNT + 1, FIELD_DELIMITER);
+
+        let mut fields = [""; REQUIRED_FIELD_COUNT];
+
+        for (i, field) in REQUIRED_FIELDS.iter().enumerate() {
+            fields[i] = components.next().ok_or(ParseError::MissingField(*field))?;
+        }
+
+        Ok(Self {
+            line: s,
+            fields,
+            genotypes: components.next(),
+        })
+    }
+}
+
+impl<'a> TryFrom<Record<'a>> for super::Record {
+    type Error = ParseError;
+
+    fn try_from(record: Record<'a>) -> Result<Self, Self::Error> {
+        parse(record.line, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_str_for_record() {
+        assert_eq!(
+            Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS"),
+            Err(ParseError::MissingField(Field::Info))
+        );
+    }
+
+    #[test]
+    fn test_genotypes() -> Result<(), ParseError> {
+        let record = Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.\tGT")?;
+        assert!(record.genotypes()?.is_empty());
+
+        let record = Record::try_from("sq0\t8\t.\tA\t.\t.\tPASS\t.")?;
+        assert!(record.genotypes()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_lazy_record_for_record() -> Result<(), ParseError> {
+        let s = "sq0\t8\t.\tA\t.\t.\tPASS\tNS=3\tGT:GQ\t0|0:13";
+        let record = Record::try_from(s)?;
+        assert_eq!(super::super::Record::try_from(record), s.parse());
+        Ok(())
+    }
+}
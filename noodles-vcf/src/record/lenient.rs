@@ -0,0 +1,159 @@
+//! Lenient VCF record parsing.
+
+use std::fmt;
+
+use super::{parse, ParseError, QualityScore, Record, FIELD_DELIMITER, MISSING_FIELD};
+use crate::Header;
+
+const CARRIAGE_RETURN: char = '\r';
+const QUALITY_SCORE_INDEX: usize = 5;
+const FIRST_SAMPLE_INDEX: usize = 9;
+const GENOTYPE_FIELD_DELIMITER: char = ':';
+
+/// A recoverable problem found when leniently parsing a VCF record.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// The record ends with a carriage return (`\r`), e.g., from a CRLF line ending.
+    TrailingCarriageReturn,
+    /// The record ends with one or more empty fields, i.e., trailing tabs.
+    TrailingTabs,
+    /// A sample field contains leading or trailing whitespace around its values.
+    ///
+    /// This includes the 0-based sample index.
+    SampleFieldWhitespace(usize),
+    /// The quality score is invalid (e.g., negative) and was set to missing.
+    ///
+    /// This includes the raw value.
+    InvalidQualityScore(String),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TrailingCarriageReturn => f.write_str("trailing carriage return"),
+            Self::TrailingTabs => f.write_str("trailing tabs"),
+            Self::SampleFieldWhitespace(i) => write!(f, "whitespace in sample field {}", i),
+            Self::InvalidQualityScore(s) => write!(f, "invalid quality score: {}", s),
+        }
+    }
+}
+
+impl Record {
+    /// Parses a raw VCF record using header definitions, tolerating common formatting problems.
+    ///
+    /// This is like [`Self::try_from_str`] but accepts
+    ///
+    ///   * a trailing carriage return (CRLF line endings),
+    ///   * trailing tabs,
+    ///   * whitespace around sample field values, and
+    ///   * invalid quality scores (e.g., negative values), which are set to missing.
+    ///
+    /// Each correction is recorded as a [`Warning`]. Other problems are still returned as parse
+    /// errors, so a caller can skip a single malformed record rather than aborting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::lenient::Warning};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let (record, warnings) =
+    ///     vcf::Record::try_from_str_lenient("sq0\t1\t.\tA\t.\t-8\tPASS\t.\t\r", &header)?;
+    ///
+    /// assert_eq!(record.to_string(), "sq0\t1\t.\tA\t.\t.\tPASS\t.");
+    /// assert_eq!(
+    ///     warnings,
+    ///     [
+    ///         Warning::TrailingCarriageReturn,
+    ///         Warning::TrailingTabs,
+    ///         Warning::InvalidQualityScore(String::from("-8")),
+    ///     ]
+    /// );
+    /// # Ok::<(), vcf::record::ParseError>(())
+    /// ```
+    pub fn try_from_str_lenient(
+        s: &str,
+        header: &Header,
+    ) -> Result<(Self, Vec<Warning>), ParseError> {
+        let mut warnings = Vec::new();
+
+        let mut s = s;
+
+        if let Some(t) = s.strip_suffix(CARRIAGE_RETURN) {
+            warnings.push(Warning::TrailingCarriageReturn);
+            s = t;
+        }
+
+        let t = s.trim_end_matches(FIELD_DELIMITER);
+
+        if t.len() < s.len() {
+            warnings.push(Warning::TrailingTabs);
+            s = t;
+        }
+
+        let mut fields: Vec<String> = s.split(FIELD_DELIMITER).map(String::from).collect();
+
+        if let Some(raw_quality_score) = fields.get_mut(QUALITY_SCORE_INDEX) {
+            if raw_quality_score.parse::<QualityScore>().is_err() {
+                warnings.push(Warning::InvalidQualityScore(raw_quality_score.clone()));
+                *raw_quality_score = MISSING_FIELD.into();
+            }
+        }
+
+        for (i, field) in fields.iter_mut().skip(FIRST_SAMPLE_INDEX).enumerate() {
+            let trimmed_field = field
+                .split(GENOTYPE_FIELD_DELIMITER)
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join(&GENOTYPE_FIELD_DELIMITER.to_string());
+
+            if trimmed_field != *field {
+                warnings.push(Warning::SampleFieldWhitespace(i));
+                *field = trimmed_field;
+            }
+        }
+
+        let s = fields.join("\t");
+        let record = parse(&s, Some(header))?;
+
+        Ok((record, warnings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_str_lenient() -> Result<(), ParseError> {
+        let header = Header::default();
+
+        let (record, warnings) =
+            Record::try_from_str_lenient("sq0\t1\t.\tA\t.\t.\tPASS\t.", &header)?;
+        assert_eq!(record.to_string(), "sq0\t1\t.\tA\t.\t.\tPASS\t.");
+        assert!(warnings.is_empty());
+
+        let (record, warnings) = Record::try_from_str_lenient(
+            "sq0\t1\t.\tA\tC\t-inf\tPASS\t.\tGT:GQ\t 0/1 : 13\t1/1\t\t\r",
+            &header,
+        )?;
+        assert_eq!(
+            record.to_string(),
+            "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT:GQ\t0/1:13\t1/1"
+        );
+        assert_eq!(
+            warnings,
+            [
+                Warning::TrailingCarriageReturn,
+                Warning::TrailingTabs,
+                Warning::InvalidQualityScore(String::from("-inf")),
+                Warning::SampleFieldWhitespace(0),
+            ]
+        );
+
+        assert!(Record::try_from_str_lenient("sq0\t1", &header).is_err());
+
+        Ok(())
+    }
+}
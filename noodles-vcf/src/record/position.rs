@@ -5,6 +5,11 @@ use std::{convert::TryFrom, error, fmt, num, str::FromStr};
 const MIN: i32 = 0;
 
 /// A VCF record position.
+///
+/// This is typically a 1-based position, but it can also be 0 to represent a telomeric breakend.
+/// Because of this, VCF records do not use [`noodles_core::Position`] directly. The checked
+/// conversions to and from [`noodles_core::Position`] can be used when a non-zero position is
+/// required, e.g., to build a [`noodles_core::region::Interval`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Position(i32);
 
@@ -67,6 +72,22 @@ impl TryFrom<i32> for Position {
     }
 }
 
+impl TryFrom<Position> for noodles_core::Position {
+    type Error = noodles_core::position::TryFromIntError;
+
+    fn try_from(position: Position) -> Result<Self, Self::Error> {
+        Self::try_from(position.0)
+    }
+}
+
+impl TryFrom<noodles_core::Position> for Position {
+    type Error = num::TryFromIntError;
+
+    fn try_from(position: noodles_core::Position) -> Result<Self, Self::Error> {
+        i32::try_from(position).map(Self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +115,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_position_for_core_position() {
+        assert_eq!(
+            noodles_core::Position::try_from(Position(8)),
+            Ok(noodles_core::Position::new(8).unwrap())
+        );
+        assert!(noodles_core::Position::try_from(Position(0)).is_err());
+    }
+
+    #[test]
+    fn test_try_from_core_position_for_position() {
+        let position = noodles_core::Position::new(8).unwrap();
+        assert_eq!(Position::try_from(position), Ok(Position(8)));
+
+        let position = noodles_core::Position::new(usize::MAX).unwrap();
+        assert!(Position::try_from(position).is_err());
+    }
+
     #[test]
     fn test_try_from_i32_for_position() {
         assert_eq!(Position::try_from(0), Ok(Position(0)));
@@ -21,6 +21,8 @@ impl Deref for QualityScore {
 impl fmt::Display for QualityScore {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0 {
+            // Rust formats infinity as `inf`, which is not a valid VCF float.
+            Some(score) if score.is_infinite() => f.write_str("Infinity"),
             Some(score) => write!(f, "{}", score),
             None => f.write_str(MISSING_FIELD),
         }
@@ -102,6 +104,15 @@ mod tests {
         let quality_score = QualityScore(Some(1.3));
         assert_eq!(quality_score.to_string(), "1.3");
 
+        let quality_score = QualityScore(Some(30.0));
+        assert_eq!(quality_score.to_string(), "30");
+
+        let quality_score = QualityScore(Some(0.0));
+        assert_eq!(quality_score.to_string(), "0");
+
+        let quality_score = QualityScore(Some(f32::INFINITY));
+        assert_eq!(quality_score.to_string(), "Infinity");
+
         let quality_score = QualityScore(None);
         assert_eq!(quality_score.to_string(), ".");
     }
@@ -117,9 +128,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_str_round_trip() -> Result<(), ParseError> {
+        for s in &[".", "0", "30", "5.8", "Infinity"] {
+            let quality_score: QualityScore = s.parse()?;
+            assert_eq!(quality_score.to_string(), *s);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(".".parse(), Ok(QualityScore(None)));
+        assert_eq!("0".parse(), Ok(QualityScore(Some(0.0))));
         assert_eq!("5.8".parse(), Ok(QualityScore(Some(5.8))));
         assert_eq!("Infinity".parse(), Ok(QualityScore(Some(f32::INFINITY))));
 
@@ -57,16 +57,28 @@ impl FromStr for ReferenceBases {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "" => Err(ParseError::Empty),
-            MISSING_FIELD => Err(ParseError::Missing),
-            _ => s
-                .chars()
-                .map(|c| c.to_ascii_uppercase())
-                .map(Base::try_from)
-                .collect::<Result<_, _>>()
-                .map(Self)
-                .map_err(ParseError::InvalidBase),
+        let mut reference_bases = Self(Vec::new());
+        parse_into(s, &mut reference_bases)?;
+        Ok(reference_bases)
+    }
+}
+
+// Parses raw reference bases into existing reference bases, reusing its buffer.
+pub(crate) fn parse_into(s: &str, reference_bases: &mut ReferenceBases) -> Result<(), ParseError> {
+    match s {
+        "" => Err(ParseError::Empty),
+        MISSING_FIELD => Err(ParseError::Missing),
+        _ => {
+            let bases = &mut reference_bases.0;
+            bases.clear();
+
+            for c in s.chars() {
+                let base =
+                    Base::try_from(c.to_ascii_uppercase()).map_err(ParseError::InvalidBase)?;
+                bases.push(base);
+            }
+
+            Ok(())
         }
     }
 }
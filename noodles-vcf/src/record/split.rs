@@ -0,0 +1,384 @@
+//! VCF record multiallelic split.
+
+use std::{convert::TryFrom, error, fmt};
+
+use crate::{header::Number, Header};
+
+use super::{
+    genotype::{self, field::value::genotype::Allele},
+    info, AlternateBases, Genotype, Info, Record,
+};
+
+/// An error returned when a VCF record fails to split.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SplitError {
+    /// An info field value does not have the number of values defined by its cardinality.
+    InvalidInfoFieldValue(info::field::Key),
+    /// The info fields are invalid.
+    InvalidInfo(info::TryFromFieldsError),
+    /// A genotype field value does not have the number of values defined by its cardinality.
+    InvalidGenotypeFieldValue(genotype::field::Key),
+    /// A genotype (`GT`) field value is invalid.
+    InvalidGenotypeValue(genotype::GenotypeError),
+    /// The genotype fields are invalid.
+    InvalidGenotype(genotype::TryFromFieldsError),
+}
+
+impl error::Error for SplitError {}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInfoFieldValue(key) => write!(f, "invalid info field value: {}", key),
+            Self::InvalidInfo(e) => write!(f, "invalid info: {}", e),
+            Self::InvalidGenotypeFieldValue(key) => {
+                write!(f, "invalid genotype field value: {}", key)
+            }
+            Self::InvalidGenotypeValue(e) => write!(f, "invalid genotype value: {}", e),
+            Self::InvalidGenotype(e) => write!(f, "invalid genotype: {}", e),
+        }
+    }
+}
+
+impl Record {
+    /// Splits a multiallelic record into biallelic records, one per alternate allele.
+    ///
+    /// This is similar to `bcftools norm -m-`. For each alternate allele, INFO and genotype
+    /// field values with a cardinality of `A`, `R`, or `G` are subset to the values of the
+    /// reference and that alternate allele. Cardinalities are taken from the header definitions,
+    /// if present, and the keys otherwise. Genotype (`GT`) allele positions are rewritten, where
+    /// the alternate allele becomes 1 and other alternate alleles become the reference allele
+    /// (0).
+    ///
+    /// `G` values are expected to have the number of values for either a haploid or diploid
+    /// genotype.
+    ///
+    /// If the record has less than two alternate alleles, a copy of the record is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::{genotype::field::Key, info}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(vcf::header::Info::from(info::field::Key::AlleleFrequencies))
+    ///     .add_format(vcf::header::Format::from(Key::Genotype))
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str(
+    ///     "sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.25,0.5\tGT\t1/2",
+    ///     &header,
+    /// )?;
+    ///
+    /// let records = record.split_multiallelic(&header)?;
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tC\t.\tPASS\tAF=0.25\tGT\t1/0");
+    /// assert_eq!(records[1].to_string(), "sq0\t1\t.\tA\tG\t.\tPASS\tAF=0.5\tGT\t0/1");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn split_multiallelic(&self, header: &Header) -> Result<Vec<Self>, SplitError> {
+        let alternate_allele_count = self.alternate_bases().len();
+
+        if alternate_allele_count < 2 {
+            return Ok(vec![self.clone()]);
+        }
+
+        (1..=alternate_allele_count)
+            .map(|i| split_allele(self, header, i))
+            .collect()
+    }
+}
+
+fn split_allele(record: &Record, header: &Header, i: usize) -> Result<Record, SplitError> {
+    let allele_count = record.alternate_bases().len() + 1;
+
+    let alternate_bases = AlternateBases::from(vec![record.alternate_bases()[i - 1].clone()]);
+
+    let info_fields = record
+        .info()
+        .values()
+        .map(|field| {
+            let key = field.key();
+
+            let number = header
+                .infos()
+                .get(key)
+                .map(|info| info.number())
+                .unwrap_or_else(|| key.number());
+
+            split_info_field_value(field.value(), number, allele_count, i)
+                .map(|value| info::Field::new(key.clone(), value))
+                .ok_or_else(|| SplitError::InvalidInfoFieldValue(key.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let info = Info::try_from(info_fields).map_err(SplitError::InvalidInfo)?;
+
+    let genotypes = record
+        .genotypes()
+        .iter()
+        .map(|genotype| split_genotype(genotype, header, allele_count, i))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Record {
+        chromosome: record.chromosome().clone(),
+        position: record.position(),
+        ids: record.ids().clone(),
+        reference_bases: record.reference_bases().clone(),
+        alternate_bases,
+        quality_score: record.quality_score(),
+        filters: record.filters().clone(),
+        info,
+        format: record.format().cloned(),
+        genotypes,
+    })
+}
+
+fn split_genotype(
+    genotype: &Genotype,
+    header: &Header,
+    allele_count: usize,
+    i: usize,
+) -> Result<Genotype, SplitError> {
+    use genotype::field::{Key, Value};
+
+    let gt = genotype
+        .genotype()
+        .transpose()
+        .map_err(SplitError::InvalidGenotypeValue)?;
+
+    let fields = genotype
+        .values()
+        .map(|field| {
+            let key = field.key();
+
+            let value = match field.value() {
+                Some(_) if key == &Key::Genotype => gt
+                    .as_ref()
+                    .map(|gt| Value::String(split_genotype_value(gt, i))),
+                Some(value) => {
+                    let number = header
+                        .formats()
+                        .get(key)
+                        .map(|format| format.number())
+                        .unwrap_or_else(|| key.number());
+
+                    split_genotype_field_value(value, number, allele_count, i)
+                        .map(Some)
+                        .ok_or_else(|| SplitError::InvalidGenotypeFieldValue(key.clone()))?
+                }
+                None => None,
+            };
+
+            Ok(genotype::Field::new(key.clone(), value))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Genotype::try_from(fields).map_err(SplitError::InvalidGenotype)
+}
+
+fn split_genotype_value(genotype: &genotype::field::value::Genotype, i: usize) -> String {
+    genotype
+        .iter()
+        .map(|allele| {
+            let position = allele
+                .position()
+                .map(|position| if position == i { 1 } else { 0 });
+
+            Allele::new(position, allele.phasing()).to_string()
+        })
+        .collect()
+}
+
+fn split_info_field_value(
+    value: &info::field::Value,
+    number: Number,
+    allele_count: usize,
+    i: usize,
+) -> Option<info::field::Value> {
+    use info::field::Value;
+
+    match value {
+        Value::IntegerArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::IntegerArray)
+        }
+        Value::FloatArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::FloatArray)
+        }
+        Value::CharacterArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::CharacterArray)
+        }
+        Value::StringArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::StringArray)
+        }
+        _ => Some(value.clone()),
+    }
+}
+
+fn split_genotype_field_value(
+    value: &genotype::field::Value,
+    number: Number,
+    allele_count: usize,
+    i: usize,
+) -> Option<genotype::field::Value> {
+    use genotype::field::Value;
+
+    match value {
+        Value::IntegerArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::IntegerArray)
+        }
+        Value::FloatArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::FloatArray)
+        }
+        Value::CharacterArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::CharacterArray)
+        }
+        Value::StringArray(values) => {
+            split_values(values, number, allele_count, i).map(Value::StringArray)
+        }
+        _ => Some(value.clone()),
+    }
+}
+
+// Returns `None` if the number of values does not match the cardinality.
+fn split_values<T>(values: &[T], number: Number, allele_count: usize, i: usize) -> Option<Vec<T>>
+where
+    T: Clone,
+{
+    let indices = match number {
+        Number::A if values.len() == allele_count - 1 => vec![i - 1],
+        Number::R if values.len() == allele_count => vec![0, i],
+        Number::G if Some(values.len()) == Number::G.expected_count(allele_count - 1, 1) => {
+            vec![0, i]
+        }
+        Number::G if Some(values.len()) == Number::G.expected_count(allele_count - 1, 2) => {
+            vec![0, genotype_index(0, i), genotype_index(i, i)]
+        }
+        Number::A | Number::R | Number::G => return None,
+        _ => return Some(values.to_vec()),
+    };
+
+    Some(indices.into_iter().map(|j| values[j].clone()).collect())
+}
+
+// § 1.6.2 Genotype fields (2021-01-13): "...the ordering of genotypes for the likelihoods is given
+// by: F(j/k) = (k*(k+1)/2)+j."
+fn genotype_index(j: usize, k: usize) -> usize {
+    k * (k + 1) / 2 + j
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        header,
+        record::{Format, Position},
+    };
+
+    use super::*;
+
+    fn build_header() -> Header {
+        use genotype::field::Key;
+
+        Header::builder()
+            .add_info(header::Info::from(info::field::Key::TotalDepth))
+            .add_info(header::Info::from(info::field::Key::AlleleCount))
+            .add_info(header::Info::from(info::field::Key::TotalReadDepths))
+            .add_format(header::Format::from(Key::Genotype))
+            .add_format(header::Format::from(Key::ReadDepths))
+            .add_format(header::Format::from(Key::RoundedGenotypeLikelihoods))
+            .build()
+    }
+
+    #[test]
+    fn test_split_multiallelic() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let record = Record::try_from_str(
+            "sq0\t8\tnd0\tA\tC,G\t13\tPASS\tDP=8;AC=1,2;AD=4,1,3\tGT:AD:PL\t0/1:5,3,0:0,1,2,3,4,5\t1|2:0,4,4:10,20,30,40,50,60\t.",
+            &header,
+        )?;
+
+        let actual = record.split_multiallelic(&header)?;
+
+        let expected = [
+            "sq0\t8\tnd0\tA\tC\t13\tPASS\tDP=8;AC=1;AD=4,1\tGT:AD:PL\t0/1:5,3:0,1,2\t1|0:0,4:10,20,30\t.",
+            "sq0\t8\tnd0\tA\tG\t13\tPASS\tDP=8;AC=2;AD=4,3\tGT:AD:PL\t0/0:5,0:0,3,5\t0|1:0,4:10,40,60\t.",
+        ];
+
+        assert_eq!(actual.len(), expected.len());
+
+        for (record, expected_record) in actual.iter().zip(expected.iter()) {
+            assert_eq!(&record.to_string(), expected_record);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelic_with_biallelic_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let record = Record::try_from_str("sq0\t8\t.\tA\tC\t.\tPASS\tAC=1", &header)?;
+        assert_eq!(record.split_multiallelic(&header)?, [record]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelic_with_invalid_value_count() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header = build_header();
+
+        let record = Record::try_from_str("sq0\t8\t.\tA\tC,G\t.\tPASS\tAC=1", &header)?;
+        assert_eq!(
+            record.split_multiallelic(&header),
+            Err(SplitError::InvalidInfoFieldValue(
+                info::field::Key::AlleleCount
+            ))
+        );
+
+        // The parser rejects invalid genotype value counts, so the record is built directly.
+        let format: Format = "GT:AD".parse()?;
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases("C,G".parse()?)
+            .set_format(format.clone())
+            .add_genotype(Genotype::try_from_str(
+                "0/1:1,2",
+                &format,
+                header.formats(),
+            )?)
+            .build()?;
+        assert_eq!(
+            record.split_multiallelic(&header),
+            Err(SplitError::InvalidGenotypeFieldValue(
+                genotype::field::Key::ReadDepths
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_values() {
+        let values = [1, 2, 3, 4, 5, 6];
+
+        assert_eq!(split_values(&values[..2], Number::A, 3, 2), Some(vec![2]));
+        assert_eq!(
+            split_values(&values[..3], Number::R, 3, 2),
+            Some(vec![1, 3])
+        );
+        assert_eq!(
+            split_values(&values[..3], Number::G, 3, 2),
+            Some(vec![1, 3])
+        );
+        assert_eq!(split_values(&values, Number::G, 3, 1), Some(vec![1, 2, 3]));
+        assert_eq!(split_values(&values, Number::G, 3, 2), Some(vec![1, 4, 6]));
+        assert_eq!(
+            split_values(&values[..2], Number::Count(2), 3, 2),
+            Some(vec![1, 2])
+        );
+        assert_eq!(split_values(&values[..2], Number::R, 3, 2), None);
+    }
+}
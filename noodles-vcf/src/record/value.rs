@@ -1,7 +1,16 @@
-use std::{borrow::Cow, num, str};
+use std::{borrow::Cow, fmt::Write, num, str};
 
 use percent_encoding::percent_decode_str;
 
+use crate::header::FileFormat;
+
+// § 1.2 Character encoding, non-printable characters and characters with special meaning
+// (2021-01-13)
+pub(crate) const INFO_RESERVED_CHARS: &[char] = &[':', ';', '=', ',', '\r', '\n', '\t'];
+
+// Semicolons are not encoded in genotype values because they delimit filters in `FT`.
+pub(crate) const GENOTYPE_RESERVED_CHARS: &[char] = &[':', '=', ',', '\r', '\n', '\t'];
+
 /// Parses a single-precision floating-point.
 ///
 /// This is extended to support case-insensitive values of (+/-)infinity and NaN.
@@ -21,6 +30,60 @@ pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
 
+/// Percent-encodes the given reserved characters in a string value.
+///
+/// The percent sign (`%`) is always encoded.
+pub(crate) fn percent_encode<'a>(s: &'a str, reserved: &[char]) -> Cow<'a, str> {
+    let is_reserved = |c: char| c == '%' || reserved.contains(&c);
+
+    if !s.contains(is_reserved) {
+        return Cow::from(s);
+    }
+
+    let mut buf = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        if is_reserved(c) {
+            // Writing to a `String` is infallible.
+            write!(buf, "%{:02X}", c as u32).ok();
+        } else {
+            buf.push(c);
+        }
+    }
+
+    Cow::from(buf)
+}
+
+/// Decodes a raw string value for the given file format.
+///
+/// Percent-encoding was introduced in VCFv4.3. Strings in earlier versions are taken literally.
+pub(crate) fn decode_string(
+    s: &str,
+    file_format: FileFormat,
+) -> Result<Cow<'_, str>, str::Utf8Error> {
+    if file_format >= FileFormat::new(4, 3) {
+        percent_decode(s)
+    } else {
+        Ok(Cow::from(s))
+    }
+}
+
+/// Encodes a string value for the given file format.
+///
+/// This is the inverse of [`decode_string`]. Strings in file formats earlier than VCFv4.3 are
+/// written literally.
+pub(crate) fn encode_string<'a>(
+    s: &'a str,
+    file_format: FileFormat,
+    reserved: &[char],
+) -> Cow<'a, str> {
+    if file_format >= FileFormat::new(4, 3) {
+        percent_encode(s, reserved)
+    } else {
+        Cow::from(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,6 +125,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_decode_string() -> Result<(), str::Utf8Error> {
+        assert_eq!(
+            decode_string("noodles%3Dvcf", FileFormat::new(4, 3))?,
+            "noodles=vcf"
+        );
+        assert_eq!(
+            decode_string("noodles%3Dvcf", FileFormat::new(4, 2))?,
+            "noodles%3Dvcf"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("noodles", &[';']), "noodles");
+        assert_eq!(percent_encode("noodles;vcf", &[';']), "noodles%3Bvcf");
+        assert_eq!(percent_encode("noodles:vcf", &[';']), "noodles:vcf");
+        assert_eq!(percent_encode("50%", &[]), "50%25");
+        assert_eq!(
+            percent_encode("n\td\nl\rs", &['\t', '\n', '\r']),
+            "n%09d%0Al%0Ds"
+        );
+    }
+
     #[test]
     fn test_percent_decode() -> Result<(), str::Utf8Error> {
         assert_eq!(percent_decode("noodles")?, "noodles");
@@ -0,0 +1,290 @@
+//! VCF record variant key.
+
+use super::{alternate_bases::Allele, Record};
+
+/// A VCF record variant key.
+///
+/// A variant key identifies a variant by its chromosome (`CHROM`), position (`POS`), reference
+/// bases (`REF`), and alternate bases (`ALT`). It can be used to compare or deduplicate records
+/// across call sets.
+///
+/// Keys are ordered by chromosome name, position, reference bases, and then alternate bases.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, record::VariantKey};
+///
+/// let record: vcf::Record = "sq0\t8\t.\tACT\tAGT\t.\tPASS\t.".parse()?;
+/// let key = VariantKey::from(&record);
+///
+/// assert_eq!(key.chromosome(), "sq0");
+/// assert_eq!(key.position(), 8);
+/// assert_eq!(key.reference_bases(), "ACT");
+/// assert_eq!(key.alternate_bases(), [String::from("AGT")]);
+/// # Ok::<(), vcf::record::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VariantKey {
+    chromosome: String,
+    position: i32,
+    reference_bases: String,
+    alternate_bases: Vec<String>,
+}
+
+impl VariantKey {
+    /// Creates a variant key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::VariantKey;
+    ///
+    /// let key = VariantKey::new(
+    ///     String::from("sq0"),
+    ///     8,
+    ///     String::from("A"),
+    ///     vec![String::from("G")],
+    /// );
+    /// ```
+    pub fn new(
+        chromosome: String,
+        position: i32,
+        reference_bases: String,
+        alternate_bases: Vec<String>,
+    ) -> Self {
+        Self {
+            chromosome,
+            position,
+            reference_bases,
+            alternate_bases,
+        }
+    }
+
+    /// Returns the chromosome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::VariantKey;
+    /// let key = VariantKey::new(String::from("sq0"), 8, String::from("A"), vec![String::from("G")]);
+    /// assert_eq!(key.chromosome(), "sq0");
+    /// ```
+    pub fn chromosome(&self) -> &str {
+        &self.chromosome
+    }
+
+    /// Returns the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::VariantKey;
+    /// let key = VariantKey::new(String::from("sq0"), 8, String::from("A"), vec![String::from("G")]);
+    /// assert_eq!(key.position(), 8);
+    /// ```
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    /// Returns the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::VariantKey;
+    /// let key = VariantKey::new(String::from("sq0"), 8, String::from("A"), vec![String::from("G")]);
+    /// assert_eq!(key.reference_bases(), "A");
+    /// ```
+    pub fn reference_bases(&self) -> &str {
+        &self.reference_bases
+    }
+
+    /// Returns the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::VariantKey;
+    /// let key = VariantKey::new(String::from("sq0"), 8, String::from("A"), vec![String::from("G")]);
+    /// assert_eq!(key.alternate_bases(), [String::from("G")]);
+    /// ```
+    pub fn alternate_bases(&self) -> &[String] {
+        &self.alternate_bases
+    }
+
+    /// Returns a normalized variant key.
+    ///
+    /// The alleles are trimmed to their most parsimonious representation: bases shared by all
+    /// alleles are removed from the end and then from the start, leaving at least one base per
+    /// allele. The position is adjusted for each base removed from the start.
+    ///
+    /// Keys with a missing alternate allele or with alleles that are not simple bases (symbolic
+    /// alleles, breakends, and overlapping deletions) are returned unchanged. This does not
+    /// left-align indels, which requires the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::VariantKey;
+    ///
+    /// let key = VariantKey::new(
+    ///     String::from("sq0"),
+    ///     8,
+    ///     String::from("ACT"),
+    ///     vec![String::from("AGT")],
+    /// );
+    ///
+    /// let normalized_key = key.normalize();
+    ///
+    /// assert_eq!(normalized_key.position(), 9);
+    /// assert_eq!(normalized_key.reference_bases(), "C");
+    /// assert_eq!(normalized_key.alternate_bases(), [String::from("G")]);
+    /// ```
+    pub fn normalize(mut self) -> Self {
+        if self.alternate_bases.is_empty()
+            || !is_bases(&self.reference_bases)
+            || !self.alternate_bases.iter().all(|a| is_bases(a))
+        {
+            return self;
+        }
+
+        while has_shared_base(&self, |s| s.as_bytes().last().copied()) {
+            self.reference_bases.pop();
+
+            for allele in &mut self.alternate_bases {
+                allele.pop();
+            }
+        }
+
+        while has_shared_base(&self, |s| s.as_bytes().first().copied()) {
+            self.reference_bases.remove(0);
+
+            for allele in &mut self.alternate_bases {
+                allele.remove(0);
+            }
+
+            self.position += 1;
+        }
+
+        self
+    }
+}
+
+impl From<&Record> for VariantKey {
+    fn from(record: &Record) -> Self {
+        Self {
+            chromosome: record.chromosome().to_string(),
+            position: i32::from(record.position()),
+            reference_bases: record.reference_bases().to_string(),
+            alternate_bases: record
+                .alternate_bases()
+                .iter()
+                .map(|allele| allele.to_string())
+                .collect(),
+        }
+    }
+}
+
+fn is_bases(s: &str) -> bool {
+    !s.is_empty()
+        && s.parse::<Allele>()
+            .map(|allele| matches!(allele, Allele::Bases(_)))
+            .unwrap_or(false)
+}
+
+// Returns whether every allele has more than one base and all alleles share the base selected by
+// `f`.
+fn has_shared_base<F>(key: &VariantKey, f: F) -> bool
+where
+    F: Fn(&str) -> Option<u8>,
+{
+    if key.reference_bases.len() < 2 || key.alternate_bases.iter().any(|a| a.len() < 2) {
+        return false;
+    }
+
+    let base = f(&key.reference_bases);
+    key.alternate_bases.iter().all(|a| f(a) == base)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn build_key(position: i32, reference_bases: &str, alternate_bases: &[&str]) -> VariantKey {
+        VariantKey::new(
+            String::from("sq0"),
+            position,
+            reference_bases.into(),
+            alternate_bases.iter().map(|&s| s.into()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_from_record() -> Result<(), crate::record::ParseError> {
+        let record: Record = "sq0\t8\t.\tA\tG,<DEL>\t.\tPASS\t.".parse()?;
+        assert_eq!(
+            VariantKey::from(&record),
+            build_key(8, "A", &["G", "<DEL>"])
+        );
+
+        let record: Record = "sq0\t8\tr0\tA\tG\t13\tq10\tNS=1".parse()?;
+        let duplicate_record: Record = "sq0\t8\t.\tA\tG\t.\tPASS\t.".parse()?;
+
+        let keys: HashSet<_> = [&record, &duplicate_record]
+            .iter()
+            .map(|r| VariantKey::from(*r))
+            .collect();
+
+        assert_eq!(keys.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            build_key(8, "ACT", &["AGT"]).normalize(),
+            build_key(9, "C", &["G"])
+        );
+        assert_eq!(
+            build_key(8, "ACTT", &["AT"]).normalize(),
+            build_key(8, "ACT", &["A"])
+        );
+        assert_eq!(
+            build_key(8, "CAGG", &["CTGG", "CAG"]).normalize(),
+            build_key(9, "AG", &["TG", "A"])
+        );
+        assert_eq!(
+            build_key(8, "A", &["G"]).normalize(),
+            build_key(8, "A", &["G"])
+        );
+        assert_eq!(
+            build_key(8, "AC", &["<DEL>"]).normalize(),
+            build_key(8, "AC", &["<DEL>"])
+        );
+        assert_eq!(build_key(8, "AC", &[]).normalize(), build_key(8, "AC", &[]));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut keys = vec![
+            build_key(13, "A", &["G"]),
+            build_key(8, "C", &["T"]),
+            build_key(8, "A", &["T"]),
+        ];
+
+        keys.sort();
+
+        assert_eq!(
+            keys,
+            [
+                build_key(8, "A", &["T"]),
+                build_key(8, "C", &["T"]),
+                build_key(13, "A", &["G"]),
+            ]
+        );
+    }
+}
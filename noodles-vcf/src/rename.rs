@@ -0,0 +1,294 @@
+//! VCF chromosome renaming.
+//!
+//! This renames reference sequences using a map of old to new names, e.g., to convert between
+//! Ensembl (`1`) and UCSC (`chr1`) naming conventions. A header and its records should be renamed
+//! with the same map.
+
+use std::{collections::HashMap, error, fmt};
+
+use crate::{
+    header::Contigs,
+    record::{
+        alternate_bases::{
+            allele::{breakend::Mate, Breakend},
+            Allele,
+        },
+        AlternateBases, Chromosome,
+    },
+    Header, Record,
+};
+
+/// An error returned when a VCF header fails to be renamed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RenameError {
+    /// More than one contig is renamed to the same name, or a contig is renamed to the name of
+    /// another contig.
+    DuplicateName(String),
+}
+
+impl error::Error for RenameError {}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateName(name) => write!(f, "duplicate name: {}", name),
+        }
+    }
+}
+
+/// Renames the contig records (`contig`) of a VCF header.
+///
+/// Contigs with names not in the map are unchanged. The order of the contigs is kept.
+///
+/// This returns an error if the renamed contigs do not have unique names. The header is not
+/// modified in that case.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use noodles_vcf::{self as vcf, header::Contig, rename::rename_header};
+///
+/// let mut header = vcf::Header::builder()
+///     .add_contig(Contig::new(String::from("1")))
+///     .add_contig(Contig::new(String::from("MT")))
+///     .build();
+///
+/// let names: HashMap<_, _> = vec![(String::from("1"), String::from("chr1"))]
+///     .into_iter()
+///     .collect();
+///
+/// rename_header(&mut header, &names)?;
+///
+/// let ids: Vec<_> = header.contigs().keys().map(|id| id.as_str()).collect();
+/// assert_eq!(ids, ["chr1", "MT"]);
+/// # Ok::<_, vcf::rename::RenameError>(())
+/// ```
+pub fn rename_header(
+    header: &mut Header,
+    names: &HashMap<String, String>,
+) -> Result<(), RenameError> {
+    let mut contigs = Contigs::with_capacity(header.contigs().len());
+
+    for contig in header.contigs().values() {
+        let mut contig = contig.clone();
+
+        if let Some(name) = names.get(contig.id()) {
+            *contig.id_mut() = name.clone();
+        }
+
+        let id = contig.id().to_string();
+
+        if contigs.contains_key(&id) {
+            return Err(RenameError::DuplicateName(id));
+        }
+
+        contigs.insert(id, contig);
+    }
+
+    header.replace_contigs(contigs, names);
+
+    Ok(())
+}
+
+/// Renames the chromosome (`CHROM`) and breakend mate chromosomes of a VCF record.
+///
+/// Names not in the map and symbolic chromosomes (e.g., `<ctg0>`) are unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// use std::collections::HashMap;
+/// use noodles_vcf::{self as vcf, record::Position, rename::rename_record};
+///
+/// let mut record = vcf::Record::builder()
+///     .set_chromosome("1".parse()?)
+///     .set_position(Position::try_from(8)?)
+///     .set_reference_bases("A".parse()?)
+///     .set_alternate_bases("A[2:13[".parse()?)
+///     .build()?;
+///
+/// let names: HashMap<_, _> = vec![
+///     (String::from("1"), String::from("chr1")),
+///     (String::from("2"), String::from("chr2")),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// rename_record(&mut record, &names);
+///
+/// assert_eq!(record.chromosome().to_string(), "chr1");
+/// assert_eq!(record.alternate_bases().to_string(), "A[chr2:13[");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn rename_record(record: &mut Record, names: &HashMap<String, String>) {
+    if let Some(chromosome) = rename_chromosome(record.chromosome(), names) {
+        *record.chromosome_mut() = chromosome;
+    }
+
+    let alternate_bases = record.alternate_bases();
+
+    if !alternate_bases
+        .iter()
+        .any(|allele| is_renamed(allele, names))
+    {
+        return;
+    }
+
+    let alleles: Vec<_> = alternate_bases
+        .iter()
+        .map(|allele| match allele {
+            Allele::Breakend(breakend) => Allele::Breakend(rename_breakend(breakend, names)),
+            _ => allele.clone(),
+        })
+        .collect();
+
+    *record.alternate_bases_mut() = AlternateBases::from(alleles);
+}
+
+fn rename_chromosome(
+    chromosome: &Chromosome,
+    names: &HashMap<String, String>,
+) -> Option<Chromosome> {
+    match chromosome {
+        Chromosome::Name(name) => names.get(name).cloned().map(Chromosome::Name),
+        Chromosome::Symbol(_) => None,
+    }
+}
+
+fn is_renamed(allele: &Allele, names: &HashMap<String, String>) -> bool {
+    match allele {
+        Allele::Breakend(breakend) => breakend
+            .mate()
+            .and_then(|mate| rename_chromosome(mate.chromosome(), names))
+            .is_some(),
+        _ => false,
+    }
+}
+
+fn rename_breakend(breakend: &Breakend, names: &HashMap<String, String>) -> Breakend {
+    let mate = breakend.mate().map(|mate| {
+        let chromosome = rename_chromosome(mate.chromosome(), names)
+            .unwrap_or_else(|| mate.chromosome().clone());
+
+        Mate::new(chromosome, mate.position(), mate.direction())
+    });
+
+    Breakend::new(breakend.sequence().into(), breakend.orientation(), mate)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::{header::Contig, record::Position};
+
+    use super::*;
+
+    fn build_names() -> HashMap<String, String> {
+        vec![
+            (String::from("1"), String::from("chr1")),
+            (String::from("2"), String::from("chr2")),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_rename_header() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header: Header = r#"##fileformat=VCFv4.3
+##contig=<ID=1>
+##fileDate=20200506
+##contig=<ID=MT>
+##contig=<ID=2,length=13>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        rename_header(&mut header, &build_names())?;
+
+        let contigs = header.contigs();
+        let ids: Vec<_> = contigs.keys().map(|id| id.as_str()).collect();
+        assert_eq!(ids, ["chr1", "MT", "chr2"]);
+        assert_eq!(contigs["chr2"].id(), "chr2");
+        assert_eq!(contigs["chr2"].len(), Some(13));
+
+        let expected = r#"##fileformat=VCFv4.3
+##contig=<ID=chr1>
+##fileDate=20200506
+##contig=<ID=MT>
+##contig=<ID=chr2,length=13>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(header.to_string(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_header_with_duplicate_names() {
+        let header = Header::builder()
+            .add_contig(Contig::new(String::from("1")))
+            .add_contig(Contig::new(String::from("chr1")))
+            .build();
+
+        let mut actual = header.clone();
+        assert_eq!(
+            rename_header(&mut actual, &build_names()),
+            Err(RenameError::DuplicateName(String::from("chr1")))
+        );
+        assert_eq!(actual, header);
+
+        let header = Header::builder()
+            .add_contig(Contig::new(String::from("1")))
+            .add_contig(Contig::new(String::from("2")))
+            .build();
+
+        let names = vec![
+            (String::from("1"), String::from("chr1")),
+            (String::from("2"), String::from("chr1")),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut actual = header;
+        assert_eq!(
+            rename_header(&mut actual, &names),
+            Err(RenameError::DuplicateName(String::from("chr1")))
+        );
+    }
+
+    #[test]
+    fn test_rename_record() -> Result<(), Box<dyn std::error::Error>> {
+        let names = build_names();
+
+        let mut record = Record::builder()
+            .set_chromosome("1".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases("C,]2:21]A,A[MT:5[,<DEL>".parse()?)
+            .build()?;
+
+        rename_record(&mut record, &names);
+
+        assert_eq!(record.chromosome().to_string(), "chr1");
+        assert_eq!(
+            record.alternate_bases().to_string(),
+            "C,]chr2:21]A,A[MT:5[,<DEL>"
+        );
+
+        let mut record = Record::builder()
+            .set_chromosome("<ctg0>".parse()?)
+            .set_position(Position::try_from(8)?)
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        rename_record(&mut record, &names);
+
+        assert_eq!(record.chromosome().to_string(), "<ctg0>");
+
+        Ok(())
+    }
+}
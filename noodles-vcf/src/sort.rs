@@ -0,0 +1,377 @@
+//! VCF external-memory sorting.
+//!
+//! Records are sorted by their reference sequence (`CHROM`), in the order of the header contigs,
+//! and then by position (`POS`). Input larger than memory is split into sorted runs that are
+//! spilled to temporary files and then merged.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{Header, Reader, Writer};
+
+const DEFAULT_MAX_RECORD_COUNT: usize = 1 << 20;
+
+const FIELD_DELIMITER: char = '\t';
+
+static RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Records on reference sequences that are not in the header sort after all defined contigs, by
+// name.
+type SortKey = (usize, String, i32);
+
+/// A VCF record sorter.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, sort::Sorter};
+///
+/// let data = concat!(
+///     "##fileformat=VCFv4.3\n",
+///     "##contig=<ID=sq0>\n",
+///     "##contig=<ID=sq1>\n",
+///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+///     "sq1\t5\t.\tA\t.\t.\tPASS\t.\n",
+///     "sq0\t13\t.\tA\t.\t.\tPASS\t.\n",
+///     "sq0\t8\t.\tA\t.\t.\tPASS\t.\n",
+/// );
+///
+/// let mut reader = vcf::Reader::new(data.as_bytes());
+/// let header: vcf::Header = reader.read_header()?.parse()?;
+///
+/// let mut writer = vcf::Writer::new(Vec::new());
+/// writer.write_header(&header)?;
+///
+/// let sorter = Sorter::default().set_max_record_count(2);
+/// let record_count = sorter.sort(&header, &mut reader, &mut writer)?;
+/// assert_eq!(record_count, 3);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sorter {
+    max_record_count: usize,
+    temp_dir: PathBuf,
+}
+
+impl Sorter {
+    /// Sets the maximum number of records held in memory.
+    ///
+    /// When this is reached, the buffered records are sorted and spilled to a temporary file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::sort::Sorter;
+    /// let sorter = Sorter::default().set_max_record_count(1024);
+    /// ```
+    pub fn set_max_record_count(mut self, max_record_count: usize) -> Self {
+        self.max_record_count = max_record_count.max(1);
+        self
+    }
+
+    /// Sets the directory used for temporary files.
+    ///
+    /// By default, this is the system temporary directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::sort::Sorter;
+    /// let sorter = Sorter::default().set_temp_dir("/tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = temp_dir.into();
+        self
+    }
+
+    /// Sorts all remaining records of a reader and writes them to a writer.
+    ///
+    /// The reader is expected to be directly after the header. The header is not written, i.e.,
+    /// the caller is responsible for writing it before sorting. Records that compare equal keep
+    /// their input order.
+    ///
+    /// Records are not parsed beyond their sort keys and are written as read, i.e., record
+    /// validation of the writer (see [`Writer::set_validation`]) does not apply.
+    ///
+    /// This returns the number of records written.
+    pub fn sort<R, W>(
+        &self,
+        header: &Header,
+        reader: &mut Reader<R>,
+        writer: &mut Writer<W>,
+    ) -> io::Result<u64>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        let mut runs = Runs::default();
+        let mut lines = Vec::new();
+        let mut record_count = 0;
+
+        loop {
+            let mut line = String::new();
+
+            if reader.read_record(&mut line)? == 0 {
+                break;
+            }
+
+            let key = sort_key(header, &line)?;
+            lines.push((key, line));
+            record_count += 1;
+
+            if lines.len() >= self.max_record_count {
+                let file = runs.create(&self.temp_dir)?;
+                write_run(file, &mut lines)?;
+            }
+        }
+
+        if runs.paths.is_empty() {
+            lines.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            for (_, line) in lines {
+                write_line(writer, &line)?;
+            }
+        } else {
+            if !lines.is_empty() {
+                let file = runs.create(&self.temp_dir)?;
+                write_run(file, &mut lines)?;
+            }
+
+            merge_runs(header, &runs.paths, writer)?;
+        }
+
+        Ok(record_count)
+    }
+}
+
+impl Default for Sorter {
+    fn default() -> Self {
+        Self {
+            max_record_count: DEFAULT_MAX_RECORD_COUNT,
+            temp_dir: env::temp_dir(),
+        }
+    }
+}
+
+/// Sorts all remaining records of a reader and writes them to a writer using the default sorter.
+///
+/// See [`Sorter::sort`].
+pub fn sort<R, W>(
+    header: &Header,
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+) -> io::Result<u64>
+where
+    R: BufRead,
+    W: Write,
+{
+    Sorter::default().sort(header, reader, writer)
+}
+
+// Temporary run files, which are removed when dropped.
+#[derive(Default)]
+struct Runs {
+    paths: Vec<PathBuf>,
+}
+
+impl Runs {
+    // Creates a new run file. Existing files are never opened, so only files created here are
+    // removed on drop.
+    fn create(&mut self, temp_dir: &Path) -> io::Result<File> {
+        loop {
+            let id = RUN_ID.fetch_add(1, Ordering::Relaxed);
+            let src = temp_dir.join(format!("noodles-vcf-sort-{}-{}.vcf", process::id(), id));
+
+            match OpenOptions::new().write(true).create_new(true).open(&src) {
+                Ok(file) => {
+                    self.paths.push(src);
+                    return Ok(file);
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for Runs {
+    fn drop(&mut self) {
+        for src in &self.paths {
+            let _ = fs::remove_file(src);
+        }
+    }
+}
+
+fn sort_key(header: &Header, line: &str) -> io::Result<SortKey> {
+    let mut fields = line.splitn(3, FIELD_DELIMITER);
+
+    let name = fields.next().unwrap_or_default();
+
+    let position = fields
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))
+        .and_then(|s| {
+            s.parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
+
+    let key = match header.contigs().get_index_of(name) {
+        Some(i) => (i, String::new(), position),
+        None => (header.contigs().len(), name.into(), position),
+    };
+
+    Ok(key)
+}
+
+fn write_run(file: File, lines: &mut Vec<(SortKey, String)>) -> io::Result<()> {
+    lines.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut writer = BufWriter::new(file);
+
+    for (_, line) in lines.drain(..) {
+        writeln!(writer, "{}", line)?;
+    }
+
+    writer.flush()
+}
+
+fn merge_runs<W>(header: &Header, srcs: &[PathBuf], writer: &mut Writer<W>) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut readers = srcs
+        .iter()
+        .map(|src| File::open(src).map(BufReader::new).map(Reader::new))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+
+    for (i, reader) in readers.iter_mut().enumerate() {
+        if let Some((key, line)) = read_keyed_line(header, reader)? {
+            heap.push(Reverse((key, i, line)));
+        }
+    }
+
+    // Ties are broken by run index, which keeps equal records in their input order.
+    while let Some(Reverse((_, i, line))) = heap.pop() {
+        write_line(writer, &line)?;
+
+        if let Some((key, line)) = read_keyed_line(header, &mut readers[i])? {
+            heap.push(Reverse((key, i, line)));
+        }
+    }
+
+    Ok(())
+}
+
+fn read_keyed_line<R>(
+    header: &Header,
+    reader: &mut Reader<R>,
+) -> io::Result<Option<(SortKey, String)>>
+where
+    R: BufRead,
+{
+    let mut line = String::new();
+
+    if reader.read_record(&mut line)? == 0 {
+        return Ok(None);
+    }
+
+    sort_key(header, &line).map(|key| Some((key, line)))
+}
+
+fn write_line<W>(writer: &mut Writer<W>, line: &str) -> io::Result<()>
+where
+    W: Write,
+{
+    let inner = writer.get_mut();
+    inner.write_all(line.as_bytes())?;
+    inner.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DATA: &str = concat!(
+        "##fileformat=VCFv4.3\n",
+        "##contig=<ID=sq0>\n",
+        "##contig=<ID=sq1>\n",
+        "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+        "sq2\t1\t.\tA\t.\t.\tPASS\t.\n",
+        "sq1\t5\t.\tA\t.\t.\tPASS\t.\n",
+        "sq0\t13\tr0\tA\t.\t.\tPASS\t.\n",
+        "sq0\t8\t.\tA\t.\t.\tPASS\t.\n",
+        "sq0\t13\tr1\tA\t.\t.\tPASS\t.\n",
+    );
+
+    static EXPECTED: &str = concat!(
+        "sq0\t8\t.\tA\t.\t.\tPASS\t.\n",
+        "sq0\t13\tr0\tA\t.\t.\tPASS\t.\n",
+        "sq0\t13\tr1\tA\t.\t.\tPASS\t.\n",
+        "sq1\t5\t.\tA\t.\t.\tPASS\t.\n",
+        "sq2\t1\t.\tA\t.\t.\tPASS\t.\n",
+    );
+
+    fn sort_data(sorter: &Sorter) -> Result<String, Box<dyn std::error::Error>> {
+        let mut reader = Reader::new(DATA.as_bytes());
+        let header: Header = reader.read_header()?.parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        let record_count = sorter.sort(&header, &mut reader, &mut writer)?;
+        assert_eq!(record_count, 5);
+
+        Ok(String::from_utf8(writer.get_ref().clone())?)
+    }
+
+    #[test]
+    fn test_sort() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(sort_data(&Sorter::default())?, EXPECTED);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_with_runs() -> Result<(), Box<dyn std::error::Error>> {
+        let sorter = Sorter::default().set_max_record_count(2);
+        assert_eq!(sort_data(&sorter)?, EXPECTED);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_writes_raw_lines() -> Result<(), Box<dyn std::error::Error>> {
+        let data = concat!(
+            "##fileformat=VCFv4.3\n",
+            "##contig=<ID=sq0>\n",
+            "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n",
+            "sq0\t13\t.\tA\t.\t5.00\tPASS\tAF=0.50\n",
+            "sq0\t8\t.\tA\t.\t.\tPASS\t.\n",
+        );
+
+        let mut reader = Reader::new(data.as_bytes());
+        let header: Header = reader.read_header()?.parse()?;
+
+        let mut writer = Writer::new(Vec::new());
+        let sorter = Sorter::default().set_max_record_count(1);
+        sorter.sort(&header, &mut reader, &mut writer)?;
+
+        let expected = concat!(
+            "sq0\t8\t.\tA\t.\t.\tPASS\t.\n",
+            "sq0\t13\t.\tA\t.\t5.00\tPASS\tAF=0.50\n",
+        );
+
+        assert_eq!(writer.get_ref(), expected.as_bytes());
+
+        Ok(())
+    }
+}
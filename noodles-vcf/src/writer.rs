@@ -1,6 +1,14 @@
+mod record;
+
 use std::io::{self, Write};
 
-use super::{Header, Record};
+use super::{
+    header::{FileFormat, Number},
+    record::{genotype, info},
+    Header, Record,
+};
+
+const LINE_FEED: u8 = b'\n';
 
 /// A VCF writer.
 ///
@@ -38,6 +46,11 @@ use super::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    file_format: Option<FileFormat>,
+    record_file_format: FileFormat,
+    validate: bool,
+    header: Option<Header>,
+    buf: Vec<u8>,
 }
 
 impl<W> Writer<W>
@@ -53,7 +66,36 @@ where
     /// let writer = vcf::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            file_format: None,
+            record_file_format: FileFormat::default(),
+            validate: false,
+            header: None,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Creates a VCF writer that targets the given file format.
+    ///
+    /// Headers are written using this file format rather than the one set in the header. See
+    /// [`Self::write_header`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::FileFormat};
+    /// let writer = vcf::Writer::with_file_format(Vec::new(), FileFormat::new(4, 2));
+    /// ```
+    pub fn with_file_format(inner: W, file_format: FileFormat) -> Self {
+        Self {
+            inner,
+            file_format: Some(file_format),
+            record_file_format: file_format,
+            validate: false,
+            header: None,
+            buf: Vec::new(),
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -69,26 +111,110 @@ where
         &self.inner
     }
 
-    /// Writes a VCF header.
+    /// Returns a mutable reference to the underlying writer.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use std::io;
     /// use noodles_vcf as vcf;
+    /// let mut writer = vcf::Writer::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Sets whether records are validated against the header before they are written.
+    ///
+    /// When enabled, [`Self::write_record`] checks that each INFO and FORMAT key in the record is
+    /// defined in the last written header and that the number of values of each field matches
+    /// its declared cardinality (`Number`). Cardinalities of `A`, `R`, and `G` are relative to the
+    /// number of alternate alleles and, for `G`, the ploidy of the sample's genotype (`GT`) or
+    /// diploid if unknown. A single missing value is always accepted.
+    ///
+    /// Validation can be enabled before or after the header is written. It is disabled by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// use noodles_vcf::{self as vcf, record::Position};
     ///
     /// let mut writer = vcf::Writer::new(Vec::new());
+    /// writer.set_validation(true);
+    ///
+    /// writer.write_header(&vcf::Header::default())?;
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::try_from(1)?)
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_info("NS=2".parse()?)
+    ///     .build()?;
+    ///
+    /// assert!(writer.write_record(&record).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_validation(&mut self, validate: bool) {
+        self.validate = validate;
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// If the writer targets a file format (see [`Self::with_file_format`]), the header is
+    /// written with that file format, and the header records are validated against it, i.e., this
+    /// returns an error if the header uses features introduced in a later version:
+    ///
+    ///   * `META` records require VCFv4.3; and
+    ///   * `INFO` and `FORMAT` records with `Number=R` require VCFv4.2.
+    ///
+    /// Otherwise, the header is written as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf::{self as vcf, header::FileFormat};
+    ///
+    /// let mut writer = vcf::Writer::with_file_format(Vec::new(), FileFormat::new(4, 2));
     ///
     /// let header = vcf::Header::default();
     /// writer.write_header(&header)?;
+    ///
+    /// assert!(writer.get_ref().starts_with(b"##fileformat=VCFv4.2\n"));
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write!(self.inner, "{}", header)
+        // The header is kept even when validation is disabled so that validation can be enabled
+        // after the header is written.
+        self.header = Some(header.clone());
+
+        self.record_file_format = self.file_format.unwrap_or_else(|| header.file_format());
+
+        match self.file_format {
+            Some(file_format) if file_format != header.file_format() => {
+                validate_header(header, file_format)?;
+                let mut header = header.clone();
+                *header.file_format_mut() = file_format;
+                write!(self.inner, "{}", header)
+            }
+            Some(file_format) => {
+                validate_header(header, file_format)?;
+                write!(self.inner, "{}", header)
+            }
+            None => write!(self.inner, "{}", header),
+        }
     }
 
     /// Writes a VCF record.
     ///
+    /// If validation is enabled (see [`Self::set_validation`]), the record is validated against
+    /// the last written header and is not written if it is invalid.
+    ///
+    /// String values in INFO and genotype fields are percent-encoded when the written file format
+    /// is VCFv4.3 or later.
+    ///
     /// # Examples
     ///
     /// ```
@@ -106,10 +232,160 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        writeln!(self.inner, "{}", record)
+        if self.validate {
+            let header = self.header.as_ref().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "a header must be written before validating records",
+                )
+            })?;
+
+            validate_record(header, record)?;
+        }
+
+        self.buf.clear();
+        record::write_record(&mut self.buf, record, self.record_file_format)?;
+        self.buf.push(LINE_FEED);
+
+        self.inner.write_all(&self.buf)
+    }
+}
+
+fn validate_record(header: &Header, record: &Record) -> io::Result<()> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    for field in record.info().fields() {
+        let key = field.key();
+
+        let info = header.infos().get(key).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("INFO key is not defined in the header: {}", key),
+            )
+        })?;
+
+        let (len, is_missing) = info_value_len(field.value());
+        let expected_len = info.number().expected_count(alternate_allele_count, 2);
+
+        if !is_valid_len(len, is_missing, expected_len) {
+            return Err(invalid_count_error("INFO", key, info.number(), len));
+        }
+    }
+
+    for key in record.format().into_iter().flat_map(|format| format.iter()) {
+        if !header.formats().contains_key(key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("FORMAT key is not defined in the header: {}", key),
+            ));
+        }
+    }
+
+    for genotype in record.genotypes() {
+        let ploidy = match genotype.genotype() {
+            Some(Ok(gt)) => gt.len(),
+            _ => 2,
+        };
+
+        for field in genotype.values() {
+            let key = field.key();
+
+            if !record.format().map(|f| f.contains(key)).unwrap_or(false) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("genotype key is not in the record FORMAT: {}", key),
+                ));
+            }
+
+            let format = header.formats().get(key).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("FORMAT key is not defined in the header: {}", key),
+                )
+            })?;
+
+            let value = match field.value() {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let number = format.number();
+
+            let (len, is_missing) = genotype::value_len(value);
+            let expected_len = number.expected_count(alternate_allele_count, ploidy);
+
+            if !is_valid_len(len, is_missing, expected_len) {
+                return Err(invalid_count_error("FORMAT", key, number, len));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn invalid_count_error<K>(field: &str, key: &K, number: Number, len: usize) -> io::Error
+where
+    K: std::fmt::Display,
+{
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!(
+            "{} field {} has an invalid number of values: expected Number={}, got {}",
+            field, key, number, len
+        ),
+    )
+}
+
+fn is_valid_len(len: usize, is_missing: bool, expected_len: Option<usize>) -> bool {
+    match expected_len {
+        Some(n) => len == n || (len == 1 && is_missing),
+        None => true,
     }
 }
 
+// Returns the number of values and whether the value is a single missing value.
+fn info_value_len(value: &info::field::Value) -> (usize, bool) {
+    use info::field::Value;
+
+    match value {
+        Value::Flag => (0, false),
+        Value::Integer(_) | Value::Float(_) | Value::Character(_) | Value::String(_) => (1, false),
+        Value::IntegerArray(values) => (values.len(), values.iter().all(Option::is_none)),
+        Value::FloatArray(values) => (values.len(), values.iter().all(Option::is_none)),
+        Value::CharacterArray(values) => (values.len(), values.iter().all(Option::is_none)),
+        Value::StringArray(values) => (values.len(), values.iter().all(Option::is_none)),
+    }
+}
+
+fn validate_header(header: &Header, file_format: FileFormat) -> io::Result<()> {
+    if file_format < FileFormat::new(4, 3) && !header.meta().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("META records are not supported in {}", file_format),
+        ));
+    }
+
+    if file_format < FileFormat::new(4, 2) {
+        let has_number_r = header
+            .infos()
+            .values()
+            .any(|info| info.number() == Number::R)
+            || header
+                .formats()
+                .values()
+                .any(|format| format.number() == Number::R);
+
+        if has_number_r {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Number=R is not supported in {}", file_format),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
@@ -134,6 +410,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_header_with_file_format() -> io::Result<()> {
+        use crate::{header::Meta, record::info::field::Key};
+
+        let mut writer = Writer::with_file_format(Vec::new(), FileFormat::new(4, 2));
+        let header = Header::builder()
+            .add_info(crate::header::Info::from(Key::TotalReadDepths))
+            .build();
+        writer.write_header(&header)?;
+        assert!(writer.get_ref().starts_with(b"##fileformat=VCFv4.2\n"));
+
+        let mut writer = Writer::with_file_format(Vec::new(), FileFormat::new(4, 1));
+        assert!(writer.write_header(&header).is_err());
+
+        let mut writer = Writer::with_file_format(Vec::new(), FileFormat::new(4, 2));
+        let header = Header::builder()
+            .add_meta(Meta::new(
+                String::from("Assay"),
+                vec![String::from("Exome")],
+            ))
+            .build();
+        assert!(writer.write_header(&header).is_err());
+
+        let mut writer = Writer::new(Vec::new());
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 1))
+            .add_meta(Meta::new(
+                String::from("Assay"),
+                vec![String::from("Exome")],
+            ))
+            .build();
+        writer.write_header(&header)?;
+        assert!(writer.get_ref().starts_with(b"##fileformat=VCFv4.1\n"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = Writer::new(Vec::new());
@@ -153,6 +466,124 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_record_with_validation() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header,
+            record::{genotype::field::Key as GenotypeKey, info::field::Key as InfoKey},
+        };
+
+        let header = Header::builder()
+            .add_info(header::Info::from(InfoKey::SamplesWithDataCount))
+            .add_info(header::Info::from(InfoKey::AlleleFrequencies))
+            .add_format(header::Format::from(GenotypeKey::Genotype))
+            .add_format(header::Format::from(GenotypeKey::GenotypeLikelihoods))
+            .add_sample_name("sample0")
+            .build();
+
+        let build_record =
+            |info: &str, genotype: &str| -> Result<Record, Box<dyn std::error::Error>> {
+                let format: Format = "GT:GL".parse()?;
+
+                let record = Record::builder()
+                    .set_chromosome("sq0".parse()?)
+                    .set_position(Position::try_from(1)?)
+                    .set_reference_bases("A".parse()?)
+                    .set_alternate_bases("C,G".parse()?)
+                    .set_info(info.parse()?)
+                    .set_format(format.clone())
+                    .add_genotype(Genotype::from_str_format(genotype, &format)?)
+                    .build()?;
+
+                Ok(record)
+            };
+
+        let mut writer = Writer::new(Vec::new());
+        writer.set_validation(true);
+
+        let record = build_record("NS=1;AF=0.25,0.5", "0/1:1,2,3,4,5,6")?;
+        assert!(writer.write_record(&record).is_err());
+
+        writer.write_header(&header)?;
+        assert!(writer.write_record(&record).is_ok());
+
+        let record = build_record("NS=1;AF=.", "0:1,2,3")?;
+        assert!(writer.write_record(&record).is_ok());
+
+        let record = build_record("NS=1;AF=0.25", "0/1:.")?;
+        assert!(writer.write_record(&record).is_err());
+
+        let record = build_record("NS=1;AF=0.25,0.5", "0/1:1,2,3")?;
+        assert!(writer.write_record(&record).is_err());
+
+        let record = build_record("NS=1;DP=8", "0/1:.")?;
+        assert!(writer.write_record(&record).is_err());
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.set_validation(true);
+
+        let record = build_record("NS=1;AF=0.25,0.5", "0/1:1,2,3,4,5,6")?;
+        assert!(writer.write_record(&record).is_ok());
+
+        let record = build_record("NS=1;DP=8", "0/1:.")?;
+        assert!(writer.write_record(&record).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_validation_and_undefined_genotype_keys(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header,
+            record::genotype::{
+                field::{Key, Value},
+                Field,
+            },
+        };
+
+        let header = Header::builder()
+            .add_format(header::Format::from(Key::Genotype))
+            .add_sample_name("sample0")
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.set_validation(true);
+        writer.write_header(&header)?;
+
+        let genotype = Genotype::try_from(vec![
+            Field::new(Key::Genotype, Some(Value::String(String::from("0/1")))),
+            Field::new(Key::ConditionalGenotypeQuality, Some(Value::Integer(13))),
+        ])?;
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .set_format("GT".parse()?)
+            .add_genotype(genotype.clone())
+            .build()?;
+
+        let e = writer.write_record(&record).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+        assert!(e.to_string().contains("GQ"));
+
+        let record = Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::try_from(1)?)
+            .set_reference_bases("A".parse()?)
+            .set_format("GT:GQ".parse()?)
+            .add_genotype(genotype)
+            .build()?;
+
+        let e = writer.write_record(&record).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+        assert!(e.to_string().contains("GQ"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_with_format() -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = Writer::new(Vec::new());
@@ -0,0 +1,333 @@
+use std::io::{self, Write};
+
+use crate::{
+    header::FileFormat,
+    record::{
+        alternate_bases::Allele,
+        genotype, info,
+        reference_bases::Base,
+        value::{encode_string, GENOTYPE_RESERVED_CHARS, INFO_RESERVED_CHARS},
+        Chromosome, Filters, Format, Genotype, Info,
+    },
+    Record,
+};
+
+const MISSING: &[u8] = b".";
+const FIELD_DELIMITER: &[u8] = b"\t";
+const ID_DELIMITER: &[u8] = b";";
+const ALLELE_DELIMITER: &[u8] = b",";
+const FILTERS_PASS: &[u8] = b"PASS";
+const INFO_DELIMITER: &[u8] = b";";
+const INFO_SEPARATOR: &[u8] = b"=";
+const FORMAT_DELIMITER: &[u8] = b":";
+const VALUE_DELIMITER: &[u8] = b",";
+
+// Serializes a record field by field. This is equivalent to its `Display` implementation but
+// writes each field directly to the buffer rather than through the formatting machinery, and
+// percent-encodes string values for VCFv4.3 and later.
+pub(super) fn write_record(
+    buf: &mut Vec<u8>,
+    record: &Record,
+    file_format: FileFormat,
+) -> io::Result<()> {
+    match record.chromosome() {
+        Chromosome::Name(name) => buf.extend_from_slice(name.as_bytes()),
+        Chromosome::Symbol(symbol) => write!(buf, "<{}>", symbol)?,
+    }
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write!(buf, "{}", i32::from(record.position()))?;
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write_ids(buf, record)?;
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write_bases(buf, record.reference_bases());
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write_alternate_bases(buf, record)?;
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write!(buf, "{}", record.quality_score())?;
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write_filters(buf, record.filters())?;
+
+    buf.extend_from_slice(FIELD_DELIMITER);
+    write_info(buf, record.info(), file_format)?;
+
+    if let Some(format) = record.format() {
+        buf.extend_from_slice(FIELD_DELIMITER);
+        write_format(buf, format)?;
+
+        for genotype in record.genotypes() {
+            buf.extend_from_slice(FIELD_DELIMITER);
+            write_genotype(buf, genotype, file_format)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_ids<W>(writer: &mut W, record: &Record) -> io::Result<()>
+where
+    W: Write,
+{
+    let ids = record.ids();
+
+    if ids.is_empty() {
+        return writer.write_all(MISSING);
+    }
+
+    for (i, id) in ids.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(ID_DELIMITER)?;
+        }
+
+        writer.write_all(id.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn write_bases(buf: &mut Vec<u8>, bases: &[Base]) {
+    buf.extend(bases.iter().map(|&base| char::from(base) as u8));
+}
+
+fn write_alternate_bases(buf: &mut Vec<u8>, record: &Record) -> io::Result<()> {
+    let alternate_bases = record.alternate_bases();
+
+    if alternate_bases.is_empty() {
+        buf.extend_from_slice(MISSING);
+        return Ok(());
+    }
+
+    for (i, allele) in alternate_bases.iter().enumerate() {
+        if i > 0 {
+            buf.extend_from_slice(ALLELE_DELIMITER);
+        }
+
+        match allele {
+            Allele::Bases(bases) => write_bases(buf, bases),
+            _ => write!(buf, "{}", allele)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_filters<W>(writer: &mut W, filters: &Filters) -> io::Result<()>
+where
+    W: Write,
+{
+    match filters {
+        Filters::Missing => writer.write_all(MISSING),
+        Filters::Pass => writer.write_all(FILTERS_PASS),
+        Filters::Fail(ids) => {
+            for (i, id) in ids.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(ID_DELIMITER)?;
+                }
+
+                writer.write_all(id.as_bytes())?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn write_info<W>(writer: &mut W, info: &Info, file_format: FileFormat) -> io::Result<()>
+where
+    W: Write,
+{
+    if info.is_empty() {
+        return writer.write_all(MISSING);
+    }
+
+    for (i, field) in info.values().enumerate() {
+        if i > 0 {
+            writer.write_all(INFO_DELIMITER)?;
+        }
+
+        writer.write_all(field.key().as_ref().as_bytes())?;
+
+        if !matches!(field.value(), info::field::Value::Flag) {
+            writer.write_all(INFO_SEPARATOR)?;
+            write_info_value(writer, field.value(), file_format)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_info_value<W>(
+    writer: &mut W,
+    value: &info::field::Value,
+    file_format: FileFormat,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    use info::field::Value;
+
+    match value {
+        Value::Integer(n) => write!(writer, "{}", n),
+        Value::Float(n) => write!(writer, "{}", n),
+        Value::Flag => Ok(()),
+        Value::Character(c) => write_char(writer, *c),
+        Value::String(s) => write_string(writer, s, file_format, INFO_RESERVED_CHARS),
+        Value::IntegerArray(values) => write_array(writer, values, |w, n| write!(w, "{}", n)),
+        Value::FloatArray(values) => write_array(writer, values, |w, n| write!(w, "{}", n)),
+        Value::CharacterArray(values) => write_array(writer, values, |w, c| write_char(w, *c)),
+        Value::StringArray(values) => write_array(writer, values, |w, s| {
+            write_string(w, s, file_format, INFO_RESERVED_CHARS)
+        }),
+    }
+}
+
+fn write_format<W>(writer: &mut W, format: &Format) -> io::Result<()>
+where
+    W: Write,
+{
+    for (i, key) in format.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(FORMAT_DELIMITER)?;
+        }
+
+        writer.write_all(key.as_ref().as_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn write_genotype<W>(writer: &mut W, genotype: &Genotype, file_format: FileFormat) -> io::Result<()>
+where
+    W: Write,
+{
+    if genotype.is_empty() {
+        return writer.write_all(MISSING);
+    }
+
+    for (i, field) in genotype.values().enumerate() {
+        if i > 0 {
+            writer.write_all(FORMAT_DELIMITER)?;
+        }
+
+        match field.value() {
+            Some(value) => write_genotype_value(writer, value, file_format)?,
+            None => writer.write_all(MISSING)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_genotype_value<W>(
+    writer: &mut W,
+    value: &genotype::field::Value,
+    file_format: FileFormat,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    use genotype::field::Value;
+
+    match value {
+        Value::Integer(n) => write!(writer, "{}", n),
+        Value::Float(n) => write!(writer, "{}", n),
+        Value::Character(c) => write_char(writer, *c),
+        Value::String(s) => write_string(writer, s, file_format, GENOTYPE_RESERVED_CHARS),
+        Value::IntegerArray(values) => write_array(writer, values, |w, n| write!(w, "{}", n)),
+        Value::FloatArray(values) => write_array(writer, values, |w, n| write!(w, "{}", n)),
+        Value::CharacterArray(values) => write_array(writer, values, |w, c| write_char(w, *c)),
+        Value::StringArray(values) => write_array(writer, values, |w, s| {
+            write_string(w, s, file_format, GENOTYPE_RESERVED_CHARS)
+        }),
+    }
+}
+
+fn write_array<W, T, F>(writer: &mut W, values: &[Option<T>], mut write_value: F) -> io::Result<()>
+where
+    W: Write,
+    F: FnMut(&mut W, &T) -> io::Result<()>,
+{
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(VALUE_DELIMITER)?;
+        }
+
+        match value {
+            Some(v) => write_value(writer, v)?,
+            None => writer.write_all(MISSING)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_char<W>(writer: &mut W, c: char) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut buf = [0; 4];
+    writer.write_all(c.encode_utf8(&mut buf).as_bytes())
+}
+
+fn write_string<W>(
+    writer: &mut W,
+    s: &str,
+    file_format: FileFormat,
+    reserved: &[char],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all(encode_string(s, file_format, reserved).as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            "sq0\t1\t.\tA\t.\t.\t.\t.",
+            "sq0\t8\tr0;r1\tACGT\tA,<DEL>,*\t13.5\tq10;s50\tNS=2;AF=0.25,0.5\tGT:GQ\t0|1:8\t./.:.",
+            "<sq1>\t13\t.\tN\tG]sq0:5]\t.\tPASS\tSVTYPE=BND",
+            "sq0\t21\t.\tAC\tA\t.\t.\tDB;NDLS=vcf\tGT:AD:FT:HQ\t0/1:3,.:PASS:15,2\t./.:.:.:.",
+        ];
+
+        for s in &records {
+            let record: Record = s.parse()?;
+
+            let mut buf = Vec::new();
+            write_record(&mut buf, &record, FileFormat::default())?;
+
+            assert_eq!(buf, record.to_string().as_bytes());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_reserved_characters() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "sq0\t1\t.\tA\t.\t.\tPASS\tNDLS=a%3Bb%25\tGT:NDLS\t0|0:d%3De;f";
+
+        let header = crate::Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
+        let record = Record::try_from_str(s, &header)?;
+
+        let mut buf = Vec::new();
+        write_record(&mut buf, &record, FileFormat::new(4, 3))?;
+        assert_eq!(buf, s.as_bytes());
+
+        let mut buf = Vec::new();
+        write_record(&mut buf, &record, FileFormat::new(4, 2))?;
+        assert_eq!(buf, record.to_string().as_bytes());
+
+        Ok(())
+    }
+}